@@ -1,6 +1,31 @@
 use lexopt::prelude::*;
+use std::path::PathBuf;
 
 use super::help;
+use crate::commands::blacklist::BlacklistAction;
+use crate::commands::clear_cache::{CacheKind, CacheSelection};
+use crate::commands::completions::Shell;
+use crate::commands::deploy::deploy_items::DEFAULT_NAME_TEMPLATE;
+use crate::commands::download_workshop_items::RetryPolicy;
+use crate::commands::item_changelog::DEFAULT_CHANGELOG_LIMIT;
+use crate::commands::item_index::{IndexAction, IndexQuery};
+use crate::commands::local_meta::LocalMetaAction;
+use crate::commands::profiles::ProfileAction;
+use crate::commands::publish::ItemUpdate;
+use crate::commands::search_cache::CacheSearch;
+use crate::commands::search_workshop::{
+    DEFAULT_ALL_PAGES_LIMIT, DateFilter, STEAM_LANGUAGES, SearchQuery, sort_option,
+};
+use crate::commands::steam_status::STEAM_STATUS_APP_ID;
+use crate::commands::unsubscribe_all::SubscriptionFilter;
+use crate::commands::watch::WatchOptions;
+use crate::commands::watch::webhook::WebhookFormat;
+use crate::core::config::Config;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::workshop::UgcItemVisibility;
+use crate::utils::parse_date::parse_date;
+use crate::utils::parse_duration::parse_duration;
 
 #[derive(Debug)]
 pub enum Command {
@@ -8,6 +33,14 @@ pub enum Command {
         app_id: u32,
         item_id: u64,
     },
+    CheckItemsDownload {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    ItemState {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
     CollectionItems {
         app_id: u32,
         item_id: u64,
@@ -15,10 +48,12 @@ pub enum Command {
     WorkshopItems {
         app_id: u32,
         item_ids: Vec<u64>,
+        with_local_meta: bool,
     },
     Subscribe {
         app_id: u32,
         item_ids: Vec<u64>,
+        with_dependencies: bool,
     },
     Unsubscribe {
         app_id: u32,
@@ -27,17 +62,111 @@ pub enum Command {
     DownloadWorkshopItem {
         app_id: u32,
         item_id: u64,
+        backend: String,
+        steamcmd_path: Option<String>,
+        retry: RetryPolicy,
     },
     SubscribedItems {
         app_id: u32,
+        with_local_meta: bool,
     },
     SearchWorkshop {
         app_id: u32,
-        query: String,
-        sort_by: String,
-        period: Option<String>,
+        query: SearchQuery,
+        /// Set by --all-pages: maximum number of items to collect across pages
+        all_pages_limit: Option<usize>,
+        date_filter: DateFilter,
+    },
+    InstalledItems {
+        app_id: u32,
+    },
+    OrphanedItems {
+        app_id: u32,
+    },
+    CleanupWorkshop {
+        app_id: u32,
+        dry_run: bool,
+    },
+    ForceRedownload {
+        app_id: u32,
+        item_id: u64,
+        retry: RetryPolicy,
+    },
+    RemoveItemFiles {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    VerifyItems {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    ItemInstallPath {
+        app_id: u32,
+        item_id: u64,
+    },
+    DeployItems {
+        app_id: u32,
+        item_ids: Vec<u64>,
+        target: String,
+        name_template: String,
+        copy: bool,
+    },
+    Undeploy {
+        target: String,
+        item_ids: Vec<u64>,
+    },
+    ItemConflicts {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    Profile {
+        app_id: u32,
+        action: ProfileAction,
+    },
+    ItemMeta {
+        app_id: u32,
+        action: LocalMetaAction,
+    },
+    Blacklist {
+        app_id: u32,
+        action: BlacklistAction,
+    },
+    Watch {
+        app_id: u32,
+        options: WatchOptions,
+    },
+    Index {
+        app_id: u32,
+        action: IndexAction,
+    },
+    SearchCache {
+        app_id: u32,
+        search: CacheSearch,
+    },
+    ItemPreviews {
+        item_ids: Vec<u64>,
+    },
+    ItemChangelog {
+        item_id: u64,
+        limit: u32,
+    },
+    ItemComments {
+        item_id: u64,
         page: u32,
-        tags: Option<String>,
+    },
+    ItemStats {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    RequiredDlc {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    DownloadPreviews {
+        app_id: u32,
+        item_ids: Vec<u64>,
+        target: String,
+        concurrency: usize,
     },
     WorkshopPath {
         app_id: u32,
@@ -46,18 +175,189 @@ pub enum Command {
         app_id: u32,
     },
     SteamLibraryPaths,
-    ClearCache,
+    /// JSON Schema of one command's output, or of all of them
+    Schema {
+        command: Option<String>,
+    },
+    /// Shell completion script
+    Completions {
+        shell: Shell,
+    },
+    SelfUpdate {
+        check_only: bool,
+    },
+    RestartSteam {
+        app_id: u32,
+    },
+    AccountInfo {
+        app_id: u32,
+    },
+    CheckOwnership {
+        app_id: u32,
+    },
+    ListDlc {
+        app_id: u32,
+    },
+    /// Whether the Steam client runs and who is logged in; `app_id` is what Steamworks is
+    /// initialized for
+    SteamStatus {
+        app_id: u32,
+        launch: bool,
+    },
+    ClearCache {
+        selection: CacheSelection,
+    },
+    CacheStats,
+    CacheInspect {
+        selection: CacheSelection,
+    },
+    CacheExport {
+        app_id: Option<u32>,
+        output: String,
+    },
+    CacheImport {
+        input: String,
+    },
     DiscoverTags {
         app_id: u32,
     },
+    CreateItem {
+        app_id: u32,
+        update: ItemUpdate,
+    },
+    UploadItem {
+        app_id: u32,
+        item_id: u64,
+        update: ItemUpdate,
+    },
+    UpdateItem {
+        app_id: u32,
+        item_id: u64,
+        update: ItemUpdate,
+    },
+    SetItemVisibility {
+        app_id: u32,
+        item_id: u64,
+        visibility: UgcItemVisibility,
+    },
+    DeleteItem {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    MyPublishedItems {
+        app_id: u32,
+        page: u32,
+    },
+    ItemsByCreator {
+        app_id: u32,
+        steam_id: u64,
+        page: u32,
+    },
+    CreatorProfile {
+        app_id: u32,
+        steam_id: u64,
+    },
+    Favorite {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    Unfavorite {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    ListFavorites {
+        app_id: u32,
+        page: u32,
+    },
+    Vote {
+        app_id: u32,
+        item_id: u64,
+        vote_up: bool,
+    },
+    GetVote {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    CreateCollection {
+        app_id: u32,
+        update: ItemUpdate,
+        item_ids: Vec<u64>,
+    },
+    CollectionAdd {
+        app_id: u32,
+        collection_id: u64,
+        item_ids: Vec<u64>,
+    },
+    CollectionRemove {
+        app_id: u32,
+        collection_id: u64,
+        item_ids: Vec<u64>,
+    },
+    DependencyGraph {
+        app_id: u32,
+        item_ids: Vec<u64>,
+    },
+    UnsubscribeAll {
+        app_id: u32,
+        filter: SubscriptionFilter,
+        dry_run: bool,
+    },
+    ExportSubscriptions {
+        app_id: u32,
+        output: String,
+    },
+    ImportSubscriptions {
+        app_id: u32,
+        input: String,
+        prune: bool,
+    },
+    SubscriptionsDiff {
+        app_id: u32,
+        collection_id: u64,
+    },
+    Sync {
+        app_id: u32,
+        input: String,
+        dry_run: bool,
+    },
+    CheckUpdates {
+        app_id: u32,
+        rss: bool,
+        quiet_if_none: bool,
+    },
+    DownloadUpdates {
+        app_id: u32,
+        concurrency: usize,
+        retry: RetryPolicy,
+    },
+    DownloadWorkshopItems {
+        app_id: u32,
+        item_ids: Vec<u64>,
+        concurrency: usize,
+        retry: RetryPolicy,
+    },
     Combined {
+        /// The global `--app-id`; blocks with their own are keyed by their app in the output
+        app_id: Option<u32>,
         commands: Vec<Command>,
     },
+    /// A `combined` block started with `--then`, run with the item IDs found in the output of
+    /// the block before it
+    Then {
+        command: Box<Command>,
+    },
+    /// Commands read from stdin, one JSON object per line; see [`parse_batch_line`]
+    Batch {
+        app_id: Option<u32>,
+    },
 }
 
-pub fn parse_args() -> Result<Command, lexopt::Error> {
+/// Parses the command line into the command's name, as typed, and the command.
+pub fn parse_args() -> Result<(String, Command), S7forgeError> {
     let mut parser = lexopt::Parser::from_env();
-    let mut app_id: Option<u32> = None;
+    // Global flags are collected first and win over the environment and the config file
+    let mut flags = Config::default();
+    let mut config_path: Option<PathBuf> = None;
 
     loop {
         match parser.next()? {
@@ -65,26 +365,236 @@ pub fn parse_args() -> Result<Command, lexopt::Error> {
                 help::print_general_help();
                 std::process::exit(0);
             }
-            Some(Long("version") | Short('v')) => {
+            Some(Long("version") | Short('V')) => {
                 help::print_version();
                 std::process::exit(0);
             }
+            Some(Long("config")) => {
+                config_path = Some(parser.value()?.into());
+            }
             Some(Long("app-id")) => {
-                app_id = Some(parser.value()?.parse()?);
+                flags.app_id = Some(parser.value()?.parse()?);
+            }
+            Some(Long("no-client")) => {
+                flags.no_client = Some(true);
+            }
+            Some(Long("anonymous")) => {
+                flags.anonymous = Some(true);
+            }
+            Some(Long("auto-launch")) => {
+                flags.auto_launch = Some(true);
+            }
+            Some(Long("progress-events")) => {
+                flags.progress_events = Some(true);
+            }
+            Some(Long("no-cache")) => {
+                flags.no_cache = Some(true);
+            }
+            Some(Long("output")) => {
+                flags.output = Some(parser.value()?.to_string_lossy().to_string());
+            }
+            Some(Long("dates")) => {
+                flags.dates = Some(parser.value()?.to_string_lossy().to_string());
+            }
+            Some(Long("sizes")) => {
+                flags.sizes = Some(parser.value()?.to_string_lossy().to_string());
+            }
+            Some(Long("description-format")) => {
+                flags.description_format = Some(parser.value()?.to_string_lossy().to_string());
+            }
+            Some(Long("web-api-key")) => {
+                flags.web_api_key = Some(parser.value()?.to_string_lossy().to_string());
+            }
+            Some(Long("envelope")) => {
+                flags.envelope = Some(true);
+            }
+            Some(Long("verbose") | Short('v')) => {
+                flags.verbose = Some(flags.verbose.unwrap_or_default().saturating_add(1));
+            }
+            Some(Long("quiet") | Short('q')) => {
+                flags.quiet = Some(true);
+            }
+            Some(Long("log-file")) => {
+                flags.log.file = Some(parser.value()?.into());
             }
             Some(Value(cmd)) => {
+                let config_path =
+                    config_path.or_else(|| std::env::var_os("S7FORGE_CONFIG").map(PathBuf::from));
+                let config = flags
+                    .or(Config::from_env()?)
+                    .or(Config::load(config_path.as_deref())?);
+                // Command parsing reads defaults such as the concurrency from the settings
+                settings::init(config.settings()?);
+
                 let cmd_str = cmd.to_string_lossy().to_string();
-                return parse_command(&cmd_str, app_id, &mut parser);
+                let command = parse_command(&cmd_str, config.app_id, &mut parser)?;
+                return Ok((cmd_str, command));
             }
             None => {
                 help::print_general_help();
-                return Err("Missing command".into());
+                return Err(S7forgeError::InvalidArgs("Missing command".into()));
             }
-            _ => return Err("Unexpected argument".into()),
+            _ => return Err(S7forgeError::InvalidArgs("Unexpected argument".into())),
         }
     }
 }
 
+// Commands a batch line may run. Left out are batch itself, help, and commands that don't
+// return on their own (watch) or replace the running executable (self-update).
+const BATCH_COMMANDS: &[&str] = &[
+    "combined",
+    "check-item-download",
+    "item-state",
+    "collection-items",
+    "search-workshop",
+    "workshop-items",
+    "subscribe",
+    "unsubscribe",
+    "download-workshop-item",
+    "create-item",
+    "upload-item",
+    "update-item",
+    "set-item-visibility",
+    "delete-item",
+    "my-published-items",
+    "items-by-creator",
+    "creator-profile",
+    "favorite",
+    "unfavorite",
+    "list-favorites",
+    "vote",
+    "get-vote",
+    "create-collection",
+    "collection-add",
+    "collection-remove",
+    "dependency-graph",
+    "unsubscribe-all",
+    "export-subscriptions",
+    "import-subscriptions",
+    "subscriptions-diff",
+    "sync",
+    "check-updates",
+    "download-updates",
+    "download-workshop-items",
+    "cleanup-workshop",
+    "force-redownload",
+    "remove-item-files",
+    "verify-items",
+    "item-install-path",
+    "deploy-items",
+    "undeploy",
+    "item-conflicts",
+    "profile",
+    "item-meta",
+    "blacklist",
+    "index",
+    "search-cache",
+    "item-previews",
+    "item-changelog",
+    "item-comments",
+    "item-stats",
+    "required-dlc",
+    "download-previews",
+    "subscribed-items",
+    "installed-items",
+    "orphaned-items",
+    "workshop-path",
+    "discover-tags",
+    "app-installation-path",
+    "clear-cache",
+    "cache-stats",
+    "cache-inspect",
+    "cache-export",
+    "cache-import",
+    "steam-library-paths",
+    "schema",
+    "completions",
+    "steam-status",
+    "check-ownership",
+    "list-dlc",
+    "account-info",
+    "restart-steam",
+];
+
+/// Parses one line of `batch` input, e.g. `{"command": "workshop-items", "item_ids": [1, 2]}`.
+/// Other keys become flags (`item_ids` is `--item-ids`): `true` is a bare flag, arrays are
+/// joined with commas, and `false` and `null` leave the flag out. Strings in `args` are passed
+/// first and unchanged, for positional values and `combined` blocks. `id` is left for the caller
+/// to echo back.
+pub fn parse_batch_line(line: &str, global_app_id: Option<u32>) -> Result<Command, S7forgeError> {
+    let invalid = |message: &str| S7forgeError::InvalidArgs(message.to_string());
+    let object = match serde_json::from_str(line) {
+        Ok(serde_json::Value::Object(object)) => object,
+        Ok(_) => return Err(invalid("Expected a JSON object")),
+        Err(e) => return Err(S7forgeError::InvalidArgs(format!("Invalid JSON: {}", e))),
+    };
+    let command = match object.get("command") {
+        Some(serde_json::Value::String(command)) => command.clone(),
+        _ => return Err(invalid("Missing \"command\"")),
+    };
+    if !BATCH_COMMANDS.contains(&command.as_str()) {
+        return Err(S7forgeError::InvalidArgs(match command.as_str() {
+            "batch" => "batch can't run inside batch".to_string(),
+            "watch" | "self-update" | "help" => format!("{} isn't available in batch", command),
+            _ => format!("Unknown command: {}", command),
+        }));
+    }
+
+    let mut args: Vec<std::ffi::OsString> = Vec::new();
+    if let Some(values) = object.get("args") {
+        let values = values
+            .as_array()
+            .ok_or_else(|| invalid("\"args\" must be an array of strings"))?;
+        for value in values {
+            let value = value
+                .as_str()
+                .ok_or_else(|| invalid("\"args\" must be an array of strings"))?;
+            args.push(value.into());
+        }
+    }
+    for (key, value) in &object {
+        if matches!(key.as_str(), "command" | "args" | "id") {
+            continue;
+        }
+        let flag = format!("--{}", key.replace('_', "-"));
+        let value = match value {
+            serde_json::Value::Null | serde_json::Value::Bool(false) => continue,
+            serde_json::Value::Bool(true) => {
+                args.push(flag.into());
+                continue;
+            }
+            serde_json::Value::Array(values) => values
+                .iter()
+                .map(|value| batch_value(key, value))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(S7forgeError::InvalidArgs)?
+                .join(","),
+            value => batch_value(key, value).map_err(S7forgeError::InvalidArgs)?,
+        };
+        args.push(flag.into());
+        args.push(value.into());
+    }
+
+    // Help prints text onto the result stream and exits, and --quiet-if-none exits too
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        return Err(invalid("help isn't available in batch"));
+    }
+    if command == "check-updates" && args.iter().any(|arg| arg == "--quiet-if-none") {
+        return Err(invalid("--quiet-if-none isn't available in batch"));
+    }
+
+    let mut parser = lexopt::Parser::from_args(args);
+    Ok(parse_command(&command, global_app_id, &mut parser)?)
+}
+
+fn batch_value(key: &str, value: &serde_json::Value) -> Result<String, String> {
+    match value {
+        serde_json::Value::String(value) => Ok(value.clone()),
+        serde_json::Value::Number(value) => Ok(value.to_string()),
+        _ => Err(format!("Unsupported value for \"{}\": {}", key, value)),
+    }
+}
+
 struct CommandBuilder {
     app_id: Option<u32>,
     item_id: Option<u64>,
@@ -94,6 +604,44 @@ struct CommandBuilder {
     period: Option<String>,
     page: u32,
     tags: Option<String>,
+    backend: String,
+    steamcmd_path: Option<String>,
+    item_update: ItemUpdate,
+    confirm: bool,
+    steam_id: Option<u64>,
+    vote_up: Option<bool>,
+    collection_id: Option<u64>,
+    with_dependencies: bool,
+    creator: Option<u64>,
+    title_contains: Option<String>,
+    dry_run: bool,
+    output: Option<String>,
+    input: Option<String>,
+    prune: bool,
+    concurrency: usize,
+    retry: RetryPolicy,
+    target: Option<String>,
+    name_template: String,
+    copy: bool,
+    note: Option<String>,
+    pinned: Option<bool>,
+    with_local_meta: bool,
+    reason: Option<String>,
+    watch: WatchOptions,
+    format: Option<String>,
+    quiet_if_none: bool,
+    all_pages: bool,
+    limit: Option<usize>,
+    match_all_tags: bool,
+    date_filter: DateFilter,
+    language: Option<String>,
+    kv_tags: Vec<(String, String)>,
+    with_kv_tags: bool,
+    cursor: Option<String>,
+    index_query: IndexQuery,
+    cache_kind: CacheKind,
+    check_only: bool,
+    launch: bool,
 }
 
 impl CommandBuilder {
@@ -107,18 +655,153 @@ impl CommandBuilder {
             period: None,
             page: 1,
             tags: None,
+            backend: "steamworks".to_string(),
+            steamcmd_path: None,
+            item_update: ItemUpdate::default(),
+            confirm: false,
+            steam_id: None,
+            vote_up: None,
+            collection_id: None,
+            with_dependencies: false,
+            creator: None,
+            title_contains: None,
+            dry_run: false,
+            output: None,
+            input: None,
+            prune: false,
+            concurrency: settings::get().concurrency,
+            retry: RetryPolicy::default(),
+            target: None,
+            name_template: DEFAULT_NAME_TEMPLATE.to_string(),
+            copy: false,
+            note: None,
+            pinned: None,
+            with_local_meta: false,
+            reason: None,
+            watch: WatchOptions::default(),
+            format: None,
+            quiet_if_none: false,
+            all_pages: false,
+            limit: None,
+            match_all_tags: false,
+            date_filter: DateFilter::default(),
+            language: None,
+            kv_tags: Vec::new(),
+            with_kv_tags: false,
+            cursor: None,
+            index_query: IndexQuery::default(),
+            cache_kind: CacheKind::All,
+            check_only: false,
+            launch: false,
+        }
+    }
+
+    /// `--tags`, or the `tags` of the app's section in the config file
+    fn tags_or_app_default(&self) -> Option<String> {
+        self.tags.clone().or_else(|| {
+            let app = settings::get().app(self.app_id?)?;
+            (!app.tags.is_empty()).then(|| app.tags.join(","))
+        })
+    }
+
+    fn search_query(&self) -> SearchQuery {
+        SearchQuery {
+            search_text: self.query.clone(),
+            sort_by: self.sort_by.clone(),
+            period: self.period.clone(),
+            page: self.page,
+            cursor: self.cursor.clone(),
+            tags: self.tags_or_app_default(),
+            match_all_tags: self.match_all_tags,
+            creator: self.creator,
+            language: self.language.clone(),
+            kv_tags: self.kv_tags.clone(),
+            with_kv_tags: self.with_kv_tags,
         }
     }
 
+    /// Comma-separated IDs or workshop URLs, or `@<file>` to read them one per line, which
+    /// keeps modlists of thousands of items clear of command line length limits.
     fn parse_item_ids(s: &str) -> Result<Vec<u64>, String> {
-        s.split(',')
-            .map(|s| {
-                s.trim()
-                    .parse()
-                    .map_err(|_| format!("Invalid item ID: {}", s))
+        let Some(path) = s.strip_prefix('@') else {
+            return s.split(',').map(Self::parse_item_id).collect();
+        };
+
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                // Everything after '#' is a comment
+                let entry = line.split('#').next().unwrap_or_default().trim();
+                (!entry.is_empty()).then(|| {
+                    Self::parse_item_id(entry)
+                        .map_err(|e| format!("{} (line {} of {})", e, index + 1, path))
+                })
             })
             .collect()
     }
+
+    // "123" or "https://steamcommunity.com/sharedfiles/filedetails/?id=123"
+    fn parse_item_id(s: &str) -> Result<u64, String> {
+        let entry = s.trim();
+        entry
+            .parse()
+            .ok()
+            .or_else(|| {
+                entry
+                    .split(['?', '&'])
+                    .find_map(|param| param.strip_prefix("id="))
+                    .and_then(|id| id.parse().ok())
+            })
+            .ok_or_else(|| format!("Invalid item ID: {}", s))
+    }
+
+    fn parse_retry_flag(
+        &mut self,
+        flag: &str,
+        p: &mut lexopt::Parser,
+    ) -> Result<bool, lexopt::Error> {
+        match flag {
+            "retries" => self.retry.retries = p.value()?.parse()?,
+            "retry-delay" => {
+                self.retry.delay = std::time::Duration::from_secs(p.value()?.parse()?);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn parse_item_update_flag(
+        &mut self,
+        flag: &str,
+        p: &mut lexopt::Parser,
+    ) -> Result<bool, lexopt::Error> {
+        let update = &mut self.item_update;
+        match flag {
+            "title" => update.title = Some(p.value()?.to_string_lossy().to_string()),
+            "description" => update.description = Some(p.value()?.to_string_lossy().to_string()),
+            "content" => update.content_path = Some(p.value()?.to_string_lossy().to_string()),
+            "preview" => update.preview_path = Some(p.value()?.to_string_lossy().to_string()),
+            "tags" => {
+                update.tags = Some(
+                    p.value()?
+                        .to_string_lossy()
+                        .split(',')
+                        .map(|tag| tag.trim().to_string())
+                        .filter(|tag| !tag.is_empty())
+                        .collect(),
+                )
+            }
+            "visibility" => {
+                update.visibility = Some(p.value()?.to_string_lossy().parse::<UgcItemVisibility>()?)
+            }
+            "change-note" => update.change_note = Some(p.value()?.to_string_lossy().to_string()),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
 }
 
 fn parse_command(
@@ -128,123 +811,1483 @@ fn parse_command(
 ) -> Result<Command, lexopt::Error> {
     match command {
         "combined" => parse_combined_command(global_app_id, parser),
+        "batch" => parse_no_arg_command(parser, global_app_id, command, |b| {
+            Ok(Command::Batch { app_id: b.app_id })
+        }),
         "check-item-download" => parse_simple_command(
             parser,
             global_app_id,
-            help::print_check_item_help,
+            command,
             |b, flag, p| {
                 match flag {
                     "app-id" => b.app_id = Some(p.value()?.parse()?),
                     "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
                     _ => return Ok(false),
                 }
                 Ok(true)
             },
             |b| {
+                let app_id = b.app_id.ok_or("Missing --app-id")?;
+                if !b.item_ids.is_empty() {
+                    return Ok(Command::CheckItemsDownload {
+                        app_id,
+                        item_ids: b.item_ids,
+                    });
+                }
                 Ok(Command::CheckItemDownload {
+                    app_id,
+                    item_id: b.item_id.ok_or("Missing --item-id or --item-ids")?,
+                })
+            },
+        ),
+        "item-state" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::ItemState {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "collection-items" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::CollectionItems {
                     app_id: b.app_id.ok_or("Missing --app-id")?,
                     item_id: b.item_id.ok_or("Missing --item-id")?,
                 })
             },
         ),
-        "collection-items" => parse_simple_command(
+        "search-workshop" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "query" => b.query = p.value()?.to_string_lossy().to_string(),
+                    "sort-by" => b.sort_by = p.value()?.to_string_lossy().to_string(),
+                    "period" => b.period = Some(p.value()?.to_string_lossy().to_string()),
+                    "page" => b.page = p.value()?.parse()?,
+                    "cursor" => b.cursor = Some(p.value()?.to_string_lossy().to_string()),
+                    "tags" => b.tags = Some(p.value()?.to_string_lossy().to_string()),
+                    "match-all-tags" => b.match_all_tags = true,
+                    "creator" => b.creator = Some(p.value()?.parse()?),
+                    "language" => {
+                        let language = p.value()?.to_string_lossy().to_lowercase();
+                        if !STEAM_LANGUAGES.contains(&language.as_str()) {
+                            return Err(format!(
+                                "Invalid language: {} (expected one of: {})",
+                                language,
+                                STEAM_LANGUAGES.join(", ")
+                            )
+                            .into());
+                        }
+                        b.language = Some(language);
+                    }
+                    "kv-tag" => {
+                        let tag = p.value()?.to_string_lossy().to_string();
+                        let (key, value) = tag
+                            .split_once('=')
+                            .filter(|(key, _)| !key.trim().is_empty())
+                            .ok_or_else(|| {
+                                format!("Invalid --kv-tag: {} (expected key=value)", tag)
+                            })?;
+                        b.kv_tags
+                            .push((key.trim().to_string(), value.trim().to_string()));
+                    }
+                    "with-kv-tags" => b.with_kv_tags = true,
+                    "created-after" => {
+                        b.date_filter.created_after =
+                            Some(parse_date(&p.value()?.to_string_lossy())?);
+                    }
+                    "updated-after" => {
+                        b.date_filter.updated_after =
+                            Some(parse_date(&p.value()?.to_string_lossy())?);
+                    }
+                    "all-pages" => b.all_pages = true,
+                    "limit" => b.limit = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                let all_pages_limit = match (b.all_pages, b.limit) {
+                    (_, Some(0)) => return Err("--limit must be at least 1".into()),
+                    (true, limit) => Some(limit.unwrap_or(DEFAULT_ALL_PAGES_LIMIT)),
+                    (false, Some(limit)) if !b.date_filter.is_empty() => Some(limit),
+                    (false, Some(_)) => {
+                        return Err("--limit requires --all-pages or a date filter".into());
+                    }
+                    (false, None) => None,
+                };
+                sort_option(&b.sort_by)?;
+                Ok(Command::SearchWorkshop {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    query: b.search_query(),
+                    all_pages_limit,
+                    date_filter: b.date_filter,
+                })
+            },
+        ),
+        "workshop-items" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    "with-local-meta" => b.with_local_meta = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::WorkshopItems {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                    with_local_meta: b.with_local_meta,
+                })
+            },
+        ),
+        "subscribe" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    "with-dependencies" => b.with_dependencies = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::Subscribe {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                    with_dependencies: b.with_dependencies,
+                })
+            },
+        ),
+        "unsubscribe" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::Unsubscribe {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "download-workshop-item" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    "backend" => b.backend = p.value()?.to_string_lossy().to_string(),
+                    "steamcmd-path" => {
+                        b.steamcmd_path = Some(p.value()?.to_string_lossy().to_string())
+                    }
+                    _ => return b.parse_retry_flag(flag, p),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.backend != "steamworks" && b.backend != "steamcmd" {
+                    return Err(format!(
+                        "Invalid backend: {} (expected steamworks or steamcmd)",
+                        b.backend
+                    )
+                    .into());
+                }
+                Ok(Command::DownloadWorkshopItem {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    backend: b.backend,
+                    steamcmd_path: b.steamcmd_path,
+                    retry: b.retry,
+                })
+            },
+        ),
+        "create-item" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| match flag {
+                "app-id" => {
+                    b.app_id = Some(p.value()?.parse()?);
+                    Ok(true)
+                }
+                _ => b.parse_item_update_flag(flag, p),
+            },
+            |b| {
+                Ok(Command::CreateItem {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    update: b.item_update,
+                })
+            },
+        ),
+        "upload-item" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| match flag {
+                "app-id" => {
+                    b.app_id = Some(p.value()?.parse()?);
+                    Ok(true)
+                }
+                "item-id" => {
+                    b.item_id = Some(p.value()?.parse()?);
+                    Ok(true)
+                }
+                _ => b.parse_item_update_flag(flag, p),
+            },
+            |b| {
+                Ok(Command::UploadItem {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    update: b.item_update,
+                })
+            },
+        ),
+        "update-item" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| match flag {
+                "app-id" => {
+                    b.app_id = Some(p.value()?.parse()?);
+                    Ok(true)
+                }
+                "item-id" => {
+                    b.item_id = Some(p.value()?.parse()?);
+                    Ok(true)
+                }
+                _ => b.parse_item_update_flag(flag, p),
+            },
+            |b| {
+                Ok(Command::UpdateItem {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    update: b.item_update,
+                })
+            },
+        ),
+        "set-item-visibility" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| match flag {
+                "app-id" => {
+                    b.app_id = Some(p.value()?.parse()?);
+                    Ok(true)
+                }
+                "item-id" => {
+                    b.item_id = Some(p.value()?.parse()?);
+                    Ok(true)
+                }
+                "visibility" => b.parse_item_update_flag(flag, p),
+                _ => Ok(false),
+            },
+            |b| {
+                Ok(Command::SetItemVisibility {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    visibility: b.item_update.visibility.ok_or("Missing --visibility")?,
+                })
+            },
+        ),
+        "delete-item" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    "confirm" => b.confirm = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if !b.confirm {
+                    return Err(
+                        "Deleting workshop items is irreversible; pass --confirm to proceed".into(),
+                    );
+                }
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::DeleteItem {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "my-published-items" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "page" => b.page = p.value()?.parse()?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::MyPublishedItems {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    page: b.page,
+                })
+            },
+        ),
+        "items-by-creator" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "steam-id" => b.steam_id = Some(p.value()?.parse()?),
+                    "page" => b.page = p.value()?.parse()?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::ItemsByCreator {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    steam_id: b.steam_id.ok_or("Missing --steam-id")?,
+                    page: b.page,
+                })
+            },
+        ),
+        "creator-profile" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "steam-id" => b.steam_id = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::CreatorProfile {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    steam_id: b.steam_id.ok_or("Missing --steam-id")?,
+                })
+            },
+        ),
+        "favorite" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::Favorite {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "unfavorite" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::Unfavorite {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "list-favorites" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "page" => b.page = p.value()?.parse()?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::ListFavorites {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    page: b.page,
+                })
+            },
+        ),
+        "vote" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    "up" | "down" => {
+                        let vote_up = flag == "up";
+                        if b.vote_up.is_some_and(|existing| existing != vote_up) {
+                            return Err("Specify only one of --up or --down".into());
+                        }
+                        b.vote_up = Some(vote_up);
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::Vote {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    vote_up: b.vote_up.ok_or("Missing --up or --down")?,
+                })
+            },
+        ),
+        "get-vote" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::GetVote {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "create-collection" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| match flag {
+                "app-id" => {
+                    b.app_id = Some(p.value()?.parse()?);
+                    Ok(true)
+                }
+                "item-ids" => {
+                    let ids_str = p.value()?.to_string_lossy().to_string();
+                    b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    Ok(true)
+                }
+                _ => b.parse_item_update_flag(flag, p),
+            },
+            |b| {
+                Ok(Command::CreateCollection {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    update: b.item_update,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "collection-add" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "collection-id" => b.collection_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::CollectionAdd {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    collection_id: b.collection_id.ok_or("Missing --collection-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "collection-remove" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "collection-id" => b.collection_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::CollectionRemove {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    collection_id: b.collection_id.ok_or("Missing --collection-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "dependency-graph" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::DependencyGraph {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "unsubscribe-all" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "tags" => b.tags = Some(p.value()?.to_string_lossy().to_string()),
+                    "creator" => b.creator = Some(p.value()?.parse()?),
+                    "title-contains" => {
+                        b.title_contains = Some(p.value()?.to_string_lossy().to_string())
+                    }
+                    "dry-run" => b.dry_run = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                let tags = b
+                    .tags
+                    .map(|tags| {
+                        tags.split(',')
+                            .map(|tag| tag.trim().to_string())
+                            .filter(|tag| !tag.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                Ok(Command::UnsubscribeAll {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    filter: SubscriptionFilter {
+                        tags,
+                        creator: b.creator,
+                        title_contains: b.title_contains,
+                    },
+                    dry_run: b.dry_run,
+                })
+            },
+        ),
+        "export-subscriptions" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "output" => b.output = Some(p.value()?.to_string_lossy().to_string()),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::ExportSubscriptions {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    output: b.output.ok_or("Missing --output")?,
+                })
+            },
+        ),
+        "import-subscriptions" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "input" => b.input = Some(p.value()?.to_string_lossy().to_string()),
+                    "prune" => b.prune = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::ImportSubscriptions {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    input: b.input.ok_or("Missing --input")?,
+                    prune: b.prune,
+                })
+            },
+        ),
+        "subscriptions-diff" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "collection-id" => b.collection_id = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::SubscriptionsDiff {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    collection_id: b.collection_id.ok_or("Missing --collection-id")?,
+                })
+            },
+        ),
+        "sync" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "input" => b.input = Some(p.value()?.to_string_lossy().to_string()),
+                    "dry-run" => b.dry_run = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::Sync {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    input: b.input.ok_or("Missing --input")?,
+                    dry_run: b.dry_run,
+                })
+            },
+        ),
+        "check-updates" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "format" => b.format = Some(p.value()?.to_string_lossy().to_string()),
+                    "quiet-if-none" => b.quiet_if_none = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                let rss = match b.format.as_deref() {
+                    None | Some("json") => false,
+                    Some("rss") => true,
+                    Some(other) => {
+                        return Err(
+                            format!("Invalid format: {} (expected json or rss)", other).into()
+                        );
+                    }
+                };
+                Ok(Command::CheckUpdates {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    rss,
+                    quiet_if_none: b.quiet_if_none,
+                })
+            },
+        ),
+        "download-updates" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "concurrency" => b.concurrency = p.value()?.parse()?,
+                    _ => return b.parse_retry_flag(flag, p),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.concurrency == 0 {
+                    return Err("--concurrency must be at least 1".into());
+                }
+                Ok(Command::DownloadUpdates {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    concurrency: b.concurrency,
+                    retry: b.retry,
+                })
+            },
+        ),
+        "download-workshop-items" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    "concurrency" => b.concurrency = p.value()?.parse()?,
+                    _ => return b.parse_retry_flag(flag, p),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                if b.concurrency == 0 {
+                    return Err("--concurrency must be at least 1".into());
+                }
+                Ok(Command::DownloadWorkshopItems {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                    concurrency: b.concurrency,
+                    retry: b.retry,
+                })
+            },
+        ),
+        "cleanup-workshop" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "dry-run" => b.dry_run = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::CleanupWorkshop {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    dry_run: b.dry_run,
+                })
+            },
+        ),
+        "force-redownload" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    _ => return b.parse_retry_flag(flag, p),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::ForceRedownload {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    retry: b.retry,
+                })
+            },
+        ),
+        "remove-item-files" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::RemoveItemFiles {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "verify-items" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::VerifyItems {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "item-install-path" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::ItemInstallPath {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                })
+            },
+        ),
+        "deploy-items" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    "target" => b.target = Some(p.value()?.to_string_lossy().to_string()),
+                    "name-template" => b.name_template = p.value()?.to_string_lossy().to_string(),
+                    "copy" => b.copy = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                let app_id = b.app_id.ok_or("Missing --app-id")?;
+                let target = b.target.or_else(|| {
+                    settings::get()
+                        .app(app_id)
+                        .and_then(|app| app.deploy_target.clone())
+                });
+                Ok(Command::DeployItems {
+                    app_id,
+                    item_ids: b.item_ids,
+                    target: target.ok_or("Missing --target")?,
+                    name_template: b.name_template,
+                    copy: b.copy,
+                })
+            },
+        ),
+        "undeploy" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    "target" => b.target = Some(p.value()?.to_string_lossy().to_string()),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::Undeploy {
+                    target: b.target.ok_or("Missing --target")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "item-conflicts" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::ItemConflicts {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "profile" => {
+            let (action, mut values, b) =
+                parse_action_command(parser, global_app_id, command, |_, _, _| Ok(false))?;
+            let app_id = b.app_id.ok_or("Missing --app-id")?;
+            let mut name = || -> Result<String, lexopt::Error> {
+                match values.len() {
+                    0 => Err(format!("Missing profile name for '{}'", action).into()),
+                    1 => Ok(values.remove(0)),
+                    _ => Err(format!("Unexpected value: {}", values[1]).into()),
+                }
+            };
+            let action = match action.as_str() {
+                "save" => ProfileAction::Save(name()?),
+                "activate" => ProfileAction::Activate(name()?),
+                "delete" => ProfileAction::Delete(name()?),
+                "list" if values.is_empty() => ProfileAction::List,
+                "list" => return Err(format!("Unexpected value: {}", values[0]).into()),
+                other => {
+                    return Err(format!(
+                        "Unknown profile action: {}. Use save, list, activate or delete",
+                        other
+                    )
+                    .into());
+                }
+            };
+            Ok(Command::Profile { app_id, action })
+        }
+        "item-meta" => {
+            let (action, values, b) =
+                parse_action_command(parser, global_app_id, command, |b, flag, p| {
+                    match flag {
+                        "item-id" | "item-ids" => {
+                            let ids_str = p.value()?.to_string_lossy().to_string();
+                            b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                        }
+                        "note" => b.note = Some(p.value()?.to_string_lossy().to_string()),
+                        "tags" => b.tags = Some(p.value()?.to_string_lossy().to_string()),
+                        "pin" => b.pinned = Some(true),
+                        "unpin" => b.pinned = Some(false),
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })?;
+            if let Some(value) = values.first() {
+                return Err(format!("Unexpected value: {}", value).into());
+            }
+            let app_id = b.app_id.ok_or("Missing --app-id")?;
+            let require_ids = |item_ids: Vec<u64>| -> Result<Vec<u64>, lexopt::Error> {
+                if item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(item_ids)
+            };
+            let action = match action.as_str() {
+                "set" => {
+                    if b.note.is_none() && b.tags.is_none() && b.pinned.is_none() {
+                        return Err("Nothing to set: use --note, --tags, --pin or --unpin".into());
+                    }
+                    LocalMetaAction::Set {
+                        item_ids: require_ids(b.item_ids)?,
+                        note: b.note,
+                        tags: b.tags.map(|tags| {
+                            tags.split(',')
+                                .map(|tag| tag.trim().to_string())
+                                .filter(|tag| !tag.is_empty())
+                                .collect()
+                        }),
+                        pinned: b.pinned,
+                    }
+                }
+                "get" => LocalMetaAction::Get {
+                    item_ids: require_ids(b.item_ids)?,
+                },
+                "remove" => LocalMetaAction::Remove {
+                    item_ids: require_ids(b.item_ids)?,
+                },
+                "list" => LocalMetaAction::List,
+                other => {
+                    return Err(format!(
+                        "Unknown item-meta action: {}. Use set, get, remove or list",
+                        other
+                    )
+                    .into());
+                }
+            };
+            Ok(Command::ItemMeta { app_id, action })
+        }
+        "blacklist" => {
+            let (action, values, b) =
+                parse_action_command(parser, global_app_id, command, |b, flag, p| {
+                    match flag {
+                        "item-id" | "item-ids" => {
+                            let ids_str = p.value()?.to_string_lossy().to_string();
+                            b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                        }
+                        "reason" => b.reason = Some(p.value()?.to_string_lossy().to_string()),
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })?;
+            if let Some(value) = values.first() {
+                return Err(format!("Unexpected value: {}", value).into());
+            }
+            let app_id = b.app_id.ok_or("Missing --app-id")?;
+            if action != "list" && b.item_ids.is_empty() {
+                return Err("Missing --item-ids".into());
+            }
+            let action = match action.as_str() {
+                "add" => BlacklistAction::Add {
+                    item_ids: b.item_ids,
+                    reason: b.reason,
+                },
+                "remove" => BlacklistAction::Remove {
+                    item_ids: b.item_ids,
+                },
+                "list" => BlacklistAction::List,
+                other => {
+                    return Err(format!(
+                        "Unknown blacklist action: {}. Use add, remove or list",
+                        other
+                    )
+                    .into());
+                }
+            };
+            Ok(Command::Blacklist { app_id, action })
+        }
+        "index" => {
+            let (action, values, b) =
+                parse_action_command(parser, global_app_id, command, |b, flag, p| {
+                    match flag {
+                        "query" => {
+                            b.index_query.text = Some(p.value()?.to_string_lossy().to_string())
+                        }
+                        "tag" => b
+                            .index_query
+                            .tags
+                            .push(p.value()?.to_string_lossy().trim().to_string()),
+                        "min-size" => b.index_query.min_size = Some(p.value()?.parse()?),
+                        "max-size" => b.index_query.max_size = Some(p.value()?.parse()?),
+                        "updated-since" => {
+                            b.index_query.updated_since =
+                                Some(parse_date(&p.value()?.to_string_lossy())?);
+                        }
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })?;
+            if let Some(value) = values.first() {
+                return Err(format!("Unexpected value: {}", value).into());
+            }
+            let app_id = b.app_id.ok_or("Missing --app-id")?;
+            let action = match action.as_str() {
+                "update" => IndexAction::Update,
+                "query" => IndexAction::Query(b.index_query),
+                other => {
+                    return Err(
+                        format!("Unknown index action: {}. Use update or query", other).into(),
+                    );
+                }
+            };
+            Ok(Command::Index { app_id, action })
+        }
+        "watch" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "interval" => {
+                        b.watch.interval = parse_duration(&p.value()?.to_string_lossy())?;
+                    }
+                    "download" => b.watch.download = true,
+                    "feed" => b.watch.feed = Some(p.value()?.into()),
+                    "webhook" => b
+                        .watch
+                        .webhooks
+                        .push(p.value()?.to_string_lossy().to_string()),
+                    "webhook-format" => {
+                        b.watch.webhook_format =
+                            WebhookFormat::parse(&p.value()?.to_string_lossy())?;
+                    }
+                    "follow-creators" => {
+                        b.watch.follow_creators = p
+                            .value()?
+                            .to_string_lossy()
+                            .split(',')
+                            .map(|id| {
+                                id.trim()
+                                    .parse()
+                                    .map_err(|_| format!("Invalid SteamID64: {}", id))
+                            })
+                            .collect::<Result<_, _>>()?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.watch.interval.is_zero() {
+                    return Err("--interval must be greater than zero".into());
+                }
+                Ok(Command::Watch {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    options: b.watch,
+                })
+            },
+        ),
+        "search-cache" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "query" => b.query = p.value()?.to_string_lossy().to_string(),
+                    "tags" => b.tags = Some(p.value()?.to_string_lossy().to_string()),
+                    "match-all-tags" => b.match_all_tags = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                let tags = b.tags_or_app_default();
+                Ok(Command::SearchCache {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                    search: CacheSearch {
+                        query: b.query,
+                        tags: tags
+                            .as_deref()
+                            .unwrap_or_default()
+                            .split(',')
+                            .map(|tag| tag.trim().to_string())
+                            .filter(|tag| !tag.is_empty())
+                            .collect(),
+                        match_all_tags: b.match_all_tags,
+                    },
+                })
+            },
+        ),
+        "item-previews" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::ItemPreviews {
+                    item_ids: b.item_ids,
+                })
+            },
+        ),
+        "item-changelog" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    "limit" => b.limit = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                let limit = b.limit.unwrap_or(DEFAULT_CHANGELOG_LIMIT);
+                if limit == 0 {
+                    return Err("--limit must be at least 1".into());
+                }
+                Ok(Command::ItemChangelog {
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    limit: limit.min(u32::MAX as usize) as u32,
+                })
+            },
+        ),
+        "item-comments" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    "page" => b.page = p.value()?.parse()?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                if b.page == 0 {
+                    return Err("--page must be at least 1".into());
+                }
+                Ok(Command::ItemComments {
+                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    page: b.page,
+                })
+            },
+        ),
+        "item-stats" => parse_simple_command(
             parser,
             global_app_id,
-            help::print_collection_items_help,
+            command,
             |b, flag, p| {
                 match flag {
                     "app-id" => b.app_id = Some(p.value()?.parse()?),
-                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
                     _ => return Ok(false),
                 }
                 Ok(true)
             },
             |b| {
-                Ok(Command::CollectionItems {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::ItemStats {
                     app_id: b.app_id.ok_or("Missing --app-id")?,
-                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                    item_ids: b.item_ids,
                 })
             },
         ),
-        "search-workshop" => parse_simple_command(
+        "required-dlc" => parse_simple_command(
             parser,
             global_app_id,
-            help::print_search_workshop_help,
+            command,
             |b, flag, p| {
                 match flag {
                     "app-id" => b.app_id = Some(p.value()?.parse()?),
-                    "query" => b.query = p.value()?.to_string_lossy().to_string(),
-                    "sort-by" => b.sort_by = p.value()?.to_string_lossy().to_string(),
-                    "period" => b.period = Some(p.value()?.to_string_lossy().to_string()),
-                    "page" => b.page = p.value()?.parse()?,
-                    "tags" => b.tags = Some(p.value()?.to_string_lossy().to_string()),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
                     _ => return Ok(false),
                 }
                 Ok(true)
             },
             |b| {
-                Ok(Command::SearchWorkshop {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                Ok(Command::RequiredDlc {
                     app_id: b.app_id.ok_or("Missing --app-id")?,
-                    query: b.query,
-                    sort_by: b.sort_by,
-                    period: b.period,
-                    page: b.page,
-                    tags: b.tags,
+                    item_ids: b.item_ids,
                 })
             },
         ),
-        "workshop-items" => parse_simple_command(
+        "download-previews" => parse_simple_command(
             parser,
             global_app_id,
-            help::print_workshop_items_help,
+            command,
             |b, flag, p| {
                 match flag {
                     "app-id" => b.app_id = Some(p.value()?.parse()?),
-                    "item-ids" => {
+                    "item-id" | "item-ids" => {
                         let ids_str = p.value()?.to_string_lossy().to_string();
                         b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
                     }
+                    "target" => b.target = Some(p.value()?.to_string_lossy().to_string()),
+                    "concurrency" => b.concurrency = p.value()?.parse()?,
                     _ => return Ok(false),
                 }
                 Ok(true)
             },
             |b| {
-                Ok(Command::WorkshopItems {
+                if b.item_ids.is_empty() {
+                    return Err("Missing --item-ids".into());
+                }
+                if b.concurrency == 0 {
+                    return Err("--concurrency must be at least 1".into());
+                }
+                Ok(Command::DownloadPreviews {
                     app_id: b.app_id.ok_or("Missing --app-id")?,
                     item_ids: b.item_ids,
+                    target: b.target.ok_or("Missing --target")?,
+                    concurrency: b.concurrency,
                 })
             },
         ),
-        "subscribe" => parse_simple_command(
+        "subscribed-items" => parse_simple_command(
             parser,
             global_app_id,
-            help::print_subscribe_help,
+            command,
             |b, flag, p| {
                 match flag {
                     "app-id" => b.app_id = Some(p.value()?.parse()?),
-                    "item-ids" => {
-                        let ids_str = p.value()?.to_string_lossy().to_string();
-                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
-                    }
+                    "with-local-meta" => b.with_local_meta = true,
                     _ => return Ok(false),
                 }
                 Ok(true)
             },
             |b| {
-                Ok(Command::Subscribe {
+                Ok(Command::SubscribedItems {
                     app_id: b.app_id.ok_or("Missing --app-id")?,
-                    item_ids: b.item_ids,
+                    with_local_meta: b.with_local_meta,
                 })
             },
         ),
-        "unsubscribe" => parse_simple_command(
+        "installed-items" => parse_no_arg_command(parser, global_app_id, command, |b| {
+            Ok(Command::InstalledItems {
+                app_id: b.app_id.ok_or("Missing --app-id")?,
+            })
+        }),
+        "orphaned-items" => parse_no_arg_command(parser, global_app_id, command, |b| {
+            Ok(Command::OrphanedItems {
+                app_id: b.app_id.ok_or("Missing --app-id")?,
+            })
+        }),
+        "workshop-path" => parse_no_arg_command(parser, global_app_id, command, |b| {
+            Ok(Command::WorkshopPath {
+                app_id: b.app_id.ok_or("Missing --app-id")?,
+            })
+        }),
+        "discover-tags" => parse_no_arg_command(parser, global_app_id, command, |b| {
+            Ok(Command::DiscoverTags {
+                app_id: b.app_id.ok_or("Missing --app-id")?,
+            })
+        }),
+        "app-installation-path" => parse_no_arg_command(parser, global_app_id, command, |b| {
+            Ok(Command::AppInstallationPath {
+                app_id: b.app_id.ok_or("Missing --app-id")?,
+            })
+        }),
+        // The global or configured --app-id is not a filter here; only an explicit one is
+        "clear-cache" => parse_simple_command(
             parser,
-            global_app_id,
-            help::print_unsubscribe_help,
+            None,
+            command,
             |b, flag, p| {
                 match flag {
+                    "what" => b.cache_kind = CacheKind::parse(&p.value()?.to_string_lossy())?,
                     "app-id" => b.app_id = Some(p.value()?.parse()?),
-                    "item-ids" => {
+                    "item-id" | "item-ids" => {
                         let ids_str = p.value()?.to_string_lossy().to_string();
                         b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
                     }
@@ -253,91 +2296,255 @@ fn parse_command(
                 Ok(true)
             },
             |b| {
-                Ok(Command::Unsubscribe {
-                    app_id: b.app_id.ok_or("Missing --app-id")?,
-                    item_ids: b.item_ids,
+                let kind = b.cache_kind;
+                if !b.item_ids.is_empty() && !matches!(kind, CacheKind::All | CacheKind::Items) {
+                    return Err("--item-ids can only be used with --what items or all".into());
+                }
+                if b.app_id.is_some()
+                    && !matches!(kind, CacheKind::All | CacheKind::Items | CacheKind::Paths)
+                {
+                    return Err("--app-id can only be used with --what items, paths or all".into());
+                }
+                Ok(Command::ClearCache {
+                    selection: CacheSelection {
+                        kind,
+                        app_id: b.app_id,
+                        item_ids: b.item_ids,
+                    },
                 })
             },
         ),
-        "download-workshop-item" => parse_simple_command(
+        "cache-stats" => {
+            if let Some(arg) = parser.next()? {
+                match arg {
+                    Long("help") | Short('h') => {
+                        help::print_command_help(command);
+                        std::process::exit(0);
+                    }
+                    _ => return Err(arg.unexpected()),
+                }
+            }
+            Ok(Command::CacheStats)
+        }
+        "cache-inspect" => parse_simple_command(
             parser,
-            global_app_id,
-            help::print_download_workshop_item_help,
+            None,
+            command,
             |b, flag, p| {
                 match flag {
+                    "what" => b.cache_kind = CacheKind::parse(&p.value()?.to_string_lossy())?,
                     "app-id" => b.app_id = Some(p.value()?.parse()?),
-                    "item-id" => b.item_id = Some(p.value()?.parse()?),
+                    "item-id" | "item-ids" => {
+                        let ids_str = p.value()?.to_string_lossy().to_string();
+                        b.item_ids = CommandBuilder::parse_item_ids(&ids_str)?;
+                    }
                     _ => return Ok(false),
                 }
                 Ok(true)
             },
             |b| {
-                Ok(Command::DownloadWorkshopItem {
-                    app_id: b.app_id.ok_or("Missing --app-id")?,
-                    item_id: b.item_id.ok_or("Missing --item-id")?,
+                if !b.item_ids.is_empty()
+                    && !matches!(b.cache_kind, CacheKind::All | CacheKind::Items)
+                {
+                    return Err("--item-ids can only be used with --what items or all".into());
+                }
+                Ok(Command::CacheInspect {
+                    selection: CacheSelection {
+                        kind: b.cache_kind,
+                        app_id: b.app_id,
+                        item_ids: b.item_ids,
+                    },
                 })
             },
         ),
-        "subscribed-items" => parse_no_arg_command(
+        "cache-export" => parse_simple_command(
             parser,
-            global_app_id,
-            help::print_subscribed_items_help,
+            None,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "output" => b.output = Some(p.value()?.to_string_lossy().to_string()),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
             |b| {
-                Ok(Command::SubscribedItems {
-                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                Ok(Command::CacheExport {
+                    app_id: b.app_id,
+                    output: b.output.ok_or("Missing --output")?,
                 })
             },
         ),
-        "workshop-path" => {
-            parse_no_arg_command(parser, global_app_id, help::print_workshop_path_help, |b| {
-                Ok(Command::WorkshopPath {
-                    app_id: b.app_id.ok_or("Missing --app-id")?,
-                })
-            })
-        }
-        "discover-tags" => {
-            parse_no_arg_command(parser, global_app_id, help::print_discover_tags_help, |b| {
-                Ok(Command::DiscoverTags {
-                    app_id: b.app_id.ok_or("Missing --app-id")?,
-                })
-            })
-        }
-        "app-installation-path" => parse_no_arg_command(
+        "cache-import" => parse_simple_command(
             parser,
-            global_app_id,
-            help::print_app_installation_path_help,
+            None,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "input" => b.input = Some(p.value()?.to_string_lossy().to_string()),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
             |b| {
-                Ok(Command::AppInstallationPath {
-                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                Ok(Command::CacheImport {
+                    input: b.input.ok_or("Missing --input")?,
                 })
             },
         ),
-        "clear-cache" => {
+        "steam-library-paths" => {
             if let Some(arg) = parser.next()? {
                 match arg {
                     Long("help") | Short('h') => {
-                        help::print_clear_cache_help();
+                        help::print_command_help(command);
                         std::process::exit(0);
                     }
                     _ => return Err(arg.unexpected()),
                 }
             }
-            Ok(Command::ClearCache)
+            Ok(Command::SteamLibraryPaths)
         }
-        "steam-library-paths" => {
-            if let Some(arg) = parser.next()? {
+        "schema" => {
+            let mut command = None;
+            while let Some(arg) = parser.next()? {
                 match arg {
                     Long("help") | Short('h') => {
-                        help::print_steam_library_paths_help();
+                        help::print_command_help("schema");
                         std::process::exit(0);
                     }
+                    Value(name) if command.is_none() => {
+                        command = Some(name.to_string_lossy().to_string());
+                    }
                     _ => return Err(arg.unexpected()),
                 }
             }
-            Ok(Command::SteamLibraryPaths)
+            Ok(Command::Schema { command })
+        }
+        "completions" => {
+            let mut shell = None;
+            while let Some(arg) = parser.next()? {
+                match arg {
+                    Long("help") | Short('h') => {
+                        help::print_command_help(command);
+                        std::process::exit(0);
+                    }
+                    Value(name) if shell.is_none() => {
+                        shell = Some(Shell::parse(&name.to_string_lossy())?);
+                    }
+                    _ => return Err(arg.unexpected()),
+                }
+            }
+            Ok(Command::Completions {
+                shell: shell.ok_or("Missing shell (bash, zsh, fish or powershell)")?,
+            })
         }
+        "steam-status" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    "launch" => b.launch = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::SteamStatus {
+                    app_id: b.app_id.unwrap_or(STEAM_STATUS_APP_ID),
+                    launch: b.launch,
+                })
+            },
+        ),
+        "check-ownership" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::CheckOwnership {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                })
+            },
+        ),
+        "list-dlc" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::ListDlc {
+                    app_id: b.app_id.ok_or("Missing --app-id")?,
+                })
+            },
+        ),
+        "account-info" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::AccountInfo {
+                    app_id: b.app_id.unwrap_or(STEAM_STATUS_APP_ID),
+                })
+            },
+        ),
+        "restart-steam" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, p| {
+                match flag {
+                    "app-id" => b.app_id = Some(p.value()?.parse()?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::RestartSteam {
+                    app_id: b.app_id.unwrap_or(STEAM_STATUS_APP_ID),
+                })
+            },
+        ),
+        "self-update" => parse_simple_command(
+            parser,
+            global_app_id,
+            command,
+            |b, flag, _| {
+                match flag {
+                    "check" => b.check_only = true,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            },
+            |b| {
+                Ok(Command::SelfUpdate {
+                    check_only: b.check_only,
+                })
+            },
+        ),
         "help" | "--help" | "-h" => {
-            help::print_main_help();
+            help::print_general_help();
             std::process::exit(0);
         }
         _ => Err(format!("Unknown command: {}", command).into()),
@@ -348,7 +2555,7 @@ fn parse_command(
 fn parse_no_arg_command<F>(
     parser: &mut lexopt::Parser,
     global_app_id: Option<u32>,
-    help_fn: fn(),
+    command: &str,
     build_fn: F,
 ) -> Result<Command, lexopt::Error>
 where
@@ -363,7 +2570,7 @@ where
                 builder.app_id = Some(val.parse()?);
             }
             Long("help") | Short('h') => {
-                help_fn();
+                help::print_command_help(command);
                 std::process::exit(0);
             }
             _ => return Err(arg.unexpected()),
@@ -373,10 +2580,52 @@ where
     build_fn(builder)
 }
 
+/// Parses `<command> <ACTION> [VALUES...] [OPTIONS]`, e.g. `profile activate modded`.
+fn parse_action_command<F>(
+    parser: &mut lexopt::Parser,
+    global_app_id: Option<u32>,
+    command: &str,
+    mut parse_arg: F,
+) -> Result<(String, Vec<String>, CommandBuilder), lexopt::Error>
+where
+    F: FnMut(&mut CommandBuilder, &str, &mut lexopt::Parser) -> Result<bool, lexopt::Error>,
+{
+    let mut builder = CommandBuilder::new(global_app_id);
+    let mut action = None;
+    let mut values = Vec::new();
+
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Long("help") | Short('h') => {
+                help::print_command_help(command);
+                std::process::exit(0);
+            }
+            Long("app-id") => builder.app_id = Some(parser.value()?.parse()?),
+            Long(flag) => {
+                let flag = flag.to_string();
+                if !parse_arg(&mut builder, &flag, parser)? {
+                    return Err(format!("Unknown option: --{}", flag).into());
+                }
+            }
+            Short(flag) => {
+                return Err(format!("Unknown option: -{}", flag).into());
+            }
+            Value(val) if action.is_none() => action = Some(val.to_string_lossy().to_string()),
+            Value(val) => values.push(val.to_string_lossy().to_string()),
+        }
+    }
+
+    let Some(action) = action else {
+        help::print_command_help(command);
+        return Err("Missing action".into());
+    };
+    Ok((action, values, builder))
+}
+
 fn parse_simple_command<F, G>(
     parser: &mut lexopt::Parser,
     global_app_id: Option<u32>,
-    help_fn: fn(),
+    command: &str,
     mut parse_arg: F,
     build_fn: G,
 ) -> Result<Command, lexopt::Error>
@@ -389,7 +2638,7 @@ where
     while let Some(arg) = parser.next()? {
         match arg {
             Long("help") | Short('h') => {
-                help_fn();
+                help::print_command_help(command);
                 std::process::exit(0);
             }
             Long(flag) => {
@@ -414,8 +2663,6 @@ fn parse_combined_command(
     global_app_id: Option<u32>,
     parser: &mut lexopt::Parser,
 ) -> Result<Command, lexopt::Error> {
-    let app_id = global_app_id.ok_or("--app-id required for combined command")?;
-
     const KNOWN_COMMANDS: &[&str] = &[
         "subscribed-items",
         "workshop-path",
@@ -424,24 +2671,47 @@ fn parse_combined_command(
         "check-item-download",
         "collection-items",
         "discover-tags",
+        "subscribe",
+        "unsubscribe",
+        "download-workshop-item",
     ];
 
-    let mut command_blocks: Vec<(String, Vec<std::ffi::OsString>)> = Vec::new();
-    let mut current_command: Option<String> = None;
+    // Commands that take item IDs, so they can be fed by the block before them
+    const THEN_COMMANDS: &[&str] = &["workshop-items", "subscribe", "unsubscribe"];
+
+    // (command, arguments, started with --then)
+    let mut command_blocks: Vec<(String, Vec<std::ffi::OsString>, bool)> = Vec::new();
+    let mut current_command: Option<(String, bool)> = None;
     let mut current_args: Vec<std::ffi::OsString> = Vec::new();
 
     loop {
         match parser.next()? {
             Some(Long("help") | Short('h')) => {
-                help::print_combined_help();
+                help::print_command_help("combined");
                 std::process::exit(0);
             }
+            Some(Long("then")) => {
+                let target = parser.value()?.string()?;
+                if !THEN_COMMANDS.contains(&target.as_str()) {
+                    return Err(format!(
+                        "Invalid --then command: {} (expected {})",
+                        target,
+                        THEN_COMMANDS.join(" or ")
+                    )
+                    .into());
+                }
+                let (cmd, piped) = current_command
+                    .take()
+                    .ok_or("--then must follow a subcommand")?;
+                command_blocks.push((cmd, std::mem::take(&mut current_args), piped));
+                current_command = Some((target, true));
+            }
             Some(Long(flag)) => {
                 if KNOWN_COMMANDS.contains(&flag) {
-                    if let Some(cmd) = current_command.take() {
-                        command_blocks.push((cmd, std::mem::take(&mut current_args)));
+                    if let Some((cmd, piped)) = current_command.take() {
+                        command_blocks.push((cmd, std::mem::take(&mut current_args), piped));
                     }
-                    current_command = Some(flag.to_string());
+                    current_command = Some((flag.to_string(), false));
                 } else {
                     current_args.push(format!("--{}", flag).into());
                 }
@@ -452,20 +2722,66 @@ fn parse_combined_command(
         }
     }
 
-    if let Some(cmd) = current_command {
-        command_blocks.push((cmd, current_args));
+    if let Some((cmd, piped)) = current_command {
+        command_blocks.push((cmd, current_args, piped));
     }
 
     if command_blocks.is_empty() {
         return Err("No subcommands specified for combined".into());
     }
 
-    let commands = command_blocks
-        .into_iter()
-        .map(|(cmd_name, args)| parse_combined_subcommand(&cmd_name, app_id, args))
-        .collect::<Result<Vec<_>, _>>()?;
+    let mut commands = Vec::with_capacity(command_blocks.len());
+    let mut previous_app_id = None;
+    for (cmd_name, mut args, piped) in command_blocks {
+        // A --then block works on the items of the block it follows, so it shares its app
+        let app_id = take_block_app_id(&mut args)?
+            .or(previous_app_id.filter(|_| piped))
+            .or(global_app_id)
+            .ok_or_else(|| {
+                format!(
+                    "--app-id required for {} in combined (give it globally or after --{})",
+                    cmd_name, cmd_name
+                )
+            })?;
+        previous_app_id = Some(app_id);
+
+        if piped {
+            if args.iter().any(|arg| arg == "--item-ids") {
+                return Err(format!(
+                    "--then {} takes its item IDs from the previous block",
+                    cmd_name
+                )
+                .into());
+            }
+            let command = parse_combined_subcommand(&cmd_name, app_id, args)?;
+            commands.push(Command::Then {
+                command: Box::new(command),
+            });
+        } else {
+            commands.push(parse_combined_subcommand(&cmd_name, app_id, args)?);
+        }
+    }
+
+    Ok(Command::Combined {
+        app_id: global_app_id,
+        commands,
+    })
+}
 
-    Ok(Command::Combined { commands })
+/// Removes a block's own `--app-id <APP_ID>` from its arguments.
+fn take_block_app_id(args: &mut Vec<std::ffi::OsString>) -> Result<Option<u32>, lexopt::Error> {
+    let Some(index) = args.iter().position(|arg| arg == "--app-id") else {
+        return Ok(None);
+    };
+    args.remove(index);
+    if index >= args.len() {
+        return Err("Missing value for --app-id".into());
+    }
+    let value = args.remove(index).to_string_lossy().into_owned();
+    value
+        .parse()
+        .map(Some)
+        .map_err(|_| format!("Invalid app-id: {}", value).into())
 }
 
 fn parse_combined_subcommand(
@@ -477,7 +2793,10 @@ fn parse_combined_subcommand(
     let mut builder = CommandBuilder::new(Some(app_id));
 
     match command {
-        "subscribed-items" => Ok(Command::SubscribedItems { app_id }),
+        "subscribed-items" => Ok(Command::SubscribedItems {
+            app_id,
+            with_local_meta: false,
+        }),
         "workshop-path" => Ok(Command::WorkshopPath { app_id }),
         "discover-tags" => Ok(Command::DiscoverTags { app_id }),
         "search-workshop" => {
@@ -491,6 +2810,7 @@ fn parse_combined_subcommand(
                         ("--sort-by", |b, v| b.sort_by = v),
                         ("--period", |b, v| b.period = Some(v)),
                         ("--tags", |b, v| b.tags = Some(v)),
+                        ("--cursor", |b, v| b.cursor = Some(v)),
                     ],
                     &[("--page", |b, v| {
                         b.page = v.parse().map_err(|_| "Invalid page")?;
@@ -500,11 +2820,9 @@ fn parse_combined_subcommand(
             }
             Ok(Command::SearchWorkshop {
                 app_id,
-                query: builder.query,
-                sort_by: builder.sort_by,
-                period: builder.period,
-                page: builder.page,
-                tags: builder.tags,
+                query: builder.search_query(),
+                all_pages_limit: None,
+                date_filter: DateFilter::default(),
             })
         }
         "workshop-items" => {
@@ -520,6 +2838,75 @@ fn parse_combined_subcommand(
             Ok(Command::WorkshopItems {
                 app_id,
                 item_ids: builder.item_ids,
+                with_local_meta: false,
+            })
+        }
+        "subscribe" | "unsubscribe" => {
+            while let Some(arg) = iter.next() {
+                match arg.to_string_lossy().as_ref() {
+                    "--item-ids" => {
+                        let val = iter.next().ok_or("Missing value for --item-ids")?;
+                        builder.item_ids = CommandBuilder::parse_item_ids(&val.to_string_lossy())?;
+                    }
+                    "--with-dependencies" if command == "subscribe" => {
+                        builder.with_dependencies = true;
+                    }
+                    other => return Err(format!("Unexpected argument: {}", other).into()),
+                }
+            }
+            if command == "subscribe" {
+                Ok(Command::Subscribe {
+                    app_id,
+                    item_ids: builder.item_ids,
+                    with_dependencies: builder.with_dependencies,
+                })
+            } else {
+                Ok(Command::Unsubscribe {
+                    app_id,
+                    item_ids: builder.item_ids,
+                })
+            }
+        }
+        "download-workshop-item" => {
+            while let Some(arg) = iter.next() {
+                parse_arg_from_os(
+                    &mut builder,
+                    &arg,
+                    &mut iter,
+                    &[
+                        ("--backend", |b, v| b.backend = v),
+                        ("--steamcmd-path", |b, v| b.steamcmd_path = Some(v)),
+                    ],
+                    &[
+                        ("--item-id", |b, v| {
+                            b.item_id = Some(v.parse().map_err(|_| "Invalid item-id")?);
+                            Ok(())
+                        }),
+                        ("--retries", |b, v| {
+                            b.retry.retries = v.parse().map_err(|_| "Invalid retries")?;
+                            Ok(())
+                        }),
+                        ("--retry-delay", |b, v| {
+                            let secs = v.parse().map_err(|_| "Invalid retry-delay")?;
+                            b.retry.delay = std::time::Duration::from_secs(secs);
+                            Ok(())
+                        }),
+                    ],
+                )?;
+            }
+            if builder.backend != "steamworks" && builder.backend != "steamcmd" {
+                return Err(format!(
+                    "Invalid backend: {} (expected steamworks or steamcmd)",
+                    builder.backend
+                )
+                .into());
+            }
+            Ok(Command::DownloadWorkshopItem {
+                app_id,
+                item_id: builder.item_id.ok_or("Missing --item-id")?,
+                backend: builder.backend,
+                steamcmd_path: builder.steamcmd_path,
+                retry: builder.retry,
             })
         }
         "check-item-download" | "collection-items" => {
@@ -0,0 +1,2114 @@
+//! Names, options and help text of every command. The general help, each command's
+//! `--help` and `completions` are all generated from this table; the parser in `cli.rs` stays
+//! the authority on what is accepted.
+
+use crate::commands::clear_cache::CacheKind;
+use crate::commands::search_workshop::{PERIODS, SORT_OPTIONS, STEAM_LANGUAGES};
+use crate::core::workshop::UgcItemVisibility;
+
+/// What follows an option on the command line.
+#[derive(Debug, Clone, Copy)]
+pub enum ValueHint {
+    /// A flag without a value
+    None,
+    Any,
+    Path,
+    /// One of a fixed set of values, listed by the function
+    Choices(fn() -> Vec<&'static str>),
+}
+
+#[derive(Debug)]
+pub struct OptionSpec {
+    /// The long form, e.g. `--app-id`
+    pub name: &'static str,
+    /// The short form, e.g. `-v`
+    pub short: Option<&'static str>,
+    pub value: ValueHint,
+    /// Name of the value in help, e.g. `APP_ID`; empty for flags
+    pub placeholder: &'static str,
+    /// Description in help; further lines are separated by `\n`
+    pub help: &'static str,
+}
+
+/// A block of help text such as NOTES, printed after the options.
+#[derive(Debug)]
+pub struct Section {
+    pub title: &'static str,
+    pub lines: &'static [&'static str],
+}
+
+#[derive(Debug)]
+pub struct CommandSpec {
+    pub name: &'static str,
+    /// One line for the command list
+    pub about: &'static str,
+    /// Opening of the command's help: a summary line, optionally followed by paragraphs
+    pub description: &'static [&'static str],
+    pub usage: &'static [&'static str],
+    /// Positional actions, e.g. `save` and `list` of `profile`
+    pub actions: &'static [&'static str],
+    pub options: &'static [OptionSpec],
+    pub sections: &'static [Section],
+    pub examples: &'static [&'static str],
+}
+
+const fn flag(name: &'static str, help: &'static str) -> OptionSpec {
+    OptionSpec {
+        name,
+        short: None,
+        value: ValueHint::None,
+        placeholder: "",
+        help,
+    }
+}
+
+const fn value(name: &'static str, placeholder: &'static str, help: &'static str) -> OptionSpec {
+    OptionSpec {
+        name,
+        short: None,
+        value: ValueHint::Any,
+        placeholder,
+        help,
+    }
+}
+
+const fn path(name: &'static str, placeholder: &'static str, help: &'static str) -> OptionSpec {
+    OptionSpec {
+        name,
+        short: None,
+        value: ValueHint::Path,
+        placeholder,
+        help,
+    }
+}
+
+const fn choice(
+    name: &'static str,
+    placeholder: &'static str,
+    choices: fn() -> Vec<&'static str>,
+    help: &'static str,
+) -> OptionSpec {
+    OptionSpec {
+        name,
+        short: None,
+        value: ValueHint::Choices(choices),
+        placeholder,
+        help,
+    }
+}
+
+const fn short(short: &'static str, option: OptionSpec) -> OptionSpec {
+    OptionSpec {
+        short: Some(short),
+        ..option
+    }
+}
+
+fn sorts() -> Vec<&'static str> {
+    SORT_OPTIONS.iter().map(|sort| sort.name).collect()
+}
+
+fn periods() -> Vec<&'static str> {
+    PERIODS.iter().map(|(name, _)| *name).collect()
+}
+
+fn visibilities() -> Vec<&'static str> {
+    UgcItemVisibility::NAMES.to_vec()
+}
+
+fn caches() -> Vec<&'static str> {
+    CacheKind::KINDS
+        .into_iter()
+        .chain([CacheKind::All])
+        .map(CacheKind::name)
+        .collect()
+}
+
+fn languages() -> Vec<&'static str> {
+    STEAM_LANGUAGES.to_vec()
+}
+
+const APP_ID: OptionSpec = value("--app-id", "APP_ID", "Steam App ID of the game");
+// Most commands taking --item-ids also take a single --item-id
+const ITEM_ID_ALIAS: OptionSpec = value(
+    "--item-id",
+    "ITEM_ID",
+    "A single item ID instead of --item-ids",
+);
+
+/// `-h, --help`, taken by every command
+pub const HELP: OptionSpec = short("-h", flag("--help", "Print help"));
+
+/// Options accepted before the command
+pub const GLOBAL_OPTIONS: &[OptionSpec] = &[
+    value(
+        "--app-id",
+        "APP_ID",
+        "Steam App ID (required for most commands) [env: S7FORGE_APP_ID]",
+    ),
+    path(
+        "--config",
+        "PATH",
+        "Config file [default: <config dir>/s7forge/s7forge.toml] [env: S7FORGE_CONFIG]",
+    ),
+    flag(
+        "--no-client",
+        "Skip the Steam client and use the Steam Web API",
+    ),
+    flag(
+        "--anonymous",
+        "Never initialize Steamworks; metadata over HTTPS only",
+    ),
+    flag(
+        "--auto-launch",
+        "Start Steam when a command needs it and it isn't running [env: S7FORGE_AUTO_LAUNCH]",
+    ),
+    flag("--progress-events", "Emit NDJSON progress events on stderr"),
+    choice(
+        "--output",
+        "FORMAT",
+        || vec!["json", "table"],
+        "json (default) or table: aligned columns, with progress bars at a terminal [env: S7FORGE_OUTPUT]",
+    ),
+    flag(
+        "--no-cache",
+        "Ignore cached data for this run; fresh results are still cached [env: S7FORGE_NO_CACHE]",
+    ),
+    choice(
+        "--dates",
+        "FORMAT",
+        || vec!["epoch", "iso", "relative"],
+        "Timestamps as epoch (default), iso (UTC) or relative (\"3 days ago\")",
+    ),
+    choice(
+        "--sizes",
+        "FORMAT",
+        || vec!["bytes", "human", "both"],
+        "Byte counts as bytes (default), human (\"1.4 GiB\") or both",
+    ),
+    choice(
+        "--description-format",
+        "FORMAT",
+        || vec!["bbcode", "markdown", "plain", "html"],
+        "Descriptions as bbcode (default), markdown, plain or html",
+    ),
+    flag(
+        "--envelope",
+        "Wrap output in {\"schema_version\", \"command\", \"data\"} [env: S7FORGE_ENVELOPE]",
+    ),
+    short(
+        "-v",
+        flag(
+            "--verbose",
+            "Log Steam callbacks, cache hits and phase timings to stderr; -vv for more [env: S7FORGE_VERBOSE]",
+        ),
+    ),
+    short(
+        "-q",
+        flag(
+            "--quiet",
+            "Nothing on stderr but the error object [env: S7FORGE_QUIET]",
+        ),
+    ),
+    path(
+        "--log-file",
+        "PATH",
+        "Also write JSON logs to PATH, rotated daily [env: S7FORGE_LOG_FILE]",
+    ),
+    value(
+        "--web-api-key",
+        "KEY",
+        "Steam Web API key for searches and creator names [env: S7FORGE_WEB_API_KEY, STEAM_WEB_API_KEY]",
+    ),
+    HELP,
+    short("-V", flag("--version", "Print version")),
+];
+
+/// Every command, in the order of the general help
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "combined",
+        about: "Execute multiple commands at once",
+        description: &["Execute multiple commands in one invocation"],
+        usage: &["s7forge [--app-id <APP_ID>] combined [SUBCOMMANDS]"],
+        actions: &[],
+        options: &[],
+        sections: &[Section {
+            title: "NOTES",
+            lines: &[
+                "- Global --app-id is used for all commands unless overridden",
+                "- A subcommand's own --app-id overrides it; subscribed-items and",
+                "  workshop-path results for another app are keyed <command>-<APP_ID>",
+                "- --then <COMMAND> starts a block that runs on the item IDs found in the",
+                "  previous block's output; COMMAND is workshop-items, subscribe or",
+                "  unsubscribe. It is skipped when the previous block failed",
+                "- subscribe, unsubscribe and download-workshop-item blocks run in order,",
+                "  each keyed <command>-<INDEX>, so later blocks see their effects",
+                "- Each subcommand can have its own specific options",
+                "- Options are specified after the subcommand flag",
+            ],
+        }],
+        examples: &[
+            "# Simple: two commands without extra options",
+            "s7forge --app-id 1142710 combined --subscribed-items --workshop-path",
+            "",
+            "# Advanced: mix commands with different options",
+            "s7forge --app-id 548430 combined --workshop-path --search-workshop --query \"tank\" --page 1",
+            "",
+            "# Multiple parameterized commands",
+            "s7forge --app-id 1142710 combined --workshop-items --item-ids 123,456 --discover-tags",
+            "",
+            "# Search, then fetch full details of the results",
+            "s7forge --app-id 548430 combined --search-workshop --query tank --then workshop-items",
+            "",
+            "# Subscribe to every item of a collection",
+            "s7forge --app-id 548430 combined --collection-items --item-id 123 --then subscribe",
+            "",
+            "# Subscribe, wait for the download, then report where it went",
+            "s7forge --app-id 548430 combined --subscribe --item-ids 123 --download-workshop-item --item-id 123 --workshop-path",
+            "",
+            "# Several games in one launch",
+            "s7forge combined --subscribed-items --app-id 548430 --subscribed-items --app-id 1142710",
+        ],
+    },
+    CommandSpec {
+        name: "batch",
+        about: "Run JSON commands read from stdin, one per line",
+        description: &[
+            "Run commands read from stdin, one JSON object per line",
+            "",
+            "Each line names a command and its options; one result object is printed per line,",
+            "as {\"result\": ...} or {\"error\": \"...\"}, with the line's \"id\" copied over.",
+            "All commands share one Steam client and the in-memory caches.",
+        ],
+        usage: &["s7forge [--app-id <APP_ID>] batch < commands.jsonl"],
+        actions: &[],
+        options: &[value(
+            "--app-id",
+            "APP_ID",
+            "Steam App ID used by lines without their own app_id",
+        )],
+        sections: &[Section {
+            title: "NOTES",
+            lines: &[
+                "- Keys are the command's options with _ or -: \"item_ids\" is --item-ids",
+                "- true adds a bare flag, arrays are joined with commas, false and null are ignored",
+                "- \"args\" holds raw arguments passed before the options, e.g. a profile action",
+                "  or combined blocks",
+                "- A line that fails only produces an error object; the next line still runs",
+                "- watch, self-update, --help and --quiet-if-none are rejected, since they don't",
+                "  return a result for the line",
+            ],
+        }],
+        examples: &[
+            "printf '%s\\n' \\",
+            "  '{\"id\": 1, \"command\": \"workshop-items\", \"item_ids\": [123, 456]}' \\",
+            "  '{\"id\": 2, \"command\": \"check-item-download\", \"item_id\": 123}' \\",
+            "  | s7forge --app-id 548430 batch",
+            "",
+            "# Positional values and combined blocks go in args",
+            "echo '{\"command\": \"profile\", \"args\": [\"activate\", \"modded\"], \"app_id\": 548430}' | s7forge batch",
+        ],
+    },
+    CommandSpec {
+        name: "search-workshop",
+        about: "Search for workshop items",
+        description: &[
+            "Search workshop content by text query with flexible sorting options",
+            "",
+            "Returns { total_results, page, page_size, has_more, items }. With --all-pages,",
+            "page is the last page fetched and items holds every page combined.",
+            "",
+            "Date filters also walk pages. They stop as soon as results get too old when sorted",
+            "by recent (--created-after) or recently-updated (--updated-after); with other sort",
+            "orders at most 20 pages are scanned.",
+            "",
+            "Steam stops serving pages past a fixed depth. To walk a whole catalogue, start with",
+            "--cursor \"*\" and pass each returned next_cursor back until it is missing. Cursor",
+            "searches always use the Web API, so they need a Web API key.",
+        ],
+        usage: &["s7forge search-workshop --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--query",
+                "QUERY",
+                "Text to search for (optional for most sort methods)",
+            ),
+            choice(
+                "--sort-by",
+                "SORT",
+                sorts,
+                "Sort order, see SORT ORDERS below [default: relevance]",
+            ),
+            choice(
+                "--period",
+                "PERIOD",
+                periods,
+                "Trend window: today, one-week, three-months, six-months, one-year or a number of days (trend sorts only)",
+            ),
+            value("--page", "PAGE", "Page number for pagination [default: 1]"),
+            value(
+                "--cursor",
+                "CURSOR",
+                "Page by cursor instead of number; \"*\" for the first page",
+            ),
+            value(
+                "--tags",
+                "TAGS",
+                "Filter by tags, comma-separated (e.g., 'mod,weapon') [default: tags of [apps.<APP_ID>]]",
+            ),
+            flag(
+                "--match-all-tags",
+                "Only return items carrying every tag in --tags (default: any)",
+            ),
+            value(
+                "--creator",
+                "STEAM_ID",
+                "Only items published by this SteamID64 (needs the Steam client)\nsorted by recent, recently-updated, popular or most-subscribed",
+            ),
+            choice(
+                "--language",
+                "LANGUAGE",
+                languages,
+                "Steam language for titles and descriptions (e.g. german)",
+            ),
+            value(
+                "--kv-tag",
+                "KEY=VALUE",
+                "Require a key/value tag, e.g. version=1.2 (repeatable)",
+            ),
+            flag(
+                "--with-kv-tags",
+                "Include each item's key/value tags as key_value_tags",
+            ),
+            value(
+                "--created-after",
+                "DATE",
+                "Only items created since DATE (2024-05-01 or an age like 30d)",
+            ),
+            value(
+                "--updated-after",
+                "DATE",
+                "Only items updated since DATE (2024-05-01 or an age like 30d)",
+            ),
+            flag(
+                "--all-pages",
+                "Fetch consecutive pages (from --page) into one deduplicated array",
+            ),
+            value(
+                "--limit",
+                "N",
+                "Stop --all-pages or date filters after N items [default: 500]",
+            ),
+        ],
+        sections: &[Section {
+            title: "SORT ORDERS",
+            lines: &[
+                "relevance, recent, recently-updated, most-subscribed, accepted",
+                "popular, playtime-trend, average-playtime-trend, playtime-sessions-trend (use --period)",
+                "top-rated, most-votes-up, fewest-votes, not-yet-rated, most-reported",
+                "total-playtime, lifetime-average-playtime, lifetime-playtime-sessions",
+                "friends-favorites, friends-created, followed-creators (Steam client only)",
+            ],
+        }],
+        examples: &[
+            "s7forge search-workshop --app-id 548430 --query \"tank\" --sort-by relevance",
+            "s7forge search-workshop --app-id 548430 --sort-by recent --tags \"mod,weapon\"",
+            "s7forge search-workshop --app-id 548430 --tags \"Map,Multiplayer\" --match-all-tags",
+            "s7forge search-workshop --app-id 548430 --creator 76561198000000000 --sort-by recent",
+            "s7forge search-workshop --app-id 548430 --sort-by popular --period one-week",
+            "s7forge search-workshop --app-id 548430 --query \"panzer\" --language german",
+            "s7forge search-workshop --app-id 548430 --kv-tag version=1.2 --with-kv-tags",
+            "s7forge search-workshop --app-id 548430 --sort-by recent --all-pages --limit 200",
+            "s7forge search-workshop --app-id 548430 --sort-by recent --cursor \"*\"",
+        ],
+    },
+    CommandSpec {
+        name: "search-cache",
+        about: "Search locally cached items without contacting Steam",
+        description: &[
+            "Search workshop items already in the local item cache, without contacting Steam",
+            "",
+            "Only items fetched earlier (workshop-items, subscribed-items, ...) can match. Cached",
+            "entries are searched regardless of age, so details may be out of date. Results are",
+            "sorted by last update, newest first.",
+        ],
+        usage: &["s7forge search-cache --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--query",
+                "QUERY",
+                "Case-insensitive text to find in titles",
+            ),
+            value(
+                "--tags",
+                "TAGS",
+                "Filter by tags, comma-separated (e.g., 'map,weapon')",
+            ),
+            flag(
+                "--match-all-tags",
+                "Only return items carrying every tag in --tags (default: any)",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge search-cache --app-id 548430 --query \"tank\"",
+            "s7forge search-cache --app-id 548430 --tags \"Map,Multiplayer\" --match-all-tags",
+        ],
+    },
+    CommandSpec {
+        name: "discover-tags",
+        about: "Discover available workshop tags for a game",
+        description: &["Discover all available workshop tags for a game"],
+        usage: &["s7forge discover-tags --app-id <APP_ID>"],
+        actions: &[],
+        options: &[APP_ID],
+        sections: &[],
+        examples: &["s7forge discover-tags --app-id 548430"],
+    },
+    CommandSpec {
+        name: "workshop-items",
+        about: "Get details about workshop items",
+        description: &["Get detailed information about workshop items"],
+        usage: &["s7forge workshop-items --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs or URLs (comma-separated), or @<FILE>",
+            ),
+            flag(
+                "--with-local-meta",
+                "Include your local notes, tags and pin (see item-meta)",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge workshop-items --app-id 548430 --item-ids 123,456,789",
+            "s7forge workshop-items --app-id 548430 --item-ids @mods.txt",
+        ],
+    },
+    CommandSpec {
+        name: "collection-items",
+        about: "Get items from a workshop collection",
+        description: &[
+            "Get a workshop collection and full details of its items, in collection order",
+        ],
+        usage: &["s7forge collection-items --app-id <APP_ID> --item-id <ITEM_ID>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--item-id", "ITEM_ID", "Collection ID to get items from"),
+        ],
+        sections: &[],
+        examples: &["s7forge collection-items --app-id 548430 --item-id 987654321"],
+    },
+    CommandSpec {
+        name: "subscribed-items",
+        about: "List all items you're subscribed to",
+        description: &["List all items you're subscribed to for a game"],
+        usage: &["s7forge subscribed-items --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            flag(
+                "--with-local-meta",
+                "Include your local notes, tags and pin (see item-meta)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge subscribed-items --app-id 548430"],
+    },
+    CommandSpec {
+        name: "check-item-download",
+        about: "Check if a workshop item is downloaded",
+        description: &["Check download status of one or more workshop items"],
+        usage: &[
+            "s7forge check-item-download --app-id <APP_ID> --item-id <ITEM_ID>",
+            "s7forge check-item-download --app-id <APP_ID> --item-ids <ITEM_IDS>",
+        ],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-id",
+                "ITEM_ID",
+                "Workshop item ID to check download status for",
+            ),
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Several item IDs (comma-separated); returns a map by ID",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge check-item-download --app-id 548430 --item-id 123456789",
+            "s7forge check-item-download --app-id 548430 --item-ids 123,456,789",
+        ],
+    },
+    CommandSpec {
+        name: "item-state",
+        about: "Show Steamworks item state flags of workshop items",
+        description: &["Show the raw Steamworks item state flags of workshop items"],
+        usage: &["s7forge item-state --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs to look up (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge item-state --app-id 548430 --item-ids 123,456,789"],
+    },
+    CommandSpec {
+        name: "subscribe",
+        about: "Subscribe to workshop items",
+        description: &["Subscribe to workshop items"],
+        usage: &["s7forge subscribe --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Item IDs or URLs (comma-separated), or @<FILE>",
+            ),
+            flag(
+                "--with-dependencies",
+                "Also subscribe to required items, recursively",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge subscribe --app-id 548430 --item-ids 123,456,789",
+            "s7forge subscribe --app-id 548430 --item-ids 123 --with-dependencies",
+            "s7forge subscribe --app-id 548430 --item-ids @mods.txt",
+        ],
+    },
+    CommandSpec {
+        name: "unsubscribe",
+        about: "Unsubscribe from workshop items",
+        description: &["Unsubscribe from workshop items"],
+        usage: &["s7forge unsubscribe --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Item IDs or URLs (comma-separated), or @<FILE>",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge unsubscribe --app-id 548430 --item-ids 123,456,789",
+            "s7forge unsubscribe --app-id 548430 --item-ids @mods.txt",
+        ],
+    },
+    CommandSpec {
+        name: "download-workshop-item",
+        about: "Download a workshop item you own",
+        description: &["Download a workshop item you own"],
+        usage: &["s7forge download-workshop-item --app-id <APP_ID> --item-id <ITEM_ID>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--item-id", "ITEM_ID", "Workshop item ID to download"),
+            choice(
+                "--backend",
+                "BACKEND",
+                || vec!["steamworks", "steamcmd"],
+                "Download backend: steamworks, steamcmd [default: steamworks]",
+            ),
+            path(
+                "--steamcmd-path",
+                "PATH",
+                "Path to the steamcmd executable [default: steamcmd]",
+            ),
+            value(
+                "--retries",
+                "N",
+                "Retry transient download failures N times [default: 0]",
+            ),
+            value(
+                "--retry-delay",
+                "SECS",
+                "Initial retry delay, doubled per attempt [default: 2]",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge download-workshop-item --app-id 548430 --item-id 123456789",
+            "s7forge download-workshop-item --app-id 548430 --item-id 123456789 --backend steamcmd",
+        ],
+    },
+    CommandSpec {
+        name: "download-workshop-items",
+        about: "Download several workshop items concurrently",
+        description: &["Download several subscribed workshop items in one Steam session"],
+        usage: &[
+            "s7forge download-workshop-items --app-id <APP_ID> --item-ids <ITEM_IDS> [OPTIONS]",
+        ],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs to download (comma-separated)",
+            ),
+            value(
+                "--concurrency",
+                "N",
+                "Maximum downloads in flight [default: 4]",
+            ),
+            value(
+                "--retries",
+                "N",
+                "Retry transient download failures N times [default: 0]",
+            ),
+            value(
+                "--retry-delay",
+                "SECONDS",
+                "Initial retry delay, doubled per attempt [default: 2]",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge download-workshop-items --app-id 548430 --item-ids 123,456,789 --concurrency 2",
+        ],
+    },
+    CommandSpec {
+        name: "download-previews",
+        about: "Save the preview images of workshop items",
+        description: &[
+            "Download the main preview image of workshop items as <TARGET>/<ITEM_ID>.<EXT>",
+            "",
+            "Images are cached per preview URL, so repeated runs only fetch previews that",
+            "changed. Works without owning or subscribing to the items.",
+        ],
+        usage: &[
+            "s7forge download-previews --app-id <APP_ID> --item-ids <ITEM_IDS> --target <DIR>",
+        ],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs (comma-separated)",
+            ),
+            path(
+                "--target",
+                "DIR",
+                "Folder to write the images to (created if missing)",
+            ),
+            value(
+                "--concurrency",
+                "N",
+                "Maximum images fetched in parallel [default: 4]",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge download-previews --app-id 548430 --item-ids 123,456 --target ./thumbs",
+        ],
+    },
+    CommandSpec {
+        name: "item-previews",
+        about: "List screenshots and videos of workshop items",
+        description: &[
+            "List the screenshots, videos and Sketchfab models in workshop item galleries",
+            "",
+            "Returns each item's additional previews in display order: image URLs, YouTube",
+            "video IDs and Sketchfab model IDs. Requires a Steam Web API key.",
+        ],
+        usage: &["s7forge item-previews --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge --web-api-key <KEY> item-previews --item-ids 123,456"],
+    },
+    CommandSpec {
+        name: "item-changelog",
+        about: "Show the update history of a workshop item",
+        description: &[
+            "Show the update history of a workshop item",
+            "",
+            "Returns the time and change note of each update, newest first, so update checks",
+            "can show what changed. Requires a Steam Web API key.",
+        ],
+        usage: &["s7forge item-changelog --item-id <ITEM_ID> [--limit <N>]"],
+        actions: &[],
+        options: &[
+            value("--item-id", "ITEM_ID", "Workshop item ID"),
+            value(
+                "--limit",
+                "N",
+                "Maximum number of entries to return [default: 20]",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge --web-api-key <KEY> item-changelog --item-id 123456789 --limit 5"],
+    },
+    CommandSpec {
+        name: "item-comments",
+        about: "Read the comments on a workshop item",
+        description: &[
+            "Read the comments on a workshop item's page",
+            "",
+            "Returns the author, time and text of each comment, newest first, 50 per page.",
+            "Works without a Steam Web API key.",
+        ],
+        usage: &["s7forge item-comments --item-id <ITEM_ID> [--page <PAGE>]"],
+        actions: &[],
+        options: &[
+            value("--item-id", "ITEM_ID", "Workshop item ID"),
+            value("--page", "PAGE", "Page number [default: 1]"),
+        ],
+        sections: &[],
+        examples: &["s7forge item-comments --item-id 123456789 --page 2"],
+    },
+    CommandSpec {
+        name: "item-stats",
+        about: "Show subscriber, favorite and playtime counts",
+        description: &[
+            "Show subscriber, favorite, view and playtime counts of workshop items",
+            "",
+            "Counts are always queried fresh from Steam. Playtime is only reported by the",
+            "Web API and is included when a Steam Web API key is configured.",
+        ],
+        usage: &["s7forge item-stats --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge item-stats --app-id 548430 --item-ids 123,456"],
+    },
+    CommandSpec {
+        name: "required-dlc",
+        about: "List the DLC workshop items require",
+        description: &[
+            "List the DLC and other apps that workshop items require",
+            "",
+            "Read from each item's workshop page. When Steam is running, every required app",
+            "is flagged with whether the logged-in account owns it.",
+        ],
+        usage: &["s7forge required-dlc --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge required-dlc --app-id 548430 --item-ids 123,456"],
+    },
+    CommandSpec {
+        name: "create-item",
+        about: "Create a new workshop item",
+        description: &["Create a new workshop item, optionally uploading its content right away"],
+        usage: &["s7forge create-item --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--title", "TITLE", "Item title"),
+            value("--description", "TEXT", "Item description"),
+            path("--content", "DIR", "Folder containing the item content"),
+            path("--preview", "FILE", "Preview image"),
+            value("--tags", "TAGS", "Tags, comma-separated"),
+            choice(
+                "--visibility",
+                "VISIBILITY",
+                visibilities,
+                "public, friends-only, hidden, unlisted",
+            ),
+            value("--change-note", "TEXT", "Change note for this upload"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge create-item --app-id 548430",
+            "s7forge create-item --app-id 548430 --title \"My Mod\" --content ./build --preview ./preview.png --visibility hidden",
+        ],
+    },
+    CommandSpec {
+        name: "upload-item",
+        about: "Upload content for a published workshop item",
+        description: &["Upload content for a published workshop item"],
+        usage: &[
+            "s7forge upload-item --app-id <APP_ID> --item-id <ITEM_ID> --content <DIR> [OPTIONS]",
+        ],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--item-id", "ITEM_ID", "Workshop item ID to upload to"),
+            value("--title", "TITLE", "Item title"),
+            value("--description", "TEXT", "Item description"),
+            path("--content", "DIR", "Folder containing the item content"),
+            path("--preview", "FILE", "Preview image"),
+            value("--tags", "TAGS", "Tags, comma-separated"),
+            choice(
+                "--visibility",
+                "VISIBILITY",
+                visibilities,
+                "public, friends-only, hidden, unlisted",
+            ),
+            value("--change-note", "TEXT", "Change note for this upload"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge upload-item --app-id 548430 --item-id 123456789 --content ./build --change-note \"Fixed crash\"",
+        ],
+    },
+    CommandSpec {
+        name: "update-item",
+        about: "Edit metadata of a published workshop item",
+        description: &["Edit metadata of a published workshop item without re-uploading content"],
+        usage: &["s7forge update-item --app-id <APP_ID> --item-id <ITEM_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--item-id", "ITEM_ID", "Workshop item ID to update"),
+            value("--title", "TITLE", "New item title"),
+            value("--description", "TEXT", "New item description"),
+            path("--preview", "FILE", "New preview image"),
+            value("--tags", "TAGS", "Replace tags, comma-separated"),
+            choice(
+                "--visibility",
+                "VISIBILITY",
+                visibilities,
+                "public, friends-only, hidden, unlisted",
+            ),
+            value("--change-note", "TEXT", "Change note for this update"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge update-item --app-id 548430 --item-id 123456789 --description \"Now with docs\"",
+        ],
+    },
+    CommandSpec {
+        name: "set-item-visibility",
+        about: "Change the visibility of a published workshop item",
+        description: &[
+            "Change the visibility of a workshop item published by the logged-in account.",
+            "The reported visibility is read back from Steam after the change.",
+        ],
+        usage: &[
+            "s7forge set-item-visibility --app-id <APP_ID> --item-id <ITEM_ID> --visibility <VISIBILITY>",
+        ],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--item-id", "ITEM_ID", "Workshop item ID to change"),
+            choice(
+                "--visibility",
+                "VISIBILITY",
+                visibilities,
+                "public, friends-only, hidden, unlisted",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge set-item-visibility --app-id 548430 --item-id 123456789 --visibility hidden",
+        ],
+    },
+    CommandSpec {
+        name: "delete-item",
+        about: "Permanently delete published workshop items",
+        description: &["Permanently delete workshop items published by the logged-in account"],
+        usage: &["s7forge delete-item --app-id <APP_ID> --item-ids <ITEM_IDS> --confirm"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs to delete (comma-separated)",
+            ),
+            flag("--confirm", "Required, since deletion cannot be undone"),
+        ],
+        sections: &[],
+        examples: &["s7forge delete-item --app-id 548430 --item-ids 123,456 --confirm"],
+    },
+    CommandSpec {
+        name: "my-published-items",
+        about: "List items published by the logged-in account",
+        description: &[
+            "List workshop items published by the logged-in account, most recently updated first",
+        ],
+        usage: &["s7forge my-published-items --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--page", "PAGE", "Page number for pagination [default: 1]"),
+        ],
+        sections: &[],
+        examples: &["s7forge my-published-items --app-id 548430 --page 2"],
+    },
+    CommandSpec {
+        name: "items-by-creator",
+        about: "List items published by a given Steam user",
+        description: &[
+            "List workshop items published by a Steam user, most recently updated first",
+        ],
+        usage: &["s7forge items-by-creator --app-id <APP_ID> --steam-id <STEAM_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--steam-id", "STEAM_ID", "SteamID64 of the creator"),
+            value("--page", "PAGE", "Page number for pagination [default: 1]"),
+        ],
+        sections: &[],
+        examples: &["s7forge items-by-creator --app-id 548430 --steam-id 76561197960287930"],
+    },
+    CommandSpec {
+        name: "creator-profile",
+        about: "Show profile and avatars of a Steam user",
+        description: &[
+            "Show persona name, profile URL, avatars and published item count of a Steam user",
+        ],
+        usage: &["s7forge creator-profile --app-id <APP_ID> --steam-id <STEAM_ID>"],
+        actions: &[],
+        options: &[
+            value(
+                "--app-id",
+                "APP_ID",
+                "Steam App ID used to count published items",
+            ),
+            value("--steam-id", "STEAM_ID", "SteamID64 of the creator"),
+        ],
+        sections: &[],
+        examples: &["s7forge creator-profile --app-id 548430 --steam-id 76561197960287930"],
+    },
+    CommandSpec {
+        name: "favorite",
+        about: "Add workshop items to your favorites",
+        description: &["Add workshop items to your favorites"],
+        usage: &["s7forge favorite --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs to favorite (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge favorite --app-id 548430 --item-ids 123,456,789"],
+    },
+    CommandSpec {
+        name: "unfavorite",
+        about: "Remove workshop items from your favorites",
+        description: &["Remove workshop items from your favorites"],
+        usage: &["s7forge unfavorite --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs to unfavorite (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge unfavorite --app-id 548430 --item-ids 123,456,789"],
+    },
+    CommandSpec {
+        name: "list-favorites",
+        about: "List your favorited workshop items",
+        description: &["List your favorited workshop items, most recently added first"],
+        usage: &["s7forge list-favorites --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--page", "PAGE", "Page number for pagination [default: 1]"),
+        ],
+        sections: &[],
+        examples: &["s7forge list-favorites --app-id 548430"],
+    },
+    CommandSpec {
+        name: "vote",
+        about: "Vote a workshop item up or down",
+        description: &["Vote a workshop item up or down and print the recorded vote"],
+        usage: &["s7forge vote --app-id <APP_ID> --item-id <ITEM_ID> (--up | --down)"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--item-id", "ITEM_ID", "Workshop item ID to vote on"),
+            flag("--up", "Vote the item up"),
+            flag("--down", "Vote the item down"),
+        ],
+        sections: &[],
+        examples: &["s7forge vote --app-id 548430 --item-id 123456789 --up"],
+    },
+    CommandSpec {
+        name: "get-vote",
+        about: "Show your existing vote on workshop items",
+        description: &[
+            "Show the logged-in account's vote on workshop items (up, down, none or skipped)",
+        ],
+        usage: &["s7forge get-vote --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs to look up (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge get-vote --app-id 548430 --item-ids 123,456"],
+    },
+    CommandSpec {
+        name: "create-collection",
+        about: "Create a new workshop collection",
+        description: &["Create a new workshop collection, optionally filled with items"],
+        usage: &["s7forge create-collection --app-id <APP_ID> --title <TITLE> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Items to add to the collection (comma-separated)",
+            ),
+            value("--title", "TITLE", "Collection title"),
+            value("--description", "TEXT", "Collection description"),
+            path("--preview", "FILE", "Preview image"),
+            value("--tags", "TAGS", "Tags (comma-separated)"),
+            choice(
+                "--visibility",
+                "VISIBILITY",
+                visibilities,
+                "public, friends-only, hidden or unlisted",
+            ),
+            value(
+                "--change-note",
+                "TEXT",
+                "Change note for the first revision",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge create-collection --app-id 548430 --title \"My Modpack\" --item-ids 123,456",
+        ],
+    },
+    CommandSpec {
+        name: "collection-add",
+        about: "Add items to a workshop collection you own",
+        description: &["Add items to a workshop collection you own"],
+        usage: &[
+            "s7forge collection-add --app-id <APP_ID> --collection-id <ID> --item-ids <ITEM_IDS>",
+        ],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--collection-id", "ID", "Workshop collection ID"),
+            ITEM_ID_ALIAS,
+            value("--item-ids", "ITEM_IDS", "Items to add (comma-separated)"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge collection-add --app-id 548430 --collection-id 987654321 --item-ids 123,456",
+        ],
+    },
+    CommandSpec {
+        name: "collection-remove",
+        about: "Remove items from a workshop collection you own",
+        description: &["Remove items from a workshop collection you own"],
+        usage: &[
+            "s7forge collection-remove --app-id <APP_ID> --collection-id <ID> --item-ids <ITEM_IDS>",
+        ],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value("--collection-id", "ID", "Workshop collection ID"),
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Items to remove (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge collection-remove --app-id 548430 --collection-id 987654321 --item-ids 456",
+        ],
+    },
+    CommandSpec {
+        name: "dependency-graph",
+        about: "Resolve the required items of workshop items",
+        description: &[
+            "Resolve the transitive required items of workshop items as a graph",
+            "",
+            "Outputs nodes and edges, lists missing or deleted dependencies and any cycles.",
+        ],
+        usage: &["s7forge dependency-graph --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs to resolve (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge dependency-graph --app-id 548430 --item-ids 123,456"],
+    },
+    CommandSpec {
+        name: "unsubscribe-all",
+        about: "Unsubscribe from all (or filtered) items for a game",
+        description: &["Unsubscribe from every subscribed item for a game, optionally filtered"],
+        usage: &["s7forge unsubscribe-all --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--tags",
+                "TAGS",
+                "Only items having all of these tags (comma-separated)",
+            ),
+            value(
+                "--creator",
+                "STEAM_ID",
+                "Only items published by this SteamID64",
+            ),
+            value(
+                "--title-contains",
+                "TEXT",
+                "Only items whose title contains TEXT (case-insensitive)",
+            ),
+            flag("--dry-run", "List matching items without unsubscribing"),
+        ],
+        sections: &[],
+        examples: &["s7forge unsubscribe-all --app-id 548430 --tags \"map\" --dry-run"],
+    },
+    CommandSpec {
+        name: "export-subscriptions",
+        about: "Save all subscriptions for a game to a snapshot file",
+        description: &["Save the full subscription state for a game to a versioned JSON snapshot"],
+        usage: &["s7forge export-subscriptions --app-id <APP_ID> --output <FILE>"],
+        actions: &[],
+        options: &[APP_ID, path("--output", "FILE", "Snapshot file to write")],
+        sections: &[],
+        examples: &["s7forge export-subscriptions --app-id 548430 --output subscriptions.json"],
+    },
+    CommandSpec {
+        name: "import-subscriptions",
+        about: "Subscribe to everything in a snapshot file",
+        description: &["Restore subscriptions from a snapshot written by export-subscriptions"],
+        usage: &["s7forge import-subscriptions --app-id <APP_ID> --input <FILE> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            path("--input", "FILE", "Snapshot file to restore"),
+            flag("--prune", "Also unsubscribe from items not in the snapshot"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge import-subscriptions --app-id 548430 --input subscriptions.json --prune",
+        ],
+    },
+    CommandSpec {
+        name: "subscriptions-diff",
+        about: "Compare your subscriptions against a collection",
+        description: &[
+            "Compare your subscriptions against a workshop collection",
+            "",
+            "Lists collection items you're missing (\"missing\") and subscriptions",
+            "that aren't part of the collection (\"extra\").",
+        ],
+        usage: &["s7forge subscriptions-diff --app-id <APP_ID> --collection-id <ID>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--collection-id",
+                "ID",
+                "Workshop collection to compare against",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge subscriptions-diff --app-id 548430 --collection-id 987654321"],
+    },
+    CommandSpec {
+        name: "sync",
+        about: "Make subscriptions match a snapshot file",
+        description: &[
+            "Make the live subscriptions match a snapshot written by export-subscriptions",
+            "",
+            "Subscribes to missing items and unsubscribes from extras. Items deleted from the",
+            "Workshop, or subscribed on this machine after the snapshot was exported, are",
+            "reported as conflicts and left untouched.",
+        ],
+        usage: &["s7forge sync --app-id <APP_ID> --input <FILE> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            path("--input", "FILE", "Snapshot file to sync against"),
+            flag("--dry-run", "Report the changes without applying them"),
+        ],
+        sections: &[],
+        examples: &["s7forge sync --app-id 548430 --input subscriptions.json --dry-run"],
+    },
+    CommandSpec {
+        name: "profile",
+        about: "Save and switch between named subscription sets",
+        description: &[
+            "Save, list and switch between named sets of subscriptions",
+            "",
+            "Profiles are stored as editable JSON in cache/profiles/<APP_ID>/<NAME>.json; the",
+            "order of \"items\" is the load order used by item-conflicts (later items win).",
+        ],
+        usage: &[
+            "s7forge profile save <NAME> --app-id <APP_ID>",
+            "s7forge profile list --app-id <APP_ID>",
+            "s7forge profile activate <NAME> --app-id <APP_ID>",
+            "s7forge profile delete <NAME> --app-id <APP_ID>",
+        ],
+        actions: &["save", "activate", "delete", "list"],
+        options: &[APP_ID],
+        sections: &[Section {
+            title: "ACTIONS",
+            lines: &[
+                "save          Save the current subscriptions as a profile (keeps existing order)",
+                "list          List profiles and which one is active",
+                "activate      Subscribe/unsubscribe to match the profile and mark it active",
+                "delete        Delete a profile",
+            ],
+        }],
+        examples: &[
+            "s7forge profile save modded --app-id 548430",
+            "s7forge profile activate vanilla --app-id 548430",
+        ],
+    },
+    CommandSpec {
+        name: "item-meta",
+        about: "Attach local notes, tags and pins to items",
+        description: &[
+            "Attach local notes, custom tags and a pin flag to workshop items",
+            "",
+            "Stored per app in cache/local_meta/<APP_ID>.json and merged into subscribed-items",
+            "and workshop-items output with --with-local-meta. Nothing is sent to Steam.",
+        ],
+        usage: &[
+            "s7forge item-meta set --app-id <APP_ID> --item-ids <ITEM_IDS> [--note <TEXT>] [--tags <TAGS>] [--pin | --unpin]",
+            "s7forge item-meta get --app-id <APP_ID> --item-ids <ITEM_IDS>",
+            "s7forge item-meta remove --app-id <APP_ID> --item-ids <ITEM_IDS>",
+            "s7forge item-meta list --app-id <APP_ID>",
+        ],
+        actions: &["set", "get", "remove", "list"],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs (comma-separated)",
+            ),
+            value(
+                "--note",
+                "TEXT",
+                "Free-form note; an empty string clears it",
+            ),
+            value(
+                "--tags",
+                "TAGS",
+                "Custom tags (comma-separated), replacing existing ones",
+            ),
+            flag("--pin", "Pin the items"),
+            flag("--unpin", "Clear the pin flag"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge item-meta set --app-id 548430 --item-ids 123 --note \"Crashes with 456\" --pin",
+        ],
+    },
+    CommandSpec {
+        name: "blacklist",
+        about: "Prevent items from being subscribed again",
+        description: &[
+            "Keep known-broken items from being subscribed again",
+            "",
+            "Blacklisted items are skipped with a warning by subscribe (including items pulled",
+            "in by --with-dependencies), import-subscriptions, sync and profile activate.",
+        ],
+        usage: &[
+            "s7forge blacklist add --app-id <APP_ID> --item-ids <ITEM_IDS> [--reason <TEXT>]",
+            "s7forge blacklist remove --app-id <APP_ID> --item-ids <ITEM_IDS>",
+            "s7forge blacklist list --app-id <APP_ID>",
+        ],
+        actions: &["add", "remove", "list"],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs (comma-separated)",
+            ),
+            value(
+                "--reason",
+                "TEXT",
+                "Why the items are blacklisted, shown in warnings",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge blacklist add --app-id 548430 --item-ids 123 --reason \"Crashes on load\"",
+        ],
+    },
+    CommandSpec {
+        name: "check-updates",
+        about: "List subscribed items with pending updates",
+        description: &["List subscribed items whose local install is missing or outdated"],
+        usage: &["s7forge check-updates --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            choice(
+                "--format",
+                "FORMAT",
+                || vec!["json", "rss"],
+                "json or rss, a feed for feed readers (default: json)",
+            ),
+            flag(
+                "--quiet-if-none",
+                "Print nothing when up to date; exit code tells the state",
+            ),
+        ],
+        sections: &[Section {
+            title: "EXIT CODES (with --quiet-if-none)",
+            lines: &[
+                "0     No pending updates",
+                "10    Updates available (listed on stdout)",
+                "1-6   Error, see 's7forge --help'",
+            ],
+        }],
+        examples: &[
+            "s7forge check-updates --app-id 548430",
+            "s7forge check-updates --app-id 548430 --format rss > updates.xml",
+            "s7forge check-updates --app-id 548430 --quiet-if-none",
+        ],
+    },
+    CommandSpec {
+        name: "download-updates",
+        about: "Download all pending updates for subscribed items",
+        description: &[
+            "Download every subscribed item reported by check-updates and wait for completion",
+        ],
+        usage: &["s7forge download-updates --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--concurrency",
+                "N",
+                "Maximum downloads in flight [default: 4]",
+            ),
+            value(
+                "--retries",
+                "N",
+                "Retry transient download failures N times [default: 0]",
+            ),
+            value(
+                "--retry-delay",
+                "SECONDS",
+                "Initial retry delay, doubled per attempt [default: 2]",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge download-updates --app-id 548430 --concurrency 2"],
+    },
+    CommandSpec {
+        name: "watch",
+        about: "Poll subscribed items and report updates as NDJSON",
+        description: &[
+            "Poll subscribed items and print an NDJSON event whenever one is updated or added",
+            "",
+            "Runs until stopped. The first poll prints a \"ready\" event; later polls print",
+            "\"new_item\" and \"item_updated\" events, \"creator_published\" for followed",
+            "creators, and \"downloaded\" with --download.",
+            "Failed polls print an \"error\" event and watching continues. With the global",
+            "--log-file option every event is also kept in a rotated JSON log.",
+        ],
+        usage: &["s7forge watch --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--interval",
+                "DURATION",
+                "Time between polls, e.g. 90s, 15m, 1h (default: 15m)",
+            ),
+            flag(
+                "--download",
+                "Download new and updated items as they are detected",
+            ),
+            path(
+                "--feed",
+                "PATH",
+                "Keep an RSS feed of the latest 50 events at PATH",
+            ),
+            value(
+                "--webhook",
+                "URL",
+                "POST new/updated/published events to URL (repeatable)",
+            ),
+            choice(
+                "--webhook-format",
+                "FORMAT",
+                || vec!["json", "discord"],
+                "json (the event as printed) or discord (default: json)",
+            ),
+            value(
+                "--follow-creators",
+                "IDS",
+                "Also report new items published by these SteamID64s",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge watch --app-id 548430 --interval 15m --download",
+            "s7forge watch --app-id 548430 --webhook https://discord.com/api/webhooks/... --webhook-format discord",
+        ],
+    },
+    CommandSpec {
+        name: "index",
+        about: "Keep a queryable SQLite index of subscribed items",
+        description: &[
+            "Keep a local SQLite index of subscribed items and query it offline",
+            "",
+            "update syncs the index with your subscriptions: new and changed items are written,",
+            "unsubscribed ones dropped. query returns the stored item details, most recently",
+            "updated first. The database lives at cache/index/<APP_ID>.sqlite and survives",
+            "clear-cache.",
+        ],
+        usage: &[
+            "s7forge index update --app-id <APP_ID>",
+            "s7forge index query --app-id <APP_ID> [OPTIONS]",
+        ],
+        actions: &["update", "query"],
+        options: &[
+            APP_ID,
+            value("--query", "TEXT", "Case-insensitive text to find in titles"),
+            value("--tag", "TAG", "Require a tag (repeatable, all must match)"),
+            value("--min-size", "BYTES", "Only items of at least BYTES"),
+            value("--max-size", "BYTES", "Only items of at most BYTES"),
+            value(
+                "--updated-since",
+                "DATE",
+                "Only items updated since DATE (2024-05-01 or 30d)",
+            ),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge index update --app-id 548430",
+            "s7forge index query --app-id 548430 --tag Map --max-size 10485760",
+        ],
+    },
+    CommandSpec {
+        name: "force-redownload",
+        about: "Delete an item's local files and download it again",
+        description: &[
+            "Delete a workshop item's local files and download it again from scratch",
+            "",
+            "The item's entry in Steam's appworkshop manifest is removed as well, so Steam",
+            "does not treat the deleted content as installed. Use it to fix corrupted downloads.",
+        ],
+        usage: &["s7forge force-redownload --app-id <APP_ID> --item-id <ITEM_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            value(
+                "--item-id",
+                "ITEM_ID",
+                "Subscribed workshop item to re-download",
+            ),
+            value(
+                "--retries",
+                "N",
+                "Retry transient download failures N times [default: 0]",
+            ),
+            value(
+                "--retry-delay",
+                "SECONDS",
+                "Initial retry delay, doubled per attempt [default: 2]",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge force-redownload --app-id 548430 --item-id 123456789"],
+    },
+    CommandSpec {
+        name: "clear-cache",
+        about: "Clear the Steam workshop cache",
+        description: &[
+            "Clear cached data, all of it or only one kind, app or item",
+            "",
+            "With --app-id or --item-ids only the matching entries are removed and the cache",
+            "files are kept. Those filters apply to the item and path caches only.",
+        ],
+        usage: &["s7forge clear-cache [--what <KIND>] [--app-id <APP_ID>] [--item-ids <IDS>]"],
+        actions: &[],
+        options: &[
+            choice(
+                "--what",
+                "KIND",
+                caches,
+                "Cache to clear: items, paths, library, creators, searches\nor all [default: all]",
+            ),
+            value(
+                "--app-id",
+                "APP_ID",
+                "Only clear entries for this app (items, paths or all)",
+            ),
+            ITEM_ID_ALIAS,
+            value("--item-ids", "IDS", "Only clear these items (items or all)"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge clear-cache",
+            "s7forge clear-cache --what library",
+            "s7forge clear-cache --what items --app-id 548430",
+            "s7forge clear-cache --what items --item-ids 3300840848,3300840849",
+        ],
+    },
+    CommandSpec {
+        name: "cache-stats",
+        about: "Show size, age and hit counts of each cache file",
+        description: &[
+            "Show size, entry count and age of each cache file",
+            "",
+            "last_run holds the cache hits and misses of the most recent command that used",
+            "the cache. Expired entries are still stored but refreshed on the next lookup.",
+        ],
+        usage: &["s7forge cache-stats"],
+        actions: &[],
+        options: &[],
+        sections: &[],
+        examples: &["s7forge cache-stats"],
+    },
+    CommandSpec {
+        name: "cache-inspect",
+        about: "Dump cached entries with their cache times",
+        description: &[
+            "Dump cached entries as stored, with when they were cached and when they expire",
+        ],
+        usage: &["s7forge cache-inspect [--what <KIND>] [--app-id <APP_ID>] [--item-ids <IDS>]"],
+        actions: &[],
+        options: &[
+            choice(
+                "--what",
+                "KIND",
+                caches,
+                "Cache to inspect: items, paths, library, creators,\nsearches or all [default: all]",
+            ),
+            value("--app-id", "APP_ID", "Only entries for this app"),
+            ITEM_ID_ALIAS,
+            value("--item-ids", "IDS", "Only these items (items or all)"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge cache-inspect --what items --item-ids 3300840848",
+            "s7forge cache-inspect --what searches --app-id 548430",
+        ],
+    },
+    CommandSpec {
+        name: "cache-export",
+        about: "Write the workshop item cache to a JSON file",
+        description: &[
+            "Export the workshop item cache, with creator names, to a portable JSON file",
+            "",
+            "Use cache-import to seed another machine, e.g. an offline game server, with the",
+            "item metadata gathered here.",
+        ],
+        usage: &["s7forge cache-export --output <FILE> [--app-id <APP_ID>]"],
+        actions: &[],
+        options: &[
+            value("--app-id", "APP_ID", "Only export items of this app"),
+            path("--output", "FILE", "Path of the JSON file to write"),
+        ],
+        sections: &[],
+        examples: &["s7forge cache-export --app-id 548430 --output ./drg-items.json"],
+    },
+    CommandSpec {
+        name: "cache-import",
+        about: "Merge a file from cache-export into the item cache",
+        description: &[
+            "Merge a file written by cache-export into the workshop item cache",
+            "",
+            "Imported items replace cached ones with the same ID. They expire like any other",
+            "cache entry, counted from when they were fetched on the exporting machine; raise",
+            "cache.items in the config file to keep them longer on machines without internet.",
+        ],
+        usage: &["s7forge cache-import --input <FILE>"],
+        actions: &[],
+        options: &[path("--input", "FILE", "Path of the exported JSON file")],
+        sections: &[],
+        examples: &["s7forge cache-import --input ./drg-items.json"],
+    },
+    CommandSpec {
+        name: "installed-items",
+        about: "List installed workshop items from Steam's manifest",
+        description: &[
+            "List every locally installed workshop item of a game, subscribed or not",
+            "",
+            "Reads steamapps/workshop/appworkshop_<APP_ID>.acf in each Steam library, so the",
+            "Steam client does not need to be running.",
+        ],
+        usage: &["s7forge installed-items --app-id <APP_ID>"],
+        actions: &[],
+        options: &[APP_ID],
+        sections: &[],
+        examples: &["s7forge installed-items --app-id 548430"],
+    },
+    CommandSpec {
+        name: "orphaned-items",
+        about: "List downloaded but unsubscribed workshop content",
+        description: &[
+            "List downloaded workshop folders that are no longer subscribed, with their sizes",
+            "",
+            "Folders under workshop/content/<APP_ID> in every Steam library are checked against",
+            "your subscriptions; in_manifest tells whether Steam's appworkshop manifest still",
+            "lists the item.",
+        ],
+        usage: &["s7forge orphaned-items --app-id <APP_ID>"],
+        actions: &[],
+        options: &[APP_ID],
+        sections: &[],
+        examples: &["s7forge orphaned-items --app-id 548430"],
+    },
+    CommandSpec {
+        name: "cleanup-workshop",
+        about: "Delete downloaded but unsubscribed workshop content",
+        description: &[
+            "Delete downloaded workshop folders that are no longer subscribed",
+            "",
+            "Their entries in Steam's appworkshop manifest are removed as well. Folders that",
+            "aren't named after an item are kept, and the command refuses to run when the",
+            "account has no subscriptions for the app.",
+        ],
+        usage: &["s7forge cleanup-workshop --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            flag(
+                "--dry-run",
+                "Report folders and sizes without deleting anything",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge cleanup-workshop --app-id 548430 --dry-run"],
+    },
+    CommandSpec {
+        name: "remove-item-files",
+        about: "Delete local files of items but keep subscriptions",
+        description: &[
+            "Delete the downloaded files of workshop items without unsubscribing",
+            "",
+            "Only folders directly inside workshop/content/<APP_ID> are deleted.",
+        ],
+        usage: &["s7forge remove-item-files --app-id <APP_ID> --item-ids <ITEM_IDS>"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Workshop item IDs whose files to delete (comma-separated)",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge remove-item-files --app-id 548430 --item-ids 123,456"],
+    },
+    CommandSpec {
+        name: "verify-items",
+        about: "Detect missing, empty or truncated item downloads",
+        description: &[
+            "Check installed workshop items against Steam's appworkshop manifest",
+            "",
+            "Flags missing or empty folders, on-disk sizes that differ from the manifest and",
+            "items with a newer Workshop version than the one installed.",
+        ],
+        usage: &["s7forge verify-items --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Only verify these items (comma-separated) [default: all]",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge verify-items --app-id 548430"],
+    },
+    CommandSpec {
+        name: "item-conflicts",
+        about: "Find files overwritten by more than one item",
+        description: &[
+            "Find files shipped by more than one installed workshop item",
+            "",
+            "Relative paths are compared case-insensitively; each conflict lists the items",
+            "that ship the file and, when a profile is active, the winner by its load order.",
+        ],
+        usage: &["s7forge item-conflicts --app-id <APP_ID> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Items to compare (comma-separated) [default: all subscribed]",
+            ),
+        ],
+        sections: &[],
+        examples: &["s7forge item-conflicts --app-id 548430"],
+    },
+    CommandSpec {
+        name: "deploy-items",
+        about: "Link installed items into a game's mods folder",
+        description: &[
+            "Link installed workshop items into a folder the game loads mods from",
+            "",
+            "Items are symlinked, or copied when symlinks aren't permitted (Windows without",
+            "developer mode). Deployments are recorded in .s7forge-deploy.json in the target.",
+        ],
+        usage: &["s7forge deploy-items --app-id <APP_ID> --target <DIR> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            APP_ID,
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Items to deploy (comma-separated) [default: all subscribed]",
+            ),
+            path(
+                "--target",
+                "DIR",
+                "Folder to deploy into (created if missing) [default: deploy_target of [apps.<APP_ID>]]",
+            ),
+            value(
+                "--name-template",
+                "TEMPLATE",
+                "Folder name; {id} and {title} are replaced [default: {id}]",
+            ),
+            flag("--copy", "Always copy instead of symlinking"),
+        ],
+        sections: &[],
+        examples: &[
+            "s7forge deploy-items --app-id 548430 --target \"C:\\Games\\MyGame\\Mods\" --name-template \"{title} ({id})\"",
+        ],
+    },
+    CommandSpec {
+        name: "undeploy",
+        about: "Remove items deployed with deploy-items",
+        description: &[
+            "Remove workshop items previously deployed with deploy-items",
+            "",
+            "Only folders recorded in the target's .s7forge-deploy.json are removed.",
+        ],
+        usage: &["s7forge undeploy --target <DIR> [OPTIONS]"],
+        actions: &[],
+        options: &[
+            ITEM_ID_ALIAS,
+            value(
+                "--item-ids",
+                "ITEM_IDS",
+                "Items to remove (comma-separated) [default: all deployed]",
+            ),
+            path("--target", "DIR", "Folder the items were deployed into"),
+        ],
+        sections: &[],
+        examples: &["s7forge undeploy --target \"C:\\Games\\MyGame\\Mods\" --item-ids 123"],
+    },
+    CommandSpec {
+        name: "item-install-path",
+        about: "Get the local folder of an installed workshop item",
+        description: &[
+            "Get the absolute path, size and timestamp of a locally installed workshop item",
+            "",
+            "Asks the Steam client first and falls back to <workshop-path>/<ITEM_ID>.",
+        ],
+        usage: &["s7forge item-install-path --app-id <APP_ID> --item-id <ITEM_ID>"],
+        actions: &[],
+        options: &[APP_ID, value("--item-id", "ITEM_ID", "Workshop item ID")],
+        sections: &[],
+        examples: &["s7forge item-install-path --app-id 548430 --item-id 123456789"],
+    },
+    CommandSpec {
+        name: "workshop-path",
+        about: "Get the local workshop path for a game",
+        description: &["Get the local workshop path for a game"],
+        usage: &["s7forge workshop-path --app-id <APP_ID>"],
+        actions: &[],
+        options: &[APP_ID],
+        sections: &[],
+        examples: &["s7forge workshop-path --app-id 548430"],
+    },
+    CommandSpec {
+        name: "steam-library-paths",
+        about: "List all Steam library paths",
+        description: &["List all Steam library folder paths"],
+        usage: &["s7forge steam-library-paths"],
+        actions: &[],
+        options: &[],
+        sections: &[],
+        examples: &["s7forge steam-library-paths"],
+    },
+    CommandSpec {
+        name: "steam-status",
+        about: "Show whether Steam is running and who is signed in",
+        description: &[
+            "Report whether the Steam client is running and which account is signed in",
+            "",
+            "Returns { running, logged_on, offline_mode, steam_id, persona_name, error }.",
+            "A client that isn't running is reported with running: false, not as an error.",
+        ],
+        usage: &["s7forge steam-status [--app-id <APP_ID>] [--launch]"],
+        actions: &[],
+        options: &[
+            value(
+                "--app-id",
+                "APP_ID",
+                "App to initialize Steamworks for [default: 480 (Spacewar)]",
+            ),
+            flag(
+                "--launch",
+                "Start Steam if it isn't running and wait until it is ready",
+            ),
+        ],
+        sections: &[Section {
+            title: "NOTES",
+            lines: &[
+                "- Steam counts as running once an account is signed in",
+                "- offline_mode is the account's setting in Steam's config/loginusers.vdf",
+                "- --launch waits up to timeouts.launch (2m) from the config file",
+            ],
+        }],
+        examples: &["s7forge steam-status", "s7forge steam-status --launch"],
+    },
+    CommandSpec {
+        name: "account-info",
+        about: "Show the SteamID and name of the signed-in account",
+        description: &[
+            "Show the account the Steam client is signed in with",
+            "",
+            "Returns { steam_id, persona_name, logged_on, offline_mode }, e.g. to check a",
+            "script is about to subscribe with the intended account.",
+        ],
+        usage: &["s7forge account-info [--app-id <APP_ID>]"],
+        actions: &[],
+        options: &[value(
+            "--app-id",
+            "APP_ID",
+            "App to initialize Steamworks for [default: 480 (Spacewar)]",
+        )],
+        sections: &[],
+        examples: &["s7forge account-info | jq .steam_id.steam_id64"],
+    },
+    CommandSpec {
+        name: "check-ownership",
+        about: "Check whether the account owns a game, or borrows it",
+        description: &[
+            "Check whether the signed-in account owns a game",
+            "",
+            "Returns { app_id, subscribed, license, owner, installed, init_error }. license is",
+            "owned, family_shared, free_weekend, not_owned, or unknown when Steamworks can't be",
+            "initialized for the game and only access could be checked.",
+        ],
+        usage: &["s7forge check-ownership --app-id <APP_ID>"],
+        actions: &[],
+        options: &[APP_ID],
+        sections: &[Section {
+            title: "NOTES",
+            lines: &[
+                "- Family-shared games can't be subscribed to or downloaded while the owner plays",
+                "- owner is the account whose license is used, the lender for family sharing",
+            ],
+        }],
+        examples: &["s7forge check-ownership --app-id 548430"],
+    },
+    CommandSpec {
+        name: "list-dlc",
+        about: "List a game's DLC with owned and installed flags",
+        description: &[
+            "List a game's DLC with whether the signed-in account owns and has installed it",
+            "",
+            "Returns [{ app_id, name, available, owned, installed }]. available is false for",
+            "DLC without a visible store page.",
+        ],
+        usage: &["s7forge list-dlc --app-id <APP_ID>"],
+        actions: &[],
+        options: &[APP_ID],
+        sections: &[Section {
+            title: "NOTES",
+            lines: &[
+                "- Steamworks is initialized for the game, so the account has to own it",
+                "- Compare with required-dlc to see why some workshop items won't load",
+            ],
+        }],
+        examples: &["s7forge list-dlc --app-id 548430"],
+    },
+    CommandSpec {
+        name: "restart-steam",
+        about: "Shut Steam down, start it again and wait until it is ready",
+        description: &[
+            "Shut the Steam client down, launch it again and wait until it is ready",
+            "",
+            "Returns { was_running, killed, elapsed_seconds }. Restarting Steam is the usual",
+            "fix for downloads that stopped progressing.",
+        ],
+        usage: &["s7forge restart-steam [--app-id <APP_ID>]"],
+        actions: &[],
+        options: &[value(
+            "--app-id",
+            "APP_ID",
+            "App to initialize Steamworks for [default: 480 (Spacewar)]",
+        )],
+        sections: &[Section {
+            title: "NOTES",
+            lines: &[
+                "- Steam is asked to exit with -shutdown; if it is still running after",
+                "  timeouts.steam (30s) its process is killed",
+                "- Waits up to timeouts.launch (2m) for the new client to be ready",
+                "- A Steam that isn't running is just launched",
+            ],
+        }],
+        examples: &["s7forge restart-steam"],
+    },
+    CommandSpec {
+        name: "app-installation-path",
+        about: "Get the installation path for a Steam app",
+        description: &["Get the installation path for a Steam app"],
+        usage: &["s7forge app-installation-path --app-id <APP_ID>"],
+        actions: &[],
+        options: &[APP_ID],
+        sections: &[],
+        examples: &["s7forge app-installation-path --app-id 548430"],
+    },
+    CommandSpec {
+        name: "schema",
+        about: "Print JSON Schemas of command output",
+        description: &["Print JSON Schemas of command output"],
+        usage: &["s7forge schema [COMMAND]"],
+        actions: &[],
+        options: &[],
+        sections: &[
+            Section {
+                title: "ARGUMENTS",
+                lines: &[
+                    "[COMMAND]     Command whose output schema to print, or 'error' for the error",
+                    "              object. Without one, every schema is printed with the current",
+                    "              schema_version",
+                ],
+            },
+            Section {
+                title: "NOTES",
+                lines: &[
+                    "- Schemas describe the output without --envelope and the global format flags;",
+                    "  --dates and --sizes can turn numbers into strings",
+                    "- watch is described per NDJSON line; combined, batch and completions are left out",
+                ],
+            },
+        ],
+        examples: &[
+            "s7forge schema workshop-items",
+            "s7forge schema > s7forge-schemas.json",
+        ],
+    },
+    CommandSpec {
+        name: "self-update",
+        about: "Install the latest s7forge release over this executable",
+        description: &[
+            "Replace this executable with the latest s7forge release from GitHub",
+            "",
+            "Returns { current_version, latest_version, update_available, updated, asset, path }.",
+            "The release binary for this platform is only installed when its SHA-256 matches",
+            "the release's SHA256SUMS file.",
+        ],
+        usage: &["s7forge self-update [--check]"],
+        actions: &[],
+        options: &[flag(
+            "--check",
+            "Only report whether a newer release exists",
+        )],
+        sections: &[Section {
+            title: "NOTES",
+            lines: &[
+                "- Release binaries are named s7forge-<arch>-<os>[.exe], e.g.",
+                "  s7forge-x86_64-windows.exe",
+                "- Only the executable is replaced; the Steam API library next to it is kept",
+                "- The directory of the executable must be writable",
+            ],
+        }],
+        examples: &["s7forge self-update --check", "s7forge self-update"],
+    },
+    CommandSpec {
+        name: "completions",
+        about: "Print a shell completion script",
+        description: &["Print a shell completion script for every command and option"],
+        usage: &["s7forge completions <SHELL>"],
+        actions: &["bash", "zsh", "fish", "powershell"],
+        options: &[],
+        sections: &[
+            Section {
+                title: "ARGUMENTS",
+                lines: &["<SHELL>       bash, zsh, fish or powershell"],
+            },
+            Section {
+                title: "NOTES",
+                lines: &[
+                    "- The script is printed as is, without --envelope or --output table",
+                    "- Option values are completed where they are a fixed set or a path",
+                ],
+            },
+        ],
+        examples: &[
+            "s7forge completions bash > /etc/bash_completion.d/s7forge",
+            "s7forge completions zsh > \"${fpath[1]}/_s7forge\"",
+            "s7forge completions fish > ~/.config/fish/completions/s7forge.fish",
+            "s7forge completions powershell >> $PROFILE",
+        ],
+    },
+];
@@ -0,0 +1,59 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use steamworks::Client;
+
+use crate::core::acf;
+use crate::core::error::S7forgeError;
+use crate::core::localplayer::PlayerSteamId;
+use crate::core::steam_install_paths::steam_install_paths;
+use crate::core::steam_manager;
+
+/// The account the Steam client is signed in with.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AccountInfo {
+    pub steam_id: PlayerSteamId,
+    pub persona_name: String,
+    /// Connected to Steam; also false while the client is reconnecting
+    pub logged_on: bool,
+    /// The account is set to run Steam in offline mode
+    pub offline_mode: bool,
+}
+
+impl AccountInfo {
+    pub fn from_client(client: &Client) -> Self {
+        let steam_id = client.user().steam_id();
+        Self {
+            steam_id: PlayerSteamId::from_steamid(steam_id),
+            persona_name: client.friends().name(),
+            logged_on: client.user().logged_on(),
+            offline_mode: wants_offline_mode(steam_id.raw()),
+        }
+    }
+}
+
+// Steamworks has no call for this; the client keeps the choice per account as
+// "WantsOfflineMode" in config/loginusers.vdf
+fn wants_offline_mode(steam_id: u64) -> bool {
+    let Ok(install_paths) = steam_install_paths() else {
+        return false;
+    };
+    install_paths
+        .iter()
+        .map(|path| Path::new(path).join("config").join("loginusers.vdf"))
+        .find_map(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            let document = acf::parse(&path, &content).ok()?;
+            let user = document
+                .get_section("users")?
+                .get_section(&steam_id.to_string())?;
+            Some(user.get_str("WantsOfflineMode") == Some("1"))
+        })
+        .unwrap_or(false)
+}
+
+pub async fn account_info(app_id: u32) -> Result<AccountInfo, S7forgeError> {
+    let client = steam_manager::initialize_client(app_id).await?;
+    Ok(AccountInfo::from_client(&client))
+}
@@ -4,46 +4,48 @@ use std::fs;
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::commands::cache_inspect::CachedEntry;
 use crate::commands::steam_library_paths::steam_library_paths;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::utils::cache_codec;
+use crate::utils::cache_counters;
+use crate::utils::cache_file;
 use crate::utils::extract_quoted_strings::extract_quoted_strings;
 use crate::utils::get_cache_dir::get_cache_dir;
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Debug, Clone, Encode, Decode)]
 struct AppInstallPathCache {
     paths: FxHashMap<u32, Result<String, String>>,
     timestamp: u64,
 }
 
-pub fn app_installation_path(app_id: u32) -> Result<String, String> {
-    // Try to load from cache
-    if let Ok(cache_dir) = get_cache_dir() {
+pub fn app_installation_path(app_id: u32) -> Result<String, S7forgeError> {
+    // Try to load from cache; --no-cache skips this but still refreshes the cache below
+    if let Some(cache_dir) = get_cache_dir().ok().filter(|_| !settings::get().no_cache) {
         let cache_path = cache_dir.join("app_install_path_cache.bin");
-        if cache_path.exists() {
-            if let Ok(cache_content) = fs::read(&cache_path) {
-                let config = bincode::config::standard();
-                if let Ok((cache, _)) =
-                    bincode::decode_from_slice::<AppInstallPathCache, _>(&cache_content, config)
-                {
-                    let now = SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs();
-                    let cache_duration_secs = 60 * 60; // 1 hour
-
-                    if now.saturating_sub(cache.timestamp) < cache_duration_secs {
-                        if let Some(cached_result) = cache.paths.get(&app_id) {
-                            return cached_result.clone();
-                        }
-                    }
+        if let Some(cache) = cache_file::read::<AppInstallPathCache>(&cache_path) {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let cache_duration_secs = settings::get().path_cache_ttl_for(app_id).as_secs();
+
+            if now.saturating_sub(cache.timestamp) < cache_duration_secs {
+                if let Some(cached_result) = cache.paths.get(&app_id) {
+                    cache_counters::hit("app_install_path_cache.bin", 1);
+                    return cached_result.clone().map_err(S7forgeError::NotFound);
                 }
             }
         }
     }
 
-    let library_paths =
-        steam_library_paths().map_err(|e| format!("Failed to get Steam library paths: {}", e))?;
+    cache_counters::miss("app_install_path_cache.bin", 1);
+
+    let library_paths = steam_library_paths()?;
 
-    let result = 'outer: {
+    // Failures are cached too, so they are kept as messages
+    let result: Result<String, String> = 'outer: {
         for library_path in library_paths {
             let steamapps_path = Path::new(&library_path).join("steamapps");
 
@@ -52,7 +54,7 @@ pub fn app_installation_path(app_id: u32) -> Result<String, String> {
                 continue;
             }
             let manifest_content = fs::read_to_string(&manifest_file)
-                .map_err(|e| format!("Failed to read manifest file: {}", e))?;
+                .map_err(|e| S7forgeError::io("Failed to read manifest file", e))?;
 
             let quoted_strings = extract_quoted_strings(&manifest_content);
             for i in 0..quoted_strings.len() {
@@ -88,28 +90,7 @@ pub fn app_installation_path(app_id: u32) -> Result<String, String> {
         let _ = fs::create_dir_all(&cache_dir);
         let cache_path = cache_dir.join("app_install_path_cache.bin");
 
-        let mut cache = if cache_path.exists() {
-            if let Ok(cache_content) = fs::read(&cache_path) {
-                let config = bincode::config::standard();
-                bincode::decode_from_slice::<AppInstallPathCache, _>(&cache_content, config)
-                    .map(|(c, _)| c)
-                    .unwrap_or_else(|_| AppInstallPathCache {
-                        paths: FxHashMap::default(),
-                        timestamp: SystemTime::now()
-                            .duration_since(UNIX_EPOCH)
-                            .unwrap_or_default()
-                            .as_secs(),
-                    })
-            } else {
-                AppInstallPathCache {
-                    paths: FxHashMap::default(),
-                    timestamp: SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs(),
-                }
-            }
-        } else {
+        let mut cache = cache_file::read::<AppInstallPathCache>(&cache_path).unwrap_or_else(|| {
             AppInstallPathCache {
                 paths: FxHashMap::default(),
                 timestamp: SystemTime::now()
@@ -117,7 +98,7 @@ pub fn app_installation_path(app_id: u32) -> Result<String, String> {
                     .unwrap_or_default()
                     .as_secs(),
             }
-        };
+        });
 
         cache.paths.insert(app_id, result.clone());
         cache.timestamp = SystemTime::now()
@@ -125,11 +106,46 @@ pub fn app_installation_path(app_id: u32) -> Result<String, String> {
             .unwrap_or_default()
             .as_secs();
 
-        let config = bincode::config::standard();
-        if let Ok(encoded) = bincode::encode_to_vec(&cache, config) {
-            let _ = fs::write(&cache_path, encoded);
-        }
+        let _ = cache_file::write(&cache_path, &cache);
     }
 
-    result
+    result.map_err(S7forgeError::NotFound)
+}
+
+/// Drops the cached installation path of one app. Returns whether there was an entry.
+pub fn evict_cached_path(app_id: u32) -> Result<bool, S7forgeError> {
+    let cache_path = get_cache_dir()?.join("app_install_path_cache.bin");
+    let Some(mut cache) = cache_file::read::<AppInstallPathCache>(&cache_path) else {
+        return Ok(false);
+    };
+
+    if cache.paths.remove(&app_id).is_none() {
+        return Ok(false);
+    }
+    cache_file::write(&cache_path, &cache)?;
+    Ok(true)
+}
+
+/// Cached lookups; failed ones are listed as `{"error": ...}`.
+pub fn cached_entries() -> Result<Vec<CachedEntry>, S7forgeError> {
+    let cache_path = get_cache_dir()?.join("app_install_path_cache.bin");
+    let Ok(cache_content) = fs::read(&cache_path) else {
+        return Ok(Vec::new());
+    };
+    let cache = cache_codec::decode::<AppInstallPathCache>(&cache_content)
+        .map_err(|e| S7forgeError::cache(format!("Failed to read {}", cache_path.display()), e))?;
+
+    Ok(cache
+        .paths
+        .into_iter()
+        .map(|(app_id, path)| {
+            let value = match path {
+                Ok(path) => serde_json::Value::String(path),
+                Err(error) => serde_json::json!({ "error": error }),
+            };
+            CachedEntry::new("app_install_path_cache.bin", app_id.to_string(), value)
+                .cached(cache.timestamp, settings::get().path_cache_ttl_for(app_id))
+                .app(app_id)
+        })
+        .collect())
 }
@@ -0,0 +1,92 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::error::S7forgeError;
+use crate::utils::get_cache_dir::get_cache_dir;
+
+#[derive(Debug)]
+pub enum BlacklistAction {
+    Add {
+        item_ids: Vec<u64>,
+        reason: Option<String>,
+    },
+    Remove {
+        item_ids: Vec<u64>,
+    },
+    List,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, Deserialize)]
+pub struct BlacklistEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Unix time the item was blacklisted
+    pub added_at: u64,
+}
+
+pub type Blacklist = BTreeMap<u64, BlacklistEntry>;
+
+fn blacklist_path(steam_game_id: u32) -> Result<PathBuf, S7forgeError> {
+    let dir = get_cache_dir()?.join("blacklist");
+    fs::create_dir_all(&dir)
+        .map_err(|e| S7forgeError::io(format!("Failed to create {}", dir.display()), e))?;
+    Ok(dir.join(format!("{}.json", steam_game_id)))
+}
+
+pub fn load_blacklist(steam_game_id: u32) -> Result<Blacklist, S7forgeError> {
+    let path = blacklist_path(steam_game_id)?;
+    if !path.exists() {
+        return Ok(Blacklist::new());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| S7forgeError::io(format!("Failed to read {}", path.display()), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| S7forgeError::parse(format!("Invalid {}", path.display()), e))
+}
+
+fn save_blacklist(steam_game_id: u32, blacklist: &Blacklist) -> Result<(), S7forgeError> {
+    let path = blacklist_path(steam_game_id)?;
+    let json = serde_json::to_string_pretty(blacklist)
+        .map_err(|e| S7forgeError::parse("Failed to serialize blacklist", e))?;
+    fs::write(&path, json)
+        .map_err(|e| S7forgeError::io(format!("Failed to write {}", path.display()), e))
+}
+
+/// Runs `action` and returns the affected entries keyed by item ID.
+pub fn blacklist(steam_game_id: u32, action: BlacklistAction) -> Result<Blacklist, S7forgeError> {
+    let mut blacklist = load_blacklist(steam_game_id)?;
+
+    match action {
+        BlacklistAction::Add { item_ids, reason } => {
+            let added_at = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let mut added = Blacklist::new();
+            for item_id in item_ids {
+                let entry = BlacklistEntry {
+                    reason: reason.clone(),
+                    added_at,
+                };
+                blacklist.insert(item_id, entry.clone());
+                added.insert(item_id, entry);
+            }
+            save_blacklist(steam_game_id, &blacklist)?;
+            Ok(added)
+        }
+        BlacklistAction::Remove { item_ids } => {
+            let removed = item_ids
+                .into_iter()
+                .filter_map(|item_id| blacklist.remove(&item_id).map(|entry| (item_id, entry)))
+                .collect();
+            save_blacklist(steam_game_id, &blacklist)?;
+            Ok(removed)
+        }
+        BlacklistAction::List => Ok(blacklist),
+    }
+}
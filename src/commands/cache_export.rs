@@ -0,0 +1,254 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::commands::workshop_items::{
+    WorkshopItemCache, read_item_cache, store_item_rows, write_item_cache,
+};
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::workshop_item::workshop::WorkshopItem;
+use crate::utils::cache_db::{self, CacheBackend};
+use crate::utils::fetch_creator_names::{cached_creator_names, store_creator_names};
+
+/// Bumped whenever the export layout changes incompatibly.
+pub const CACHE_EXPORT_VERSION: u32 = 1;
+
+/// The workshop item cache in a form that doesn't depend on the binary's cache encoding.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CacheExport {
+    pub version: u32,
+    pub exported_at: u64,
+    /// When the items were fetched from Steam, in seconds; imported entries expire from then
+    pub cached_at: u64,
+    pub items: Vec<WorkshopItem>,
+    /// Items Steam reported as deleted or hidden
+    #[serde(default)]
+    pub deleted_items: Vec<u64>,
+    /// Names of the items' creators, by SteamID64
+    #[serde(default)]
+    pub creator_names: FxHashMap<u64, String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CacheExportResult {
+    pub path: String,
+    pub version: u32,
+    pub item_count: usize,
+    pub deleted_count: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CacheImportResult {
+    pub path: String,
+    /// Items not in the cache before
+    pub added: usize,
+    /// Cached items replaced by the imported ones
+    pub replaced: usize,
+    pub deleted_count: usize,
+    pub creator_names: usize,
+}
+
+/// Writes the cached workshop items, optionally only those of one app, to a JSON file.
+pub fn cache_export(
+    app_id: Option<u32>,
+    output: String,
+) -> Result<CacheExportResult, S7forgeError> {
+    let cache = read_item_cache()?
+        .ok_or_else(|| S7forgeError::NotFound("The workshop item cache is empty".to_string()))?;
+
+    let mut items: Vec<WorkshopItem> = cache
+        .items
+        .into_values()
+        .filter(|item| app_id.is_none_or(|app_id| item.consumer_steam_game_id == Some(app_id)))
+        .collect();
+    items.sort_by_key(|item| item.published_file_id);
+
+    // Deleted items have no app, so they are only exported with the whole cache
+    let mut deleted_items: Vec<u64> = if app_id.is_none() {
+        cache.deleted_items.into_iter().collect()
+    } else {
+        Vec::new()
+    };
+    deleted_items.sort_unstable();
+
+    let cached_names = cached_creator_names();
+    let creator_names = items
+        .iter()
+        .filter_map(|item| {
+            let creator_id = item.owner.steam_id64;
+            cached_names
+                .get(&creator_id)
+                .map(|name| (creator_id, name.clone()))
+        })
+        .collect();
+
+    let export = CacheExport {
+        version: CACHE_EXPORT_VERSION,
+        exported_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64,
+        cached_at: cache.timestamp,
+        items,
+        deleted_items,
+        creator_names,
+    };
+
+    let path = Path::new(&output);
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)
+            .map_err(|e| S7forgeError::io(format!("Failed to create {}", parent.display()), e))?;
+    }
+    let serialized = serde_json::to_string_pretty(&export)
+        .map_err(|e| S7forgeError::parse("Failed to serialize cache export", e))?;
+    fs::write(path, serialized).map_err(|e| {
+        S7forgeError::io(
+            format!("Failed to write cache export {}", path.display()),
+            e,
+        )
+    })?;
+
+    Ok(CacheExportResult {
+        path: output,
+        version: export.version,
+        item_count: export.items.len(),
+        deleted_count: export.deleted_items.len(),
+    })
+}
+
+/// Merges an exported item cache into the local one. Imported items replace cached items with
+/// the same ID. The merged cache file keeps the older of the two cache times so nothing looks
+/// fresher than it is; SQLite rows are dated by the export.
+pub fn cache_import(input: String) -> Result<CacheImportResult, S7forgeError> {
+    let path = Path::new(&input);
+    let content = fs::read_to_string(path).map_err(|e| {
+        S7forgeError::io(format!("Failed to read cache export {}", path.display()), e)
+    })?;
+    let export: CacheExport = serde_json::from_str(&content)
+        .map_err(|e| S7forgeError::parse(format!("Invalid cache export {}", path.display()), e))?;
+    if export.version > CACHE_EXPORT_VERSION {
+        return Err(format!(
+            "Cache export version {} is newer than supported version {}",
+            export.version, CACHE_EXPORT_VERSION
+        )
+        .into());
+    }
+
+    let deleted_count = export.deleted_items.len();
+    let creator_names = export.creator_names.len();
+    // Nothing to merge, and the cache time must not move
+    if export.items.is_empty() && deleted_count == 0 {
+        return Ok(CacheImportResult {
+            path: input,
+            added: 0,
+            replaced: 0,
+            deleted_count,
+            creator_names: 0,
+        });
+    }
+
+    let (added, replaced) = if settings::get().cache_backend == CacheBackend::Sqlite {
+        import_rows(export.items, export.deleted_items, export.cached_at)?
+    } else {
+        import_into_file(export.items, export.deleted_items, export.cached_at)?
+    };
+
+    if creator_names > 0 {
+        store_creator_names(export.creator_names)?;
+    }
+
+    Ok(CacheImportResult {
+        path: input,
+        added,
+        replaced,
+        deleted_count,
+        creator_names,
+    })
+}
+
+// Rows keep their own cache time, so imported ones are simply dated by the export
+fn import_rows(
+    items: Vec<WorkshopItem>,
+    deleted_items: Vec<u64>,
+    cached_at: u64,
+) -> Result<(usize, usize), S7forgeError> {
+    let keys: Vec<String> = items
+        .iter()
+        .map(|item| item.published_file_id.to_string())
+        .collect();
+    let replaced = cache_db::get(cache_db::ITEMS, &keys)?
+        .iter()
+        .filter(|row| row.value.is_some())
+        .count();
+    let added = items.len() - replaced;
+
+    let imported = WorkshopItemCache {
+        items: items
+            .into_iter()
+            .map(|item| (item.published_file_id, item))
+            .collect(),
+        deleted_items: deleted_items.into_iter().collect(),
+        timestamp: cached_at,
+        last_access: FxHashMap::default(),
+    };
+    store_item_rows(&imported, now_secs())?;
+    Ok((added, replaced))
+}
+
+fn import_into_file(
+    items: Vec<WorkshopItem>,
+    deleted_items: Vec<u64>,
+    cached_at: u64,
+) -> Result<(usize, usize), S7forgeError> {
+    // An unreadable cache is replaced, just like a regular lookup would
+    let mut cache = read_item_cache()
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| WorkshopItemCache {
+            items: FxHashMap::default(),
+            deleted_items: FxHashSet::default(),
+            timestamp: cached_at,
+            last_access: FxHashMap::default(),
+        });
+    cache.timestamp = if cache.items.is_empty() && cache.deleted_items.is_empty() {
+        cached_at
+    } else {
+        cache.timestamp.min(cached_at)
+    };
+
+    let (mut added, mut replaced) = (0, 0);
+    let imported_ids: Vec<u64> = items
+        .iter()
+        .map(|item| item.published_file_id)
+        .chain(deleted_items.iter().copied())
+        .collect();
+    for item in items {
+        cache.deleted_items.remove(&item.published_file_id);
+        match cache.items.insert(item.published_file_id, item) {
+            Some(_) => replaced += 1,
+            None => added += 1,
+        }
+    }
+    for item_id in deleted_items {
+        cache.items.remove(&item_id);
+        cache.deleted_items.insert(item_id);
+    }
+    // Imported entries count as just used, so a size limit doesn't evict them right away
+    cache.touch(&imported_ids, now_secs());
+    write_item_cache(&mut cache)?;
+    Ok((added, replaced))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
@@ -0,0 +1,104 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::commands::clear_cache::{CacheKind, CacheSelection};
+use crate::commands::{
+    app_installation_path, search_workshop, steam_library_paths, workshop_items, workshop_path,
+};
+use crate::core::error::S7forgeError;
+use crate::utils::fetch_creator_names;
+
+/// One entry of a cache file as stored, whether or not it is still used.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CachedEntry {
+    pub cache: &'static str,
+    pub key: String,
+    #[serde(skip)]
+    pub app_id: Option<u32>,
+    #[serde(skip)]
+    pub item_id: Option<u64>,
+    /// Missing for caches without a lifetime (creator names)
+    pub cached_at: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub expired: bool,
+    pub value: Value,
+}
+
+impl CachedEntry {
+    pub fn new(cache: &'static str, key: String, value: Value) -> Self {
+        Self {
+            cache,
+            key,
+            app_id: None,
+            item_id: None,
+            cached_at: None,
+            expires_at: None,
+            expired: false,
+            value,
+        }
+    }
+
+    /// Sets when the entry was written (in seconds) and how long it is used for.
+    pub fn cached(mut self, cached_at: u64, ttl: Duration) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let expires_at = cached_at.saturating_add(ttl.as_secs());
+        self.cached_at = Some(cached_at);
+        self.expires_at = Some(expires_at);
+        self.expired = now >= expires_at;
+        self
+    }
+
+    pub fn app(mut self, app_id: u32) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    pub fn item(mut self, item_id: u64) -> Self {
+        self.item_id = Some(item_id);
+        self
+    }
+}
+
+/// Entries of every cache file belonging to `kind`.
+pub fn cached_entries(kind: CacheKind) -> Result<Vec<CachedEntry>, S7forgeError> {
+    let mut entries = Vec::new();
+    if matches!(kind, CacheKind::All | CacheKind::Items) {
+        entries.extend(workshop_items::cached_entries()?);
+    }
+    if matches!(kind, CacheKind::All | CacheKind::Paths) {
+        entries.extend(workshop_path::cached_entries()?);
+        entries.extend(app_installation_path::cached_entries()?);
+    }
+    if matches!(kind, CacheKind::All | CacheKind::Library) {
+        entries.extend(steam_library_paths::cached_entries()?);
+    }
+    if matches!(kind, CacheKind::All | CacheKind::Creators) {
+        entries.extend(fetch_creator_names::cached_entries()?);
+    }
+    if matches!(kind, CacheKind::All | CacheKind::Searches) {
+        entries.extend(search_workshop::cached_entries()?);
+    }
+    Ok(entries)
+}
+
+/// Cached entries as stored on disk, including expired ones, for checking what a command
+/// would be answered with. `--app-id` keeps entries of that app; `--item-ids` keeps those items.
+pub fn cache_inspect(selection: CacheSelection) -> Result<Vec<CachedEntry>, S7forgeError> {
+    let mut entries = cached_entries(selection.kind)?;
+    if let Some(app_id) = selection.app_id {
+        entries.retain(|entry| entry.app_id == Some(app_id));
+    }
+    if !selection.item_ids.is_empty() {
+        entries.retain(|entry| {
+            entry
+                .item_id
+                .is_some_and(|item_id| selection.item_ids.contains(&item_id))
+        });
+    }
+    Ok(entries)
+}
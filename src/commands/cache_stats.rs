@@ -0,0 +1,123 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::commands::cache_inspect::{CachedEntry, cached_entries};
+use crate::commands::clear_cache::CacheKind;
+use crate::core::error::S7forgeError;
+use crate::utils::cache_counters::{self, RunCounters};
+use crate::utils::cache_db;
+use crate::utils::get_cache_dir::get_cache_dir;
+
+#[derive(Serialize, JsonSchema)]
+pub struct CacheStats {
+    pub cache_dir: String,
+    pub total_bytes: u64,
+    pub caches: Vec<CacheFileStats>,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct CacheFileStats {
+    pub kind: &'static str,
+    pub file: &'static str,
+    pub size_bytes: u64,
+    pub entries: usize,
+    /// Entries past their lifetime; they are refreshed on the next lookup
+    pub expired_entries: usize,
+    pub modified_at: Option<u64>,
+    pub age_seconds: Option<u64>,
+    /// Hits and misses of the last run that used this cache
+    pub last_run: Option<RunCounters>,
+    /// Set when the file can't be decoded, e.g. after an upgrade changed its format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+pub fn cache_stats() -> Result<CacheStats, S7forgeError> {
+    let cache_dir = get_cache_dir()?;
+    let counters = cache_counters::load();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let mut caches = Vec::new();
+    for kind in CacheKind::KINDS {
+        let entries = cached_entries(kind);
+        for &file in kind.files() {
+            let Ok(metadata) = fs::metadata(cache_dir.join(file)) else {
+                continue;
+            };
+            let modified_at = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                .map(|modified| modified.as_secs());
+
+            let (entry_count, expired_entries, error) = count_entries(&entries, file);
+
+            caches.push(CacheFileStats {
+                kind: kind.name(),
+                file,
+                size_bytes: metadata.len(),
+                entries: entry_count,
+                expired_entries,
+                modified_at,
+                age_seconds: modified_at.map(|modified| now.saturating_sub(modified)),
+                last_run: counters.get(file).cloned(),
+                error,
+            });
+        }
+
+        // Rows of this kind in the SQLite cache, counted under the database file
+        if cache_dir.join(cache_db::DB_FILE).exists()
+            && matches!(
+                kind,
+                CacheKind::Items | CacheKind::Creators | CacheKind::Searches
+            )
+        {
+            let (entry_count, expired_entries, error) = count_entries(&entries, cache_db::DB_FILE);
+            if entry_count == 0 && error.is_none() {
+                continue;
+            }
+            let (size_bytes, modified_at) = cache_db::size(kind.name())?;
+            caches.push(CacheFileStats {
+                kind: kind.name(),
+                file: cache_db::DB_FILE,
+                size_bytes,
+                entries: entry_count,
+                expired_entries,
+                modified_at,
+                age_seconds: modified_at.map(|modified| now.saturating_sub(modified)),
+                last_run: kind
+                    .files()
+                    .first()
+                    .and_then(|file| counters.get(*file))
+                    .cloned(),
+                error,
+            });
+        }
+    }
+
+    Ok(CacheStats {
+        cache_dir: cache_dir.to_string_lossy().into_owned(),
+        total_bytes: caches.iter().map(|cache| cache.size_bytes).sum(),
+        caches,
+    })
+}
+
+/// Entries and expired entries of one cache file, or why they couldn't be read.
+fn count_entries(
+    entries: &Result<Vec<CachedEntry>, S7forgeError>,
+    file: &str,
+) -> (usize, usize, Option<String>) {
+    match entries {
+        Ok(entries) => {
+            let file_entries = entries.iter().filter(|entry| entry.cache == file);
+            let expired = file_entries.clone().filter(|entry| entry.expired).count();
+            (file_entries.count(), expired, None)
+        }
+        Err(error) => (0, 0, Some(error.to_string())),
+    }
+}
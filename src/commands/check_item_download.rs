@@ -1,11 +1,14 @@
 use futures_util::FutureExt;
+use schemars::JsonSchema;
 use serde::Serialize;
 use steamworks::PublishedFileId;
 use tokio::sync::mpsc;
 
+use crate::core::error::S7forgeError;
+use crate::core::settings;
 use crate::core::steam_manager;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct DownloadInfo {
     pub is_downloading: bool,
     pub downloaded_bytes: u64,
@@ -14,57 +17,22 @@ pub struct DownloadInfo {
     pub download_complete: bool,
 }
 
-pub async fn check_item_download(steam_game_id: u32, item_id: u64) -> Result<DownloadInfo, String> {
+pub async fn check_item_download(
+    steam_game_id: u32,
+    item_id: u64,
+) -> Result<DownloadInfo, S7forgeError> {
     let steam_client = steam_manager::initialize_client(steam_game_id).await?;
 
     let (tx, mut rx) = mpsc::channel(32);
 
     let download_task = tokio::task::spawn_blocking(move || {
-        let ugc = steam_client.ugc();
-        let item = PublishedFileId(item_id);
-
         let (tx_inner, rx_inner) = std::sync::mpsc::channel();
 
         let start_time = std::time::Instant::now();
-        let timeout_duration = std::time::Duration::from_secs(30);
-
-        let state = ugc.item_state(item);
-        let is_installed = state.contains(steamworks::ItemState::INSTALLED);
-        let is_downloading = state.contains(steamworks::ItemState::DOWNLOADING);
-        let needs_update = state.contains(steamworks::ItemState::NEEDS_UPDATE);
-
-        if is_installed && !needs_update {
-            let _ = tx_inner.send(Ok(DownloadInfo {
-                is_downloading: false,
-                downloaded_bytes: ugc.item_install_info(item).unwrap().size_on_disk,
-                total_bytes: ugc.item_install_info(item).unwrap().size_on_disk,
-                progress_percentage: 100.0,
-                download_complete: true,
-            }));
-        } else if is_downloading {
-            if let Some((bytes_downloaded, bytes_total)) = ugc.item_download_info(item) {
-                let progress = if bytes_total > 0 {
-                    (bytes_downloaded as f32 / bytes_total as f32) * 100.0
-                } else {
-                    0.0
-                };
+        let timeout_duration = settings::get().steam_timeout;
 
-                let _ = tx_inner.send(Ok(DownloadInfo {
-                    is_downloading: true,
-                    downloaded_bytes: bytes_downloaded,
-                    total_bytes: bytes_total,
-                    progress_percentage: progress,
-                    download_complete: false,
-                }));
-            }
-        } else {
-            let _ = tx_inner.send(Ok(DownloadInfo {
-                is_downloading: false,
-                downloaded_bytes: 0,
-                total_bytes: 0,
-                progress_percentage: 0.0,
-                download_complete: false,
-            }));
+        if let Some(info) = read_download_info(&steam_client, item_id) {
+            let _ = tx_inner.send(Ok(info));
         }
 
         loop {
@@ -74,7 +42,7 @@ pub async fn check_item_download(steam_game_id: u32, item_id: u64) -> Result<Dow
             }
 
             if start_time.elapsed() > timeout_duration {
-                return Err("Operation timed out waiting for Steam response".to_string());
+                return Err(S7forgeError::Timeout("Operation"));
             }
 
             std::thread::sleep(std::time::Duration::from_millis(10));
@@ -90,10 +58,96 @@ pub async fn check_item_download(steam_game_id: u32, item_id: u64) -> Result<Dow
                 steam_manager::run_callbacks(steam_game_id)?;
             }
             task_result = &mut fused_task => {
-                download_result = Some(task_result.map_err(|e| format!("Task error: {:?}", e))??);
+                download_result = Some(task_result.map_err(S7forgeError::Join)??);
             }
         }
     }
 
     Ok(download_result.unwrap())
 }
+
+/// Download/install info for several items from one Steam session, keyed by item ID.
+pub async fn check_items_download(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<serde_json::Map<String, serde_json::Value>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let infos = tokio::task::spawn_blocking(move || {
+        item_ids
+            .into_iter()
+            .map(|item_id| (item_id, read_download_info(&steam_client, item_id)))
+            .collect::<Vec<_>>()
+    })
+    .await
+    .map_err(S7forgeError::Join)?;
+
+    let mut result = serde_json::Map::new();
+    for (item_id, info) in infos {
+        // Steam hasn't reported progress for a download it just started yet
+        let info = info.unwrap_or(DownloadInfo {
+            is_downloading: true,
+            downloaded_bytes: 0,
+            total_bytes: 0,
+            progress_percentage: 0.0,
+            download_complete: false,
+        });
+        result.insert(
+            item_id.to_string(),
+            serde_json::to_value(info)
+                .map_err(|e| S7forgeError::parse("Failed to serialize download info", e))?,
+        );
+    }
+
+    Ok(result)
+}
+
+// Returns None while a download is in progress but Steam has no byte counts for it yet
+fn read_download_info(steam_client: &steamworks::Client, item_id: u64) -> Option<DownloadInfo> {
+    let ugc = steam_client.ugc();
+    let item = PublishedFileId(item_id);
+
+    let state = ugc.item_state(item);
+    let is_installed = state.contains(steamworks::ItemState::INSTALLED);
+    let is_downloading = state.contains(steamworks::ItemState::DOWNLOADING);
+    let needs_update = state.contains(steamworks::ItemState::NEEDS_UPDATE);
+
+    if is_installed && !needs_update {
+        let size_on_disk = ugc
+            .item_install_info(item)
+            .map(|info| info.size_on_disk)
+            .unwrap_or_default();
+        Some(DownloadInfo {
+            is_downloading: false,
+            downloaded_bytes: size_on_disk,
+            total_bytes: size_on_disk,
+            progress_percentage: 100.0,
+            download_complete: true,
+        })
+    } else if is_downloading {
+        ugc.item_download_info(item)
+            .map(|(bytes_downloaded, bytes_total)| {
+                let progress = if bytes_total > 0 {
+                    (bytes_downloaded as f32 / bytes_total as f32) * 100.0
+                } else {
+                    0.0
+                };
+
+                DownloadInfo {
+                    is_downloading: true,
+                    downloaded_bytes: bytes_downloaded,
+                    total_bytes: bytes_total,
+                    progress_percentage: progress,
+                    download_complete: false,
+                }
+            })
+    } else {
+        Some(DownloadInfo {
+            is_downloading: false,
+            downloaded_bytes: 0,
+            total_bytes: 0,
+            progress_percentage: 0.0,
+            download_complete: false,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::AppId;
+
+use crate::commands::steam_status::STEAM_STATUS_APP_ID;
+use crate::core::error::S7forgeError;
+use crate::core::localplayer::PlayerSteamId;
+use crate::core::steam_manager;
+
+/// How the signed-in account has access to an app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum License {
+    /// Bought or activated by the account itself
+    Owned,
+    /// Borrowed from another account's library; `owner` is that account
+    FamilyShared,
+    /// Playable during a free weekend only
+    FreeWeekend,
+    /// Has access, but Steamworks couldn't be initialized for the app to tell how
+    Unknown,
+    NotOwned,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AppOwnership {
+    pub app_id: u32,
+    /// Any license, including family sharing and free weekends
+    pub subscribed: bool,
+    pub license: License,
+    /// The account whose license is used, when known
+    pub owner: Option<PlayerSteamId>,
+    pub installed: bool,
+    /// Why Steamworks couldn't be initialized for the app itself
+    pub init_error: Option<String>,
+}
+
+/// Initializes Steamworks for the app to tell owned, family-shared and free-weekend access
+/// apart. When that fails, e.g. because the account has no license, ownership is asked
+/// through Spacewar instead.
+pub async fn check_ownership(app_id: u32) -> Result<AppOwnership, S7forgeError> {
+    let error = match steam_manager::initialize_client(app_id).await {
+        Ok(client) => {
+            let apps = client.apps();
+            let subscribed = apps.is_subscribed();
+            let owner = apps.app_owner();
+            let license = if !subscribed {
+                License::NotOwned
+            } else if apps.is_subscribed_from_free_weekend() {
+                License::FreeWeekend
+            } else if owner != client.user().steam_id() {
+                License::FamilyShared
+            } else {
+                License::Owned
+            };
+            return Ok(AppOwnership {
+                app_id,
+                subscribed,
+                license,
+                owner: subscribed.then(|| PlayerSteamId::from_steamid(owner)),
+                installed: apps.is_app_installed(AppId(app_id)),
+                init_error: None,
+            });
+        }
+        Err(error @ S7forgeError::SteamInit(_)) if app_id != STEAM_STATUS_APP_ID => error,
+        Err(error) => return Err(error),
+    };
+
+    tracing::debug!("Checking ownership through Spacewar: {}", error);
+    let client = steam_manager::initialize_client(STEAM_STATUS_APP_ID).await?;
+    let apps = client.apps();
+    let subscribed = apps.is_subscribed_app(AppId(app_id));
+    Ok(AppOwnership {
+        app_id,
+        subscribed,
+        license: if subscribed {
+            License::Unknown
+        } else {
+            License::NotOwned
+        },
+        owner: None,
+        installed: apps.is_app_installed(AppId(app_id)),
+        init_error: Some(error.to_string()),
+    })
+}
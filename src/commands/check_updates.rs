@@ -0,0 +1,151 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+use steamworks::{ItemState, PublishedFileId};
+use tokio::task;
+
+use crate::commands::subscriptions::subscribed_item_ids;
+use crate::commands::workshop_items::workshop_items;
+use crate::core::error::S7forgeError;
+use crate::core::feed::{FeedEntry, item_url, rss};
+use crate::core::steam_manager;
+
+/// Exit code of `check-updates --quiet-if-none` when updates are pending; errors exit with 1.
+pub const UPDATES_AVAILABLE_EXIT_CODE: i32 = 10;
+
+#[derive(Debug, Clone, Copy, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateReason {
+    /// Steam itself flags the installed copy as outdated
+    NeedsUpdate,
+    /// The Workshop copy was updated after the local install
+    NewerOnWorkshop,
+    NotInstalled,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PendingUpdate {
+    pub item_id: u64,
+    pub title: Option<String>,
+    pub reason: UpdateReason,
+    pub installed_at: Option<u64>,
+    pub time_updated: Option<u64>,
+}
+
+struct LocalState {
+    item_id: u64,
+    needs_update: bool,
+    downloading: bool,
+    installed_at: Option<u64>,
+}
+
+pub async fn check_updates(steam_game_id: u32) -> Result<Vec<PendingUpdate>, S7forgeError> {
+    let item_ids = subscribed_item_ids(steam_game_id).await?;
+    if item_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let local_states: Vec<LocalState> = task::spawn_blocking({
+        let item_ids = item_ids.clone();
+        move || {
+            let ugc = steam_client.ugc();
+            item_ids
+                .into_iter()
+                .map(|item_id| {
+                    let item = PublishedFileId(item_id);
+                    let state = ugc.item_state(item);
+                    LocalState {
+                        item_id,
+                        needs_update: state.contains(ItemState::NEEDS_UPDATE),
+                        downloading: state.contains(ItemState::DOWNLOADING)
+                            || state.contains(ItemState::DOWNLOAD_PENDING),
+                        installed_at: state
+                            .contains(ItemState::INSTALLED)
+                            .then(|| ugc.item_install_info(item))
+                            .flatten()
+                            .map(|info| (info.timestamp as u64).saturating_mul(1000)),
+                    }
+                })
+                .collect()
+        }
+    })
+    .await
+    .map_err(S7forgeError::Join)?;
+
+    // Metadata may come from the workshop items cache, so time_updated can lag behind by up
+    // to a day; it only adds to what Steam's own NeedsUpdate flag already reports
+    let details = workshop_items(steam_game_id, item_ids).await?;
+
+    Ok(local_states
+        .into_iter()
+        .filter(|local| !local.downloading)
+        .filter_map(|local| {
+            let item = details
+                .iter()
+                .map(|enhanced| &enhanced.workshop_item)
+                .find(|item| item.published_file_id == local.item_id);
+            let time_updated = item.map(|item| item.time_updated);
+
+            let reason = if local.needs_update {
+                UpdateReason::NeedsUpdate
+            } else if local.installed_at.is_none() {
+                UpdateReason::NotInstalled
+            } else if time_updated
+                .zip(local.installed_at)
+                .is_some_and(|(updated, installed)| updated > installed)
+            {
+                UpdateReason::NewerOnWorkshop
+            } else {
+                return None;
+            };
+
+            Some(PendingUpdate {
+                item_id: local.item_id,
+                title: item.map(|item| item.title.clone()),
+                reason,
+                installed_at: local.installed_at,
+                time_updated,
+            })
+        })
+        .collect())
+}
+
+/// The pending updates as an RSS feed, newest first.
+pub fn updates_feed(steam_game_id: u32, updates: &[PendingUpdate]) -> String {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default();
+
+    let mut entries: Vec<FeedEntry> = updates
+        .iter()
+        .map(|update| {
+            let published_ms = update.time_updated.unwrap_or(now_ms);
+            FeedEntry {
+                guid: format!("{}-{}", update.item_id, published_ms),
+                title: update
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| format!("Workshop item {}", update.item_id)),
+                link: item_url(update.item_id),
+                description: match update.reason {
+                    UpdateReason::NeedsUpdate => "Steam reports the installed copy as outdated",
+                    UpdateReason::NewerOnWorkshop => {
+                        "Updated on the Workshop since it was installed"
+                    }
+                    UpdateReason::NotInstalled => "Subscribed but not installed",
+                }
+                .to_string(),
+                published_ms,
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| b.published_ms.cmp(&a.published_ms));
+
+    rss(
+        &format!("Workshop updates for app {}", steam_game_id),
+        "Subscribed workshop items with pending updates",
+        &entries,
+    )
+}
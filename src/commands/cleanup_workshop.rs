@@ -0,0 +1,93 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::commands::installed_items::remove_manifest_entries;
+use crate::commands::orphaned_items::orphaned_items;
+use crate::commands::subscriptions::subscribed_item_ids;
+use crate::core::error::S7forgeError;
+use crate::utils::remove_path::remove_path;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CleanedFolder {
+    pub item_id: u64,
+    pub path: String,
+    pub size_bytes: u64,
+    pub deleted: bool,
+    /// The item was also removed from Steam's appworkshop manifest
+    pub manifest_entry_removed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CleanupResult {
+    pub dry_run: bool,
+    pub folders: Vec<CleanedFolder>,
+    /// Bytes freed, or that would be freed with --dry-run
+    pub bytes_reclaimed: u64,
+}
+
+/// Deletes every downloaded item folder that is no longer subscribed (see `orphaned-items`),
+/// together with its appworkshop manifest entry. Folders that aren't named after an item are
+/// left alone.
+pub async fn cleanup_workshop(
+    steam_game_id: u32,
+    dry_run: bool,
+) -> Result<CleanupResult, S7forgeError> {
+    // An empty list usually means Steam answered nothing or another account is signed in,
+    // and would mark every downloaded item as orphaned
+    if subscribed_item_ids(steam_game_id).await?.is_empty() {
+        return Err(S7forgeError::NotFound(format!(
+            "No subscribed items found for app ID {}; refusing to delete every downloaded item",
+            steam_game_id
+        )));
+    }
+
+    let orphans = orphaned_items(steam_game_id).await?;
+
+    let folders: Vec<CleanedFolder> = orphans
+        .folders
+        .into_iter()
+        .filter_map(|folder| {
+            let item_id = folder.item_id?;
+            let mut cleaned = CleanedFolder {
+                item_id,
+                path: folder.path,
+                size_bytes: folder.size_bytes,
+                deleted: false,
+                manifest_entry_removed: false,
+                error: None,
+            };
+            if dry_run {
+                return Some(cleaned);
+            }
+
+            if let Err(e) = remove_path(Path::new(&cleaned.path)) {
+                cleaned.error = Some(format!("Failed to delete {}: {}", cleaned.path, e));
+                return Some(cleaned);
+            }
+            cleaned.deleted = true;
+
+            if folder.in_manifest {
+                match remove_manifest_entries(steam_game_id, item_id) {
+                    Ok(removed) => cleaned.manifest_entry_removed = removed,
+                    Err(error) => cleaned.error = Some(error.to_string()),
+                }
+            }
+            Some(cleaned)
+        })
+        .collect();
+
+    let bytes_reclaimed = folders
+        .iter()
+        .filter(|folder| dry_run || folder.deleted)
+        .map(|folder| folder.size_bytes)
+        .sum();
+
+    Ok(CleanupResult {
+        dry_run,
+        folders,
+        bytes_reclaimed,
+    })
+}
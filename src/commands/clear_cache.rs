@@ -1,17 +1,95 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fs;
 
+use crate::commands::{app_installation_path, workshop_items, workshop_path};
+use crate::core::error::S7forgeError;
+use crate::utils::cache_db;
 use crate::utils::get_cache_dir::get_cache_dir;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, JsonSchema, Deserialize)]
 pub struct ClearCacheResult {
     pub success: bool,
     pub message: String,
     pub files_cleared: usize,
     pub files: Vec<String>,
+    /// Entries evicted from cache files that were kept (with --app-id or --item-ids) or from
+    /// the SQLite cache
+    pub entries_cleared: usize,
 }
 
-pub fn clear_cache() -> Result<ClearCacheResult, String> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheKind {
+    All,
+    Items,
+    Paths,
+    Library,
+    Creators,
+    Searches,
+}
+
+impl CacheKind {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "all" => Ok(Self::All),
+            "items" => Ok(Self::Items),
+            "paths" => Ok(Self::Paths),
+            "library" => Ok(Self::Library),
+            "creators" => Ok(Self::Creators),
+            "searches" => Ok(Self::Searches),
+            other => Err(format!(
+                "Invalid cache: {} (expected items, paths, library, creators, searches or all)",
+                other
+            )),
+        }
+    }
+
+    /// Every kind except `All`
+    pub const KINDS: [CacheKind; 5] = [
+        Self::Items,
+        Self::Paths,
+        Self::Library,
+        Self::Creators,
+        Self::Searches,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Items => "items",
+            Self::Paths => "paths",
+            Self::Library => "library",
+            Self::Creators => "creators",
+            Self::Searches => "searches",
+        }
+    }
+
+    pub fn files(self) -> &'static [&'static str] {
+        match self {
+            Self::All => &[],
+            Self::Items => &["workshop_items_cache.bin"],
+            Self::Paths => &["workshop_path_cache.bin", "app_install_path_cache.bin"],
+            Self::Library => &["library_paths_cache.bin"],
+            Self::Creators => &["creator_names_cache.bin"],
+            Self::Searches => &["search_workshop_cache.bin"],
+        }
+    }
+}
+
+/// What `clear-cache` removes. With an app or item IDs only the matching entries are evicted
+/// and the cache files themselves are kept.
+#[derive(Debug)]
+pub struct CacheSelection {
+    pub kind: CacheKind,
+    pub app_id: Option<u32>,
+    pub item_ids: Vec<u64>,
+}
+
+pub fn clear_cache(selection: CacheSelection) -> Result<ClearCacheResult, S7forgeError> {
+    if selection.app_id.is_some() || !selection.item_ids.is_empty() {
+        return evict_entries(&selection);
+    }
+
     let cache_dir = get_cache_dir()?;
 
     if !cache_dir.exists() {
@@ -20,20 +98,28 @@ pub fn clear_cache() -> Result<ClearCacheResult, String> {
             message: "Cache directory does not exist, nothing to clear".to_string(),
             files_cleared: 0,
             files: Vec::new(),
+            entries_cleared: 0,
         });
     }
 
     let mut cleared_files = Vec::new();
     let mut errors = Vec::new();
 
-    let entries =
-        fs::read_dir(&cache_dir).map_err(|e| format!("Failed to read cache directory: {:?}", e))?;
+    let entries = fs::read_dir(&cache_dir)
+        .map_err(|e| S7forgeError::io("Failed to read cache directory", e))?;
 
     for entry in entries {
         match entry {
             Ok(file_entry) => {
                 let file_path = file_entry.path();
-                if file_path.is_file() {
+                let selected = selection.kind == CacheKind::All
+                    || file_path.file_name().is_some_and(|name| {
+                        selection
+                            .kind
+                            .files()
+                            .contains(&name.to_string_lossy().as_ref())
+                    });
+                if file_path.is_file() && selected {
                     match fs::remove_file(&file_path) {
                         Ok(_) => {
                             if let Some(file_name) = file_path.file_name() {
@@ -56,15 +142,33 @@ pub fn clear_cache() -> Result<ClearCacheResult, String> {
         return Err(format!(
             "Errors occurred while clearing cache: {}",
             errors.join(", ")
-        ));
+        )
+        .into());
     }
 
-    let result = if cleared_files.is_empty() {
+    // The SQLite cache holds several kinds, so only the rows of this one are removed
+    let entries_cleared = match selection.kind {
+        CacheKind::Items | CacheKind::Creators | CacheKind::Searches => {
+            cache_db::clear(selection.kind.name())?
+        }
+        _ => 0,
+    };
+
+    let result = if cleared_files.is_empty() && entries_cleared == 0 {
         ClearCacheResult {
             success: true,
             message: "Cache directory was already empty".to_string(),
             files_cleared: 0,
             files: Vec::new(),
+            entries_cleared: 0,
+        }
+    } else if cleared_files.is_empty() {
+        ClearCacheResult {
+            success: true,
+            message: format!("Successfully cleared {} cache entries", entries_cleared),
+            files_cleared: 0,
+            files: Vec::new(),
+            entries_cleared,
         }
     } else {
         ClearCacheResult {
@@ -72,8 +176,38 @@ pub fn clear_cache() -> Result<ClearCacheResult, String> {
             message: format!("Successfully cleared {} cache files", cleared_files.len()),
             files_cleared: cleared_files.len(),
             files: cleared_files,
+            entries_cleared,
         }
     };
 
     Ok(result)
 }
+
+// Only the item and path caches are keyed by item or app, so `all` narrows to those two
+fn evict_entries(selection: &CacheSelection) -> Result<ClearCacheResult, S7forgeError> {
+    let kind = selection.kind;
+    let mut entries_cleared = 0;
+
+    if matches!(kind, CacheKind::All | CacheKind::Items) {
+        entries_cleared +=
+            workshop_items::evict_cached_items(selection.app_id, &selection.item_ids)?;
+    }
+    if matches!(kind, CacheKind::All | CacheKind::Paths) {
+        if let Some(app_id) = selection.app_id {
+            entries_cleared += workshop_path::evict_cached_path(app_id)? as usize;
+            entries_cleared += app_installation_path::evict_cached_path(app_id)? as usize;
+        }
+    }
+
+    Ok(ClearCacheResult {
+        success: true,
+        message: if entries_cleared == 0 {
+            "No matching cache entries".to_string()
+        } else {
+            format!("Successfully cleared {} cache entries", entries_cleared)
+        },
+        files_cleared: 0,
+        files: Vec::new(),
+        entries_cleared,
+    })
+}
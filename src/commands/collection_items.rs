@@ -1,12 +1,16 @@
 use futures_util::FutureExt;
+use schemars::JsonSchema;
 use serde::Serialize;
 use steamworks::PublishedFileId;
 use tokio::sync::mpsc;
 
 use super::workshop_items::{EnhancedWorkshopItem, workshop_items};
+use crate::core::error::S7forgeError;
+use crate::core::settings;
 use crate::core::steam_manager;
+use crate::core::web_api;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct CollectionInfo {
     pub id: u64,
     pub title: String,
@@ -18,7 +22,7 @@ pub struct CollectionInfo {
     pub num_downvotes: u32,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct CollectionDetails {
     pub details: CollectionInfo,
     pub items: Vec<EnhancedWorkshopItem>,
@@ -27,8 +31,11 @@ pub struct CollectionDetails {
 pub async fn collection_items(
     steam_game_id: u32,
     item_id: u64,
-) -> Result<CollectionDetails, String> {
-    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+) -> Result<CollectionDetails, S7forgeError> {
+    let Some(steam_client) = steam_manager::initialize_client_or_fallback(steam_game_id).await
+    else {
+        return collection_items_from_web_api(steam_game_id, item_id).await;
+    };
 
     let (tx, mut rx) = mpsc::channel(32);
 
@@ -54,7 +61,9 @@ pub async fn collection_items(
                         .unwrap();
 
                     if collection_info.file_type != steamworks::FileType::Collection {
-                        let _ = tx_inner.send(Err("Item is not a collection".to_string()));
+                        let _ = tx_inner.send(Err(S7forgeError::InvalidArgs(
+                            "Item is not a collection".into(),
+                        )));
                         return;
                     }
 
@@ -86,12 +95,12 @@ pub async fn collection_items(
                     let _ = tx_inner.send(Ok(collection_details));
                 }
                 Err(e) => {
-                    let _ = tx_inner.send(Err(format!("Steam API error: {}", e)));
+                    let _ = tx_inner.send(Err(S7forgeError::steam_api(e)));
                 }
             });
 
         let start_time = std::time::Instant::now();
-        let timeout_duration = std::time::Duration::from_secs(30);
+        let timeout_duration = settings::get().steam_timeout;
 
         loop {
             let _ = tx.blocking_send(());
@@ -100,7 +109,7 @@ pub async fn collection_items(
             }
 
             if start_time.elapsed() > timeout_duration {
-                return Err("Operation timed out waiting for Steam response".to_string());
+                return Err(S7forgeError::Timeout("Operation"));
             }
 
             std::thread::sleep(std::time::Duration::from_millis(10));
@@ -116,7 +125,7 @@ pub async fn collection_items(
                 steam_manager::run_callbacks(steam_game_id)?;
             }
             task_result = &mut fused_task => {
-                collection_result = Some(task_result.map_err(|e| format!("Task error: {:?}", e))??);
+                collection_result = Some(task_result.map_err(S7forgeError::Join)??);
             }
         }
     }
@@ -129,3 +138,39 @@ pub async fn collection_items(
         items,
     })
 }
+
+async fn collection_items_from_web_api(
+    steam_game_id: u32,
+    item_id: u64,
+) -> Result<CollectionDetails, S7forgeError> {
+    let (collection_info, item_ids) = tokio::task::spawn_blocking(move || {
+        let collection = web_api::published_file_details(&[item_id])?
+            .into_iter()
+            .next()
+            .ok_or_else(|| S7forgeError::NotFound("Collection not found".into()))?;
+        let item_ids = web_api::collection_children(item_id)?;
+
+        Ok::<_, S7forgeError>((
+            CollectionInfo {
+                id: collection.published_file_id,
+                title: collection.title,
+                description: collection.description,
+                preview_url: collection.preview_url,
+                time_created: collection.time_created as u128,
+                time_updated: collection.time_updated as u128,
+                num_upvotes: collection.num_upvotes,
+                num_downvotes: collection.num_downvotes,
+            },
+            item_ids,
+        ))
+    })
+    .await
+    .map_err(S7forgeError::Join)??;
+
+    let items = workshop_items(steam_game_id, item_ids).await?;
+
+    Ok(CollectionDetails {
+        details: collection_info,
+        items,
+    })
+}
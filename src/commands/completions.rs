@@ -0,0 +1,347 @@
+use std::fmt::Write;
+
+use crate::command_table::{COMMANDS, CommandSpec, GLOBAL_OPTIONS, HELP, OptionSpec, ValueHint};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+impl Shell {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            "powershell" => Ok(Self::Powershell),
+            other => Err(format!(
+                "Invalid shell: {} (expected bash, zsh, fish or powershell)",
+                other
+            )),
+        }
+    }
+}
+
+/// Completion script for `shell`, generated from the command table.
+pub fn completions(shell: Shell) -> String {
+    match shell {
+        Shell::Bash => bash(),
+        Shell::Zsh => zsh(),
+        Shell::Fish => fish(),
+        Shell::Powershell => powershell(),
+    }
+}
+
+fn command_options(command: &CommandSpec) -> impl Iterator<Item = &OptionSpec> + Clone {
+    command.options.iter().chain([&HELP])
+}
+
+// Long and short names of the options
+fn option_words<'a>(options: impl Iterator<Item = &'a OptionSpec>) -> Vec<&'a str> {
+    options
+        .flat_map(|option| option.short.into_iter().chain([option.name]))
+        .collect()
+}
+
+fn takes_value(option: &OptionSpec) -> bool {
+    !matches!(option.value, ValueHint::None)
+}
+
+fn bash() -> String {
+    let mut script = String::new();
+    let global_values: Vec<&str> = option_words(GLOBAL_OPTIONS.iter().filter(|o| takes_value(o)));
+
+    script.push_str("# bash completion for s7forge\n");
+    script.push_str("_s7forge() {\n");
+    script.push_str("    local cur=\"${COMP_WORDS[COMP_CWORD]}\"\n");
+    script.push_str("    local prev=\"${COMP_WORDS[COMP_CWORD-1]}\"\n");
+    script.push_str("    local command=\"\" i\n\n");
+    script.push_str("    for ((i = 1; i < COMP_CWORD; i++)); do\n");
+    script.push_str("        case \"${COMP_WORDS[i]}\" in\n");
+    let _ = writeln!(
+        script,
+        "            {}) ((i++)) ;;",
+        global_values.join("|")
+    );
+    script.push_str("            -*) ;;\n");
+    script.push_str("            *) command=\"${COMP_WORDS[i]}\"; break ;;\n");
+    script.push_str("        esac\n");
+    script.push_str("    done\n\n");
+    script.push_str("    case \"$command\" in\n");
+
+    let commands: Vec<&str> = COMMANDS.iter().map(|command| command.name).collect();
+    bash_case(&mut script, "\"\"", GLOBAL_OPTIONS.iter(), &commands);
+    for command in COMMANDS {
+        bash_case(
+            &mut script,
+            command.name,
+            command_options(command),
+            command.actions,
+        );
+    }
+
+    script.push_str("    esac\n");
+    script.push_str("}\n\n");
+    script.push_str("complete -F _s7forge s7forge\n");
+    script
+}
+
+// One `case` branch: values of the previous option, otherwise the words that may come next
+fn bash_case<'a>(
+    script: &mut String,
+    pattern: &str,
+    options: impl Iterator<Item = &'a OptionSpec> + Clone,
+    words: &[&str],
+) {
+    let _ = writeln!(script, "        {})", pattern);
+    script.push_str("            case \"$prev\" in\n");
+    for option in options.clone() {
+        let reply = match option.value {
+            ValueHint::None => continue,
+            ValueHint::Any => "return".to_string(),
+            ValueHint::Path => "COMPREPLY=($(compgen -f -- \"$cur\")); return".to_string(),
+            ValueHint::Choices(choices) => format!(
+                "COMPREPLY=($(compgen -W \"{}\" -- \"$cur\")); return",
+                choices().join(" ")
+            ),
+        };
+        let _ = writeln!(script, "                {}) {} ;;", option.name, reply);
+    }
+    script.push_str("            esac\n");
+    let mut next: Vec<&str> = words.to_vec();
+    next.extend(option_words(options));
+    let _ = writeln!(
+        script,
+        "            COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))",
+        next.join(" ")
+    );
+    script.push_str("            ;;\n");
+}
+
+fn zsh() -> String {
+    let mut script = String::new();
+    script.push_str("#compdef s7forge\n\n");
+    script.push_str("_s7forge() {\n");
+    script.push_str("    local context state state_descr line\n");
+    script.push_str("    typeset -A opt_args\n\n");
+    script.push_str("    _arguments -C \\\n");
+    for option in GLOBAL_OPTIONS {
+        let _ = writeln!(script, "        {} \\", zsh_option(option));
+    }
+    script.push_str("        '1:command:->command' \\\n");
+    script.push_str("        '*::arg:->args'\n\n");
+    script.push_str("    case $state in\n");
+    script.push_str("        command)\n");
+    script.push_str("            local -a commands\n");
+    script.push_str("            commands=(\n");
+    for command in COMMANDS {
+        let _ = writeln!(
+            script,
+            "                '{}'",
+            zsh_quote(&format!("{}:{}", command.name, command.about))
+        );
+    }
+    script.push_str("            )\n");
+    script.push_str("            _describe -t commands 'command' commands\n");
+    script.push_str("            ;;\n");
+    script.push_str("        args)\n");
+    script.push_str("            case $line[1] in\n");
+    for command in COMMANDS {
+        let _ = writeln!(script, "                {})", command.name);
+        script.push_str("                    _arguments");
+        for option in command_options(command) {
+            let _ = write!(
+                script,
+                " \\\n                        {}",
+                zsh_option(option)
+            );
+        }
+        if !command.actions.is_empty() {
+            let _ = write!(
+                script,
+                " \\\n                        '1:action:({})' \\\n                        '*:value: '",
+                command.actions.join(" ")
+            );
+        }
+        script.push_str("\n                    ;;\n");
+    }
+    script.push_str("            esac\n");
+    script.push_str("            ;;\n");
+    script.push_str("    esac\n");
+    script.push_str("}\n\n");
+    script.push_str("_s7forge \"$@\"\n");
+    script
+}
+
+// `'*--sort-by:value:(relevance recent ...)'`; every option may be repeated
+fn zsh_option(option: &OptionSpec) -> String {
+    let value = match option.value {
+        ValueHint::None => String::new(),
+        ValueHint::Any => ":value: ".to_string(),
+        ValueHint::Path => ":path:_files".to_string(),
+        ValueHint::Choices(choices) => format!(":value:({})", choices().join(" ")),
+    };
+    match option.short {
+        Some(short) if value.is_empty() => format!("'*'{{{},{}}}", short, option.name),
+        Some(short) => format!("'*'{{{},{}}}'{}'", short, option.name, value),
+        None => format!("'*{}{}'", option.name, value),
+    }
+}
+
+fn zsh_quote(text: &str) -> String {
+    text.replace('\'', "'\\''")
+}
+
+fn fish() -> String {
+    let mut script = String::new();
+    script.push_str("complete -c s7forge -f\n\n");
+    for option in GLOBAL_OPTIONS {
+        fish_option(&mut script, "__fish_use_subcommand", option);
+    }
+    for command in COMMANDS {
+        let _ = writeln!(
+            script,
+            "complete -c s7forge -n __fish_use_subcommand -a {} -d '{}'",
+            command.name,
+            fish_quote(command.about)
+        );
+    }
+    for command in COMMANDS {
+        script.push('\n');
+        let condition = format!("'__fish_seen_subcommand_from {}'", command.name);
+        if !command.actions.is_empty() {
+            let _ = writeln!(
+                script,
+                "complete -c s7forge -n {} -a '{}'",
+                condition,
+                command.actions.join(" ")
+            );
+        }
+        for option in command_options(command) {
+            fish_option(&mut script, &condition, option);
+        }
+    }
+    script
+}
+
+fn fish_option(script: &mut String, condition: &str, option: &OptionSpec) {
+    let _ = write!(
+        script,
+        "complete -c s7forge -n {} -l {}",
+        condition,
+        option.name.trim_start_matches("--")
+    );
+    if let Some(short) = option.short {
+        let _ = write!(script, " -s {}", short.trim_start_matches('-'));
+    }
+    match option.value {
+        ValueHint::None => {}
+        ValueHint::Any => script.push_str(" -x"),
+        ValueHint::Path => script.push_str(" -r -F"),
+        ValueHint::Choices(choices) => {
+            let _ = write!(script, " -x -a '{}'", choices().join(" "));
+        }
+    }
+    script.push('\n');
+}
+
+fn fish_quote(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+fn powershell() -> String {
+    let mut script = String::new();
+    script.push_str("Register-ArgumentCompleter -Native -CommandName s7forge -ScriptBlock {\n");
+    script.push_str("    param($wordToComplete, $commandAst, $cursorPosition)\n\n");
+    script.push_str("    $global = ");
+    powershell_spec(&mut script, "", &[], GLOBAL_OPTIONS.iter());
+    script.push_str("\n    $commands = [ordered]@{\n");
+    for command in COMMANDS {
+        let _ = write!(script, "        '{}' = ", command.name);
+        powershell_spec(
+            &mut script,
+            command.about,
+            command.actions,
+            command_options(command),
+        );
+    }
+    script.push_str("    }\n");
+    script.push_str(POWERSHELL_COMPLETER);
+    script
+}
+
+// Finds the command and the previous word, then offers what may follow
+const POWERSHELL_COMPLETER: &str = r#"
+    # Words before the one being completed, without s7forge itself
+    $words = @($commandAst.CommandElements |
+        Where-Object { $_.Extent.EndOffset -lt $cursorPosition } |
+        Select-Object -Skip 1 |
+        ForEach-Object { $_.ToString() })
+
+    $command = $null
+    for ($i = 0; $i -lt $words.Count; $i++) {
+        if ($global.values -contains $words[$i]) { $i++; continue }
+        if ($words[$i].StartsWith('-')) { continue }
+        $command = $words[$i]
+        break
+    }
+
+    $spec = if ($command) { $commands[$command] } else { $global }
+    if (-not $spec) { return }
+    $prev = if ($words.Count -gt 0) { $words[-1] } else { '' }
+
+    if ($spec.choices.Contains($prev)) {
+        $candidates = $spec.choices[$prev]
+    } elseif ($spec.values -contains $prev) {
+        # Free values and paths are left to the default completion
+        return
+    } elseif ($command) {
+        $candidates = $spec.actions + $spec.options
+    } else {
+        $candidates = @($commands.Keys) + $spec.options
+    }
+
+    $candidates | Where-Object { $_ -like "$wordToComplete*" } | ForEach-Object {
+        $tooltip = if (-not $command -and $commands.Contains($_)) { $commands[$_].about } else { $_ }
+        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $tooltip)
+    }
+}
+"#;
+
+fn powershell_spec<'a>(
+    script: &mut String,
+    about: &str,
+    actions: &[&str],
+    options: impl Iterator<Item = &'a OptionSpec> + Clone,
+) {
+    let list = |words: &[&str]| {
+        words
+            .iter()
+            .map(|word| format!("'{}'", word))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let values: Vec<&str> = option_words(options.clone().filter(|o| takes_value(o)));
+    let choices: Vec<String> = options
+        .clone()
+        .filter_map(|option| match option.value {
+            ValueHint::Choices(choices) => {
+                Some(format!("'{}' = @({})", option.name, list(&choices())))
+            }
+            _ => None,
+        })
+        .collect();
+
+    let _ = writeln!(
+        script,
+        "@{{ about = '{}'; actions = @({}); options = @({}); values = @({}); choices = @{{{}}} }}",
+        about.replace('\'', "''"),
+        list(actions),
+        list(&option_words(options)),
+        list(&values),
+        choices.join("; ")
+    );
+}
@@ -0,0 +1,71 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::SteamId;
+
+use crate::commands::user_items::query_user_page;
+use crate::core::error::S7forgeError;
+use crate::core::web_api;
+use crate::core::workshop_item::workshop::{UserListOrder, UserListType};
+use crate::utils::fetch_creator_names::fetch_creator_names;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CreatorProfile {
+    pub steam_id: String,
+    pub persona_name: String,
+    pub profile_url: String,
+    pub avatar_url: Option<String>,
+    pub avatar_medium_url: Option<String>,
+    pub avatar_full_url: Option<String>,
+    pub published_items: u32,
+}
+
+pub async fn creator_profile(
+    steam_game_id: u32,
+    steam_id: u64,
+) -> Result<CreatorProfile, S7forgeError> {
+    // Individual account IDs start at 76561197960265728 (universe 1, account type 1)
+    if steam_id < 76561197960265728 {
+        return Err(S7forgeError::InvalidArgs(format!(
+            "Invalid SteamID64: {}",
+            steam_id
+        )));
+    }
+
+    let published_items = query_user_page(
+        steam_game_id,
+        SteamId::from_raw(steam_id),
+        UserListType::Published,
+        UserListOrder::LastUpdatedDesc,
+        1,
+    )
+    .await?
+    .total_results;
+
+    // Avatars are best-effort: a private or unreachable profile still yields a result
+    let summary = tokio::task::spawn_blocking(move || web_api::player_summary(steam_id))
+        .await
+        .map_err(S7forgeError::Join)?
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+
+    let persona_name = match summary.persona_name {
+        Some(name) => name,
+        None => fetch_creator_names(vec![SteamId::from_raw(steam_id)], steam_game_id)
+            .await?
+            .remove(&steam_id)
+            .unwrap_or_else(|| "[unknown]".to_string()),
+    };
+
+    Ok(CreatorProfile {
+        steam_id: steam_id.to_string(),
+        persona_name,
+        profile_url: summary
+            .profile_url
+            .unwrap_or_else(|| format!("https://steamcommunity.com/profiles/{}/", steam_id)),
+        avatar_url: summary.avatar,
+        avatar_medium_url: summary.avatar_medium,
+        avatar_full_url: summary.avatar_full,
+        published_items,
+    })
+}
@@ -0,0 +1,150 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::workshop_items::workshop_items;
+use crate::core::error::S7forgeError;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DependencyNode {
+    pub item_id: u64,
+    pub title: Option<String>,
+    pub requested: bool,
+    pub missing: bool,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DependencyEdge {
+    pub from: u64,
+    pub to: u64,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DependencyGraph {
+    pub nodes: Vec<DependencyNode>,
+    pub edges: Vec<DependencyEdge>,
+    pub missing: Vec<u64>,
+    pub cycles: Vec<Vec<u64>>,
+}
+
+impl DependencyGraph {
+    /// Every item reachable from the requested ones, excluding the requested items themselves.
+    pub fn dependency_ids(&self) -> Vec<u64> {
+        self.nodes
+            .iter()
+            .filter(|node| !node.requested && !node.missing)
+            .map(|node| node.item_id)
+            .collect()
+    }
+}
+
+/// Resolves required items level by level, so each level costs a single (cached) lookup.
+pub async fn dependency_graph(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<DependencyGraph, S7forgeError> {
+    let requested: FxHashSet<u64> = item_ids.iter().copied().collect();
+    let mut visited: FxHashSet<u64> = FxHashSet::default();
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut missing = Vec::new();
+    let mut adjacency: FxHashMap<u64, Vec<u64>> = FxHashMap::default();
+
+    let mut frontier: Vec<u64> = Vec::new();
+    for id in item_ids {
+        if visited.insert(id) {
+            frontier.push(id);
+        }
+    }
+
+    while !frontier.is_empty() {
+        let found: FxHashMap<u64, _> = workshop_items(steam_game_id, frontier.clone())
+            .await?
+            .into_iter()
+            .map(|item| (item.workshop_item.published_file_id, item.workshop_item))
+            .collect();
+
+        let mut next_frontier = Vec::new();
+        for id in frontier {
+            let Some(item) = found.get(&id) else {
+                missing.push(id);
+                nodes.push(DependencyNode {
+                    item_id: id,
+                    title: None,
+                    requested: requested.contains(&id),
+                    missing: true,
+                });
+                continue;
+            };
+
+            nodes.push(DependencyNode {
+                item_id: id,
+                title: Some(item.title.clone()),
+                requested: requested.contains(&id),
+                missing: false,
+            });
+
+            for &child in &item.required_items {
+                edges.push(DependencyEdge {
+                    from: id,
+                    to: child,
+                });
+                adjacency.entry(id).or_default().push(child);
+                if visited.insert(child) {
+                    next_frontier.push(child);
+                }
+            }
+        }
+
+        frontier = next_frontier;
+    }
+
+    let cycles = find_cycles(&nodes, &adjacency);
+
+    Ok(DependencyGraph {
+        nodes,
+        edges,
+        missing,
+        cycles,
+    })
+}
+
+// Depth-first search; every back edge closes a cycle, reported as the path from its target
+fn find_cycles(nodes: &[DependencyNode], adjacency: &FxHashMap<u64, Vec<u64>>) -> Vec<Vec<u64>> {
+    fn visit(
+        id: u64,
+        adjacency: &FxHashMap<u64, Vec<u64>>,
+        finished: &mut FxHashSet<u64>,
+        stack: &mut Vec<u64>,
+        cycles: &mut Vec<Vec<u64>>,
+    ) {
+        if let Some(position) = stack.iter().position(|&on_stack| on_stack == id) {
+            cycles.push(stack[position..].to_vec());
+            return;
+        }
+        if finished.contains(&id) {
+            return;
+        }
+
+        stack.push(id);
+        for &child in adjacency.get(&id).map(Vec::as_slice).unwrap_or_default() {
+            visit(child, adjacency, finished, stack, cycles);
+        }
+        stack.pop();
+        finished.insert(id);
+    }
+
+    let mut finished = FxHashSet::default();
+    let mut cycles = Vec::new();
+    for node in nodes {
+        visit(
+            node.item_id,
+            adjacency,
+            &mut finished,
+            &mut Vec::new(),
+            &mut cycles,
+        );
+    }
+
+    cycles
+}
@@ -0,0 +1,192 @@
+use rustc_hash::FxHashMap;
+use std::fs;
+use std::path::Path;
+
+use super::{DeployManifest, DeployMode, DeployResult, DeployedItem};
+use crate::commands::item_install_path::item_install_path;
+use crate::commands::subscriptions::subscribed_item_ids;
+use crate::commands::workshop_items::workshop_items;
+use crate::core::error::S7forgeError;
+use crate::utils::copy_dir::copy_dir;
+use crate::utils::remove_path::remove_path;
+use crate::utils::symlink_dir::symlink_dir;
+
+pub const DEFAULT_NAME_TEMPLATE: &str = "{id}";
+
+/// Links installed items (all subscribed ones when `item_ids` is empty) into `target`, e.g. a
+/// game's mods folder, named after `name_template` with `{id}` and `{title}` placeholders.
+/// Falls back to copying when Windows doesn't allow symlinks.
+pub async fn deploy_items(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+    target: String,
+    name_template: String,
+    copy: bool,
+) -> Result<Vec<DeployResult>, S7forgeError> {
+    let target = std::path::absolute(Path::new(&target))
+        .map_err(|e| S7forgeError::InvalidArgs(format!("Invalid path {}: {}", target, e)))?;
+    fs::create_dir_all(&target)
+        .map_err(|e| S7forgeError::io(format!("Failed to create {}", target.display()), e))?;
+
+    let item_ids = if item_ids.is_empty() {
+        subscribed_item_ids(steam_game_id).await?
+    } else {
+        item_ids
+    };
+
+    let titles: FxHashMap<u64, String> = if name_template.contains("{title}") {
+        workshop_items(steam_game_id, item_ids.clone())
+            .await?
+            .into_iter()
+            .map(|item| {
+                (
+                    item.workshop_item.published_file_id,
+                    item.workshop_item.title,
+                )
+            })
+            .collect()
+    } else {
+        FxHashMap::default()
+    };
+
+    let mut manifest = DeployManifest::load(&target)?;
+    let mut results = Vec::new();
+
+    for item_id in item_ids {
+        let source = match item_install_path(steam_game_id, item_id).await {
+            Ok(info) => info.path,
+            Err(e) => {
+                results.push(DeployResult::failed(item_id, e.to_string()));
+                continue;
+            }
+        };
+
+        let title = titles.get(&item_id).map(String::as_str).unwrap_or_default();
+        let name = match folder_name(&name_template, item_id, title) {
+            Ok(name) => name,
+            Err(e) => {
+                results.push(DeployResult::failed(item_id, e.to_string()));
+                continue;
+            }
+        };
+        let destination = target.join(&name);
+
+        // Redeploying replaces our own earlier deployment, but never anything else
+        let previous = manifest
+            .items
+            .iter()
+            .position(|deployed| deployed.name == name);
+        if fs::symlink_metadata(&destination).is_ok() {
+            match previous {
+                Some(index) if manifest.items[index].item_id == item_id => {
+                    if let Err(e) = remove_path(&destination) {
+                        results.push(DeployResult::failed(
+                            item_id,
+                            format!("Failed to replace {}: {}", destination.display(), e),
+                        ));
+                        continue;
+                    }
+                }
+                _ => {
+                    results.push(DeployResult::failed(
+                        item_id,
+                        format!("{} already exists", destination.display()),
+                    ));
+                    continue;
+                }
+            }
+        }
+        // A new template or title gives the item a new name; its old folder goes, or undeploy
+        // could no longer find it once the record is replaced
+        let renamed = manifest
+            .items
+            .iter()
+            .find(|deployed| deployed.item_id == item_id && deployed.name != name);
+        if let Some(renamed) = renamed.filter(|renamed| renamed.has_safe_name()) {
+            let old_destination = target.join(&renamed.name);
+            match remove_path(&old_destination) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    results.push(DeployResult::failed(
+                        item_id,
+                        format!("Failed to remove {}: {}", old_destination.display(), e),
+                    ));
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        manifest
+            .items
+            .retain(|deployed| deployed.item_id != item_id);
+
+        let source = Path::new(&source);
+        let deployed = if copy {
+            copy_dir(source, &destination).map(|_| DeployMode::Copy)
+        } else {
+            symlink_dir(source, &destination)
+                .map(|_| DeployMode::Symlink)
+                .or_else(|e| {
+                    if !symlinks_not_allowed(&e) {
+                        return Err(e);
+                    }
+                    copy_dir(source, &destination).map(|_| DeployMode::Copy)
+                })
+        };
+
+        match deployed {
+            Ok(mode) => {
+                manifest.items.push(DeployedItem {
+                    item_id,
+                    name,
+                    mode,
+                });
+                results.push(DeployResult {
+                    item_id,
+                    success: true,
+                    path: Some(destination.to_string_lossy().into_owned()),
+                    mode: Some(mode),
+                    error: None,
+                });
+            }
+            Err(e) => results.push(DeployResult::failed(
+                item_id,
+                format!("Failed to deploy into {}: {}", destination.display(), e),
+            )),
+        }
+    }
+
+    manifest.save(&target)?;
+    Ok(results)
+}
+
+// Creating symlinks on Windows needs developer mode or admin rights; without them it fails
+// with ERROR_PRIVILEGE_NOT_HELD. Any other error is reported rather than hidden by a copy.
+fn symlinks_not_allowed(error: &std::io::Error) -> bool {
+    const ERROR_PRIVILEGE_NOT_HELD: i32 = 1314;
+    cfg!(windows)
+        && (error.raw_os_error() == Some(ERROR_PRIVILEGE_NOT_HELD)
+            || error.kind() == std::io::ErrorKind::PermissionDenied)
+}
+
+fn folder_name(template: &str, item_id: u64, title: &str) -> Result<String, S7forgeError> {
+    let name: String = template
+        .replace("{id}", &item_id.to_string())
+        .replace("{title}", title)
+        .chars()
+        .map(|ch| match ch {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+            ch if ch.is_control() => '_',
+            ch => ch,
+        })
+        .collect();
+    // Windows silently drops trailing dots and spaces
+    let name = name.trim().trim_end_matches('.').to_string();
+
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(S7forgeError::InvalidArgs(format!(
+            "Name template \"{}\" produced an invalid folder name",
+            template
+        )));
+    }
+    Ok(name)
+}
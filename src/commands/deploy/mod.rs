@@ -0,0 +1,102 @@
+pub mod deploy_items;
+pub mod undeploy;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::core::error::S7forgeError;
+
+/// Written into the target folder so `undeploy` only ever removes what `deploy-items` created.
+pub const MANIFEST_FILE: &str = ".s7forge-deploy.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeployMode {
+    Symlink,
+    Copy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployedItem {
+    pub item_id: u64,
+    /// Folder name inside the target directory
+    pub name: String,
+    pub mode: DeployMode,
+}
+
+impl DeployedItem {
+    /// Whether `name` is a plain folder name. The manifest can be edited by hand, so a name
+    /// that would reach outside the target folder is never deleted.
+    pub fn has_safe_name(&self) -> bool {
+        let name = Path::new(&self.name);
+        !self.name.contains(['/', '\\', ':'])
+            && name.components().count() == 1
+            && matches!(
+                name.components().next(),
+                Some(std::path::Component::Normal(_))
+            )
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DeployManifest {
+    pub items: Vec<DeployedItem>,
+}
+
+impl DeployManifest {
+    pub fn load(target: &Path) -> Result<Self, S7forgeError> {
+        let path = target.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .map_err(|e| S7forgeError::io(format!("Failed to read {}", path.display()), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| S7forgeError::parse(format!("Invalid {}", path.display()), e))
+    }
+
+    pub fn save(&self, target: &Path) -> Result<(), S7forgeError> {
+        let path = target.join(MANIFEST_FILE);
+        if self.items.is_empty() {
+            return match fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(S7forgeError::io(
+                    format!("Failed to remove {}", path.display()),
+                    e,
+                )),
+                _ => Ok(()),
+            };
+        }
+
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| S7forgeError::parse("Failed to serialize deploy manifest", e))?;
+        fs::write(&path, json)
+            .map_err(|e| S7forgeError::io(format!("Failed to write {}", path.display()), e))
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DeployResult {
+    pub item_id: u64,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<DeployMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DeployResult {
+    fn failed(item_id: u64, error: String) -> Self {
+        Self {
+            item_id,
+            success: false,
+            path: None,
+            mode: None,
+            error: Some(error),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use super::{DeployManifest, DeployResult};
+use crate::core::error::S7forgeError;
+use crate::utils::remove_path::remove_path;
+
+/// Removes items previously deployed into `target`; with no `item_ids`, all of them.
+/// Only folders recorded in the deploy manifest are touched.
+pub fn undeploy(target: String, item_ids: Vec<u64>) -> Result<Vec<DeployResult>, S7forgeError> {
+    let target = std::path::absolute(Path::new(&target))
+        .map_err(|e| S7forgeError::InvalidArgs(format!("Invalid path {}: {}", target, e)))?;
+    let mut manifest = DeployManifest::load(&target)?;
+
+    let item_ids = if item_ids.is_empty() {
+        manifest
+            .items
+            .iter()
+            .map(|deployed| deployed.item_id)
+            .collect()
+    } else {
+        item_ids
+    };
+
+    let mut results = Vec::new();
+    for item_id in item_ids {
+        let Some(index) = manifest
+            .items
+            .iter()
+            .position(|deployed| deployed.item_id == item_id)
+        else {
+            results.push(DeployResult::failed(
+                item_id,
+                "Item was not deployed into this folder".to_string(),
+            ));
+            continue;
+        };
+
+        let deployed = &manifest.items[index];
+        if !deployed.has_safe_name() {
+            results.push(DeployResult::failed(
+                item_id,
+                format!(
+                    "Refusing to remove \"{}\": not a folder name",
+                    deployed.name
+                ),
+            ));
+            continue;
+        }
+        let path = target.join(&deployed.name);
+        match remove_path(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                results.push(DeployResult::failed(
+                    item_id,
+                    format!("Failed to remove {}: {}", path.display(), e),
+                ));
+            }
+            _ => {
+                results.push(DeployResult {
+                    item_id,
+                    success: true,
+                    path: Some(path.to_string_lossy().into_owned()),
+                    mode: Some(deployed.mode),
+                    error: None,
+                });
+                manifest.items.remove(index);
+            }
+        }
+    }
+
+    manifest.save(&target)?;
+    Ok(results)
+}
@@ -3,10 +3,12 @@ use std::collections::HashSet;
 use steamworks::{AppIDs, AppId, UGCQueryType, UGCType};
 use tokio::sync::mpsc;
 
+use crate::core::error::S7forgeError;
+use crate::core::settings;
 use crate::core::steam_manager;
 use crate::core::workshop_item::workshop::WorkshopItemsResult;
 
-pub async fn discover_tags(steam_game_id: u32) -> Result<Vec<String>, String> {
+pub async fn discover_tags(steam_game_id: u32) -> Result<Vec<String>, S7forgeError> {
     let steam_client = steam_manager::initialize_client(steam_game_id).await?;
     let mut all_tags = HashSet::new();
 
@@ -46,7 +48,7 @@ async fn sample_tags_from_source(
     steam_game_id: u32,
     query_type: UGCQueryType,
     trend_days: Option<u32>,
-) -> Result<HashSet<String>, String> {
+) -> Result<HashSet<String>, S7forgeError> {
     let (tx, mut rx) = mpsc::channel(32);
 
     let client_clone = steam_client.clone();
@@ -75,12 +77,12 @@ async fn sample_tags_from_source(
                 let _ = tx_inner.send(
                     fetch_result
                         .map(|query_results| WorkshopItemsResult::from_query_results(query_results))
-                        .map_err(|e| format!("Steam API error: {:?}", e)),
+                        .map_err(S7forgeError::steam_api),
                 );
             });
 
         let start_time = std::time::Instant::now();
-        let timeout_duration = std::time::Duration::from_secs(30);
+        let timeout_duration = settings::get().steam_timeout;
 
         loop {
             let _ = tx.blocking_send(());
@@ -89,7 +91,7 @@ async fn sample_tags_from_source(
             }
 
             if start_time.elapsed() > timeout_duration {
-                return Err("Sampling operation timed out waiting for Steam response".to_string());
+                return Err(S7forgeError::Timeout("Sampling operation"));
             }
 
             std::thread::sleep(std::time::Duration::from_millis(10));
@@ -105,7 +107,7 @@ async fn sample_tags_from_source(
                 steam_manager::run_callbacks(steam_game_id)?;
             }
             task_result = &mut fused_task => {
-                search_result = Some(task_result.map_err(|e| format!("Task error: {:?}", e))??);
+                search_result = Some(task_result.map_err(S7forgeError::Join)??);
                 break;
             }
         }
@@ -0,0 +1,180 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::commands::workshop_items::workshop_items;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::utils::get_cache_dir::get_cache_dir;
+
+/// Steam caps preview images at 1 MB; anything much larger is not an image
+const MAX_PREVIEW_BYTES: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PreviewDownloadResult {
+    pub item_id: u64,
+    pub success: bool,
+    /// Whether the image came from the preview cache instead of Steam's CDN
+    pub cached: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Saves the main preview image of each item as `<target>/<item_id>.<ext>`. Images are kept
+/// in the cache by URL, so a preview is only fetched again after its author replaces it.
+pub async fn download_previews(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+    target: PathBuf,
+    concurrency: usize,
+) -> Result<Vec<PreviewDownloadResult>, S7forgeError> {
+    fs::create_dir_all(&target)
+        .map_err(|e| S7forgeError::io(format!("Failed to create {}", target.display()), e))?;
+    let cache_dir = get_cache_dir()?.join("previews");
+    fs::create_dir_all(&cache_dir)
+        .map_err(|e| S7forgeError::io(format!("Failed to create {}", cache_dir.display()), e))?;
+
+    let items = workshop_items(steam_game_id, item_ids.clone()).await?;
+    let jobs: Vec<(u64, Option<String>)> = item_ids
+        .iter()
+        .map(|&item_id| {
+            let preview_url = items
+                .iter()
+                .find(|item| item.workshop_item.published_file_id == item_id)
+                .and_then(|item| item.workshop_item.preview_url.clone());
+            (item_id, preview_url)
+        })
+        .collect();
+
+    tokio::task::spawn_blocking(move || {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(settings::get().web_timeout)
+            .build();
+        let next_job = AtomicUsize::new(0);
+        let results = Mutex::new(Vec::with_capacity(jobs.len()));
+
+        std::thread::scope(|scope| {
+            for _ in 0..concurrency.min(jobs.len()) {
+                scope.spawn(|| {
+                    while let Some((item_id, preview_url)) =
+                        jobs.get(next_job.fetch_add(1, Ordering::Relaxed))
+                    {
+                        let result = match preview_url {
+                            None => Err("Item not found or has no preview image".into()),
+                            Some(url) => fetch_preview(&agent, *item_id, url, &cache_dir, &target),
+                        };
+                        let result = match result {
+                            Ok((path, cached)) => PreviewDownloadResult {
+                                item_id: *item_id,
+                                success: true,
+                                cached,
+                                path: Some(path.to_string_lossy().into_owned()),
+                                error: None,
+                            },
+                            Err(error) => PreviewDownloadResult {
+                                item_id: *item_id,
+                                success: false,
+                                cached: false,
+                                path: None,
+                                error: Some(error.to_string()),
+                            },
+                        };
+                        results.lock().unwrap().push(result);
+                    }
+                });
+            }
+        });
+
+        let mut results = results.into_inner().unwrap();
+        results.sort_by_key(|result| {
+            jobs.iter()
+                .position(|(item_id, _)| *item_id == result.item_id)
+        });
+        results
+    })
+    .await
+    .map_err(S7forgeError::Join)
+}
+
+fn fetch_preview(
+    agent: &ureq::Agent,
+    item_id: u64,
+    url: &str,
+    cache_dir: &Path,
+    target: &Path,
+) -> Result<(PathBuf, bool), S7forgeError> {
+    let mut hasher = rustc_hash::FxHasher::default();
+    url.hash(&mut hasher);
+    let cache_prefix = format!("{}-{:016x}.", item_id, hasher.finish());
+
+    let cached_file = fs::read_dir(cache_dir)
+        .ok()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with(&cache_prefix))
+        });
+
+    let (cache_file, cached) = match cached_file {
+        Some(path) => (path, true),
+        None => {
+            let response = agent
+                .get(url)
+                .call()
+                .map_err(|e| format!("Failed to download preview: {}", e))?;
+            let extension = image_extension(response.content_type(), url);
+            let mut bytes = Vec::new();
+            response
+                .into_reader()
+                .take(MAX_PREVIEW_BYTES)
+                .read_to_end(&mut bytes)
+                .map_err(|e| format!("Failed to download preview: {}", e))?;
+
+            let path = cache_dir.join(format!("{}{}", cache_prefix, extension));
+            fs::write(&path, bytes)
+                .map_err(|e| S7forgeError::io(format!("Failed to write {}", path.display()), e))?;
+            (path, false)
+        }
+    };
+
+    let extension = cache_file
+        .extension()
+        .map(|extension| extension.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "jpg".to_string());
+    let path = target.join(format!("{}.{}", item_id, extension));
+    fs::copy(&cache_file, &path)
+        .map_err(|e| S7forgeError::io(format!("Failed to write {}", path.display()), e))?;
+    Ok((path, cached))
+}
+
+// Steam's CDN URLs usually carry no extension, so the content type decides
+fn image_extension(content_type: &str, url: &str) -> &'static str {
+    match content_type {
+        "image/png" => "png",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        "image/jpeg" => "jpg",
+        _ => {
+            let path = url
+                .split(['?', '#'])
+                .next()
+                .unwrap_or_default()
+                .to_lowercase();
+            [".png", ".gif", ".webp"]
+                .into_iter()
+                .find(|extension| path.ends_with(extension))
+                .map(|extension| &extension[1..])
+                .unwrap_or("jpg")
+        }
+    }
+}
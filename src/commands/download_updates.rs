@@ -0,0 +1,17 @@
+use crate::commands::check_updates::check_updates;
+use crate::commands::download_workshop_items::{ItemDownloadResult, RetryPolicy, download_items};
+use crate::core::error::S7forgeError;
+
+pub async fn download_updates(
+    steam_game_id: u32,
+    concurrency: usize,
+    retry: RetryPolicy,
+) -> Result<Vec<ItemDownloadResult>, S7forgeError> {
+    let item_ids = check_updates(steam_game_id)
+        .await?
+        .into_iter()
+        .map(|update| update.item_id)
+        .collect();
+
+    download_items(steam_game_id, item_ids, concurrency, retry).await
+}
@@ -1,72 +1,237 @@
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
 use steamworks::{ItemState, PublishedFileId};
 
+use crate::commands::download_workshop_items::{RetryPolicy, download_items};
+use crate::commands::workshop_path::workshop_path;
+use crate::core::error::S7forgeError;
+use crate::core::progress::{self, ProgressEvent};
 use crate::core::steam_manager;
+use crate::utils::symlink_dir::symlink_dir;
 
-pub async fn download_workshop_item(steam_game_id: u32, item_id: u64) -> Result<(), String> {
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SteamCmdDownloadResult {
+    pub item_id: u64,
+    pub path: String,
+    pub linked: bool,
+}
+
+pub async fn download_workshop_item(
+    steam_game_id: u32,
+    item_id: u64,
+    retry: RetryPolicy,
+) -> Result<(), S7forgeError> {
     let steam_client = steam_manager::initialize_client(steam_game_id).await?;
 
-    let published_file_id = PublishedFileId(item_id);
-    {
-        let ugc = steam_client.ugc();
-        let state = ugc.item_state(published_file_id);
-        if !state.contains(ItemState::SUBSCRIBED) {
-            return Err("Workshop item is not subscribed".to_string());
-        }
+    let state = steam_client.ugc().item_state(PublishedFileId(item_id));
+    if !state.contains(ItemState::SUBSCRIBED) {
+        return Err(S7forgeError::NotSubscribed(item_id));
+    }
+
+    let result = download_items(steam_game_id, vec![item_id], 1, retry)
+        .await?
+        .pop()
+        .ok_or_else(|| S7forgeError::Download {
+            item_id,
+            message: "the download produced no result".to_string(),
+        })?;
 
-        ugc.download_item(published_file_id, true);
+    match result.error {
+        None if result.success => Ok(()),
+        error => Err(S7forgeError::Download {
+            item_id,
+            message: error.unwrap_or_else(|| "unknown error".to_string()),
+        }),
     }
+}
 
-    let timeout = Duration::from_secs(10 * 60); // 10 minutes
-    let cancelled = Arc::new(Mutex::new(false));
-    let cancelled_clone = cancelled.clone();
-
-    let (tx, rx) = tokio::sync::oneshot::channel();
-    std::thread::spawn(move || {
-        let start_time = std::time::Instant::now();
-        loop {
-            if *cancelled_clone.lock().unwrap() {
-                let _ = tx.send(Err("Download cancelled".to_string()));
-                break;
-            }
+pub async fn download_workshop_item_steamcmd(
+    steam_game_id: u32,
+    item_id: u64,
+    steamcmd_path: Option<String>,
+) -> Result<SteamCmdDownloadResult, S7forgeError> {
+    let steamcmd = steamcmd_path.unwrap_or_else(|| "steamcmd".to_string());
 
-            if start_time.elapsed() > timeout {
-                let _ = tx.send(Err(format!(
-                    "Download timeout after {} minutes",
-                    timeout.as_secs() / 60
-                )));
-                break;
-            }
+    let downloaded_path = tokio::task::spawn_blocking(move || {
+        run_steamcmd_download(&steamcmd, steam_game_id, item_id)
+    })
+    .await
+    .map_err(S7forgeError::Join)??;
 
-            let ugc = steam_client.ugc();
-            let state = ugc.item_state(published_file_id);
+    // Without a local workshop folder (e.g. dedicated servers) the item stays where SteamCMD put it
+    let Some(workshop_dir) = workshop_path(steam_game_id) else {
+        return Ok(SteamCmdDownloadResult {
+            item_id,
+            path: downloaded_path.to_string_lossy().into_owned(),
+            linked: false,
+        });
+    };
 
-            if let Some((downloaded, total)) = ugc.item_download_info(published_file_id) {
-                if downloaded == total && total > 0 {
-                    let _ = tx.send(Ok(()));
-                    break;
-                }
-            }
+    let target_path = Path::new(&workshop_dir).join(item_id.to_string());
+    if target_path == downloaded_path {
+        return Ok(SteamCmdDownloadResult {
+            item_id,
+            path: target_path.to_string_lossy().into_owned(),
+            linked: false,
+        });
+    }
 
-            if state.contains(ItemState::INSTALLED)
-                && state.contains(ItemState::SUBSCRIBED)
-                && !state.contains(ItemState::DOWNLOADING)
-                && !state.contains(ItemState::DOWNLOAD_PENDING)
-            {
-                let _ = tx.send(Ok(()));
-                break;
-            }
+    // The previous copy is moved aside rather than deleted, so a failed move or link leaves it intact
+    let backup_path = Path::new(&workshop_dir).join(format!("{}.s7forge-old", item_id));
+    let has_backup = target_path.is_symlink() || target_path.exists();
+    if has_backup {
+        remove_path(&backup_path).map_err(|e| {
+            S7forgeError::io(format!("Failed to clear {}", backup_path.display()), e)
+        })?;
+        fs::rename(&target_path, &backup_path).map_err(|e| {
+            S7forgeError::io(format!("Failed to move {} aside", target_path.display()), e)
+        })?;
+    }
 
-            std::thread::sleep(Duration::from_millis(500));
+    // Moving fails across drives, in which case the download is linked in place instead
+    let linked = if fs::rename(&downloaded_path, &target_path).is_ok() {
+        false
+    } else if let Err(e) = symlink_dir(&downloaded_path, &target_path) {
+        if has_backup {
+            let _ = fs::rename(&backup_path, &target_path);
         }
+        return Err(S7forgeError::io(
+            format!(
+                "Failed to link {} into {}",
+                downloaded_path.display(),
+                target_path.display()
+            ),
+            e,
+        ));
+    } else {
+        true
+    };
+
+    if let Err(e) = remove_path(&backup_path) {
+        tracing::warn!(
+            "Failed to remove the previous copy at {}: {}",
+            backup_path.display(),
+            e
+        );
+    }
+
+    Ok(SteamCmdDownloadResult {
+        item_id,
+        path: target_path.to_string_lossy().into_owned(),
+        linked,
+    })
+}
+
+/// Removes a file, symlink or folder, treating a missing path as already removed.
+fn remove_path(path: &Path) -> io::Result<()> {
+    let result = if path.is_symlink() || path.is_file() {
+        fs::remove_file(path)
+    } else {
+        fs::remove_dir_all(path)
+    };
+
+    match result {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+fn run_steamcmd_download(
+    steamcmd: &str,
+    app_id: u32,
+    item_id: u64,
+) -> Result<PathBuf, S7forgeError> {
+    let mut child = Command::new(steamcmd)
+        .args([
+            "+login",
+            "anonymous",
+            "+workshop_download_item",
+            &app_id.to_string(),
+            &item_id.to_string(),
+            "validate",
+            "+quit",
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| S7forgeError::io(format!("Failed to start SteamCMD ({})", steamcmd), e))?;
+
+    let (Some(stdout), Some(mut stderr)) = (child.stdout.take(), child.stderr.take()) else {
+        return Err(S7forgeError::Download {
+            item_id,
+            message: "couldn't capture SteamCMD output".to_string(),
+        });
+    };
+
+    // Drained on its own thread so a chatty stderr can't block SteamCMD while stdout is read
+    let stderr_reader = thread::spawn(move || {
+        let mut output = String::new();
+        let _ = stderr.read_to_string(&mut output);
+        output
     });
 
-    match rx.await {
-        Ok(result) => result,
-        Err(_) => {
-            *cancelled.lock().unwrap() = true;
-            Err("Download monitoring failed unexpectedly".to_string())
+    let bar = progress::bytes_bar(0, item_id.to_string());
+    let mut downloaded_path = None;
+    let mut last_status = None;
+
+    for line in BufReader::new(stdout).lines() {
+        let line = line.map_err(|e| S7forgeError::io("Failed to read SteamCMD output", e))?;
+        let line = line.trim();
+
+        // e.g. Success. Downloaded item 123 to "C:\steamcmd\steamapps\workshop\content\548430\123" (1024 bytes)
+        // Paths aren't escaped, so extract_quoted_strings would mangle the separators
+        if line.starts_with("Success. Downloaded item") {
+            if let (Some(start), Some(end)) = (line.find('"'), line.rfind('"')) {
+                if end > start {
+                    downloaded_path = Some(PathBuf::from(&line[start + 1..end]));
+                }
+            }
+        } else if let Some((downloaded, total)) = parse_progress(line) {
+            bar.set_length(total);
+            bar.set_position(downloaded);
+            progress::emit(ProgressEvent::new(
+                "downloading",
+                Some(item_id),
+                downloaded,
+                total,
+            ));
+        } else if line.starts_with("ERROR!") || line.starts_with("Downloading item") {
+            last_status = Some(line.to_string());
         }
     }
+
+    let status = child
+        .wait()
+        .map_err(|e| S7forgeError::io("Failed to wait for SteamCMD", e))?;
+    let stderr = stderr_reader.join().unwrap_or_default();
+
+    if let Some(path) = downloaded_path {
+        return Ok(path);
+    }
+
+    let mut message = match last_status {
+        Some(status) => format!("SteamCMD download failed: {}", status),
+        None => format!("SteamCMD exited with {} without downloading it", status),
+    };
+    let stderr = stderr.trim();
+    if !stderr.is_empty() {
+        message = format!("{} ({})", message, stderr);
+    }
+
+    Err(S7forgeError::Download { item_id, message })
+}
+
+/// Reads the byte counts from a SteamCMD progress line, e.g.
+/// `Update state (0x61) downloading, progress: 45.23 (1234 / 5678)`.
+fn parse_progress(line: &str) -> Option<(u64, u64)> {
+    let (_, rest) = line.split_once("progress:")?;
+    let counts = rest.split_once('(')?.1.split_once(')')?.0;
+    let (downloaded, total) = counts.split_once('/')?;
+    Some((downloaded.trim().parse().ok()?, total.trim().parse().ok()?))
 }
@@ -0,0 +1,278 @@
+use futures_util::FutureExt;
+use indicatif::ProgressBar;
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use steamworks::{DownloadItemResult, ItemState, PublishedFileId, SteamError};
+use tokio::sync::mpsc;
+
+use crate::core::error::S7forgeError;
+use crate::core::progress::{self, ProgressEvent};
+use crate::core::steam_manager;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemDownloadResult {
+    pub item_id: u64,
+    pub success: bool,
+    pub attempts: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub retries: u32,
+    pub delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: 0,
+            delay: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryPolicy {
+    const MAX_DELAY: Duration = Duration::from_secs(5 * 60);
+
+    /// Exponential backoff for the given retry (1-based) with up to 25% jitter.
+    fn backoff(&self, retry: u32) -> Duration {
+        let exponential = self
+            .delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(Self::MAX_DELAY);
+
+        // No RNG dependency; the clock's sub-second part is random enough to spread retries
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|now| now.subsec_nanos())
+            .unwrap_or_default();
+        let jitter = exponential.mul_f64((nanos % 1000) as f64 / 4000.0);
+
+        exponential + jitter
+    }
+}
+
+enum Failure {
+    Retryable(String),
+    Permanent(String),
+}
+
+// Deleted, hidden or banned items and permission problems won't fix themselves on retry
+fn classify(error: SteamError) -> Failure {
+    let message = format!("Steam API error: {:?}", error);
+    match error {
+        SteamError::FileNotFound
+        | SteamError::AccessDenied
+        | SteamError::Banned
+        | SteamError::InvalidParameter
+        | SteamError::NotLoggedOn => Failure::Permanent(message),
+        _ => Failure::Retryable(message),
+    }
+}
+
+/// Downloads `item_ids` with at most `concurrency` downloads in flight, reporting every item
+/// even when some of them fail. Transient failures are retried according to `retry`.
+pub async fn download_items(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+    concurrency: usize,
+    retry: RetryPolicy,
+) -> Result<Vec<ItemDownloadResult>, S7forgeError> {
+    if item_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    if concurrency == 0 {
+        return Err(S7forgeError::InvalidArgs(
+            "Concurrency must be at least 1".into(),
+        ));
+    }
+
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let download_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel::<DownloadItemResult>();
+        let _callback_handle = steam_client.register_callback(move |result: DownloadItemResult| {
+            let _ = tx_inner.send(result);
+        });
+
+        let per_item_timeout = Duration::from_secs(10 * 60); // 10 minutes
+        let mut queue: VecDeque<u64> = item_ids.iter().copied().collect();
+        let mut waiting_retry: Vec<(Instant, u64)> = Vec::new();
+        let mut in_flight: FxHashMap<u64, Instant> = FxHashMap::default();
+        let mut attempts: FxHashMap<u64, u32> = FxHashMap::default();
+        let mut failures: Vec<(u64, Failure)> = Vec::new();
+        let mut finished: FxHashMap<u64, Result<(), String>> = FxHashMap::default();
+        let mut last_progress: FxHashMap<u64, u64> = FxHashMap::default();
+
+        let show_bars = progress::bars_enabled();
+        let bars = progress::multi_bar();
+        let overall = bars.add(progress::count_bar(item_ids.len() as u64, "Downloading"));
+        let mut item_bars: FxHashMap<u64, ProgressBar> = FxHashMap::default();
+
+        while !queue.is_empty() || !in_flight.is_empty() || !waiting_retry.is_empty() {
+            let now = Instant::now();
+            waiting_retry.retain(|&(ready_at, item_id)| {
+                if ready_at <= now {
+                    queue.push_back(item_id);
+                    return false;
+                }
+                true
+            });
+
+            while in_flight.len() < concurrency {
+                let Some(item_id) = queue.pop_front() else {
+                    break;
+                };
+                *attempts.entry(item_id).or_default() += 1;
+                if ugc.download_item(PublishedFileId(item_id), true) {
+                    in_flight.insert(item_id, Instant::now());
+                } else {
+                    failures.push((
+                        item_id,
+                        Failure::Permanent(
+                            "Steam refused to start the download (is the item subscribed?)"
+                                .to_string(),
+                        ),
+                    ));
+                }
+            }
+
+            let _ = tx.blocking_send(());
+
+            while let Ok(result) = rx_inner.try_recv() {
+                let item_id = result.published_file_id.0;
+                if in_flight.remove(&item_id).is_some() {
+                    match result.error {
+                        Some(error) => failures.push((item_id, classify(error))),
+                        None => {
+                            finished.insert(item_id, Ok(()));
+                        }
+                    }
+                }
+            }
+
+            if progress::enabled() || show_bars {
+                for &item_id in in_flight.keys() {
+                    if let Some((downloaded, total)) =
+                        ugc.item_download_info(PublishedFileId(item_id))
+                    {
+                        if show_bars {
+                            let bar = item_bars.entry(item_id).or_insert_with(|| {
+                                bars.add(progress::bytes_bar(total, item_id.to_string()))
+                            });
+                            bar.set_length(total);
+                            bar.set_position(downloaded);
+                        }
+                        if progress::enabled()
+                            && last_progress.insert(item_id, downloaded) != Some(downloaded)
+                        {
+                            progress::emit(ProgressEvent::new(
+                                "downloading",
+                                Some(item_id),
+                                downloaded,
+                                total,
+                            ));
+                        }
+                    }
+                }
+            }
+
+            // Items that were already up to date may never produce a callback
+            in_flight.retain(|&item_id, started| {
+                let state = ugc.item_state(PublishedFileId(item_id));
+                if state.contains(ItemState::INSTALLED)
+                    && !state.contains(ItemState::NEEDS_UPDATE)
+                    && !state.contains(ItemState::DOWNLOADING)
+                    && !state.contains(ItemState::DOWNLOAD_PENDING)
+                {
+                    finished.insert(item_id, Ok(()));
+                    return false;
+                }
+                if started.elapsed() > per_item_timeout {
+                    failures.push((
+                        item_id,
+                        Failure::Retryable(format!(
+                            "Download timeout after {} minutes",
+                            per_item_timeout.as_secs() / 60
+                        )),
+                    ));
+                    return false;
+                }
+                true
+            });
+
+            for (item_id, failure) in failures.drain(..) {
+                let attempt = attempts.get(&item_id).copied().unwrap_or(1);
+                match failure {
+                    Failure::Retryable(_) if attempt <= retry.retries => {
+                        waiting_retry.push((Instant::now() + retry.backoff(attempt), item_id));
+                    }
+                    Failure::Retryable(message) | Failure::Permanent(message) => {
+                        finished.insert(item_id, Err(message));
+                    }
+                }
+            }
+
+            // Dropping a bar clears it
+            item_bars.retain(|item_id, _| in_flight.contains_key(item_id));
+            overall.set_position(finished.len() as u64);
+
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        drop(overall);
+
+        item_ids
+            .into_iter()
+            .map(|item_id| {
+                let outcome = finished
+                    .remove(&item_id)
+                    .unwrap_or_else(|| Err("Download was not started".into()));
+                let attempts = attempts.get(&item_id).copied().unwrap_or_default();
+                match outcome {
+                    Ok(()) => ItemDownloadResult {
+                        item_id,
+                        success: true,
+                        attempts,
+                        path: ugc
+                            .item_install_info(PublishedFileId(item_id))
+                            .map(|info| info.folder),
+                        error: None,
+                    },
+                    Err(error) => ItemDownloadResult {
+                        item_id,
+                        success: false,
+                        attempts,
+                        path: None,
+                        error: Some(error),
+                    },
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut download_result = None;
+    let mut fused_task = download_task.fuse();
+
+    while download_result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut fused_task => {
+                download_result = Some(task_result.map_err(S7forgeError::Join)?);
+            }
+        }
+    }
+
+    Ok(download_result.unwrap())
+}
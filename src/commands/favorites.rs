@@ -0,0 +1,128 @@
+use futures_util::FutureExt;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use steamworks::{AppId, PublishedFileId};
+use tokio::sync::mpsc;
+
+use crate::commands::user_items::query_user_items;
+use crate::commands::workshop_items::EnhancedWorkshopItem;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::steam_manager;
+use crate::core::workshop_item::workshop::{UserListOrder, UserListType};
+
+#[derive(Debug, Serialize, JsonSchema, Deserialize)]
+pub struct FavoriteResult {
+    pub item_id: u64,
+    pub success: bool,
+}
+
+pub async fn favorite(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<FavoriteResult>, S7forgeError> {
+    set_favorites(steam_game_id, item_ids, true).await
+}
+
+pub async fn unfavorite(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<FavoriteResult>, S7forgeError> {
+    set_favorites(steam_game_id, item_ids, false).await
+}
+
+pub async fn list_favorites(
+    steam_game_id: u32,
+    page: u32,
+) -> Result<Vec<EnhancedWorkshopItem>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let steam_id = steam_client.user().steam_id();
+
+    query_user_items(
+        steam_game_id,
+        steam_id,
+        UserListType::Favorited,
+        UserListOrder::CreationOrderDesc,
+        page,
+    )
+    .await
+}
+
+async fn set_favorites(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+    add: bool,
+) -> Result<Vec<FavoriteResult>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let mut results = Vec::new();
+
+    for item_id in item_ids {
+        let success = set_single_favorite(&steam_client, steam_game_id, item_id, add).await?;
+        results.push(FavoriteResult { item_id, success });
+    }
+
+    Ok(results)
+}
+
+async fn set_single_favorite(
+    steam_client: &steamworks::Client,
+    steam_game_id: u32,
+    item_id: u64,
+    add: bool,
+) -> Result<bool, S7forgeError> {
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let steam_client_clone = steam_client.clone();
+    let fav_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client_clone.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel();
+
+        let callback = move |result| {
+            let _ = tx_inner.send(result);
+        };
+        if add {
+            ugc.add_item_to_favorites(AppId(steam_game_id), PublishedFileId(item_id), callback);
+        } else {
+            ugc.remove_item_from_favorites(
+                AppId(steam_game_id),
+                PublishedFileId(item_id),
+                callback,
+            );
+        }
+
+        let start_time = std::time::Instant::now();
+        let timeout_duration = settings::get().steam_timeout;
+
+        loop {
+            let _ = tx.blocking_send(());
+            if let Ok(result) = rx_inner.try_recv() {
+                return result
+                    .map(|_| true)
+                    .map_err(|e| S7forgeError::item_steam_api(item_id, e));
+            }
+
+            if start_time.elapsed() > timeout_duration {
+                return Err(S7forgeError::Timeout("Operation"));
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut result = None;
+    let mut fav_task = fav_task.fuse();
+
+    while result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut fav_task => {
+                result = Some(task_result.map_err(S7forgeError::Join)?);
+                break;
+            }
+        }
+    }
+
+    result.unwrap()
+}
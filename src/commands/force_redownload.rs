@@ -0,0 +1,90 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use steamworks::{ItemState, PublishedFileId};
+
+use crate::commands::download_workshop_items::{ItemDownloadResult, RetryPolicy, download_items};
+use crate::commands::installed_items::remove_manifest_entries;
+use crate::commands::orphaned_items::content_dirs;
+use crate::commands::remove_item_files::ensure_inside;
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+use crate::utils::remove_path::remove_path;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ForceRedownloadResult {
+    /// Content folder that was deleted, if the item had one
+    pub deleted_path: Option<String>,
+    pub manifest_entry_removed: bool,
+    #[serde(flatten)]
+    pub download: ItemDownloadResult,
+}
+
+/// Deletes an item's local content and manifest entry, then downloads it again from scratch.
+pub async fn force_redownload(
+    steam_game_id: u32,
+    item_id: u64,
+    retry: RetryPolicy,
+) -> Result<ForceRedownloadResult, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let ugc = steam_client.ugc();
+
+    if !ugc
+        .item_state(PublishedFileId(item_id))
+        .contains(ItemState::SUBSCRIBED)
+    {
+        return Err(S7forgeError::NotSubscribed(item_id));
+    }
+
+    let install_folder = ugc
+        .item_install_info(PublishedFileId(item_id))
+        .map(|info| info.folder);
+    drop(ugc);
+
+    let deleted_path = match install_folder {
+        Some(folder) if Path::new(&folder).exists() => {
+            // Steam reports the folder, but it is only deleted if it's where workshop content lives
+            ensure_inside(Path::new(&folder), &content_dirs(steam_game_id)?)?;
+            remove_path(Path::new(&folder))
+                .map_err(|e| S7forgeError::io(format!("Failed to delete {}", folder), e))?;
+            Some(folder)
+        }
+        _ => None,
+    };
+
+    let manifest_entry_removed = remove_manifest_entries(steam_game_id, item_id)?;
+
+    let download = download_items(steam_game_id, vec![item_id], 1, retry)
+        .await?
+        .pop()
+        .ok_or_else(|| S7forgeError::Download {
+            item_id,
+            message: "the download produced no result".to_string(),
+        })?;
+
+    if let Some(error) = &download.error {
+        return Err(S7forgeError::Download {
+            item_id,
+            message: error.clone(),
+        });
+    }
+
+    // Steam can consider the item up to date from its in-memory state and skip the download
+    let restored = download
+        .path
+        .as_deref()
+        .map(PathBuf::from)
+        .is_some_and(|path| path.is_dir());
+    if !restored {
+        return Err(S7forgeError::Download {
+            item_id,
+            message: "Steam did not restore its files; restart Steam so it re-reads the workshop manifest, then run this command again".to_string(),
+        });
+    }
+
+    Ok(ForceRedownloadResult {
+        deleted_path,
+        manifest_entry_removed,
+        download,
+    })
+}
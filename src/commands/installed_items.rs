@@ -0,0 +1,138 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::steam_library_paths::steam_library_paths;
+use crate::core::acf;
+use crate::core::error::S7forgeError;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct InstalledItem {
+    pub item_id: u64,
+    pub size_on_disk: u64,
+    pub time_updated: u64,
+    pub manifest_id: u64,
+    /// Last update time Steam knows of on the Workshop, when newer than the installed one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_time_updated: Option<u64>,
+    /// Account ID that subscribed to the item on this machine, if Steam recorded one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribed_by: Option<u32>,
+    /// Content folder, if it exists on disk
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// `steamapps/workshop` folders of every library that has an appworkshop manifest for the app.
+pub fn app_workshop_dirs(steam_game_id: u32) -> Result<Vec<PathBuf>, S7forgeError> {
+    let library_paths = steam_library_paths()?;
+
+    Ok(library_paths
+        .into_iter()
+        .map(|library_path| Path::new(&library_path).join("steamapps").join("workshop"))
+        .filter(|workshop_dir| {
+            workshop_dir
+                .join(format!("appworkshop_{}.acf", steam_game_id))
+                .is_file()
+        })
+        .collect())
+}
+
+/// Every item Steam has installed for the app, read from the appworkshop manifests
+/// rather than the subscription list, so items of other accounts show up too.
+pub fn installed_items(steam_game_id: u32) -> Result<Vec<InstalledItem>, S7forgeError> {
+    let mut items: Vec<InstalledItem> = Vec::new();
+
+    for workshop_dir in app_workshop_dirs(steam_game_id)? {
+        let manifest_path = workshop_dir.join(format!("appworkshop_{}.acf", steam_game_id));
+        let content = fs::read_to_string(&manifest_path).map_err(|e| {
+            S7forgeError::io(format!("Failed to read {}", manifest_path.display()), e)
+        })?;
+        let document = acf::parse(&manifest_path, &content)?;
+
+        let Some(app_workshop) = document.get_section("AppWorkshop") else {
+            continue;
+        };
+        let Some(installed) = app_workshop.get_section("WorkshopItemsInstalled") else {
+            continue;
+        };
+        let details = app_workshop.get_section("WorkshopItemDetails");
+        let content_dir = workshop_dir.join("content").join(steam_game_id.to_string());
+
+        for (key, entry) in installed.sections() {
+            let Ok(item_id) = key.parse::<u64>() else {
+                continue;
+            };
+            if items.iter().any(|item| item.item_id == item_id) {
+                continue;
+            }
+
+            let item_path = content_dir.join(key);
+            let detail = details.and_then(|details| details.get_section(key));
+            let time_updated = entry.get_u64("timeupdated").unwrap_or_default();
+            items.push(InstalledItem {
+                item_id,
+                size_on_disk: entry.get_u64("size").unwrap_or_default(),
+                time_updated,
+                manifest_id: entry.get_u64("manifest").unwrap_or_default(),
+                latest_time_updated: detail
+                    .and_then(|detail| detail.get_u64("timeupdated"))
+                    .filter(|&latest| latest > time_updated),
+                subscribed_by: detail
+                    .and_then(|detail| detail.get_u64("subscribedby"))
+                    .and_then(|account_id| u32::try_from(account_id).ok())
+                    .filter(|&account_id| account_id != 0),
+                path: item_path
+                    .is_dir()
+                    .then(|| item_path.to_string_lossy().into_owned()),
+            });
+        }
+    }
+
+    Ok(items)
+}
+
+/// Removes an item from the appworkshop manifest of every library, so Steam stops treating
+/// its deleted content as installed. Returns whether any manifest listed it.
+pub fn remove_manifest_entries(steam_game_id: u32, item_id: u64) -> Result<bool, S7forgeError> {
+    let mut removed = false;
+    for workshop_dir in app_workshop_dirs(steam_game_id)? {
+        let manifest_path = workshop_dir.join(format!("appworkshop_{}.acf", steam_game_id));
+        removed |= remove_manifest_entry(&manifest_path, item_id)?;
+    }
+    Ok(removed)
+}
+
+// Only rewrites manifests that parse cleanly, so an unexpected layout is left untouched
+fn remove_manifest_entry(manifest_path: &Path, item_id: u64) -> Result<bool, S7forgeError> {
+    let Ok(content) = fs::read_to_string(manifest_path) else {
+        return Ok(false);
+    };
+    let Ok(mut document) = acf::parse(manifest_path, &content) else {
+        return Ok(false);
+    };
+    let Some(app_workshop) = document.get_section_mut("AppWorkshop") else {
+        return Ok(false);
+    };
+
+    let key = item_id.to_string();
+    let mut removed = false;
+    for section in ["WorkshopItemsInstalled", "WorkshopItemDetails"] {
+        if let Some(items) = app_workshop.get_section_mut(section) {
+            removed |= items.remove(&key);
+        }
+    }
+    if !removed {
+        return Ok(false);
+    }
+
+    let temp_path = manifest_path.with_extension("acf.tmp");
+    fs::write(&temp_path, acf::to_string(&document))
+        .and_then(|_| fs::rename(&temp_path, manifest_path))
+        .map_err(|e| {
+            S7forgeError::io(format!("Failed to update {}", manifest_path.display()), e)
+        })?;
+
+    Ok(true)
+}
@@ -0,0 +1,50 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::core::error::S7forgeError;
+use crate::core::web_api::{self, json_str, json_u64};
+
+pub const DEFAULT_CHANGELOG_LIMIT: usize = 20;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemChangelog {
+    pub item_id: u64,
+    /// Number of entries the item has in total, which can exceed `--limit`
+    pub total: u64,
+    pub changes: Vec<ChangelogEntry>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ChangelogEntry {
+    /// Unix time in seconds
+    pub timestamp: u64,
+    /// BBCode as written by the author; empty when the update had no note
+    pub change_note: String,
+}
+
+/// Returns an item's update history, newest first. steamworks-rs has no access to change
+/// notes, so this reads the Web API and needs a Web API key.
+pub async fn item_changelog(item_id: u64, limit: u32) -> Result<ItemChangelog, S7forgeError> {
+    let (changes, total) =
+        tokio::task::spawn_blocking(move || web_api::change_history(item_id, limit))
+            .await
+            .map_err(S7forgeError::Join)??;
+
+    let mut changes: Vec<ChangelogEntry> = changes
+        .iter()
+        .map(|change| ChangelogEntry {
+            timestamp: json_u64(change, "timestamp").unwrap_or_default(),
+            change_note: json_str(change, "change_description")
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        })
+        .collect();
+    changes.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    Ok(ItemChangelog {
+        item_id,
+        total,
+        changes,
+    })
+}
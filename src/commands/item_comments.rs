@@ -0,0 +1,115 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::SteamId;
+
+use crate::core::error::S7forgeError;
+use crate::core::localplayer::PlayerSteamId;
+use crate::core::web_api::{self, json_str, json_u64};
+use crate::utils::html_to_text::html_to_text;
+
+pub const COMMENTS_PER_PAGE: u32 = 50;
+
+// Account IDs in the comment markup are relative to the individual account base
+const STEAM_ID64_BASE: u64 = 76561197960265728;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemComments {
+    pub item_id: u64,
+    pub page: u32,
+    pub total: u64,
+    pub has_more: bool,
+    pub comments: Vec<ItemComment>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemComment {
+    pub comment_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<PlayerSteamId>,
+    pub author_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_profile_url: Option<String>,
+    /// Unix time in seconds
+    pub timestamp: u64,
+    pub text: String,
+}
+
+/// Returns one page of an item's comments, newest first. Steam has no API for workshop
+/// comments, so this reads the community site's comment thread and needs no Web API key.
+pub async fn item_comments(item_id: u64, page: u32) -> Result<ItemComments, S7forgeError> {
+    tokio::task::spawn_blocking(move || {
+        let owner_id = web_api::published_file_details(&[item_id])?
+            .first()
+            .map(|item| item.owner.steam_id64)
+            .ok_or(S7forgeError::ItemNotFound(item_id))?;
+
+        let start = (page - 1) * COMMENTS_PER_PAGE;
+        let response = web_api::item_comments_page(owner_id, item_id, start, COMMENTS_PER_PAGE)?;
+        let total = json_u64(&response, "total_count").unwrap_or_default();
+        let comments = parse_comments(&json_str(&response, "comments_html").unwrap_or_default());
+
+        Ok(ItemComments {
+            item_id,
+            page,
+            total,
+            has_more: u64::from(start) + (comments.len() as u64) < total,
+            comments,
+        })
+    })
+    .await
+    .map_err(S7forgeError::Join)?
+}
+
+// Each comment is a `commentthread_comment` block holding the author link (with the account ID
+// in `data-miniprofile`), a `data-timestamp` span and the `commentthread_comment_text` body
+fn parse_comments(html: &str) -> Vec<ItemComment> {
+    html.split("<div class=\"commentthread_comment ")
+        .skip(1)
+        .filter_map(|block| {
+            let comment_id = attribute(block, "id=\"comment_")?.parse().ok()?;
+            let author = attribute(block, "data-miniprofile=\"")
+                .and_then(|account_id| account_id.parse::<u64>().ok())
+                .map(|account_id| {
+                    PlayerSteamId::from_steamid(SteamId::from_raw(STEAM_ID64_BASE + account_id))
+                });
+            let author_profile_url = block
+                .find("commentthread_author_link")
+                .and_then(|start| attribute(&block[start..], "href=\""))
+                .map(|url| url.to_string());
+            let author_name = between(block, "<bdi>", "</bdi>")
+                .map(html_to_text)
+                .unwrap_or_default();
+            let timestamp = attribute(block, "data-timestamp=\"")
+                .and_then(|timestamp| timestamp.parse().ok())
+                .unwrap_or_default();
+            let text = block
+                .find("class=\"commentthread_comment_text\"")
+                .and_then(|start| {
+                    let body = &block[start..];
+                    between(body, ">", "</div>")
+                })
+                .map(html_to_text)
+                .unwrap_or_default();
+
+            Some(ItemComment {
+                comment_id,
+                author,
+                author_name,
+                author_profile_url,
+                timestamp,
+                text,
+            })
+        })
+        .collect()
+}
+
+fn between<'a>(text: &'a str, open: &str, close: &str) -> Option<&'a str> {
+    let start = text.find(open)? + open.len();
+    let end = start + text[start..].find(close)?;
+    Some(&text[start..end])
+}
+
+// Value of the first attribute starting with `prefix`, up to the closing quote
+fn attribute<'a>(block: &'a str, prefix: &str) -> Option<&'a str> {
+    between(block, prefix, "\"")
+}
@@ -0,0 +1,97 @@
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::commands::item_install_path::item_install_path;
+use crate::commands::profiles::active_profile;
+use crate::commands::subscriptions::subscribed_item_ids;
+use crate::core::error::S7forgeError;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FileConflict {
+    /// Path relative to the item folder, with forward slashes
+    pub path: String,
+    /// Items shipping this file, in the order they were scanned
+    pub item_ids: Vec<u64>,
+    /// Item whose copy is loaded last according to the active profile, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub winner: Option<u64>,
+}
+
+/// Finds files that more than one installed item ships under the same relative path.
+/// With no `item_ids`, all subscribed items are scanned.
+pub async fn item_conflicts(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<FileConflict>, S7forgeError> {
+    let item_ids = if item_ids.is_empty() {
+        subscribed_item_ids(steam_game_id).await?
+    } else {
+        item_ids
+    };
+
+    // Keyed case-insensitively since most games run on Windows
+    let mut files: FxHashMap<String, FileConflict> = FxHashMap::default();
+    for item_id in item_ids {
+        let Ok(info) = item_install_path(steam_game_id, item_id).await else {
+            continue;
+        };
+
+        let mut relative_paths = Vec::new();
+        collect_files(Path::new(&info.path), "", &mut relative_paths)
+            .map_err(|e| S7forgeError::io(format!("Failed to scan {}", info.path), e))?;
+
+        for path in relative_paths {
+            let entry = files
+                .entry(path.to_lowercase())
+                .or_insert_with(|| FileConflict {
+                    path,
+                    item_ids: Vec::new(),
+                    winner: None,
+                });
+            if !entry.item_ids.contains(&item_id) {
+                entry.item_ids.push(item_id);
+            }
+        }
+    }
+
+    let mut conflicts: Vec<FileConflict> = files
+        .into_values()
+        .filter(|file| file.item_ids.len() > 1)
+        .collect();
+    conflicts.sort_by(|a, b| a.path.cmp(&b.path));
+
+    if let Some(profile) = active_profile(steam_game_id)? {
+        for conflict in &mut conflicts {
+            conflict.winner = profile
+                .items
+                .iter()
+                .rev()
+                .find(|item_id| conflict.item_ids.contains(item_id))
+                .copied();
+        }
+    }
+
+    Ok(conflicts)
+}
+
+fn collect_files(dir: &Path, prefix: &str, files: &mut Vec<String>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let relative = if prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", prefix, name)
+        };
+
+        if entry.path().is_dir() {
+            collect_files(&entry.path(), &relative, files)?;
+        } else {
+            files.push(relative);
+        }
+    }
+    Ok(())
+}
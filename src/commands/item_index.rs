@@ -0,0 +1,253 @@
+use rusqlite::{Connection, params, params_from_iter, types::Value as SqlValue};
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::commands::subscriptions::subscribed_item_ids;
+use crate::commands::workshop_items::fetch_workshop_items;
+use crate::core::error::S7forgeError;
+use crate::utils::get_cache_dir::get_cache_dir;
+
+#[derive(Debug)]
+pub enum IndexAction {
+    Update,
+    Query(IndexQuery),
+}
+
+#[derive(Debug, Default)]
+pub struct IndexQuery {
+    /// Case-insensitive substring of the title
+    pub text: Option<String>,
+    /// Every tag must be present
+    pub tags: Vec<String>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    /// Unix time in milliseconds
+    pub updated_since: Option<u64>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct IndexUpdateResult {
+    pub added: Vec<u64>,
+    pub updated: Vec<u64>,
+    pub removed: Vec<u64>,
+    pub unchanged: usize,
+    pub database: String,
+}
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS items (
+    item_id INTEGER PRIMARY KEY,
+    title TEXT NOT NULL,
+    owner INTEGER NOT NULL,
+    time_created INTEGER NOT NULL,
+    time_updated INTEGER NOT NULL,
+    file_size INTEGER NOT NULL,
+    num_upvotes INTEGER NOT NULL,
+    num_downvotes INTEGER NOT NULL,
+    details TEXT NOT NULL,
+    indexed_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS item_tags (
+    item_id INTEGER NOT NULL REFERENCES items(item_id) ON DELETE CASCADE,
+    tag TEXT NOT NULL COLLATE NOCASE,
+    PRIMARY KEY (item_id, tag)
+);
+CREATE INDEX IF NOT EXISTS item_tags_tag ON item_tags(tag);
+CREATE INDEX IF NOT EXISTS items_time_updated ON items(time_updated);
+";
+
+// Kept outside the cache files so clear-cache doesn't drop the index
+fn index_path(steam_game_id: u32) -> Result<PathBuf, S7forgeError> {
+    let dir = get_cache_dir()?.join("index");
+    fs::create_dir_all(&dir)
+        .map_err(|e| S7forgeError::io(format!("Failed to create {}", dir.display()), e))?;
+    Ok(dir.join(format!("{}.sqlite", steam_game_id)))
+}
+
+fn open_index(steam_game_id: u32) -> Result<(Connection, PathBuf), S7forgeError> {
+    let path = index_path(steam_game_id)?;
+    let connection = Connection::open(&path)
+        .map_err(|e| S7forgeError::cache(format!("Failed to open {}", path.display()), e))?;
+    connection
+        .execute_batch(&format!("PRAGMA foreign_keys = ON;{}", SCHEMA))
+        .map_err(|e| S7forgeError::cache(format!("Failed to initialize {}", path.display()), e))?;
+    Ok((connection, path))
+}
+
+/// Syncs the index with the current subscriptions. Only rows of new or changed items are
+/// rewritten, and items that are no longer subscribed are dropped.
+pub async fn update_index(steam_game_id: u32) -> Result<IndexUpdateResult, S7forgeError> {
+    let item_ids = subscribed_item_ids(steam_game_id).await?;
+    let items = fetch_workshop_items(steam_game_id, item_ids).await?;
+
+    let (mut connection, path) = open_index(steam_game_id)?;
+    let sql_error =
+        |e: rusqlite::Error| S7forgeError::cache(format!("Failed to update {}", path.display()), e);
+
+    let indexed: FxHashMap<u64, u64> = {
+        let mut statement = connection
+            .prepare("SELECT item_id, time_updated FROM items")
+            .map_err(sql_error)?;
+        statement
+            .query_map([], |row| {
+                Ok((row.get::<_, i64>(0)? as u64, row.get::<_, i64>(1)? as u64))
+            })
+            .map_err(sql_error)?
+            .collect::<Result<_, _>>()
+            .map_err(sql_error)?
+    };
+
+    let indexed_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut result = IndexUpdateResult {
+        added: Vec::new(),
+        updated: Vec::new(),
+        removed: Vec::new(),
+        unchanged: 0,
+        database: path.to_string_lossy().into_owned(),
+    };
+
+    let transaction = connection.transaction().map_err(sql_error)?;
+    for item in &items {
+        let item_id = item.published_file_id;
+        match indexed.get(&item_id) {
+            Some(&time_updated) if time_updated == item.time_updated => {
+                result.unchanged += 1;
+                continue;
+            }
+            Some(_) => result.updated.push(item_id),
+            None => result.added.push(item_id),
+        }
+
+        let details = serde_json::to_string(item)
+            .map_err(|e| S7forgeError::parse(format!("Failed to serialize item {}", item_id), e))?;
+        transaction
+            .execute(
+                "INSERT OR REPLACE INTO items (item_id, title, owner, time_created, time_updated,
+                     file_size, num_upvotes, num_downvotes, details, indexed_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    item_id as i64,
+                    item.title,
+                    item.owner.steam_id64 as i64,
+                    item.time_created as i64,
+                    item.time_updated as i64,
+                    item.file_size as i64,
+                    item.num_upvotes,
+                    item.num_downvotes,
+                    details,
+                    indexed_at as i64,
+                ],
+            )
+            .map_err(sql_error)?;
+        transaction
+            .execute("DELETE FROM item_tags WHERE item_id = ?1", [item_id as i64])
+            .map_err(sql_error)?;
+        for tag in item
+            .tags
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+        {
+            transaction
+                .execute(
+                    "INSERT OR IGNORE INTO item_tags (item_id, tag) VALUES (?1, ?2)",
+                    params![item_id as i64, tag],
+                )
+                .map_err(sql_error)?;
+        }
+    }
+
+    let current: FxHashSet<u64> = items.iter().map(|item| item.published_file_id).collect();
+    for &item_id in indexed.keys() {
+        if !current.contains(&item_id) {
+            transaction
+                .execute("DELETE FROM items WHERE item_id = ?1", [item_id as i64])
+                .map_err(sql_error)?;
+            result.removed.push(item_id);
+        }
+    }
+    transaction.commit().map_err(sql_error)?;
+
+    result.removed.sort_unstable();
+    Ok(result)
+}
+
+/// Drops items from the index, e.g. after they were deleted from the workshop. Returns how
+/// many rows were removed; an index that was never built is left alone.
+pub fn remove_from_index(steam_game_id: u32, item_ids: &[u64]) -> Result<usize, S7forgeError> {
+    if !index_path(steam_game_id)?.exists() {
+        return Ok(0);
+    }
+
+    let (mut connection, path) = open_index(steam_game_id)?;
+    let sql_error =
+        |e: rusqlite::Error| S7forgeError::cache(format!("Failed to update {}", path.display()), e);
+
+    let transaction = connection.transaction().map_err(sql_error)?;
+    let mut removed = 0;
+    for &item_id in item_ids {
+        removed += transaction
+            .execute("DELETE FROM items WHERE item_id = ?1", [item_id as i64])
+            .map_err(sql_error)?;
+    }
+    transaction.commit().map_err(sql_error)?;
+    Ok(removed)
+}
+
+/// Returns the stored details of matching items, most recently updated first.
+pub fn query_index(
+    steam_game_id: u32,
+    query: &IndexQuery,
+) -> Result<Vec<serde_json::Value>, S7forgeError> {
+    let (connection, path) = open_index(steam_game_id)?;
+    let sql_error =
+        |e: rusqlite::Error| S7forgeError::cache(format!("Failed to query {}", path.display()), e);
+
+    let mut conditions: Vec<&str> = Vec::new();
+    let mut values: Vec<SqlValue> = Vec::new();
+    if let Some(text) = &query.text {
+        conditions.push("instr(lower(title), lower(?)) > 0");
+        values.push(SqlValue::Text(text.clone()));
+    }
+    for tag in &query.tags {
+        conditions.push("item_id IN (SELECT item_id FROM item_tags WHERE tag = ?)");
+        values.push(SqlValue::Text(tag.clone()));
+    }
+    if let Some(min_size) = query.min_size {
+        conditions.push("file_size >= ?");
+        values.push(SqlValue::Integer(min_size as i64));
+    }
+    if let Some(max_size) = query.max_size {
+        conditions.push("file_size <= ?");
+        values.push(SqlValue::Integer(max_size as i64));
+    }
+    if let Some(updated_since) = query.updated_since {
+        conditions.push("time_updated >= ?");
+        values.push(SqlValue::Integer(updated_since as i64));
+    }
+
+    let mut sql = String::from("SELECT details FROM items");
+    if !conditions.is_empty() {
+        sql.push_str(" WHERE ");
+        sql.push_str(&conditions.join(" AND "));
+    }
+    sql.push_str(" ORDER BY time_updated DESC");
+
+    let mut statement = connection.prepare(&sql).map_err(sql_error)?;
+    let rows = statement
+        .query_map(params_from_iter(values), |row| row.get::<_, String>(0))
+        .map_err(sql_error)?;
+
+    rows.map(|row| {
+        let details = row.map_err(sql_error)?;
+        serde_json::from_str(&details).map_err(|e| S7forgeError::parse("Corrupt index entry", e))
+    })
+    .collect()
+}
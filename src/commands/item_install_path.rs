@@ -0,0 +1,80 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+use steamworks::PublishedFileId;
+
+use crate::commands::installed_items::installed_items;
+use crate::commands::workshop_path::workshop_path;
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+use crate::utils::dir_size::dir_size;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemInstallPath {
+    pub item_id: u64,
+    pub path: String,
+    pub size_on_disk: u64,
+    /// Unix time of the installed version
+    pub timestamp: u64,
+    /// "steam" when reported by the Steam client, "workshop_path" for the fallback lookup
+    pub source: &'static str,
+}
+
+pub async fn item_install_path(
+    steam_game_id: u32,
+    item_id: u64,
+) -> Result<ItemInstallPath, S7forgeError> {
+    if let Some(steam_client) = steam_manager::initialize_client_or_fallback(steam_game_id).await {
+        if let Some(info) = steam_client
+            .ugc()
+            .item_install_info(PublishedFileId(item_id))
+        {
+            return Ok(ItemInstallPath {
+                item_id,
+                path: info.folder,
+                size_on_disk: info.size_on_disk,
+                timestamp: info.timestamp as u64,
+                source: "steam",
+            });
+        }
+    }
+
+    // Without the client (or for items installed under another account) use the default layout
+    let workshop_dir = workshop_path(steam_game_id).ok_or_else(|| {
+        S7forgeError::NotFound(format!(
+            "Workshop path not found for app ID {}",
+            steam_game_id
+        ))
+    })?;
+    let item_path = Path::new(&workshop_dir).join(item_id.to_string());
+    if !item_path.is_dir() {
+        return Err(S7forgeError::NotFound(format!(
+            "Workshop item {} is not installed",
+            item_id
+        )));
+    }
+
+    let manifest_entry = installed_items(steam_game_id)
+        .ok()
+        .and_then(|items| items.into_iter().find(|item| item.item_id == item_id));
+    let timestamp = match &manifest_entry {
+        Some(entry) => entry.time_updated,
+        None => std::fs::metadata(&item_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default(),
+    };
+
+    Ok(ItemInstallPath {
+        item_id,
+        size_on_disk: dir_size(&item_path).map_err(|e| {
+            S7forgeError::io(format!("Failed to measure {}", item_path.display()), e)
+        })?,
+        timestamp,
+        path: item_path.to_string_lossy().into_owned(),
+        source: "workshop_path",
+    })
+}
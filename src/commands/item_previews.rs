@@ -0,0 +1,83 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::core::error::S7forgeError;
+use crate::core::web_api::{self, json_str, json_u64};
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemPreviews {
+    pub item_id: u64,
+    pub previews: Vec<AdditionalPreview>,
+}
+
+/// One entry of an item's gallery, in the order the author arranged it.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AdditionalPreview {
+    /// image, youtube, sketchfab, environment_map_horizontal_cross, environment_map_lat_long,
+    /// clip or unknown
+    pub preview_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub youtube_video_id: Option<String>,
+    /// Sketchfab model ID, or the original file name of uploaded images
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+/// Lists the screenshots, videos and Sketchfab models shown on each item's page besides the
+/// main preview image. steamworks-rs doesn't wrap `GetQueryUGCAdditionalPreview`, so this
+/// reads the same data from the Web API and needs a Web API key.
+pub async fn item_previews(item_ids: Vec<u64>) -> Result<Vec<ItemPreviews>, S7forgeError> {
+    let details = tokio::task::spawn_blocking(move || {
+        web_api::file_details(&item_ids, &[("includeadditionalpreviews", "true")])
+    })
+    .await
+    .map_err(S7forgeError::Join)??;
+
+    Ok(details
+        .iter()
+        .filter_map(|detail| {
+            let mut previews: Vec<(u64, AdditionalPreview)> = detail
+                .get("previews")
+                .and_then(|previews| previews.as_array())
+                .map(|previews| previews.iter().map(additional_preview).collect())
+                .unwrap_or_default();
+            previews.sort_by_key(|(sort_order, _)| *sort_order);
+
+            Some(ItemPreviews {
+                item_id: json_u64(detail, "publishedfileid")?,
+                previews: previews.into_iter().map(|(_, preview)| preview).collect(),
+            })
+        })
+        .collect())
+}
+
+fn additional_preview(preview: &Value) -> (u64, AdditionalPreview) {
+    // EItemPreviewType
+    let preview_type = match json_u64(preview, "preview_type").unwrap_or(0) {
+        0 => "image",
+        1 => "youtube",
+        2 => "sketchfab",
+        3 => "environment_map_horizontal_cross",
+        4 => "environment_map_lat_long",
+        5 => "clip",
+        _ => "unknown",
+    };
+
+    (
+        json_u64(preview, "sortorder").unwrap_or_default(),
+        AdditionalPreview {
+            preview_type: preview_type.to_string(),
+            url: json_str(preview, "url").filter(|url| !url.is_empty()),
+            youtube_video_id: json_str(preview, "youtubevideoid").filter(|id| !id.is_empty()),
+            external_reference: json_str(preview, "external_reference")
+                .or_else(|| json_str(preview, "filename"))
+                .filter(|reference| !reference.is_empty()),
+            size: json_u64(preview, "size").filter(|&size| size > 0),
+        },
+    )
+}
@@ -0,0 +1,64 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::{ItemState, PublishedFileId};
+
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemStateFlags {
+    pub subscribed: bool,
+    pub installed: bool,
+    pub needs_update: bool,
+    pub downloading: bool,
+    pub download_pending: bool,
+    pub legacy: bool,
+    /// Raw EItemState bitmask, for flags newer SDKs may add
+    pub raw: u32,
+}
+
+impl From<ItemState> for ItemStateFlags {
+    fn from(state: ItemState) -> Self {
+        Self {
+            subscribed: state.contains(ItemState::SUBSCRIBED),
+            installed: state.contains(ItemState::INSTALLED),
+            needs_update: state.contains(ItemState::NEEDS_UPDATE),
+            downloading: state.contains(ItemState::DOWNLOADING),
+            download_pending: state.contains(ItemState::DOWNLOAD_PENDING),
+            legacy: state.contains(ItemState::LEGACY_ITEM),
+            raw: state.bits(),
+        }
+    }
+}
+
+/// Item state flags for several items from one Steam session, keyed by item ID.
+pub async fn item_state(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<serde_json::Map<String, serde_json::Value>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let states = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client.ugc();
+        item_ids
+            .into_iter()
+            .map(|item_id| {
+                let state = ugc.item_state(PublishedFileId(item_id));
+                (item_id, ItemStateFlags::from(state))
+            })
+            .collect::<Vec<_>>()
+    })
+    .await
+    .map_err(S7forgeError::Join)?;
+
+    let mut result = serde_json::Map::new();
+    for (item_id, flags) in states {
+        result.insert(
+            item_id.to_string(),
+            serde_json::to_value(flags)
+                .map_err(|e| S7forgeError::parse("Failed to serialize item state", e))?,
+        );
+    }
+
+    Ok(result)
+}
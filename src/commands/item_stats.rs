@@ -0,0 +1,95 @@
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::commands::workshop_items::fetch_workshop_items;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::web_api::{self, json_u64};
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemStats {
+    pub item_id: u64,
+    pub title: String,
+    pub current_subscribers: Option<u64>,
+    pub lifetime_subscriptions: Option<u64>,
+    pub current_favorites: Option<u64>,
+    pub lifetime_favorites: Option<u64>,
+    pub followers: Option<u64>,
+    pub unique_views: Option<u64>,
+    pub comments: Option<u64>,
+    pub num_upvotes: u32,
+    pub num_downvotes: u32,
+    /// Total seconds the item has been used across all players
+    pub playtime_seconds: Option<u64>,
+    pub playtime_sessions: Option<u64>,
+}
+
+/// Returns the usage counters Steam keeps for each item, always queried fresh. Playtime is
+/// only reported by the Web API, so it is filled in when a Web API key is configured.
+pub async fn item_stats(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<ItemStats>, S7forgeError> {
+    let items = fetch_workshop_items(steam_game_id, item_ids.clone()).await?;
+
+    let playtime: FxHashMap<u64, (Option<u64>, Option<u64>)> =
+        if settings::get().web_api_key.is_some() {
+            let details = tokio::task::spawn_blocking(move || {
+                web_api::file_details(&item_ids, &[("return_playtime_stats", "0")])
+            })
+            .await
+            .map_err(S7forgeError::Join)??;
+
+            details
+                .iter()
+                .filter_map(|detail| {
+                    Some((
+                        json_u64(detail, "publishedfileid")?,
+                        (
+                            json_u64(detail, "lifetime_playtime"),
+                            json_u64(detail, "lifetime_playtime_sessions"),
+                        ),
+                    ))
+                })
+                .collect()
+        } else {
+            FxHashMap::default()
+        };
+
+    Ok(items
+        .into_iter()
+        .map(|item| {
+            let statistics = item.statistics;
+            // Steam reports zero playtime for games that don't track it
+            let (web_seconds, web_sessions) = playtime
+                .get(&item.published_file_id)
+                .copied()
+                .unwrap_or_default();
+            let playtime_seconds = statistics
+                .num_seconds_played
+                .filter(|&seconds| seconds > 0)
+                .or(web_seconds);
+            let playtime_sessions = statistics
+                .num_playtime_sessions
+                .filter(|&sessions| sessions > 0)
+                .or(web_sessions);
+
+            ItemStats {
+                item_id: item.published_file_id,
+                title: item.title,
+                current_subscribers: statistics.num_subscriptions,
+                lifetime_subscriptions: statistics.num_unique_subscriptions,
+                current_favorites: statistics.num_favorites,
+                lifetime_favorites: statistics.num_unique_favorites,
+                followers: statistics.num_followers,
+                unique_views: statistics.num_unique_website_views,
+                comments: statistics.num_comments,
+                num_upvotes: item.num_upvotes,
+                num_downvotes: item.num_downvotes,
+                playtime_seconds,
+                playtime_sessions,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,29 @@
+use steamworks::SteamId;
+
+use crate::commands::user_items::query_user_items;
+use crate::commands::workshop_items::EnhancedWorkshopItem;
+use crate::core::error::S7forgeError;
+use crate::core::workshop_item::workshop::{UserListOrder, UserListType};
+
+pub async fn items_by_creator(
+    steam_game_id: u32,
+    steam_id: u64,
+    page: u32,
+) -> Result<Vec<EnhancedWorkshopItem>, S7forgeError> {
+    // Individual account IDs start at 76561197960265728 (universe 1, account type 1)
+    if steam_id < 76561197960265728 {
+        return Err(S7forgeError::InvalidArgs(format!(
+            "Invalid SteamID64: {}",
+            steam_id
+        )));
+    }
+
+    query_user_items(
+        steam_game_id,
+        SteamId::from_raw(steam_id),
+        UserListType::Published,
+        UserListOrder::LastUpdatedDesc,
+        page,
+    )
+    .await
+}
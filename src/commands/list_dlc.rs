@@ -0,0 +1,74 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use steamworks::{AppId, Client};
+
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+
+// Longest DLC name Steam returns; longer ones are cut off
+const NAME_BUFFER_SIZE: usize = 256;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct AppDlc {
+    pub app_id: u32,
+    pub name: String,
+    /// Has a visible store page
+    pub available: bool,
+    /// Whether the logged-in account owns the DLC, including through family sharing
+    pub owned: bool,
+    pub installed: bool,
+}
+
+/// Lists the DLC of a game as Steam knows it, with what the logged-in account owns and has
+/// installed. Steamworks is initialized for the game itself, as `ISteamApps` only reports the
+/// DLC of the running app.
+pub async fn list_dlc(app_id: u32) -> Result<Vec<AppDlc>, S7forgeError> {
+    let client = steam_manager::initialize_client(app_id).await?;
+    let apps = client.apps();
+
+    Ok(dlc_data(&client)
+        .into_iter()
+        .map(|(dlc_id, available, name)| AppDlc {
+            app_id: dlc_id,
+            name,
+            available,
+            owned: apps.is_subscribed_app(AppId(dlc_id)),
+            installed: apps.is_dlc_installed(AppId(dlc_id)),
+        })
+        .collect())
+}
+
+// steamworks-rs doesn't wrap `GetDLCCount` and `BGetDLCDataByIndex`, so they are called
+// through steamworks-sys. The client shows that the Steam API is initialized.
+fn dlc_data(_client: &Client) -> Vec<(u32, bool, String)> {
+    // SAFETY: the API stays initialized while `_client` is alive, and the buffers outlive the
+    // calls writing to them
+    unsafe {
+        let apps = steamworks_sys::SteamAPI_SteamApps_v008();
+        if apps.is_null() {
+            return Vec::new();
+        }
+        let count = steamworks_sys::SteamAPI_ISteamApps_GetDLCCount(apps);
+        (0..count)
+            .filter_map(|index| {
+                let mut app_id = 0;
+                let mut available = false;
+                let mut name = [0 as c_char; NAME_BUFFER_SIZE];
+                steamworks_sys::SteamAPI_ISteamApps_BGetDLCDataByIndex(
+                    apps,
+                    index,
+                    &mut app_id,
+                    &mut available,
+                    name.as_mut_ptr(),
+                    NAME_BUFFER_SIZE as i32,
+                )
+                .then(|| {
+                    let name = CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned();
+                    (app_id, available, name)
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,147 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::core::error::S7forgeError;
+use crate::utils::get_cache_dir::get_cache_dir;
+
+#[derive(Debug)]
+pub enum LocalMetaAction {
+    Set {
+        item_ids: Vec<u64>,
+        note: Option<String>,
+        tags: Option<Vec<String>>,
+        pinned: Option<bool>,
+    },
+    Get {
+        item_ids: Vec<u64>,
+    },
+    Remove {
+        item_ids: Vec<u64>,
+    },
+    List,
+}
+
+/// User-owned notes about an item that never leave this machine.
+#[derive(Debug, Clone, Default, Serialize, JsonSchema, Deserialize)]
+pub struct LocalMeta {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+}
+
+impl LocalMeta {
+    fn is_empty(&self) -> bool {
+        self.note.is_none() && self.tags.is_empty() && !self.pinned
+    }
+}
+
+type LocalMetaStore = BTreeMap<u64, LocalMeta>;
+
+fn store_path(steam_game_id: u32) -> Result<PathBuf, S7forgeError> {
+    let dir = get_cache_dir()?.join("local_meta");
+    fs::create_dir_all(&dir)
+        .map_err(|e| S7forgeError::io(format!("Failed to create {}", dir.display()), e))?;
+    Ok(dir.join(format!("{}.json", steam_game_id)))
+}
+
+fn load_store(steam_game_id: u32) -> Result<LocalMetaStore, S7forgeError> {
+    let path = store_path(steam_game_id)?;
+    if !path.exists() {
+        return Ok(LocalMetaStore::new());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| S7forgeError::io(format!("Failed to read {}", path.display()), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| S7forgeError::parse(format!("Invalid {}", path.display()), e))
+}
+
+fn save_store(steam_game_id: u32, store: &LocalMetaStore) -> Result<(), S7forgeError> {
+    let path = store_path(steam_game_id)?;
+    let json = serde_json::to_string_pretty(store)
+        .map_err(|e| S7forgeError::parse("Failed to serialize local metadata", e))?;
+    fs::write(&path, json)
+        .map_err(|e| S7forgeError::io(format!("Failed to write {}", path.display()), e))
+}
+
+/// Runs `action` and returns the affected entries keyed by item ID.
+pub fn local_meta(
+    steam_game_id: u32,
+    action: LocalMetaAction,
+) -> Result<BTreeMap<u64, LocalMeta>, S7forgeError> {
+    let mut store = load_store(steam_game_id)?;
+
+    match action {
+        LocalMetaAction::Set {
+            item_ids,
+            note,
+            tags,
+            pinned,
+        } => {
+            let mut updated = BTreeMap::new();
+            for item_id in item_ids {
+                let meta = store.entry(item_id).or_default();
+                if let Some(note) = &note {
+                    // An empty note clears it
+                    meta.note = Some(note.clone()).filter(|note| !note.is_empty());
+                }
+                if let Some(tags) = &tags {
+                    meta.tags = tags.clone();
+                }
+                if let Some(pinned) = pinned {
+                    meta.pinned = pinned;
+                }
+                updated.insert(item_id, meta.clone());
+            }
+            store.retain(|_, meta| !meta.is_empty());
+            save_store(steam_game_id, &store)?;
+            Ok(updated)
+        }
+        LocalMetaAction::Get { item_ids } => Ok(item_ids
+            .into_iter()
+            .map(|item_id| (item_id, store.get(&item_id).cloned().unwrap_or_default()))
+            .collect()),
+        LocalMetaAction::Remove { item_ids } => {
+            let removed = item_ids
+                .into_iter()
+                .filter_map(|item_id| store.remove(&item_id).map(|meta| (item_id, meta)))
+                .collect();
+            save_store(steam_game_id, &store)?;
+            Ok(removed)
+        }
+        LocalMetaAction::List => Ok(store),
+    }
+}
+
+/// Adds a `local_meta` field to every item (objects with a `published_file_id`) that has any.
+pub fn merge_local_meta<T: Serialize>(
+    steam_game_id: u32,
+    items: &T,
+) -> Result<serde_json::Value, S7forgeError> {
+    let store = load_store(steam_game_id)?;
+    let mut value = serde_json::to_value(items)
+        .map_err(|e| S7forgeError::parse("Failed to serialize items", e))?;
+
+    if let Some(items) = value.as_array_mut() {
+        for item in items.iter_mut().filter_map(|item| item.as_object_mut()) {
+            let Some(meta) = item
+                .get("published_file_id")
+                .and_then(|id| id.as_u64())
+                .and_then(|item_id| store.get(&item_id))
+            else {
+                continue;
+            };
+            let meta = serde_json::to_value(meta)
+                .map_err(|e| S7forgeError::parse("Failed to serialize local metadata", e))?;
+            item.insert("local_meta".to_string(), meta);
+        }
+    }
+
+    Ok(value)
+}
@@ -1,13 +1,59 @@
+pub mod account_info;
 pub mod app_installation_path;
+pub mod blacklist;
+pub mod cache_export;
+pub mod cache_inspect;
+pub mod cache_stats;
 pub mod check_item_download;
+pub mod check_ownership;
+pub mod check_updates;
+pub mod cleanup_workshop;
 pub mod clear_cache;
 pub mod collection_items;
+pub mod completions;
+pub mod creator_profile;
+pub mod dependency_graph;
+pub mod deploy;
 pub mod discover_tags;
+pub mod download_previews;
+pub mod download_updates;
 pub mod download_workshop_item;
+pub mod download_workshop_items;
+pub mod favorites;
+pub mod force_redownload;
+pub mod installed_items;
+pub mod item_changelog;
+pub mod item_comments;
+pub mod item_conflicts;
+pub mod item_index;
+pub mod item_install_path;
+pub mod item_previews;
+pub mod item_state;
+pub mod item_stats;
+pub mod items_by_creator;
+pub mod list_dlc;
+pub mod local_meta;
+pub mod my_published_items;
+pub mod orphaned_items;
+pub mod profiles;
+pub mod publish;
+pub mod remove_item_files;
+pub mod required_dlc;
+pub mod restart_steam;
+pub mod schema;
+pub mod search_cache;
 pub mod search_workshop;
+pub mod self_update;
 pub mod steam_library_paths;
+pub mod steam_status;
 pub mod subscribe;
 pub mod subscribed_items;
+pub mod subscriptions;
 pub mod unsubscribe;
+pub mod unsubscribe_all;
+pub mod user_items;
+pub mod verify_items;
+pub mod vote;
+pub mod watch;
 pub mod workshop_items;
 pub mod workshop_path;
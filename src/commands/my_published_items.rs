@@ -0,0 +1,22 @@
+use crate::commands::user_items::query_user_items;
+use crate::commands::workshop_items::EnhancedWorkshopItem;
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+use crate::core::workshop_item::workshop::{UserListOrder, UserListType};
+
+pub async fn my_published_items(
+    steam_game_id: u32,
+    page: u32,
+) -> Result<Vec<EnhancedWorkshopItem>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let steam_id = steam_client.user().steam_id();
+
+    query_user_items(
+        steam_game_id,
+        steam_id,
+        UserListType::Published,
+        UserListOrder::LastUpdatedDesc,
+        page,
+    )
+    .await
+}
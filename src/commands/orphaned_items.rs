@@ -0,0 +1,94 @@
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::installed_items::{app_workshop_dirs, installed_items};
+use crate::commands::subscriptions::subscribed_item_ids;
+use crate::commands::workshop_path::workshop_path;
+use crate::core::error::S7forgeError;
+use crate::utils::dir_size::dir_size;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct OrphanedFolder {
+    /// None for folders that aren't named after a workshop item
+    pub item_id: Option<u64>,
+    pub path: String,
+    pub size_bytes: u64,
+    /// Whether Steam's appworkshop manifest still lists the item as installed
+    pub in_manifest: bool,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct OrphanedContent {
+    pub total_bytes: u64,
+    pub folders: Vec<OrphanedFolder>,
+}
+
+/// `workshop/content/<app_id>` folders in every Steam library that has one.
+pub fn content_dirs(steam_game_id: u32) -> Result<Vec<PathBuf>, S7forgeError> {
+    let mut dirs: Vec<PathBuf> = app_workshop_dirs(steam_game_id)?
+        .into_iter()
+        .map(|workshop_dir| workshop_dir.join("content").join(steam_game_id.to_string()))
+        .collect();
+
+    if let Some(path) = workshop_path(steam_game_id) {
+        let path = PathBuf::from(path);
+        if !dirs.contains(&path) {
+            dirs.push(path);
+        }
+    }
+
+    dirs.retain(|dir| dir.is_dir());
+    Ok(dirs)
+}
+
+/// Content folders that are downloaded but no longer subscribed by the current account.
+pub async fn orphaned_items(steam_game_id: u32) -> Result<OrphanedContent, S7forgeError> {
+    let subscribed: FxHashSet<u64> = subscribed_item_ids(steam_game_id)
+        .await?
+        .into_iter()
+        .collect();
+    let in_manifest: FxHashSet<u64> = installed_items(steam_game_id)?
+        .into_iter()
+        .map(|item| item.item_id)
+        .collect();
+
+    let mut folders = Vec::new();
+    for content_dir in content_dirs(steam_game_id)? {
+        let entries = fs::read_dir(&content_dir).map_err(|e| {
+            S7forgeError::io(format!("Failed to read {}", content_dir.display()), e)
+        })?;
+
+        for entry in entries {
+            let path = entry
+                .map_err(|e| {
+                    S7forgeError::io(format!("Failed to read {}", content_dir.display()), e)
+                })?
+                .path();
+            let item_id = folder_item_id(&path);
+            if item_id.is_some_and(|item_id| subscribed.contains(&item_id)) {
+                continue;
+            }
+
+            folders.push(OrphanedFolder {
+                item_id,
+                size_bytes: dir_size(&path).map_err(|e| {
+                    S7forgeError::io(format!("Failed to measure {}", path.display()), e)
+                })?,
+                in_manifest: item_id.is_some_and(|item_id| in_manifest.contains(&item_id)),
+                path: path.to_string_lossy().into_owned(),
+            });
+        }
+    }
+
+    Ok(OrphanedContent {
+        total_bytes: folders.iter().map(|folder| folder.size_bytes).sum(),
+        folders,
+    })
+}
+
+fn folder_item_id(path: &Path) -> Option<u64> {
+    path.file_name()?.to_str()?.parse().ok()
+}
@@ -0,0 +1,235 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::commands::subscribe::subscribe;
+use crate::commands::subscriptions::{SubscriptionDiff, subscribed_item_ids};
+use crate::commands::unsubscribe::unsubscribe;
+use crate::core::error::S7forgeError;
+use crate::utils::get_cache_dir::get_cache_dir;
+
+// Dot-prefixed so it can never collide with a profile name
+const ACTIVE_FILE: &str = ".active";
+
+#[derive(Debug)]
+pub enum ProfileAction {
+    Save(String),
+    List,
+    Activate(String),
+    Delete(String),
+}
+
+/// A named set of subscriptions. `items` is also the load order: later items win conflicts.
+#[derive(Debug, Serialize, JsonSchema, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub app_id: u32,
+    pub items: Vec<u64>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ProfileSummary {
+    pub name: String,
+    pub item_count: usize,
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ActivateResult {
+    pub name: String,
+    pub added: Vec<u64>,
+    pub removed: Vec<u64>,
+    pub unchanged: Vec<u64>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DeleteResult {
+    pub name: String,
+    pub deleted: bool,
+}
+
+fn profiles_dir(steam_game_id: u32) -> Result<PathBuf, S7forgeError> {
+    let dir = get_cache_dir()?
+        .join("profiles")
+        .join(steam_game_id.to_string());
+    fs::create_dir_all(&dir)
+        .map_err(|e| S7forgeError::io(format!("Failed to create {}", dir.display()), e))?;
+    Ok(dir)
+}
+
+fn profile_path(steam_game_id: u32, name: &str) -> Result<PathBuf, S7forgeError> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|ch| ch.is_alphanumeric() || matches!(ch, '-' | '_' | '.' | ' '));
+    if !valid {
+        return Err(S7forgeError::InvalidArgs(format!(
+            "Invalid profile name \"{}\": use letters, digits, spaces, '-', '_' or '.'",
+            name
+        )));
+    }
+
+    Ok(profiles_dir(steam_game_id)?.join(format!("{}.json", name)))
+}
+
+impl Profile {
+    pub fn load(steam_game_id: u32, name: &str) -> Result<Self, S7forgeError> {
+        let path = profile_path(steam_game_id, name)?;
+        let content = fs::read_to_string(&path)
+            .map_err(|_| S7forgeError::NotFound(format!("Profile \"{}\" does not exist", name)))?;
+        serde_json::from_str(&content)
+            .map_err(|e| S7forgeError::parse(format!("Invalid profile {}", path.display()), e))
+    }
+
+    fn save(&self) -> Result<(), S7forgeError> {
+        let path = profile_path(self.app_id, &self.name)?;
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| S7forgeError::parse("Failed to serialize profile", e))?;
+        fs::write(&path, json)
+            .map_err(|e| S7forgeError::io(format!("Failed to write {}", path.display()), e))
+    }
+}
+
+pub fn active_profile_name(steam_game_id: u32) -> Result<Option<String>, S7forgeError> {
+    let path = profiles_dir(steam_game_id)?.join(ACTIVE_FILE);
+    Ok(fs::read_to_string(path)
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty()))
+}
+
+/// The active profile, if one was activated and still exists.
+pub fn active_profile(steam_game_id: u32) -> Result<Option<Profile>, S7forgeError> {
+    Ok(active_profile_name(steam_game_id)?
+        .and_then(|name| Profile::load(steam_game_id, &name).ok()))
+}
+
+fn set_active(steam_game_id: u32, name: Option<&str>) -> Result<(), S7forgeError> {
+    let path = profiles_dir(steam_game_id)?.join(ACTIVE_FILE);
+    match name {
+        Some(name) => fs::write(&path, name),
+        None => match fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        },
+    }
+    .map_err(|e| S7forgeError::io(format!("Failed to update {}", path.display()), e))
+}
+
+/// Saves the current subscriptions under `name`. Re-saving keeps the existing load order
+/// and appends newly subscribed items at the end.
+pub async fn save_profile(steam_game_id: u32, name: String) -> Result<Profile, S7forgeError> {
+    let subscribed = subscribed_item_ids(steam_game_id).await?;
+
+    let mut items: Vec<u64> = match Profile::load(steam_game_id, &name) {
+        Ok(existing) => existing
+            .items
+            .into_iter()
+            .filter(|item_id| subscribed.contains(item_id))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    for item_id in subscribed {
+        if !items.contains(&item_id) {
+            items.push(item_id);
+        }
+    }
+
+    let profile = Profile {
+        name,
+        app_id: steam_game_id,
+        items,
+    };
+    profile.save()?;
+    Ok(profile)
+}
+
+pub fn list_profiles(steam_game_id: u32) -> Result<Vec<ProfileSummary>, S7forgeError> {
+    let dir = profiles_dir(steam_game_id)?;
+    let active = active_profile_name(steam_game_id)?;
+
+    let mut profiles = Vec::new();
+    let entries = fs::read_dir(&dir)
+        .map_err(|e| S7forgeError::io(format!("Failed to read {}", dir.display()), e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|extension| extension != "json") {
+            continue;
+        }
+        let Some(name) = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+        else {
+            continue;
+        };
+        let Ok(profile) = Profile::load(steam_game_id, &name) else {
+            continue;
+        };
+
+        profiles.push(ProfileSummary {
+            active: active.as_deref() == Some(name.as_str()),
+            item_count: profile.items.len(),
+            name,
+        });
+    }
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(profiles)
+}
+
+/// Subscribes and unsubscribes so the subscriptions match the profile exactly.
+pub async fn activate_profile(
+    steam_game_id: u32,
+    name: String,
+) -> Result<ActivateResult, S7forgeError> {
+    let profile = Profile::load(steam_game_id, &name)?;
+
+    let subscribed = subscribed_item_ids(steam_game_id).await?;
+    let diff = SubscriptionDiff::between(&profile.items, &subscribed);
+
+    // Blacklisted items are skipped by subscribe and must not be reported as added
+    let added: Vec<u64> = if diff.missing.is_empty() {
+        Vec::new()
+    } else {
+        subscribe(steam_game_id, diff.missing.clone(), false)
+            .await?
+            .into_iter()
+            .filter(|result| result.success)
+            .map(|result| result.item_id)
+            .collect()
+    };
+    if !diff.extra.is_empty() {
+        unsubscribe(steam_game_id, diff.extra.clone()).await?;
+    }
+    set_active(steam_game_id, Some(&name))?;
+
+    Ok(ActivateResult {
+        name,
+        added,
+        removed: diff.extra,
+        unchanged: diff.unchanged,
+    })
+}
+
+pub fn delete_profile(steam_game_id: u32, name: String) -> Result<DeleteResult, S7forgeError> {
+    let path = profile_path(steam_game_id, &name)?;
+    if !path.exists() {
+        return Err(S7forgeError::NotFound(format!(
+            "Profile \"{}\" does not exist",
+            name
+        )));
+    }
+
+    fs::remove_file(&path)
+        .map_err(|e| S7forgeError::io(format!("Failed to delete {}", path.display()), e))?;
+    if active_profile_name(steam_game_id)?.as_deref() == Some(name.as_str()) {
+        set_active(steam_game_id, None)?;
+    }
+
+    Ok(DeleteResult {
+        name,
+        deleted: true,
+    })
+}
@@ -0,0 +1,158 @@
+use futures_util::FutureExt;
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::{FileType, PublishedFileId};
+use tokio::sync::mpsc;
+
+use super::create_item::create_published_file;
+use super::{ItemUpdate, PublishResult};
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::steam_manager;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CollectionChangeResult {
+    pub item_id: u64,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CreateCollectionResult {
+    #[serde(flatten)]
+    pub collection: PublishResult,
+    pub added: Vec<CollectionChangeResult>,
+}
+
+pub async fn create_collection(
+    steam_game_id: u32,
+    update: ItemUpdate,
+    item_ids: Vec<u64>,
+) -> Result<CreateCollectionResult, S7forgeError> {
+    if update.content_path.is_some() {
+        return Err(S7forgeError::InvalidArgs(
+            "Collections have no content folder; drop --content".into(),
+        ));
+    }
+
+    let collection = create_published_file(steam_game_id, FileType::Collection, update).await?;
+    let added = collection_add(steam_game_id, collection.item_id, item_ids).await?;
+
+    Ok(CreateCollectionResult { collection, added })
+}
+
+pub async fn collection_add(
+    steam_game_id: u32,
+    collection_id: u64,
+    item_ids: Vec<u64>,
+) -> Result<Vec<CollectionChangeResult>, S7forgeError> {
+    change_collection(steam_game_id, collection_id, item_ids, true).await
+}
+
+pub async fn collection_remove(
+    steam_game_id: u32,
+    collection_id: u64,
+    item_ids: Vec<u64>,
+) -> Result<Vec<CollectionChangeResult>, S7forgeError> {
+    change_collection(steam_game_id, collection_id, item_ids, false).await
+}
+
+async fn change_collection(
+    steam_game_id: u32,
+    collection_id: u64,
+    item_ids: Vec<u64>,
+    add: bool,
+) -> Result<Vec<CollectionChangeResult>, S7forgeError> {
+    if item_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let mut results = Vec::new();
+
+    // Keep going after a failure so one bad ID doesn't leave the rest of a batch untouched
+    for item_id in item_ids {
+        match change_single_child(&steam_client, steam_game_id, collection_id, item_id, add).await {
+            Ok(()) => results.push(CollectionChangeResult {
+                item_id,
+                success: true,
+                error: None,
+            }),
+            Err(error) => results.push(CollectionChangeResult {
+                item_id,
+                success: false,
+                error: Some(error.to_string()),
+            }),
+        }
+    }
+
+    Ok(results)
+}
+
+// Collection membership is modelled by Steam as dependencies of the collection item
+async fn change_single_child(
+    steam_client: &steamworks::Client,
+    steam_game_id: u32,
+    collection_id: u64,
+    item_id: u64,
+    add: bool,
+) -> Result<(), S7forgeError> {
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let steam_client_clone = steam_client.clone();
+    let change_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client_clone.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel();
+
+        let callback = move |result| {
+            let _ = tx_inner.send(result);
+        };
+        if add {
+            ugc.add_dependency(
+                PublishedFileId(collection_id),
+                PublishedFileId(item_id),
+                callback,
+            );
+        } else {
+            ugc.remove_dependency(
+                PublishedFileId(collection_id),
+                PublishedFileId(item_id),
+                callback,
+            );
+        }
+
+        let start_time = std::time::Instant::now();
+        let timeout_duration = settings::get().steam_timeout;
+
+        loop {
+            let _ = tx.blocking_send(());
+            if let Ok(result) = rx_inner.try_recv() {
+                return result.map_err(S7forgeError::steam_api);
+            }
+
+            if start_time.elapsed() > timeout_duration {
+                return Err(S7forgeError::Timeout("Operation"));
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut result = None;
+    let mut change_task = change_task.fuse();
+
+    while result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut change_task => {
+                result = Some(task_result.map_err(S7forgeError::Join)?);
+                break;
+            }
+        }
+    }
+
+    result.unwrap()
+}
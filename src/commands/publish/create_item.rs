@@ -0,0 +1,93 @@
+use futures_util::FutureExt;
+use steamworks::{AppId, FileType};
+use tokio::sync::mpsc;
+
+use super::{ItemUpdate, PublishResult, submit_item_update};
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::steam_manager;
+
+pub async fn create_item(
+    steam_game_id: u32,
+    update: ItemUpdate,
+) -> Result<PublishResult, S7forgeError> {
+    create_published_file(steam_game_id, FileType::Community, update).await
+}
+
+/// Creates an empty published file of `file_type` and applies `update` to it, if any.
+pub(super) async fn create_published_file(
+    steam_game_id: u32,
+    file_type: FileType,
+    update: ItemUpdate,
+) -> Result<PublishResult, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let steam_client_clone = steam_client.clone();
+    let create_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client_clone.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel();
+
+        ugc.create_item(AppId(steam_game_id), file_type, move |result| {
+            let _ = tx_inner.send(result);
+        });
+
+        let start_time = std::time::Instant::now();
+        let timeout_duration = settings::get().steam_timeout;
+
+        loop {
+            let _ = tx.blocking_send(());
+            if let Ok(result) = rx_inner.try_recv() {
+                return result
+                    .map(
+                        |(published_file_id, needs_workshop_agreement)| PublishResult {
+                            item_id: published_file_id.0,
+                            needs_workshop_agreement,
+                        },
+                    )
+                    .map_err(S7forgeError::steam_api);
+            }
+
+            if start_time.elapsed() > timeout_duration {
+                return Err(S7forgeError::Timeout("Operation"));
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut create_result = None;
+    let mut fused_task = create_task.fuse();
+
+    while create_result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut fused_task => {
+                create_result = Some(task_result.map_err(S7forgeError::Join)??);
+            }
+        }
+    }
+
+    let created = create_result.unwrap();
+    if update.is_empty() {
+        return Ok(created);
+    }
+
+    let uploaded = submit_item_update(steam_client, steam_game_id, created.item_id, update)
+        .await
+        .map_err(|e| {
+            format!(
+                "Item {} was created but uploading failed: {}",
+                created.item_id, e
+            )
+        })?;
+
+    Ok(PublishResult {
+        item_id: created.item_id,
+        needs_workshop_agreement: created.needs_workshop_agreement
+            || uploaded.needs_workshop_agreement,
+    })
+}
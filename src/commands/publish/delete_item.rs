@@ -0,0 +1,111 @@
+use futures_util::FutureExt;
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::PublishedFileId;
+use tokio::sync::mpsc;
+
+use crate::commands::item_index::remove_from_index;
+use crate::commands::workshop_items::evict_cached_items;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::steam_manager;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct DeleteResult {
+    pub item_id: u64,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+pub async fn delete_item(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<DeleteResult>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let mut results = Vec::new();
+
+    // Keep going after a failure so one bad ID doesn't leave the rest of a batch untouched
+    for item_id in item_ids {
+        match delete_single_item(&steam_client, steam_game_id, item_id).await {
+            Ok(()) => results.push(DeleteResult {
+                item_id,
+                success: true,
+                error: None,
+            }),
+            Err(error) => results.push(DeleteResult {
+                item_id,
+                success: false,
+                error: Some(error.to_string()),
+            }),
+        }
+    }
+
+    let deleted: Vec<u64> = results
+        .iter()
+        .filter(|result| result.success)
+        .map(|result| result.item_id)
+        .collect();
+    // The items are gone either way, so a stale cache only costs a warning
+    if !deleted.is_empty() {
+        if let Err(e) = evict_cached_items(Some(steam_game_id), &deleted) {
+            tracing::warn!("Failed to evict deleted items from the item cache: {}", e);
+        }
+        if let Err(e) = remove_from_index(steam_game_id, &deleted) {
+            tracing::warn!("Failed to remove deleted items from the item index: {}", e);
+        }
+    }
+
+    Ok(results)
+}
+
+async fn delete_single_item(
+    steam_client: &steamworks::Client,
+    steam_game_id: u32,
+    item_id: u64,
+) -> Result<(), S7forgeError> {
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let steam_client_clone = steam_client.clone();
+    let delete_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client_clone.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel();
+
+        ugc.delete_item(PublishedFileId(item_id), move |result| {
+            let _ = tx_inner.send(result);
+        });
+
+        let start_time = std::time::Instant::now();
+        let timeout_duration = settings::get().steam_timeout;
+
+        loop {
+            let _ = tx.blocking_send(());
+            if let Ok(result) = rx_inner.try_recv() {
+                return result.map_err(|e| S7forgeError::item_steam_api(item_id, e));
+            }
+
+            if start_time.elapsed() > timeout_duration {
+                return Err(S7forgeError::Timeout("Operation"));
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut result = None;
+    let mut delete_task = delete_task.fuse();
+
+    while result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut delete_task => {
+                result = Some(task_result.map_err(S7forgeError::Join)?);
+                break;
+            }
+        }
+    }
+
+    result.unwrap()
+}
@@ -0,0 +1,173 @@
+pub mod collection;
+pub mod create_item;
+pub mod delete_item;
+pub mod set_item_visibility;
+pub mod update_item;
+pub mod upload_item;
+
+use futures_util::FutureExt;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use steamworks::{AppId, PublishedFileId};
+use tokio::sync::mpsc;
+
+use crate::core::error::S7forgeError;
+use crate::core::progress::{self, ProgressEvent};
+use crate::core::steam_manager;
+use crate::core::workshop::{UgcItemVisibility, UpdateStatus};
+
+#[derive(Debug, Default)]
+pub struct ItemUpdate {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub content_path: Option<String>,
+    pub preview_path: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub visibility: Option<UgcItemVisibility>,
+    pub change_note: Option<String>,
+}
+
+impl ItemUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.description.is_none()
+            && self.content_path.is_none()
+            && self.preview_path.is_none()
+            && self.tags.is_none()
+            && self.visibility.is_none()
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PublishResult {
+    pub item_id: u64,
+    pub needs_workshop_agreement: bool,
+}
+
+// Steam requires absolute paths for both the content folder and the preview image
+fn resolve_path(path: &str, expect_dir: bool) -> Result<PathBuf, S7forgeError> {
+    let absolute = std::path::absolute(Path::new(path))
+        .map_err(|e| S7forgeError::InvalidArgs(format!("Invalid path {}: {}", path, e)))?;
+
+    if expect_dir && !absolute.is_dir() {
+        return Err(S7forgeError::NotFound(format!(
+            "Content folder not found: {}",
+            absolute.display()
+        )));
+    }
+    if !expect_dir && !absolute.is_file() {
+        return Err(S7forgeError::NotFound(format!(
+            "Preview image not found: {}",
+            absolute.display()
+        )));
+    }
+
+    Ok(absolute)
+}
+
+pub async fn submit_item_update(
+    steam_client: steamworks::Client,
+    steam_game_id: u32,
+    item_id: u64,
+    update: ItemUpdate,
+) -> Result<PublishResult, S7forgeError> {
+    let content_path = update
+        .content_path
+        .as_deref()
+        .map(|path| resolve_path(path, true))
+        .transpose()?;
+    let preview_path = update
+        .preview_path
+        .as_deref()
+        .map(|path| resolve_path(path, false))
+        .transpose()?;
+
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let upload_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel();
+
+        let mut handle = ugc.start_item_update(AppId(steam_game_id), PublishedFileId(item_id));
+        if let Some(title) = &update.title {
+            handle = handle.title(title);
+        }
+        if let Some(description) = &update.description {
+            handle = handle.description(description);
+        }
+        if let Some(path) = &content_path {
+            handle = handle.content_path(path);
+        }
+        if let Some(path) = &preview_path {
+            handle = handle.preview_path(path);
+        }
+        if let Some(tags) = update.tags {
+            handle = handle.tags(tags, false);
+        }
+        if let Some(visibility) = update.visibility {
+            handle = handle.visibility(visibility.into());
+        }
+
+        let watch_handle = handle.submit(update.change_note.as_deref(), move |result| {
+            let _ = tx_inner.send(result);
+        });
+
+        let start_time = std::time::Instant::now();
+        let timeout_duration = std::time::Duration::from_secs(60 * 60);
+        let mut last_reported: Option<(UpdateStatus, u64)> = None;
+
+        loop {
+            let _ = tx.blocking_send(());
+            if let Ok(result) = rx_inner.try_recv() {
+                return result
+                    .map(
+                        |(published_file_id, needs_workshop_agreement)| PublishResult {
+                            item_id: published_file_id.0,
+                            needs_workshop_agreement,
+                        },
+                    )
+                    .map_err(S7forgeError::steam_api);
+            }
+
+            let (status, processed, total) = watch_handle.progress();
+            let status = UpdateStatus::from(status);
+            let percent = if total > 0 {
+                processed * 100 / total
+            } else {
+                0
+            };
+            if last_reported != Some((status, percent)) && status != UpdateStatus::Invalid {
+                if progress::enabled() {
+                    let phase = format!("{:?}", status);
+                    progress::emit(ProgressEvent::new(&phase, Some(item_id), processed, total));
+                } else {
+                    tracing::info!("{:?}: {}% ({}/{} bytes)", status, percent, processed, total);
+                }
+                last_reported = Some((status, percent));
+            }
+
+            if start_time.elapsed() > timeout_duration {
+                return Err(S7forgeError::Timeout("Upload"));
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut upload_result = None;
+    let mut fused_task = upload_task.fuse();
+
+    while upload_result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut fused_task => {
+                upload_result = Some(task_result.map_err(S7forgeError::Join)??);
+            }
+        }
+    }
+
+    Ok(upload_result.unwrap())
+}
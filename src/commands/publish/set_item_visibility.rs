@@ -0,0 +1,59 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::{ItemUpdate, submit_item_update};
+use crate::commands::workshop_items::{evict_cached_items, fetch_workshop_items};
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+use crate::core::workshop::UgcItemVisibility;
+use crate::core::workshop_item::workshop::WorkshopItem;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct VisibilityResult {
+    pub item_id: u64,
+    pub visibility: UgcItemVisibility,
+    pub needs_workshop_agreement: bool,
+}
+
+pub async fn set_item_visibility(
+    steam_game_id: u32,
+    item_id: u64,
+    visibility: UgcItemVisibility,
+) -> Result<VisibilityResult, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let item = fetch_item(steam_game_id, item_id).await?;
+    let current_user = steam_client.user().steam_id().raw();
+    if item.owner.steam_id64 != current_user {
+        return Err(format!(
+            "Workshop item {} is not owned by the logged-in account",
+            item_id
+        )
+        .into());
+    }
+
+    let update = ItemUpdate {
+        visibility: Some(visibility),
+        ..ItemUpdate::default()
+    };
+    let result = submit_item_update(steam_client, steam_game_id, item_id, update).await?;
+
+    // Report what Steam now has rather than what was asked for
+    let item = fetch_item(steam_game_id, item_id).await?;
+    evict_cached_items(Some(steam_game_id), &[item_id])?;
+
+    Ok(VisibilityResult {
+        item_id: result.item_id,
+        visibility: item.visibility,
+        needs_workshop_agreement: result.needs_workshop_agreement,
+    })
+}
+
+// Uncached, so neither the ownership check nor the result can be stale
+async fn fetch_item(steam_game_id: u32, item_id: u64) -> Result<WorkshopItem, S7forgeError> {
+    fetch_workshop_items(steam_game_id, vec![item_id])
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(S7forgeError::ItemNotFound(item_id))
+}
@@ -0,0 +1,28 @@
+use super::{ItemUpdate, PublishResult, submit_item_update};
+use crate::commands::workshop_items::evict_cached_items;
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+
+pub async fn update_item(
+    steam_game_id: u32,
+    item_id: u64,
+    update: ItemUpdate,
+) -> Result<PublishResult, S7forgeError> {
+    if update.content_path.is_some() {
+        return Err(S7forgeError::InvalidArgs(
+            "update-item only edits metadata; use upload-item to upload content".into(),
+        ));
+    }
+    if update.is_empty() && update.change_note.is_none() {
+        return Err(S7forgeError::InvalidArgs(
+            "Nothing to update: specify at least one field to change".into(),
+        ));
+    }
+
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let result = submit_item_update(steam_client, steam_game_id, item_id, update).await?;
+    // The cached metadata is out of date now
+    evict_cached_items(Some(steam_game_id), &[item_id])?;
+    Ok(result)
+}
@@ -0,0 +1,19 @@
+use super::{ItemUpdate, PublishResult, submit_item_update};
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+
+pub async fn upload_item(
+    steam_game_id: u32,
+    item_id: u64,
+    update: ItemUpdate,
+) -> Result<PublishResult, S7forgeError> {
+    if update.content_path.is_none() {
+        return Err(S7forgeError::InvalidArgs(
+            "Missing --content folder to upload".into(),
+        ));
+    }
+
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    submit_item_update(steam_client, steam_game_id, item_id, update).await
+}
@@ -0,0 +1,101 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::orphaned_items::content_dirs;
+use crate::core::error::S7forgeError;
+use crate::utils::dir_size::dir_size;
+use crate::utils::remove_path::remove_path;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RemoveItemFilesResult {
+    pub item_id: u64,
+    pub deleted_paths: Vec<String>,
+    pub bytes_freed: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Deletes the downloaded content of `item_ids` while keeping the subscriptions.
+pub fn remove_item_files(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<RemoveItemFilesResult>, S7forgeError> {
+    let content_dirs = content_dirs(steam_game_id)?;
+    if content_dirs.is_empty() {
+        return Err(S7forgeError::NotFound(format!(
+            "Workshop content folder not found for app ID {}",
+            steam_game_id
+        )));
+    }
+
+    Ok(item_ids
+        .into_iter()
+        .map(|item_id| {
+            let mut result = RemoveItemFilesResult {
+                item_id,
+                deleted_paths: Vec::new(),
+                bytes_freed: 0,
+                error: None,
+            };
+
+            for content_dir in &content_dirs {
+                let item_path = content_dir.join(item_id.to_string());
+                if fs::symlink_metadata(&item_path).is_err() {
+                    continue;
+                }
+
+                let removed = ensure_inside(&item_path, &content_dirs).and_then(|_| {
+                    let size = dir_size(&item_path).unwrap_or_default();
+                    remove_path(&item_path).map(|_| size).map_err(|e| {
+                        S7forgeError::io(format!("Failed to delete {}", item_path.display()), e)
+                    })
+                });
+                match removed {
+                    Ok(size) => {
+                        result.bytes_freed += size;
+                        result
+                            .deleted_paths
+                            .push(item_path.to_string_lossy().into_owned());
+                    }
+                    Err(error) => {
+                        result.error = Some(error.to_string());
+                        break;
+                    }
+                }
+            }
+
+            if result.deleted_paths.is_empty() && result.error.is_none() {
+                result.error = Some("No local files found".to_string());
+            }
+            result
+        })
+        .collect())
+}
+
+/// Fails unless `item_path` sits directly in one of the workshop content directories.
+// The folder itself may be a symlink (SteamCMD backend), so its parent is what must resolve
+// to the content directory
+pub fn ensure_inside(item_path: &Path, content_dirs: &[PathBuf]) -> Result<(), S7forgeError> {
+    let parent = item_path
+        .parent()
+        .and_then(|parent| fs::canonicalize(parent).ok());
+    let inside = parent.is_some_and(|parent| {
+        content_dirs
+            .iter()
+            .filter_map(|content_dir| fs::canonicalize(content_dir).ok())
+            .any(|content_dir| content_dir == parent)
+    });
+
+    if !inside {
+        return Err(S7forgeError::io(
+            format!("Refusing to delete {}", item_path.display()),
+            std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "not inside the workshop content folder",
+            ),
+        ));
+    }
+    Ok(())
+}
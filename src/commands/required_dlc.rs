@@ -0,0 +1,100 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::AppId;
+
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+use crate::core::web_api;
+use crate::utils::html_to_text::html_to_text;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ItemRequiredDlc {
+    pub item_id: u64,
+    pub required_apps: Vec<RequiredApp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RequiredApp {
+    pub app_id: u32,
+    pub name: String,
+    /// Whether the logged-in account owns the app; absent when no Steam client is available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owned: Option<bool>,
+}
+
+/// Lists the DLC and other apps each item depends on. steamworks-rs doesn't wrap
+/// `GetAppDependencies` and no Web API returns them, so they are read from the "Required DLC"
+/// section of the item's community page. Ownership is checked through the Steam client.
+pub async fn required_dlc(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<ItemRequiredDlc>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client_or_fallback(steam_game_id).await;
+
+    let mut results = tokio::task::spawn_blocking(move || {
+        item_ids
+            .into_iter()
+            .map(|item_id| match web_api::item_page(item_id) {
+                Ok(page) => ItemRequiredDlc {
+                    item_id,
+                    required_apps: parse_required_apps(&page),
+                    error: None,
+                },
+                Err(error) => ItemRequiredDlc {
+                    item_id,
+                    required_apps: Vec::new(),
+                    error: Some(error.to_string()),
+                },
+            })
+            .collect::<Vec<_>>()
+    })
+    .await
+    .map_err(S7forgeError::Join)?;
+
+    if let Some(steam_client) = steam_client {
+        let apps = steam_client.apps();
+        for app in results
+            .iter_mut()
+            .flat_map(|result| result.required_apps.iter_mut())
+        {
+            app.owned = Some(apps.is_subscribed_app(AppId(app.app_id)));
+        }
+    }
+
+    Ok(results)
+}
+
+// Each dependency is a `requiredDLCItem` block linking to the app's store page, with the app
+// name as the link text
+fn parse_required_apps(page: &str) -> Vec<RequiredApp> {
+    let mut apps: Vec<RequiredApp> = Vec::new();
+
+    for block in page.split("class=\"requiredDLCItem\"").skip(1) {
+        let block = block.split("</div>").next().unwrap_or_default();
+        let Some(app_id) = block
+            .split("store.steampowered.com/app/")
+            .nth(1)
+            .and_then(|rest| {
+                let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+                digits.parse().ok()
+            })
+        else {
+            continue;
+        };
+        if apps.iter().any(|app| app.app_id == app_id) {
+            continue;
+        }
+
+        // Drop the rest of the opening tag before reducing the block to its text
+        let content = block.split_once('>').map_or(block, |(_, content)| content);
+        apps.push(RequiredApp {
+            app_id,
+            name: html_to_text(content),
+            owned: None,
+        });
+    }
+
+    apps
+}
@@ -0,0 +1,62 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::steam_manager::{self, STEAM_MANAGER};
+use crate::core::steam_process;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RestartResult {
+    /// Steam was running and was shut down first
+    pub was_running: bool,
+    /// Steam ignored the shutdown request and its process was killed
+    pub killed: bool,
+    /// From the shutdown request until Steamworks initialized again
+    pub elapsed_seconds: u64,
+}
+
+/// Shuts the Steam client down, killing it if it doesn't exit within `timeouts.steam`, then
+/// launches it again and waits until Steamworks initializes for `app_id`.
+pub async fn restart_steam(app_id: u32) -> Result<RestartResult, S7forgeError> {
+    if settings::get().anonymous {
+        return Err(S7forgeError::ClientRequired);
+    }
+
+    let start = Instant::now();
+    STEAM_MANAGER.reset();
+
+    let was_running = steam_process::pid().is_some();
+    let mut killed = false;
+    if was_running {
+        steam_process::shutdown()?;
+        if !exited_within(settings::get().steam_timeout).await {
+            if let Some(pid) = steam_process::pid() {
+                steam_process::kill(pid)?;
+                killed = true;
+            }
+            if !exited_within(Duration::from_secs(10)).await {
+                return Err("Steam is still running after being killed".into());
+            }
+        }
+    }
+
+    steam_manager::initialize_client_launching(app_id).await?;
+    Ok(RestartResult {
+        was_running,
+        killed,
+        elapsed_seconds: start.elapsed().as_secs(),
+    })
+}
+
+async fn exited_within(timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    while steam_process::pid().is_some() {
+        if Instant::now() >= deadline {
+            return false;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    true
+}
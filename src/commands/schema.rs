@@ -0,0 +1,226 @@
+use schemars::{JsonSchema, Schema, schema_for};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+
+use crate::commands::account_info::AccountInfo;
+use crate::commands::blacklist::Blacklist;
+use crate::commands::cache_export::{CacheExportResult, CacheImportResult};
+use crate::commands::cache_inspect::CachedEntry;
+use crate::commands::cache_stats::CacheStats;
+use crate::commands::check_item_download::DownloadInfo;
+use crate::commands::check_ownership::AppOwnership;
+use crate::commands::check_updates::PendingUpdate;
+use crate::commands::cleanup_workshop::CleanupResult;
+use crate::commands::clear_cache::ClearCacheResult;
+use crate::commands::collection_items::CollectionDetails;
+use crate::commands::creator_profile::CreatorProfile;
+use crate::commands::dependency_graph::DependencyGraph;
+use crate::commands::deploy::DeployResult;
+use crate::commands::download_previews::PreviewDownloadResult;
+use crate::commands::download_workshop_item::SteamCmdDownloadResult;
+use crate::commands::download_workshop_items::ItemDownloadResult;
+use crate::commands::favorites::FavoriteResult;
+use crate::commands::force_redownload::ForceRedownloadResult;
+use crate::commands::installed_items::InstalledItem;
+use crate::commands::item_changelog::ItemChangelog;
+use crate::commands::item_comments::ItemComments;
+use crate::commands::item_conflicts::FileConflict;
+use crate::commands::item_index::IndexUpdateResult;
+use crate::commands::item_install_path::ItemInstallPath;
+use crate::commands::item_previews::ItemPreviews;
+use crate::commands::item_state::ItemStateFlags;
+use crate::commands::item_stats::ItemStats;
+use crate::commands::list_dlc::AppDlc;
+use crate::commands::local_meta::LocalMeta;
+use crate::commands::orphaned_items::OrphanedContent;
+use crate::commands::profiles::{ActivateResult, DeleteResult, Profile, ProfileSummary};
+use crate::commands::publish::PublishResult;
+use crate::commands::publish::collection::{CollectionChangeResult, CreateCollectionResult};
+use crate::commands::publish::delete_item::DeleteResult as DeleteItemResult;
+use crate::commands::publish::set_item_visibility::VisibilityResult;
+use crate::commands::remove_item_files::RemoveItemFilesResult;
+use crate::commands::required_dlc::ItemRequiredDlc;
+use crate::commands::restart_steam::RestartResult;
+use crate::commands::search_workshop::SearchPage;
+use crate::commands::self_update::SelfUpdateResult;
+use crate::commands::steam_status::SteamStatus;
+use crate::commands::subscribe::SubscribeResult;
+use crate::commands::subscriptions::diff::CollectionDiff;
+use crate::commands::subscriptions::export::ExportResult;
+use crate::commands::subscriptions::import::ImportResult;
+use crate::commands::subscriptions::sync::SyncResult;
+use crate::commands::unsubscribe::UnsubscribeResult;
+use crate::commands::unsubscribe_all::UnsubscribeAllResult;
+use crate::commands::verify_items::VerifyResult;
+use crate::commands::vote::{VoteResult, VoteState};
+use crate::commands::watch::WatchEvent;
+use crate::commands::workshop_items::EnhancedWorkshopItem;
+use crate::core::error::{ErrorReport, S7forgeError};
+use crate::core::output::SCHEMA_VERSION;
+use crate::core::workshop_item::workshop::WorkshopItem;
+
+// Commands whose output depends on an option or action are described as any of their shapes.
+// These types only exist for their schemas.
+
+/// One item's info with `--item-id`, info keyed by item ID with `--item-ids`
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[serde(untagged)]
+enum CheckDownloadOutput {
+    Item(DownloadInfo),
+    Items(ItemMap<DownloadInfo>),
+}
+
+/// A message with the Steam backend, a result with `--backend steamcmd`
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[serde(untagged)]
+enum DownloadItemOutput {
+    Steam(String),
+    SteamCmd(SteamCmdDownloadResult),
+}
+
+/// Output of `profile save`, `profile list`, `profile activate` and `profile delete`
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[serde(untagged)]
+enum ProfileOutput {
+    Save(Profile),
+    List(Vec<ProfileSummary>),
+    Activate(ActivateResult),
+    Delete(DeleteResult),
+}
+
+/// Output of `index update` and `index query`
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[serde(untagged)]
+enum IndexOutput {
+    Update(IndexUpdateResult),
+    Query(Vec<WorkshopItem>),
+}
+
+// Keyed by item ID
+type ItemMap<T> = BTreeMap<String, T>;
+
+type SchemaFn = fn() -> Schema;
+
+/// Schema of each command's output on success. `watch` is described per NDJSON line;
+/// `combined` and `batch` only wrap the output of other commands and are left out, as is the
+/// script printed by `completions`.
+const SCHEMAS: &[(&str, SchemaFn)] = &[
+    ("account-info", || schema_for!(AccountInfo)),
+    ("app-installation-path", || schema_for!(String)),
+    ("blacklist", || schema_for!(Blacklist)),
+    ("cache-export", || schema_for!(CacheExportResult)),
+    ("cache-import", || schema_for!(CacheImportResult)),
+    ("cache-inspect", || schema_for!(Vec<CachedEntry>)),
+    ("cache-stats", || schema_for!(CacheStats)),
+    ("check-item-download", || schema_for!(CheckDownloadOutput)),
+    ("check-ownership", || schema_for!(AppOwnership)),
+    ("check-updates", || schema_for!(Vec<PendingUpdate>)),
+    ("cleanup-workshop", || schema_for!(CleanupResult)),
+    ("clear-cache", || schema_for!(ClearCacheResult)),
+    ("collection-add", || {
+        schema_for!(Vec<CollectionChangeResult>)
+    }),
+    ("collection-items", || schema_for!(CollectionDetails)),
+    ("collection-remove", || {
+        schema_for!(Vec<CollectionChangeResult>)
+    }),
+    ("create-collection", || schema_for!(CreateCollectionResult)),
+    ("create-item", || schema_for!(PublishResult)),
+    ("creator-profile", || schema_for!(CreatorProfile)),
+    ("delete-item", || schema_for!(Vec<DeleteItemResult>)),
+    ("dependency-graph", || schema_for!(DependencyGraph)),
+    ("deploy-items", || schema_for!(Vec<DeployResult>)),
+    ("discover-tags", || schema_for!(Vec<String>)),
+    ("download-previews", || {
+        schema_for!(Vec<PreviewDownloadResult>)
+    }),
+    ("download-updates", || schema_for!(Vec<ItemDownloadResult>)),
+    ("download-workshop-item", || schema_for!(DownloadItemOutput)),
+    ("download-workshop-items", || {
+        schema_for!(Vec<ItemDownloadResult>)
+    }),
+    ("export-subscriptions", || schema_for!(ExportResult)),
+    ("favorite", || schema_for!(Vec<FavoriteResult>)),
+    ("force-redownload", || schema_for!(ForceRedownloadResult)),
+    ("get-vote", || schema_for!(ItemMap<VoteState>)),
+    ("import-subscriptions", || schema_for!(ImportResult)),
+    ("index", || schema_for!(IndexOutput)),
+    ("installed-items", || schema_for!(Vec<InstalledItem>)),
+    ("item-changelog", || schema_for!(ItemChangelog)),
+    ("item-comments", || schema_for!(ItemComments)),
+    ("item-conflicts", || schema_for!(Vec<FileConflict>)),
+    ("item-install-path", || schema_for!(ItemInstallPath)),
+    ("item-meta", || schema_for!(BTreeMap<u64, LocalMeta>)),
+    ("item-previews", || schema_for!(Vec<ItemPreviews>)),
+    ("item-state", || schema_for!(ItemMap<ItemStateFlags>)),
+    ("item-stats", || schema_for!(Vec<ItemStats>)),
+    ("items-by-creator", || {
+        schema_for!(Vec<EnhancedWorkshopItem>)
+    }),
+    ("list-dlc", || schema_for!(Vec<AppDlc>)),
+    ("list-favorites", || schema_for!(Vec<EnhancedWorkshopItem>)),
+    ("my-published-items", || {
+        schema_for!(Vec<EnhancedWorkshopItem>)
+    }),
+    ("orphaned-items", || schema_for!(OrphanedContent)),
+    ("profile", || schema_for!(ProfileOutput)),
+    ("remove-item-files", || {
+        schema_for!(Vec<RemoveItemFilesResult>)
+    }),
+    ("required-dlc", || schema_for!(Vec<ItemRequiredDlc>)),
+    ("restart-steam", || schema_for!(RestartResult)),
+    ("search-cache", || schema_for!(Vec<EnhancedWorkshopItem>)),
+    ("search-workshop", || schema_for!(SearchPage)),
+    ("self-update", || schema_for!(SelfUpdateResult)),
+    ("set-item-visibility", || schema_for!(VisibilityResult)),
+    ("steam-library-paths", || schema_for!(Vec<String>)),
+    ("steam-status", || schema_for!(SteamStatus)),
+    ("subscribe", || schema_for!(Vec<SubscribeResult>)),
+    ("subscribed-items", || {
+        schema_for!(Vec<EnhancedWorkshopItem>)
+    }),
+    ("subscriptions-diff", || schema_for!(CollectionDiff)),
+    ("sync", || schema_for!(SyncResult)),
+    ("undeploy", || schema_for!(Vec<DeployResult>)),
+    ("unfavorite", || schema_for!(Vec<FavoriteResult>)),
+    ("unsubscribe", || schema_for!(Vec<UnsubscribeResult>)),
+    ("unsubscribe-all", || schema_for!(UnsubscribeAllResult)),
+    ("update-item", || schema_for!(PublishResult)),
+    ("upload-item", || schema_for!(PublishResult)),
+    ("verify-items", || schema_for!(Vec<VerifyResult>)),
+    ("vote", || schema_for!(VoteResult)),
+    ("watch", || schema_for!(WatchEvent)),
+    ("workshop-items", || schema_for!(Vec<EnhancedWorkshopItem>)),
+    ("workshop-path", || schema_for!(String)),
+];
+
+/// The schema of one command's output, or of the error object with `error`. Without a name,
+/// every schema together with the current `schema_version`.
+pub fn schema(command: Option<String>) -> Result<Value, S7forgeError> {
+    let Some(command) = command else {
+        let commands: serde_json::Map<String, Value> = SCHEMAS
+            .iter()
+            .map(|(name, schema)| (name.to_string(), schema().to_value()))
+            .collect();
+        return Ok(json!({
+            "schema_version": SCHEMA_VERSION,
+            "commands": commands,
+            "error": schema_for!(ErrorReport),
+        }));
+    };
+
+    if command == "error" {
+        return Ok(schema_for!(ErrorReport).to_value());
+    }
+    SCHEMAS
+        .iter()
+        .find(|(name, _)| *name == command)
+        .map(|(_, schema)| schema().to_value())
+        .ok_or_else(|| {
+            S7forgeError::Args(format!("Unknown command: {} (no output schema)", command).into())
+        })
+}
@@ -0,0 +1,69 @@
+use crate::commands::workshop_items::{EnhancedWorkshopItem, read_item_cache};
+use crate::core::error::S7forgeError;
+use crate::utils::fetch_creator_names::cached_creator_names;
+
+#[derive(Debug)]
+pub struct CacheSearch {
+    /// Case-insensitive substring of the title
+    pub query: String,
+    pub tags: Vec<String>,
+    /// Require every tag instead of any one of them
+    pub match_all_tags: bool,
+}
+
+/// Searches items previously fetched into the local item cache, newest updates first.
+/// Never contacts Steam; entries are used regardless of age, so results can be stale.
+pub fn search_cache(
+    steam_game_id: u32,
+    search: CacheSearch,
+) -> Result<Vec<EnhancedWorkshopItem>, S7forgeError> {
+    let Some(cache) = read_item_cache()? else {
+        return Ok(Vec::new());
+    };
+    let creator_names = cached_creator_names();
+
+    let query = search.query.trim().to_lowercase();
+    let wanted_tags: Vec<String> = search.tags.iter().map(|tag| tag.to_lowercase()).collect();
+
+    let mut items: Vec<EnhancedWorkshopItem> = cache
+        .items
+        .into_values()
+        .filter(|item| {
+            // Items cached from the Web API may not record their app
+            item.consumer_steam_game_id
+                .is_none_or(|app_id| app_id == steam_game_id)
+        })
+        .filter(|item| query.is_empty() || item.title.to_lowercase().contains(&query))
+        .filter(|item| {
+            if wanted_tags.is_empty() {
+                return true;
+            }
+            let item_tags: Vec<String> = item
+                .tags
+                .split(',')
+                .map(|tag| tag.trim().to_lowercase())
+                .collect();
+            let has_tag = |tag: &String| item_tags.contains(tag);
+            if search.match_all_tags {
+                wanted_tags.iter().all(has_tag)
+            } else {
+                wanted_tags.iter().any(has_tag)
+            }
+        })
+        .map(|item| {
+            let creator_id = item.owner.steam_id64;
+            let creator_name = creator_names
+                .get(&creator_id)
+                .cloned()
+                .unwrap_or_else(|| "[unknown]".to_string());
+            EnhancedWorkshopItem::new(item, creator_id.to_string(), creator_name)
+        })
+        .collect();
+
+    items.sort_by(|a, b| {
+        b.workshop_item
+            .time_updated
+            .cmp(&a.workshop_item.time_updated)
+    });
+    Ok(items)
+}
@@ -1,36 +1,382 @@
 use bincode::{Decode, Encode};
 use futures_util::FutureExt;
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use steamworks::{AppIDs, AppId, UGCQueryType, UGCType};
+use steamworks::{AppIDs, AppId, SteamId, UGCQueryType, UGCType};
 use tokio::sync::mpsc;
 
+use crate::commands::cache_inspect::CachedEntry;
 use crate::commands::workshop_items::EnhancedWorkshopItem;
+use crate::core::error::S7forgeError;
+use crate::core::progress;
+use crate::core::settings;
 use crate::core::steam_manager;
-use crate::core::workshop_item::workshop::{WorkshopItem, WorkshopItemsResult};
+use crate::core::web_api;
+use crate::core::workshop_item::workshop::{
+    UserListOrder, UserListType, WorkshopItem, WorkshopItemsResult,
+};
+use crate::utils::cache_codec;
+use crate::utils::cache_counters;
+use crate::utils::cache_db::{self, CacheBackend, CacheRow};
+use crate::utils::cache_file;
 use crate::utils::fetch_creator_names::fetch_creator_names;
 use crate::utils::get_cache_dir::get_cache_dir;
 
+/// Upper bound for `--all-pages` when no `--limit` is given.
+pub const DEFAULT_ALL_PAGES_LIMIT: usize = 500;
+/// Pages scanned for date filters when the sort order doesn't allow stopping early.
+const MAX_FILTER_PAGES: u32 = 20;
+/// Steam returns a fixed number of results per page, for both UGC queries and QueryFiles.
+pub const PAGE_SIZE: u32 = 50;
+
+#[derive(Debug, Clone, Serialize, JsonSchema, Encode, Decode)]
+pub struct SearchPage {
+    pub total_results: u32,
+    pub page: u32,
+    pub page_size: u32,
+    pub has_more: bool,
+    /// Pass back as `--cursor` to fetch the following page of a cursor-based search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    pub items: Vec<EnhancedWorkshopItem>,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+pub struct SearchQuery {
+    pub search_text: String,
+    pub sort_by: String,
+    pub period: Option<String>,
+    pub page: u32,
+    /// QueryFiles cursor used instead of `page`; "*" for the first page
+    pub cursor: Option<String>,
+    pub tags: Option<String>,
+    /// Require every tag in `tags` instead of any one of them
+    pub match_all_tags: bool,
+    /// SteamID64 whose published items are searched instead of the whole Workshop
+    pub creator: Option<u64>,
+    /// Steam API language name, e.g. "german"; titles and descriptions come back translated
+    /// where the author provided a translation
+    pub language: Option<String>,
+    /// Required key/value tags, all of which must match
+    pub kv_tags: Vec<(String, String)>,
+    /// Return each item's key/value tags
+    pub with_kv_tags: bool,
+}
+
+pub struct SortOption {
+    pub name: &'static str,
+    query_type: UGCQueryType,
+    /// EPublishedFileQueryType for the Web API; `None` for sorts tied to the logged-in user
+    web_query_type: Option<u32>,
+    /// Whether `--period` sets the trend window
+    trend: bool,
+}
+
+const fn sort(
+    name: &'static str,
+    query_type: UGCQueryType,
+    web_query_type: Option<u32>,
+    trend: bool,
+) -> SortOption {
+    SortOption {
+        name,
+        query_type,
+        web_query_type,
+        trend,
+    }
+}
+
+/// Every EUGCQuery ranking, by `--sort-by` name.
+pub const SORT_OPTIONS: &[SortOption] = &[
+    sort(
+        "relevance",
+        UGCQueryType::RankedByTextSearch,
+        Some(12),
+        false,
+    ),
+    sort(
+        "recent",
+        UGCQueryType::RankedByPublicationDate,
+        Some(1),
+        false,
+    ),
+    sort("popular", UGCQueryType::RankedByTrend, Some(3), true),
+    sort(
+        "most-subscribed",
+        UGCQueryType::RankedByTotalUniqueSubscriptions,
+        Some(9),
+        false,
+    ),
+    sort(
+        "recently-updated",
+        UGCQueryType::RankedByLastUpdatedDate,
+        Some(21),
+        false,
+    ),
+    sort("top-rated", UGCQueryType::RankedByVote, Some(0), false),
+    sort(
+        "most-votes-up",
+        UGCQueryType::RankedByVotesUp,
+        Some(11),
+        false,
+    ),
+    sort(
+        "fewest-votes",
+        UGCQueryType::RankedByTotalVotesAsc,
+        Some(10),
+        false,
+    ),
+    sort("not-yet-rated", UGCQueryType::NotYetRated, Some(8), false),
+    sort(
+        "most-reported",
+        UGCQueryType::RankedByNumTimesReported,
+        Some(6),
+        false,
+    ),
+    sort(
+        "accepted",
+        UGCQueryType::AcceptedForGameRankedByAcceptanceDate,
+        Some(2),
+        false,
+    ),
+    sort(
+        "playtime-trend",
+        UGCQueryType::RankedByPlaytimeTrend,
+        Some(13),
+        true,
+    ),
+    sort(
+        "total-playtime",
+        UGCQueryType::RankedByTotalPlaytime,
+        Some(14),
+        false,
+    ),
+    sort(
+        "average-playtime-trend",
+        UGCQueryType::RankedByAveragePlaytimeTrend,
+        Some(15),
+        true,
+    ),
+    sort(
+        "lifetime-average-playtime",
+        UGCQueryType::RankedByLifetimeAveragePlaytime,
+        Some(16),
+        false,
+    ),
+    sort(
+        "playtime-sessions-trend",
+        UGCQueryType::RankedByPlaytimeSessionsTrend,
+        Some(17),
+        true,
+    ),
+    sort(
+        "lifetime-playtime-sessions",
+        UGCQueryType::RankedByLifetimePlaytimeSessions,
+        Some(18),
+        false,
+    ),
+    sort(
+        "friends-favorites",
+        UGCQueryType::FavoritedByFriendsRankedByPublicationDate,
+        None,
+        false,
+    ),
+    sort(
+        "friends-created",
+        UGCQueryType::CreatedByFriendsRankedByPublicationDate,
+        None,
+        false,
+    ),
+    sort(
+        "followed-creators",
+        UGCQueryType::CreatedByFollowedUsersRankedByPublicationDate,
+        None,
+        false,
+    ),
+];
+
+pub fn sort_option(name: &str) -> Result<&'static SortOption, String> {
+    SORT_OPTIONS
+        .iter()
+        .find(|option| option.name == name)
+        .ok_or_else(|| {
+            let names: Vec<&str> = SORT_OPTIONS.iter().map(|option| option.name).collect();
+            format!(
+                "Invalid sort: {} (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+}
+
+/// Named `--period` trend windows and their length in days.
+pub const PERIODS: &[(&str, u32)] = &[
+    ("today", 1),
+    ("one-week", 7),
+    ("three-months", 90),
+    ("six-months", 180),
+    ("one-year", 365),
+];
+
+/// Trend window in days for `--period`, a name from [`PERIODS`] or a number of days; only
+/// trend sorts accept one.
+fn trend_days(sort: &SortOption, period: Option<&str>) -> Result<Option<u32>, S7forgeError> {
+    if !sort.trend {
+        return match period {
+            Some(_) => Err(S7forgeError::InvalidArgs(format!(
+                "Period filter is only applicable for trend sorts: {}",
+                SORT_OPTIONS
+                    .iter()
+                    .filter(|option| option.trend)
+                    .map(|option| option.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+            None => Ok(None),
+        };
+    }
+
+    let period = period.unwrap_or("one-week");
+    if let Some(&(_, days)) = PERIODS.iter().find(|(name, _)| *name == period) {
+        return Ok(Some(days));
+    }
+    match period.parse::<u32>() {
+        Ok(days) if days > 0 => Ok(Some(days)),
+        _ => Err(S7forgeError::InvalidArgs(format!(
+            "Invalid period: {} (expected a number of days or one of: {})",
+            period,
+            PERIODS
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+/// `--sort-by` values a `--creator` search supports; user queries have no other orders.
+const CREATOR_SORTS: &[&str] = &[
+    "relevance",
+    "recent",
+    "recently-updated",
+    "popular",
+    "most-subscribed",
+];
+
+fn creator_sort_order(sort_by: &str) -> Result<UserListOrder, S7forgeError> {
+    match sort_by {
+        // There is no search text to rank by, so the default lists the latest updates first
+        "relevance" | "recently-updated" => Ok(UserListOrder::LastUpdatedDesc),
+        "recent" => Ok(UserListOrder::CreationOrderDesc),
+        "popular" | "most-subscribed" => Ok(UserListOrder::VoteScoreDesc),
+        _ => Err(S7forgeError::InvalidArgs(format!(
+            "--sort-by {} cannot be combined with --creator (expected one of: {})",
+            sort_by,
+            CREATOR_SORTS.join(", ")
+        ))),
+    }
+}
+
+/// Steam API language names, indexed by their ELanguage value (used by the Web API).
+pub const STEAM_LANGUAGES: [&str; 29] = [
+    "english",
+    "german",
+    "french",
+    "italian",
+    "koreana",
+    "spanish",
+    "schinese",
+    "tchinese",
+    "russian",
+    "thai",
+    "japanese",
+    "portuguese",
+    "polish",
+    "danish",
+    "dutch",
+    "finnish",
+    "norwegian",
+    "swedish",
+    "hungarian",
+    "czech",
+    "romanian",
+    "turkish",
+    "brazilian",
+    "bulgarian",
+    "greek",
+    "arabic",
+    "ukrainian",
+    "latam",
+    "vietnamese",
+];
+
+/// Client-side timestamp filters (Unix milliseconds), applied while walking result pages.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DateFilter {
+    pub created_after: Option<u64>,
+    pub updated_after: Option<u64>,
+}
+
+impl DateFilter {
+    pub fn is_empty(&self) -> bool {
+        self.created_after.is_none() && self.updated_after.is_none()
+    }
+
+    fn matches(&self, item: &WorkshopItem) -> bool {
+        self.created_after
+            .is_none_or(|after| item.time_created >= after)
+            && self
+                .updated_after
+                .is_none_or(|after| item.time_updated >= after)
+    }
+
+    /// Whether a page sorted by `sort_by` proves no later page can match: results are
+    /// newest-first, so once the oldest item is before the cutoff the rest are too.
+    fn exhausted(&self, sort_by: &str, items: &[EnhancedWorkshopItem]) -> bool {
+        let oldest = |timestamp: fn(&WorkshopItem) -> u64| {
+            items
+                .iter()
+                .map(|item| timestamp(&item.workshop_item))
+                .min()
+        };
+        match (sort_by, self.created_after, self.updated_after) {
+            ("recent", Some(after), _) => {
+                oldest(|item| item.time_created).is_some_and(|t| t < after)
+            }
+            ("recently-updated", _, Some(after)) => {
+                oldest(|item| item.time_updated).is_some_and(|t| t < after)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether pages come newest-first by a filtered timestamp, so walking can stop early.
+    fn ordered_by(&self, sort_by: &str) -> bool {
+        matches!(
+            (sort_by, self.created_after, self.updated_after),
+            ("recent", Some(_), _) | ("recently-updated", _, Some(_))
+        )
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
 struct SearchCacheKey {
     steam_game_id: u32,
-    search_text: String,
-    sort_by: String,
-    period: Option<String>,
-    page: u32,
-    tags: Option<String>,
+    query: SearchQuery,
 }
 
 #[derive(Debug, Clone, Serialize, Encode, Decode)]
 struct CachedSearchResult {
-    items: Vec<EnhancedWorkshopItem>,
+    result: SearchPage,
     timestamp: u64,
 }
 
-#[derive(Debug, Default, Serialize, Encode, Decode)]
+#[derive(Debug, Clone, Default, Serialize, Encode, Decode)]
 struct SearchCache {
     entries: HashMap<SearchCacheKey, CachedSearchResult>,
 }
@@ -39,49 +385,34 @@ impl SearchCache {
     const CACHE_DURATION_MINUTES: u64 = 10;
 
     fn load_from_disk() -> Self {
+        // SQLite entries are read one query at a time
+        if settings::get().cache_backend == CacheBackend::Sqlite {
+            return Self::default();
+        }
         match Self::get_cache_file_path() {
             Ok(cache_path) => {
-                if cache_path.exists() {
-                    match fs::read(&cache_path) {
-                        Ok(data) => {
-                            let config = bincode::config::standard();
-                            match bincode::decode_from_slice(&data, config) {
-                                Ok((cache, _)) => {
-                                    let mut cleaned_cache: SearchCache = cache;
-                                    cleaned_cache.clean_expired_entries();
-                                    return cleaned_cache;
-                                }
-                                Err(e) => {
-                                    eprintln!("Failed to decode search cache: {}", e);
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to read search cache file: {}", e);
-                        }
-                    }
+                if let Some(mut cache) = cache_file::read::<SearchCache>(&cache_path) {
+                    cache.clean_expired_entries();
+                    return cache;
                 }
             }
             Err(e) => {
-                eprintln!("Failed to get cache file path: {}", e);
+                tracing::warn!("Failed to get cache file path: {}", e);
             }
         }
         Self::default()
     }
 
-    fn save_to_disk(&self) -> Result<(), String> {
-        let cache_path = Self::get_cache_file_path()?;
-        let config = bincode::config::standard();
-        let encoded = bincode::encode_to_vec(self, config)
-            .map_err(|e| format!("Failed to encode search cache: {}", e))?;
-
-        fs::write(&cache_path, encoded)
-            .map_err(|e| format!("Failed to write search cache to disk: {}", e))?;
+    fn save_to_disk(&self) -> Result<(), S7forgeError> {
+        cache_file::write(&Self::get_cache_file_path()?, self)
+    }
 
-        Ok(())
+    // The key's JSON is stable, unlike the bincode of a hash map
+    fn row_key(key: &SearchCacheKey) -> String {
+        serde_json::to_string(key).unwrap_or_default()
     }
 
-    fn get_cache_file_path() -> Result<PathBuf, String> {
+    fn get_cache_file_path() -> Result<PathBuf, S7forgeError> {
         let cache_dir = get_cache_dir()?;
         Ok(cache_dir.join("search_workshop_cache.bin"))
     }
@@ -102,15 +433,26 @@ impl SearchCache {
             .as_secs()
     }
 
-    fn get(&mut self, key: &SearchCacheKey) -> Option<Vec<EnhancedWorkshopItem>> {
+    fn get(&mut self, key: &SearchCacheKey) -> Option<SearchPage> {
         self.clean_expired_entries();
+        if settings::get().no_cache {
+            return None;
+        }
+        if settings::get().cache_backend == CacheBackend::Sqlite {
+            let now = Self::current_timestamp();
+            let rows = cache_db::get(cache_db::SEARCHES, &[Self::row_key(key)]).ok()?;
+            return rows
+                .into_iter()
+                .find(|row| now.saturating_sub(row.cached_at) < Self::CACHE_DURATION_MINUTES * 60)
+                .and_then(|row| row.decode::<SearchPage>()?.ok());
+        }
 
         if let Some(cached_result) = self.entries.get(key) {
             let now = Self::current_timestamp();
             let expiry_duration_secs = Self::CACHE_DURATION_MINUTES * 60;
 
             if now.saturating_sub(cached_result.timestamp) < expiry_duration_secs {
-                return Some(cached_result.items.clone());
+                return Some(cached_result.result.clone());
             } else {
                 self.entries.remove(key);
             }
@@ -118,9 +460,24 @@ impl SearchCache {
         None
     }
 
-    fn insert(&mut self, key: SearchCacheKey, items: Vec<EnhancedWorkshopItem>) {
+    fn insert(&mut self, key: SearchCacheKey, result: SearchPage) {
+        if settings::get().cache_backend == CacheBackend::Sqlite {
+            let now = Self::current_timestamp();
+            let stored = CacheRow::encode(Self::row_key(&key), &result, now).and_then(|row| {
+                cache_db::put(cache_db::SEARCHES, &[row.app(Some(key.steam_game_id))])?;
+                cache_db::remove_expired(
+                    cache_db::SEARCHES,
+                    now.saturating_sub(Self::CACHE_DURATION_MINUTES * 60),
+                )
+            });
+            if let Err(e) = stored {
+                tracing::warn!("Failed to save search cache: {}", e);
+            }
+            return;
+        }
+
         let cached_result = CachedSearchResult {
-            items,
+            result,
             timestamp: Self::current_timestamp(),
         };
         self.entries.insert(key, cached_result);
@@ -128,38 +485,259 @@ impl SearchCache {
         self.clean_expired_entries();
 
         if let Err(e) = self.save_to_disk() {
-            eprintln!("Warning: Failed to save search cache to disk: {}", e);
+            tracing::warn!("Failed to save search cache to disk: {}", e);
+        }
+    }
+}
+
+/// Rows for the SQLite cache from a `search_workshop_cache.bin` file.
+pub fn legacy_rows(content: &[u8]) -> Result<Vec<CacheRow>, S7forgeError> {
+    cache_codec::decode::<SearchCache>(content)?
+        .entries
+        .iter()
+        .map(|(key, cached_result)| {
+            CacheRow::encode(
+                SearchCache::row_key(key),
+                &cached_result.result,
+                cached_result.timestamp,
+            )
+            .map(|row| row.app(Some(key.steam_game_id)))
+        })
+        .collect()
+}
+
+/// Search cache entries as stored, keyed by the query they answer.
+pub fn cached_entries() -> Result<Vec<CachedEntry>, S7forgeError> {
+    let ttl = Duration::from_secs(SearchCache::CACHE_DURATION_MINUTES * 60);
+    if settings::get().cache_backend == CacheBackend::Sqlite {
+        return cache_db::all(cache_db::SEARCHES).map(|rows| {
+            rows.into_iter()
+                .map(|row| {
+                    let key = serde_json::from_str::<SearchCacheKey>(&row.key).ok();
+                    let value = match row.decode::<SearchPage>() {
+                        Some(Ok(page)) => serde_json::to_value(&page).unwrap_or_default(),
+                        _ => serde_json::Value::Null,
+                    };
+                    let mut entry = CachedEntry::new(
+                        cache_db::DB_FILE,
+                        key.map_or(row.key.clone(), |key| {
+                            serde_json::to_string(&key.query).unwrap_or_default()
+                        }),
+                        value,
+                    )
+                    .cached(row.cached_at, ttl);
+                    entry.app_id = row.app_id;
+                    entry
+                })
+                .collect()
+        });
+    }
+
+    let cache_path = SearchCache::get_cache_file_path()?;
+    let Ok(data) = fs::read(&cache_path) else {
+        return Ok(Vec::new());
+    };
+    let cache = cache_codec::decode::<SearchCache>(&data)
+        .map_err(|e| S7forgeError::cache(format!("Failed to read {}", cache_path.display()), e))?;
+
+    Ok(cache
+        .entries
+        .into_iter()
+        .map(|(key, cached_result)| {
+            CachedEntry::new(
+                "search_workshop_cache.bin",
+                serde_json::to_string(&key.query).unwrap_or_default(),
+                serde_json::to_value(&cached_result.result).unwrap_or_default(),
+            )
+            .cached(cached_result.timestamp, ttl)
+            .app(key.steam_game_id)
+        })
+        .collect())
+}
+
+/// Walks result pages starting at `query.page` until Steam runs out of results or `limit`
+/// unique items matching `filter` are collected. Each page goes through the regular search
+/// cache. The returned `page` is the last page fetched; cursor searches follow `next_cursor`
+/// instead and return the cursor after the last page.
+pub async fn search_workshop_all_pages(
+    steam_game_id: u32,
+    mut query: SearchQuery,
+    limit: usize,
+    filter: DateFilter,
+) -> Result<SearchPage, S7forgeError> {
+    let mut seen = FxHashSet::default();
+    let bar = progress::count_bar(limit as u64, "Searching");
+    let mut combined = search_workshop(steam_game_id, query.clone()).await?;
+    let mut exhausted = filter.exhausted(&query.sort_by, &combined.items);
+    let mut pages_scanned = 1;
+    combined.items.retain(|item| {
+        filter.matches(&item.workshop_item) && seen.insert(item.workshop_item.published_file_id)
+    });
+
+    while combined.has_more && !exhausted && combined.items.len() < limit {
+        bar.set_position(combined.items.len() as u64);
+        // Filters that can't stop early would otherwise crawl the whole catalogue
+        if !filter.is_empty()
+            && !filter.ordered_by(&query.sort_by)
+            && pages_scanned >= MAX_FILTER_PAGES
+        {
+            break;
         }
+
+        match combined.next_cursor.take() {
+            Some(cursor) => query.cursor = Some(cursor),
+            None => query.page += 1,
+        }
+        let next = search_workshop(steam_game_id, query.clone()).await?;
+        exhausted = filter.exhausted(&query.sort_by, &next.items);
+        pages_scanned += 1;
+
+        // Rankings can shift between page requests, repeating items across pages
+        combined.items.extend(next.items.into_iter().filter(|item| {
+            filter.matches(&item.workshop_item) && seen.insert(item.workshop_item.published_file_id)
+        }));
+        combined.page = next.page;
+        combined.total_results = next.total_results;
+        combined.has_more = next.has_more;
+        combined.next_cursor = next.next_cursor;
+    }
+
+    if exhausted {
+        combined.has_more = false;
+        combined.next_cursor = None;
+    }
+    if combined.items.len() > limit {
+        combined.items.truncate(limit);
+        combined.has_more = true;
     }
+    Ok(combined)
 }
 
 pub async fn search_workshop(
     steam_game_id: u32,
-    search_text: String,
-    sort_by: String,
-    period: Option<String>,
-    page: u32,
-    tags: Option<String>,
-) -> Result<Vec<EnhancedWorkshopItem>, String> {
-    if page == 0 {
-        return Err("Page number must be at least 1".to_string());
+    query: SearchQuery,
+) -> Result<SearchPage, S7forgeError> {
+    if query.page == 0 {
+        return Err(S7forgeError::InvalidArgs(
+            "Page number must be at least 1".into(),
+        ));
     }
     let cache_key = SearchCacheKey {
         steam_game_id,
-        search_text: search_text.clone(),
-        sort_by: sort_by.clone(),
-        period: period.clone(),
-        page,
-        tags: tags.clone(),
+        query: query.clone(),
     };
+    let page = query.page;
+    let cursor = query.cursor.clone();
+    if let Some(creator) = query.creator {
+        // Individual account IDs start at 76561197960265728 (universe 1, account type 1)
+        if creator < 76561197960265728 {
+            return Err(S7forgeError::InvalidArgs(format!(
+                "Invalid SteamID64: {}",
+                creator
+            )));
+        }
+        if !query.search_text.trim().is_empty() {
+            return Err(S7forgeError::InvalidArgs(
+                "--query cannot be combined with --creator".into(),
+            ));
+        }
+        if query.cursor.is_some() {
+            return Err(S7forgeError::InvalidArgs(
+                "--cursor cannot be combined with --creator".into(),
+            ));
+        }
+        creator_sort_order(&query.sort_by)?;
+    } else {
+        // A bad --period is reported before Steam or the Web API is contacted
+        trend_days(sort_option(&query.sort_by)?, query.period.as_deref())?;
+    }
+    if query
+        .cursor
+        .as_deref()
+        .is_some_and(|cursor| cursor.is_empty())
+    {
+        return Err(S7forgeError::InvalidArgs(
+            "--cursor must not be empty, use \"*\" for the first page".into(),
+        ));
+    }
 
     let mut cache = SearchCache::load_from_disk();
     if let Some(cached_result) = cache.get(&cache_key) {
+        cache_counters::hit("search_workshop_cache.bin", 1);
         return Ok(cached_result);
     }
+    cache_counters::miss("search_workshop_cache.bin", 1);
+
+    // The cursor variant of SendQueryUGCRequest isn't exposed by steamworks-rs, so cursor
+    // queries always go through QueryFiles
+    let queried = match steam_manager::initialize_client_or_fallback(steam_game_id).await {
+        _ if query.cursor.is_some() => query_workshop_from_web_api(steam_game_id, query).await?,
+        Some(steam_client) => {
+            query_workshop_from_client(steam_client, steam_game_id, query).await?
+        }
+        None if query.creator.is_some() => {
+            return Err(S7forgeError::ClientRequired);
+        }
+        None => query_workshop_from_web_api(steam_game_id, query).await?,
+    };
+
+    let total_results = queried.total_results;
+    let next_cursor = queried.next_cursor;
+    let workshop_items = queried
+        .items
+        .into_iter()
+        .filter_map(|item| match item {
+            Some(it) if it.file_type == "Community" => Some(it),
+            _ => None,
+        })
+        .collect::<Vec<WorkshopItem>>();
+
+    let creator_ids: Vec<steamworks::SteamId> = workshop_items
+        .iter()
+        .map(|item| steamworks::SteamId::from_raw(item.owner.steam_id64))
+        .collect();
+
+    let creator_names = fetch_creator_names(creator_ids, steam_game_id).await?;
+
+    let items: Vec<EnhancedWorkshopItem> = workshop_items
+        .into_iter()
+        .map(|item| {
+            let owner = item.owner.clone();
+            let creator_name = creator_names
+                .get(&item.owner.steam_id64)
+                .cloned()
+                .unwrap_or_else(|| "[unknown]".to_string());
+            EnhancedWorkshopItem::new(item, owner.steam_id64.to_string(), creator_name)
+        })
+        .collect();
 
-    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let has_more = match &cursor {
+        // Steam repeats the last cursor once the results run out
+        Some(cursor) => {
+            !items.is_empty() && next_cursor.as_ref().is_some_and(|next| next != cursor)
+        }
+        None => page.saturating_mul(PAGE_SIZE) < total_results,
+    };
+    let result = SearchPage {
+        total_results,
+        page,
+        page_size: PAGE_SIZE,
+        has_more,
+        next_cursor: next_cursor.filter(|_| has_more),
+        items,
+    };
+
+    cache.insert(cache_key, result.clone());
 
+    Ok(result)
+}
+
+#[tracing::instrument(level = "debug", skip_all, fields(app_id = steam_game_id, page = query.page))]
+async fn query_workshop_from_client(
+    steam_client: steamworks::Client,
+    steam_game_id: u32,
+    query: SearchQuery,
+) -> Result<WorkshopItemsResult, S7forgeError> {
     let (tx, mut rx) = mpsc::channel(32);
 
     let search_task = tokio::task::spawn_blocking(move || {
@@ -169,62 +747,74 @@ pub async fn search_workshop(
             creator: AppId(steam_game_id),
             consumer: AppId(steam_game_id),
         };
-        let query_type = match sort_by.as_str() {
-            "relevance" => UGCQueryType::RankedByTextSearch,
-            "recent" => UGCQueryType::RankedByPublicationDate,
-            "popular" => UGCQueryType::RankedByTrend,
-            "most-subscribed" => UGCQueryType::RankedByTotalUniqueSubscriptions,
-            "recently-updated" => UGCQueryType::RankedByLastUpdatedDate,
-            _ => UGCQueryType::RankedByTextSearch,
-        };
-
-        let query_handle = ugc
-            .query_all(query_type, UGCType::Items, app_ids, page)
-            .map_err(|e| format!("Failed to create search query: {:?}", e))?;
+        let sort = sort_option(&query.sort_by)?;
+
+        let query_handle = match query.creator {
+            Some(creator) => {
+                let sort_order = creator_sort_order(&query.sort_by)?;
+                ugc.query_user(
+                    SteamId::from_raw(creator).account_id(),
+                    UserListType::Published.into(),
+                    UGCType::Items,
+                    sort_order.into(),
+                    app_ids,
+                    query.page,
+                )
+            }
+            None => ugc.query_all(sort.query_type, UGCType::Items, app_ids, query.page),
+        }
+        .map_err(|e| format!("Failed to create search query: {:?}", e))?;
 
         let mut configured_query = query_handle
             .set_return_metadata(true)
             .set_return_children(true)
             .set_return_additional_previews(true)
-            .set_return_key_value_tags(true);
+            .set_return_key_value_tags(query.with_kv_tags);
 
-        if !search_text.trim().is_empty() {
-            configured_query = configured_query.set_search_text(&search_text);
+        if !query.search_text.trim().is_empty() {
+            configured_query = configured_query.set_search_text(&query.search_text);
         }
-        if query_type == UGCQueryType::RankedByTrend {
-            let period_str = period.as_deref().unwrap_or("one-week");
-            let trend_days = match period_str {
-                "today" => 1,
-                "one-week" => 7,
-                "three-months" => 90,
-                "six-months" => 180,
-                "one-year" => 365,
-                _ => 7,
-            };
-            configured_query = configured_query.set_ranked_by_trend_days(trend_days);
-        } else if period.is_some() {
-            return Err("Period filter is only applicable for popular sort type".to_string());
+        if query.creator.is_some() {
+            // User queries are ordered by UserListOrder and have no trend window
+            if query.period.is_some() {
+                return Err(S7forgeError::InvalidArgs(
+                    "Period filter cannot be combined with --creator".into(),
+                ));
+            }
+        } else if let Some(days) = trend_days(sort, query.period.as_deref())? {
+            configured_query = configured_query.set_ranked_by_trend_days(days);
         }
 
-        if let Some(ref tag_filter) = tags {
+        if let Some(ref tag_filter) = query.tags {
             let tag_list: Vec<&str> = tag_filter.split(',').map(|s| s.trim()).collect();
             for tag in tag_list {
                 if !tag.is_empty() {
                     configured_query = configured_query.add_required_tag(tag);
                 }
             }
+            configured_query = configured_query.set_match_any_tag(!query.match_all_tags);
+        }
+        for (key, value) in &query.kv_tags {
+            configured_query = configured_query.add_required_key_value_tag(key, value);
+        }
+        if let Some(ref language) = query.language {
+            // Short descriptions are truncated, so fetch the full translated text
+            configured_query = configured_query
+                .set_language(language)
+                .set_return_long_description(true);
         }
 
         configured_query.fetch(move |fetch_result| {
+            tracing::debug!(ok = fetch_result.is_ok(), "Search query callback");
             let _ = tx_inner.send(
                 fetch_result
                     .map(|query_results| WorkshopItemsResult::from_query_results(query_results))
-                    .map_err(|e| format!("Steam API error: {:?}", e)),
+                    .map_err(S7forgeError::steam_api),
             );
         });
 
         let start_time = std::time::Instant::now();
-        let timeout_duration = std::time::Duration::from_secs(30);
+        let timeout_duration = settings::get().steam_timeout;
 
         loop {
             let _ = tx.blocking_send(());
@@ -233,7 +823,7 @@ pub async fn search_workshop(
             }
 
             if start_time.elapsed() > timeout_duration {
-                return Err("Search operation timed out waiting for Steam response".to_string());
+                return Err(S7forgeError::Timeout("Search operation"));
             }
 
             std::thread::sleep(std::time::Duration::from_millis(10));
@@ -249,46 +839,60 @@ pub async fn search_workshop(
                 steam_manager::run_callbacks(steam_game_id)?;
             }
             task_result = &mut fused_task => {
-                search_result = Some(task_result.map_err(|e| format!("Task error: {:?}", e))??);
+                search_result = Some(task_result.map_err(S7forgeError::Join)??);
                 break;
             }
         }
     }
 
-    let items_result = search_result.unwrap();
-    let workshop_items = items_result
-        .items
-        .into_iter()
-        .filter_map(|item| match item {
-            Some(it) if it.file_type == "Community" => Some(it),
-            _ => None,
-        })
-        .collect::<Vec<WorkshopItem>>();
-
-    if workshop_items.is_empty() {
-        return Ok(Vec::new());
-    }
-
-    let creator_ids: Vec<steamworks::SteamId> = workshop_items
-        .iter()
-        .map(|item| steamworks::SteamId::from_raw(item.owner.steam_id64))
-        .collect();
-
-    let creator_names = fetch_creator_names(creator_ids, steam_game_id).await?;
+    Ok(search_result.unwrap())
+}
 
-    let result: Vec<EnhancedWorkshopItem> = workshop_items
-        .into_iter()
-        .map(|item| {
-            let owner = item.owner.clone();
-            let creator_name = creator_names
-                .get(&item.owner.steam_id64)
-                .cloned()
-                .unwrap_or_else(|| "[unknown]".to_string());
-            EnhancedWorkshopItem::new(item, owner.steam_id64.to_string(), creator_name)
+#[tracing::instrument(level = "debug", skip_all, fields(app_id = steam_game_id, page = query.page))]
+async fn query_workshop_from_web_api(
+    steam_game_id: u32,
+    query: SearchQuery,
+) -> Result<WorkshopItemsResult, S7forgeError> {
+    let sort = sort_option(&query.sort_by)?;
+    let query_type = sort.web_query_type.ok_or_else(|| {
+        format!(
+            "Sorting by {} needs the Steam client of a logged-in user",
+            sort.name
+        )
+    })?;
+    let trend_days = trend_days(sort, query.period.as_deref())?;
+
+    let tag_list: Vec<String> = query
+        .tags
+        .as_deref()
+        .map(|tag_filter| {
+            tag_filter
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|tag| !tag.is_empty())
+                .collect()
         })
-        .collect();
-
-    cache.insert(cache_key, result.clone());
-
-    Ok(result)
+        .unwrap_or_default();
+
+    tokio::task::spawn_blocking(move || {
+        web_api::query_files(&web_api::QueryFilesRequest {
+            app_id: steam_game_id,
+            query_type,
+            page: query.page,
+            cursor: query.cursor.as_deref(),
+            search_text: &query.search_text,
+            tags: &tag_list,
+            match_all_tags: query.match_all_tags,
+            kv_tags: &query.kv_tags,
+            with_kv_tags: query.with_kv_tags,
+            language: query
+                .language
+                .as_deref()
+                .and_then(|language| STEAM_LANGUAGES.iter().position(|known| *known == language))
+                .map(|index| index as u32),
+            trend_days,
+        })
+    })
+    .await
+    .map_err(S7forgeError::Join)?
 }
@@ -0,0 +1,189 @@
+use schemars::JsonSchema;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use crate::core::error::S7forgeError;
+use crate::core::progress;
+use crate::core::settings;
+
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/7otion/s7forge/releases/latest";
+/// Release asset listing `<sha256>  <file>` for every binary, as written by `sha256sum`
+const CHECKSUMS_ASSET: &str = "SHA256SUMS";
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SelfUpdateResult {
+    pub current_version: String,
+    pub latest_version: String,
+    pub update_available: bool,
+    /// Set once the running executable was replaced
+    pub updated: bool,
+    /// Release asset that was installed
+    pub asset: Option<String>,
+    /// Path of the replaced executable
+    pub path: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Release {
+    tag_name: String,
+    assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Deserialize)]
+struct ReleaseAsset {
+    name: String,
+    browser_download_url: String,
+    size: u64,
+}
+
+/// Name of this platform's binary in a release, e.g. `s7forge-x86_64-windows.exe`.
+pub fn asset_name() -> String {
+    format!(
+        "s7forge-{}-{}{}",
+        std::env::consts::ARCH,
+        std::env::consts::OS,
+        std::env::consts::EXE_SUFFIX
+    )
+}
+
+/// Compares the running version with the latest GitHub release and, unless `check_only`,
+/// installs the release binary over the running executable once its checksum matches.
+pub async fn self_update(check_only: bool) -> Result<SelfUpdateResult, S7forgeError> {
+    tokio::task::spawn_blocking(move || update(check_only))
+        .await
+        .map_err(S7forgeError::Join)?
+}
+
+fn update(check_only: bool) -> Result<SelfUpdateResult, S7forgeError> {
+    // Release downloads can outlast the request timeout; only stalls count
+    let timeout = settings::get().web_timeout;
+    let agent = ureq::AgentBuilder::new()
+        .timeout_connect(timeout)
+        .timeout_read(timeout)
+        .user_agent(concat!("s7forge/", env!("CARGO_PKG_VERSION")))
+        .build();
+
+    let release: Release = agent
+        .get(LATEST_RELEASE_URL)
+        .set("Accept", "application/vnd.github+json")
+        .call()
+        .map_err(|e| S7forgeError::http("GitHub release request failed", e))?
+        .into_json()
+        .map_err(|e| S7forgeError::http("Failed to parse GitHub release", e))?;
+
+    let current = Version::parse(env!("CARGO_PKG_VERSION"))
+        .map_err(|e| format!("Invalid version of this build: {}", e))?;
+    // A tag that isn't a version is a broken release, not something the user passed
+    let latest = Version::parse(release.tag_name.trim_start_matches('v')).map_err(|e| {
+        S7forgeError::http(
+            format!("Invalid version in latest release tag {}", release.tag_name),
+            e,
+        )
+    })?;
+
+    let mut result = SelfUpdateResult {
+        current_version: current.to_string(),
+        latest_version: latest.to_string(),
+        update_available: latest > current,
+        updated: false,
+        asset: None,
+        path: None,
+    };
+    if !result.update_available || check_only {
+        return Ok(result);
+    }
+
+    let name = asset_name();
+    let find_asset = |wanted: &str| release.assets.iter().find(|asset| asset.name == wanted);
+    let binary = find_asset(&name).ok_or_else(|| {
+        S7forgeError::NotFound(format!(
+            "Release {} has no binary for this platform ({})",
+            release.tag_name, name
+        ))
+    })?;
+    let checksums = find_asset(CHECKSUMS_ASSET).ok_or_else(|| {
+        S7forgeError::NotFound(format!(
+            "Release {} has no {} file; not installing an unverified binary",
+            release.tag_name, CHECKSUMS_ASSET
+        ))
+    })?;
+
+    let checksums = agent
+        .get(&checksums.browser_download_url)
+        .call()
+        .map_err(|e| S7forgeError::http("Failed to download release checksums", e))?
+        .into_string()
+        .map_err(|e| S7forgeError::http("Failed to read release checksums", e))?;
+    let expected = expected_checksum(&checksums, &name).ok_or_else(|| {
+        S7forgeError::NotFound(format!(
+            "{} of release {} has no checksum for {}",
+            CHECKSUMS_ASSET, release.tag_name, name
+        ))
+    })?;
+
+    tracing::info!("Downloading {} {}", name, release.tag_name);
+    let response = agent
+        .get(&binary.browser_download_url)
+        .call()
+        .map_err(|e| S7forgeError::http("Failed to download release binary", e))?;
+    let bar = progress::bytes_bar(binary.size, "Downloading");
+    let mut bytes = Vec::with_capacity(binary.size as usize);
+    bar.wrap_read(response.into_reader().take(binary.size))
+        .read_to_end(&mut bytes)
+        .map_err(|e| S7forgeError::http("Failed to download release binary", e))?;
+    drop(bar);
+
+    let actual = hex(&Sha256::digest(&bytes));
+    if !actual.eq_ignore_ascii_case(&expected) {
+        // A corrupted or tampered download, so the release request counts as failed
+        return Err(S7forgeError::http(
+            format!("Checksum mismatch for {}", name),
+            format!("expected {}, got {}", expected, actual),
+        ));
+    }
+
+    let current_exe = std::env::current_exe()
+        .map_err(|e| S7forgeError::io("Failed to locate the running executable", e))?;
+    install(&bytes, &current_exe)?;
+
+    result.updated = true;
+    result.asset = Some(name);
+    result.path = Some(current_exe.to_string_lossy().into_owned());
+    Ok(result)
+}
+
+// Lines of `sha256sum` output: `<hex>  <file>`, or `<hex> *<file>` in binary mode
+fn expected_checksum(checksums: &str, name: &str) -> Option<String> {
+    checksums.lines().find_map(|line| {
+        let (hash, file) = line.trim().split_once(char::is_whitespace)?;
+        (file.trim_start().trim_start_matches('*') == name).then(|| hash.to_string())
+    })
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// The new binary is written next to the old one first, so the swap stays on one file system
+fn install(bytes: &[u8], current_exe: &Path) -> Result<(), S7forgeError> {
+    let staged = current_exe.with_file_name(format!(".{}.new", asset_name()));
+    fs::write(&staged, bytes)
+        .map_err(|e| S7forgeError::io(format!("Failed to write {}", staged.display()), e))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))
+            .map_err(|e| S7forgeError::io(format!("Failed to write {}", staged.display()), e))?;
+    }
+
+    // Windows can't overwrite a running executable; self_replace moves it aside first
+    let replaced = self_replace::self_replace(&staged)
+        .map_err(|e| S7forgeError::io(format!("Failed to replace {}", current_exe.display()), e));
+    let _ = fs::remove_file(&staged);
+    replaced
+}
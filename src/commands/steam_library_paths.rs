@@ -2,40 +2,42 @@ use bincode::{Decode, Encode};
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::{fs, path::Path};
 
+use crate::commands::cache_inspect::CachedEntry;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
 use crate::core::steam_install_paths::steam_install_paths;
+use crate::utils::cache_codec;
+use crate::utils::cache_counters;
+use crate::utils::cache_file;
 use crate::utils::extract_quoted_strings::extract_quoted_strings;
 use crate::utils::get_cache_dir::get_cache_dir;
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Debug, Clone, Encode, Decode)]
 struct LibraryPathsCache {
     paths: Vec<String>,
     timestamp: u64,
 }
 
-pub fn steam_library_paths() -> Result<Vec<String>, String> {
-    // Try to load from cache
-    if let Ok(cache_dir) = get_cache_dir() {
+pub fn steam_library_paths() -> Result<Vec<String>, S7forgeError> {
+    // Try to load from cache; --no-cache skips this but still refreshes the cache below
+    if let Some(cache_dir) = get_cache_dir().ok().filter(|_| !settings::get().no_cache) {
         let cache_path = cache_dir.join("library_paths_cache.bin");
-        if cache_path.exists() {
-            if let Ok(cache_content) = fs::read(&cache_path) {
-                let config = bincode::config::standard();
-                if let Ok((cache, _)) =
-                    bincode::decode_from_slice::<LibraryPathsCache, _>(&cache_content, config)
-                {
-                    let now = SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs();
-                    let cache_duration_secs = 60 * 60; // 1 hour
-
-                    if now.saturating_sub(cache.timestamp) < cache_duration_secs {
-                        return Ok(cache.paths);
-                    }
-                }
+        if let Some(cache) = cache_file::read::<LibraryPathsCache>(&cache_path) {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let cache_duration_secs = settings::get().path_cache_ttl.as_secs();
+
+            if now.saturating_sub(cache.timestamp) < cache_duration_secs {
+                cache_counters::hit("library_paths_cache.bin", 1);
+                return Ok(cache.paths);
             }
         }
     }
 
+    cache_counters::miss("library_paths_cache.bin", 1);
+
     let steam_install_paths = steam_install_paths()?;
     let mut library_folder_paths = Vec::new();
 
@@ -49,7 +51,7 @@ pub fn steam_library_paths() -> Result<Vec<String>, String> {
         }
 
         let file_data = fs::read_to_string(&library_meta_file)
-            .map_err(|e| format!("Failed to read library metadata file: {:?}", e))?;
+            .map_err(|e| S7forgeError::io("Failed to read library metadata file", e))?;
 
         let quoted_strings = extract_quoted_strings(&file_data);
 
@@ -78,11 +80,27 @@ pub fn steam_library_paths() -> Result<Vec<String>, String> {
                 .as_secs(),
         };
 
-        let config = bincode::config::standard();
-        if let Ok(encoded) = bincode::encode_to_vec(&cache, config) {
-            let _ = fs::write(&cache_path, encoded);
-        }
+        let _ = cache_file::write(&cache_path, &cache);
     }
 
     Ok(library_folder_paths)
 }
+
+/// The cached library list as a single entry.
+pub fn cached_entries() -> Result<Vec<CachedEntry>, S7forgeError> {
+    let cache_path = get_cache_dir()?.join("library_paths_cache.bin");
+    let Ok(cache_content) = fs::read(&cache_path) else {
+        return Ok(Vec::new());
+    };
+    let cache = cache_codec::decode::<LibraryPathsCache>(&cache_content)
+        .map_err(|e| S7forgeError::cache(format!("Failed to read {}", cache_path.display()), e))?;
+
+    Ok(vec![
+        CachedEntry::new(
+            "library_paths_cache.bin",
+            "paths".to_string(),
+            cache.paths.into(),
+        )
+        .cached(cache.timestamp, settings::get().path_cache_ttl),
+    ])
+}
@@ -0,0 +1,59 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::commands::account_info::AccountInfo;
+use crate::core::error::S7forgeError;
+use crate::core::localplayer::PlayerSteamId;
+use crate::core::steam_manager;
+
+/// Spacewar, Valve's test app that every account can initialize Steamworks for. Used when
+/// no `--app-id` is given.
+pub const STEAM_STATUS_APP_ID: u32 = 480;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SteamStatus {
+    /// Steamworks initialized, so the client is running with an account signed in
+    pub running: bool,
+    /// Connected to Steam; also false while the client is reconnecting
+    pub logged_on: bool,
+    /// The account is set to run Steam in offline mode
+    pub offline_mode: bool,
+    pub steam_id: Option<PlayerSteamId>,
+    pub persona_name: Option<String>,
+    /// Why Steamworks couldn't be initialized
+    pub error: Option<String>,
+}
+
+/// Reports the state of the Steam client. With `launch`, Steam is started when it isn't
+/// running and the status is taken once Steamworks initializes.
+pub async fn steam_status(app_id: u32, launch: bool) -> Result<SteamStatus, S7forgeError> {
+    let client = if launch {
+        steam_manager::initialize_client_launching(app_id).await
+    } else {
+        steam_manager::initialize_client(app_id).await
+    };
+
+    match client {
+        Ok(client) => {
+            let account = AccountInfo::from_client(&client);
+            Ok(SteamStatus {
+                running: true,
+                logged_on: account.logged_on,
+                offline_mode: account.offline_mode,
+                steam_id: Some(account.steam_id),
+                persona_name: Some(account.persona_name),
+                error: None,
+            })
+        }
+        // Not running is a status, not a failure of this command
+        Err(error @ S7forgeError::SteamInit(_)) => Ok(SteamStatus {
+            running: false,
+            logged_on: false,
+            offline_mode: false,
+            steam_id: None,
+            persona_name: None,
+            error: Some(error.to_string()),
+        }),
+        Err(error) => Err(error),
+    }
+}
@@ -1,34 +1,76 @@
 use futures_util::FutureExt;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use steamworks::PublishedFileId;
 use tokio::sync::mpsc;
 
+use crate::commands::blacklist::load_blacklist;
+use crate::commands::dependency_graph::dependency_graph;
+use crate::core::error::S7forgeError;
+use crate::core::progress;
+use crate::core::settings;
 use crate::core::steam_manager;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, JsonSchema, Deserialize)]
 pub struct SubscribeResult {
     pub item_id: u64,
     pub success: bool,
+    /// Set for required items pulled in by `--with-dependencies` rather than requested directly
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dependency: bool,
+    /// Why the item was skipped instead of subscribed, e.g. because it is blacklisted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 pub async fn subscribe(
     steam_game_id: u32,
     item_ids: Vec<u64>,
-) -> Result<Vec<SubscribeResult>, String> {
+    with_dependencies: bool,
+) -> Result<Vec<SubscribeResult>, S7forgeError> {
+    let dependency_ids = if with_dependencies {
+        dependency_graph(steam_game_id, item_ids.clone())
+            .await?
+            .dependency_ids()
+    } else {
+        Vec::new()
+    };
+
+    let blacklist = load_blacklist(steam_game_id)?;
     let steam_client = steam_manager::initialize_client(steam_game_id).await?;
     let mut results = Vec::new();
+    let bar = progress::count_bar(
+        (item_ids.len() + dependency_ids.len()) as u64,
+        "Subscribing",
+    );
 
-    for item_id in item_ids {
-        let result = subscribe_single_item(&steam_client, steam_game_id, item_id).await;
-        match result {
-            Ok(success) => results.push(SubscribeResult { item_id, success }),
-            Err(error) => {
-                return Err(format!(
-                    "Failed to subscribe to item {}: {}",
-                    item_id, error
-                ));
-            }
+    let targets = item_ids
+        .into_iter()
+        .map(|id| (id, false))
+        .chain(dependency_ids.into_iter().map(|id| (id, true)));
+
+    for (item_id, dependency) in targets {
+        bar.set_position(results.len() as u64);
+        if let Some(entry) = blacklist.get(&item_id) {
+            results.push(SubscribeResult {
+                item_id,
+                success: false,
+                dependency,
+                warning: Some(match &entry.reason {
+                    Some(reason) => format!("Item is blacklisted: {}", reason),
+                    None => "Item is blacklisted".to_string(),
+                }),
+            });
+            continue;
         }
+
+        let success = subscribe_single_item(&steam_client, steam_game_id, item_id).await?;
+        results.push(SubscribeResult {
+            item_id,
+            success,
+            dependency,
+            warning: None,
+        });
     }
 
     Ok(results)
@@ -38,7 +80,7 @@ async fn subscribe_single_item(
     steam_client: &steamworks::Client,
     steam_game_id: u32,
     item_id: u64,
-) -> Result<bool, String> {
+) -> Result<bool, S7forgeError> {
     let (tx, mut rx) = mpsc::channel(32);
 
     let steam_client_clone = steam_client.clone();
@@ -51,18 +93,18 @@ async fn subscribe_single_item(
         });
 
         let start_time = std::time::Instant::now();
-        let timeout_duration = std::time::Duration::from_secs(30);
+        let timeout_duration = settings::get().steam_timeout;
 
         loop {
             let _ = tx.blocking_send(());
             if let Ok(result) = rx_inner.try_recv() {
                 return result
                     .map(|_| true)
-                    .map_err(|e| format!("Steam API error: {:?}", e));
+                    .map_err(|e| S7forgeError::item_steam_api(item_id, e));
             }
 
             if start_time.elapsed() > timeout_duration {
-                return Err("Operation timed out waiting for Steam response".to_string());
+                return Err(S7forgeError::Timeout("Operation"));
             }
 
             std::thread::sleep(std::time::Duration::from_millis(10));
@@ -78,7 +120,7 @@ async fn subscribe_single_item(
                 steam_manager::run_callbacks(steam_game_id)?;
             }
             task_result = &mut sub_task => {
-                result = Some(task_result.map_err(|e| format!("Task join error: {:?}", e))?);
+                result = Some(task_result.map_err(S7forgeError::Join)?);
                 break;
             }
         }
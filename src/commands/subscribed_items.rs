@@ -2,9 +2,12 @@ use steamworks::PublishedFileId;
 use tokio::task;
 
 use crate::commands::workshop_items::{EnhancedWorkshopItem, workshop_items};
+use crate::core::error::S7forgeError;
 use crate::core::steam_manager;
 
-pub async fn subscribed_items(steam_game_id: u32) -> Result<Vec<EnhancedWorkshopItem>, String> {
+pub async fn subscribed_items(
+    steam_game_id: u32,
+) -> Result<Vec<EnhancedWorkshopItem>, S7forgeError> {
     let steam_client = steam_manager::initialize_client(steam_game_id).await?;
 
     let subscribed_items: Vec<PublishedFileId> = task::spawn_blocking({
@@ -12,7 +15,7 @@ pub async fn subscribed_items(steam_game_id: u32) -> Result<Vec<EnhancedWorkshop
         move || steam_client.ugc().subscribed_items()
     })
     .await
-    .map_err(|e| format!("Failed to fetch subscribed items: {:?}", e))?;
+    .map_err(S7forgeError::Join)?;
 
     let item_ids: Vec<u64> = subscribed_items.iter().map(|id| id.0).collect();
     if item_ids.is_empty() {
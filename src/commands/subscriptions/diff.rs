@@ -0,0 +1,31 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use super::{SubscriptionDiff, subscribed_item_ids};
+use crate::commands::collection_items::collection_items;
+use crate::core::error::S7forgeError;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CollectionDiff {
+    pub collection_id: u64,
+    #[serde(flatten)]
+    pub diff: SubscriptionDiff,
+}
+
+pub async fn subscriptions_diff(
+    steam_game_id: u32,
+    collection_id: u64,
+) -> Result<CollectionDiff, S7forgeError> {
+    let collection_ids: Vec<u64> = collection_items(steam_game_id, collection_id)
+        .await?
+        .items
+        .iter()
+        .map(|item| item.workshop_item.published_file_id)
+        .collect();
+    let subscribed = subscribed_item_ids(steam_game_id).await?;
+
+    Ok(CollectionDiff {
+        collection_id,
+        diff: SubscriptionDiff::between(&collection_ids, &subscribed),
+    })
+}
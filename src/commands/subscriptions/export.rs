@@ -0,0 +1,111 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use steamworks::PublishedFileId;
+use tokio::task;
+
+use super::{SNAPSHOT_VERSION, SnapshotItem, SubscriptionSnapshot, subscribed_item_ids};
+use crate::commands::workshop_items::workshop_items;
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ExportResult {
+    pub path: String,
+    pub version: u32,
+    pub item_count: usize,
+}
+
+pub async fn snapshot_subscriptions(
+    steam_game_id: u32,
+) -> Result<SubscriptionSnapshot, S7forgeError> {
+    let item_ids = subscribed_item_ids(steam_game_id).await?;
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let install_times: Vec<Option<u64>> = task::spawn_blocking({
+        let item_ids = item_ids.clone();
+        move || {
+            let ugc = steam_client.ugc();
+            item_ids
+                .iter()
+                .map(|&id| {
+                    ugc.item_install_info(PublishedFileId(id))
+                        .map(|info| (info.timestamp as u64).saturating_mul(1000))
+                })
+                .collect()
+        }
+    })
+    .await
+    .map_err(S7forgeError::Join)?;
+
+    let details = workshop_items(steam_game_id, item_ids.clone()).await?;
+
+    // Deleted or hidden items have no metadata but are still part of the subscription state
+    let items = item_ids
+        .into_iter()
+        .zip(install_times)
+        .map(|(item_id, installed_at)| {
+            let item = details
+                .iter()
+                .map(|enhanced| &enhanced.workshop_item)
+                .find(|item| item.published_file_id == item_id);
+
+            SnapshotItem {
+                item_id,
+                title: item.map(|item| item.title.clone()),
+                time_created: item.map(|item| item.time_created),
+                time_updated: item.map(|item| item.time_updated),
+                installed_at,
+                tags: item
+                    .map(|item| {
+                        item.tags
+                            .split(',')
+                            .map(|tag| tag.trim().to_string())
+                            .filter(|tag| !tag.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    let exported_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or(std::time::Duration::ZERO)
+        .as_millis() as u64;
+
+    Ok(SubscriptionSnapshot {
+        version: SNAPSHOT_VERSION,
+        app_id: steam_game_id,
+        exported_at,
+        items,
+    })
+}
+
+pub async fn export_subscriptions(
+    steam_game_id: u32,
+    output: String,
+) -> Result<ExportResult, S7forgeError> {
+    let snapshot = snapshot_subscriptions(steam_game_id).await?;
+
+    let path = Path::new(&output);
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)
+            .map_err(|e| S7forgeError::io(format!("Failed to create {}", parent.display()), e))?;
+    }
+
+    let serialized = serde_json::to_string_pretty(&snapshot)
+        .map_err(|e| S7forgeError::parse("Failed to serialize snapshot", e))?;
+    fs::write(path, serialized)
+        .map_err(|e| S7forgeError::io(format!("Failed to write snapshot {}", path.display()), e))?;
+
+    Ok(ExportResult {
+        path: output,
+        version: snapshot.version,
+        item_count: snapshot.items.len(),
+    })
+}
@@ -0,0 +1,57 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::path::Path;
+
+use super::{SubscriptionDiff, SubscriptionSnapshot, subscribed_item_ids};
+use crate::commands::subscribe::subscribe;
+use crate::commands::unsubscribe::unsubscribe;
+use crate::core::error::S7forgeError;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ImportResult {
+    pub added: Vec<u64>,
+    pub removed: Vec<u64>,
+    pub unchanged: Vec<u64>,
+}
+
+pub async fn import_subscriptions(
+    steam_game_id: u32,
+    input: String,
+    prune: bool,
+) -> Result<ImportResult, S7forgeError> {
+    let snapshot = SubscriptionSnapshot::load(Path::new(&input))?;
+    if snapshot.app_id != steam_game_id {
+        return Err(S7forgeError::InvalidArgs(format!(
+            "Snapshot was exported for app {}, not {}",
+            snapshot.app_id, steam_game_id
+        )));
+    }
+
+    let subscribed = subscribed_item_ids(steam_game_id).await?;
+    let diff = SubscriptionDiff::between(&snapshot.item_ids(), &subscribed);
+
+    // Blacklisted items are skipped by subscribe and must not be reported as added
+    let added: Vec<u64> = if diff.missing.is_empty() {
+        Vec::new()
+    } else {
+        subscribe(steam_game_id, diff.missing.clone(), false)
+            .await?
+            .into_iter()
+            .filter(|result| result.success)
+            .map(|result| result.item_id)
+            .collect()
+    };
+
+    let removed = if prune && !diff.extra.is_empty() {
+        unsubscribe(steam_game_id, diff.extra.clone()).await?;
+        diff.extra
+    } else {
+        Vec::new()
+    };
+
+    Ok(ImportResult {
+        added,
+        removed,
+        unchanged: diff.unchanged,
+    })
+}
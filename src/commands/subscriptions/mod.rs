@@ -0,0 +1,107 @@
+pub mod diff;
+pub mod export;
+pub mod import;
+pub mod sync;
+
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use steamworks::PublishedFileId;
+use tokio::task;
+
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+
+/// Bumped whenever the snapshot layout changes incompatibly.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscriptionSnapshot {
+    pub version: u32,
+    pub app_id: u32,
+    pub exported_at: u64,
+    pub items: Vec<SnapshotItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotItem {
+    pub item_id: u64,
+    pub title: Option<String>,
+    pub time_created: Option<u64>,
+    pub time_updated: Option<u64>,
+    pub installed_at: Option<u64>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl SubscriptionSnapshot {
+    pub fn load(path: &Path) -> Result<Self, S7forgeError> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            S7forgeError::io(format!("Failed to read snapshot {}", path.display()), e)
+        })?;
+        let snapshot: Self = serde_json::from_str(&content)
+            .map_err(|e| S7forgeError::parse(format!("Invalid snapshot {}", path.display()), e))?;
+
+        if snapshot.version > SNAPSHOT_VERSION {
+            return Err(format!(
+                "Snapshot version {} is newer than supported version {}",
+                snapshot.version, SNAPSHOT_VERSION
+            )
+            .into());
+        }
+
+        Ok(snapshot)
+    }
+
+    pub fn item_ids(&self) -> Vec<u64> {
+        self.items.iter().map(|item| item.item_id).collect()
+    }
+}
+
+pub async fn subscribed_item_ids(steam_game_id: u32) -> Result<Vec<u64>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let subscribed_items: Vec<PublishedFileId> =
+        task::spawn_blocking(move || steam_client.ugc().subscribed_items())
+            .await
+            .map_err(S7forgeError::Join)?;
+
+    Ok(subscribed_items.iter().map(|id| id.0).collect())
+}
+
+#[derive(Debug, Default, Serialize, JsonSchema)]
+pub struct SubscriptionDiff {
+    /// In the target list but not subscribed
+    pub missing: Vec<u64>,
+    /// Subscribed but not in the target list
+    pub extra: Vec<u64>,
+    pub unchanged: Vec<u64>,
+}
+
+impl SubscriptionDiff {
+    /// Compares `target` against the live subscriptions, keeping the order of each input.
+    pub fn between(target: &[u64], subscribed: &[u64]) -> Self {
+        let target_set: FxHashSet<u64> = target.iter().copied().collect();
+        let subscribed_set: FxHashSet<u64> = subscribed.iter().copied().collect();
+
+        Self {
+            missing: target
+                .iter()
+                .copied()
+                .filter(|id| !subscribed_set.contains(id))
+                .collect(),
+            extra: subscribed
+                .iter()
+                .copied()
+                .filter(|id| !target_set.contains(id))
+                .collect(),
+            unchanged: target
+                .iter()
+                .copied()
+                .filter(|id| subscribed_set.contains(id))
+                .collect(),
+        }
+    }
+}
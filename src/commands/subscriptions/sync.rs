@@ -0,0 +1,104 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::path::Path;
+
+use super::export::snapshot_subscriptions;
+use super::{SubscriptionDiff, SubscriptionSnapshot};
+use crate::commands::blacklist::load_blacklist;
+use crate::commands::subscribe::subscribe;
+use crate::commands::unsubscribe::unsubscribe;
+use crate::commands::workshop_items::workshop_items;
+use crate::core::error::S7forgeError;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SyncConflict {
+    pub item_id: u64,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SyncResult {
+    pub dry_run: bool,
+    pub subscribed: Vec<u64>,
+    pub unsubscribed: Vec<u64>,
+    pub unchanged: Vec<u64>,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+pub async fn sync(
+    steam_game_id: u32,
+    input: String,
+    dry_run: bool,
+) -> Result<SyncResult, S7forgeError> {
+    let snapshot = SubscriptionSnapshot::load(Path::new(&input))?;
+    if snapshot.app_id != steam_game_id {
+        return Err(S7forgeError::InvalidArgs(format!(
+            "Snapshot was exported for app {}, not {}",
+            snapshot.app_id, steam_game_id
+        )));
+    }
+
+    let live = snapshot_subscriptions(steam_game_id).await?;
+    let diff = SubscriptionDiff::between(&snapshot.item_ids(), &live.item_ids());
+    let mut conflicts = Vec::new();
+
+    // Items deleted from the Workshop since the snapshot can't be subscribed to anymore
+    let available: Vec<u64> = workshop_items(steam_game_id, diff.missing.clone())
+        .await?
+        .iter()
+        .map(|item| item.workshop_item.published_file_id)
+        .collect();
+    let blacklist = load_blacklist(steam_game_id)?;
+    let mut to_subscribe = Vec::new();
+    for id in diff.missing {
+        if blacklist.contains_key(&id) {
+            conflicts.push(SyncConflict {
+                item_id: id,
+                reason: "Item is blacklisted".to_string(),
+            });
+        } else if available.contains(&id) {
+            to_subscribe.push(id);
+        } else {
+            conflicts.push(SyncConflict {
+                item_id: id,
+                reason: "No longer available on the Workshop".to_string(),
+            });
+        }
+    }
+
+    // Anything installed here after the snapshot was taken is a local addition the
+    // snapshot can't know about, so it's reported instead of silently removed
+    let mut to_unsubscribe = Vec::new();
+    for id in diff.extra {
+        let installed_at = live
+            .items
+            .iter()
+            .find(|item| item.item_id == id)
+            .and_then(|item| item.installed_at);
+        if installed_at.is_some_and(|installed_at| installed_at > snapshot.exported_at) {
+            conflicts.push(SyncConflict {
+                item_id: id,
+                reason: "Subscribed on this machine after the snapshot was exported".to_string(),
+            });
+        } else {
+            to_unsubscribe.push(id);
+        }
+    }
+
+    if !dry_run {
+        if !to_subscribe.is_empty() {
+            subscribe(steam_game_id, to_subscribe.clone(), false).await?;
+        }
+        if !to_unsubscribe.is_empty() {
+            unsubscribe(steam_game_id, to_unsubscribe.clone()).await?;
+        }
+    }
+
+    Ok(SyncResult {
+        dry_run,
+        subscribed: to_subscribe,
+        unsubscribed: to_unsubscribe,
+        unchanged: diff.unchanged,
+        conflicts,
+    })
+}
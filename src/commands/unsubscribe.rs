@@ -1,11 +1,14 @@
 use futures_util::FutureExt;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use steamworks::PublishedFileId;
 use tokio::sync::mpsc;
 
+use crate::core::error::S7forgeError;
+use crate::core::settings;
 use crate::core::steam_manager;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, JsonSchema, Deserialize)]
 pub struct UnsubscribeResult {
     pub item_id: u64,
     pub success: bool,
@@ -14,21 +17,13 @@ pub struct UnsubscribeResult {
 pub async fn unsubscribe(
     steam_game_id: u32,
     item_ids: Vec<u64>,
-) -> Result<Vec<UnsubscribeResult>, String> {
+) -> Result<Vec<UnsubscribeResult>, S7forgeError> {
     let steam_client = steam_manager::initialize_client(steam_game_id).await?;
     let mut results = Vec::new();
 
     for item_id in item_ids {
-        let result = unsubscribe_single_item(&steam_client, steam_game_id, item_id).await;
-        match result {
-            Ok(success) => results.push(UnsubscribeResult { item_id, success }),
-            Err(error) => {
-                return Err(format!(
-                    "Failed to unsubscribe from item {}: {}",
-                    item_id, error
-                ));
-            }
-        }
+        let success = unsubscribe_single_item(&steam_client, steam_game_id, item_id).await?;
+        results.push(UnsubscribeResult { item_id, success });
     }
 
     Ok(results)
@@ -38,7 +33,7 @@ async fn unsubscribe_single_item(
     steam_client: &steamworks::Client,
     steam_game_id: u32,
     item_id: u64,
-) -> Result<bool, String> {
+) -> Result<bool, S7forgeError> {
     let (tx, mut rx) = mpsc::channel(32);
 
     let steam_client_clone = steam_client.clone();
@@ -51,18 +46,18 @@ async fn unsubscribe_single_item(
         });
 
         let start_time = std::time::Instant::now();
-        let timeout_duration = std::time::Duration::from_secs(30);
+        let timeout_duration = settings::get().steam_timeout;
 
         loop {
             let _ = tx.blocking_send(());
             if let Ok(result) = rx_inner.try_recv() {
                 return result
                     .map(|_| true)
-                    .map_err(|e| format!("Steam API error: {:?}", e));
+                    .map_err(|e| S7forgeError::item_steam_api(item_id, e));
             }
 
             if start_time.elapsed() > timeout_duration {
-                return Err("Operation timed out waiting for Steam response".to_string());
+                return Err(S7forgeError::Timeout("Operation"));
             }
 
             std::thread::sleep(std::time::Duration::from_millis(10));
@@ -78,7 +73,7 @@ async fn unsubscribe_single_item(
                 steam_manager::run_callbacks(steam_game_id)?;
             }
             task_result = &mut unsub_task => {
-                result = Some(task_result.map_err(|e| format!("Task join error: {:?}", e))?);
+                result = Some(task_result.map_err(S7forgeError::Join)?);
                 break;
             }
         }
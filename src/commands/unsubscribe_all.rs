@@ -0,0 +1,127 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::PublishedFileId;
+use tokio::task;
+
+use crate::commands::unsubscribe::{UnsubscribeResult, unsubscribe};
+use crate::commands::workshop_items::workshop_items;
+use crate::core::error::S7forgeError;
+use crate::core::steam_manager;
+
+#[derive(Debug, Default)]
+pub struct SubscriptionFilter {
+    pub tags: Vec<String>,
+    pub creator: Option<u64>,
+    pub title_contains: Option<String>,
+}
+
+impl SubscriptionFilter {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.creator.is_none() && self.title_contains.is_none()
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct MatchedItem {
+    pub item_id: u64,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct UnsubscribeAllResult {
+    pub dry_run: bool,
+    pub matched: Vec<MatchedItem>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub results: Vec<UnsubscribeResult>,
+}
+
+pub async fn unsubscribe_all(
+    steam_game_id: u32,
+    filter: SubscriptionFilter,
+    dry_run: bool,
+) -> Result<UnsubscribeAllResult, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let subscribed_ids: Vec<u64> = task::spawn_blocking({
+        let steam_client = steam_client.clone();
+        move || steam_client.ugc().subscribed_items()
+    })
+    .await
+    .map_err(S7forgeError::Join)?
+    .iter()
+    .map(|id: &PublishedFileId| id.0)
+    .collect();
+
+    let matched = match_subscriptions(steam_game_id, subscribed_ids, &filter).await?;
+
+    let results = if dry_run || matched.is_empty() {
+        Vec::new()
+    } else {
+        unsubscribe(
+            steam_game_id,
+            matched.iter().map(|item| item.item_id).collect(),
+        )
+        .await?
+    };
+
+    Ok(UnsubscribeAllResult {
+        dry_run,
+        matched,
+        results,
+    })
+}
+
+// Without filters every subscription matches, including deleted items that have no metadata
+async fn match_subscriptions(
+    steam_game_id: u32,
+    subscribed_ids: Vec<u64>,
+    filter: &SubscriptionFilter,
+) -> Result<Vec<MatchedItem>, S7forgeError> {
+    let items = workshop_items(steam_game_id, subscribed_ids.clone()).await?;
+
+    if filter.is_empty() {
+        return Ok(subscribed_ids
+            .into_iter()
+            .map(|item_id| MatchedItem {
+                item_id,
+                title: items
+                    .iter()
+                    .find(|item| item.workshop_item.published_file_id == item_id)
+                    .map(|item| item.workshop_item.title.clone()),
+            })
+            .collect());
+    }
+
+    let required_tags: Vec<String> = filter.tags.iter().map(|tag| tag.to_lowercase()).collect();
+    let title_contains = filter
+        .title_contains
+        .as_ref()
+        .map(|text| text.to_lowercase());
+
+    Ok(items
+        .into_iter()
+        .map(|item| item.workshop_item)
+        .filter(|item| {
+            filter
+                .creator
+                .is_none_or(|creator| item.owner.steam_id64 == creator)
+        })
+        .filter(|item| {
+            title_contains
+                .as_ref()
+                .is_none_or(|text| item.title.to_lowercase().contains(text))
+        })
+        .filter(|item| {
+            let item_tags: Vec<String> = item
+                .tags
+                .split(',')
+                .map(|tag| tag.trim().to_lowercase())
+                .collect();
+            required_tags.iter().all(|tag| item_tags.contains(tag))
+        })
+        .map(|item| MatchedItem {
+            item_id: item.published_file_id,
+            title: Some(item.title),
+        })
+        .collect())
+}
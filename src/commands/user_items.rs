@@ -0,0 +1,135 @@
+use futures_util::FutureExt;
+use steamworks::{AppIDs, AppId, SteamId};
+use tokio::sync::mpsc;
+
+use crate::commands::workshop_items::EnhancedWorkshopItem;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::steam_manager;
+use crate::core::workshop_item::workshop::{
+    UGCType, UserListOrder, UserListType, WorkshopItem, WorkshopItemsResult,
+};
+use crate::utils::fetch_creator_names::fetch_creator_names;
+
+/// Runs a user-scoped UGC query (published, favorited, ...) for `steam_id` and returns one raw page.
+#[tracing::instrument(level = "debug", skip(steam_id, list_type, sort_order))]
+pub async fn query_user_page(
+    steam_game_id: u32,
+    steam_id: SteamId,
+    list_type: UserListType,
+    sort_order: UserListOrder,
+    page: u32,
+) -> Result<WorkshopItemsResult, S7forgeError> {
+    if page == 0 {
+        return Err(S7forgeError::InvalidArgs(
+            "Page number must be at least 1".into(),
+        ));
+    }
+
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let query_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel();
+        let app_ids = AppIDs::Both {
+            creator: AppId(steam_game_id),
+            consumer: AppId(steam_game_id),
+        };
+
+        let query_handle = ugc
+            .query_user(
+                steam_id.account_id(),
+                list_type.into(),
+                UGCType::Items.into(),
+                sort_order.into(),
+                app_ids,
+                page,
+            )
+            .map_err(|e| format!("Failed to create user query: {:?}", e))?;
+
+        query_handle
+            .set_return_metadata(true)
+            .set_return_children(true)
+            .fetch(move |fetch_result| {
+                let _ = tx_inner.send(
+                    fetch_result
+                        .map(|query_results| WorkshopItemsResult::from_query_results(query_results))
+                        .map_err(S7forgeError::steam_api),
+                );
+            });
+
+        let start_time = std::time::Instant::now();
+        let timeout_duration = settings::get().steam_timeout;
+
+        loop {
+            let _ = tx.blocking_send(());
+            if let Ok(result) = rx_inner.try_recv() {
+                return result;
+            }
+
+            if start_time.elapsed() > timeout_duration {
+                return Err(S7forgeError::Timeout("Operation"));
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut query_result = None;
+    let mut fused_task = query_task.fuse();
+
+    while query_result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut fused_task => {
+                query_result = Some(task_result.map_err(S7forgeError::Join)??);
+            }
+        }
+    }
+
+    Ok(query_result.unwrap())
+}
+
+/// Same as [`query_user_page`], with creator names resolved like `workshop-items`.
+pub async fn query_user_items(
+    steam_game_id: u32,
+    steam_id: SteamId,
+    list_type: UserListType,
+    sort_order: UserListOrder,
+    page: u32,
+) -> Result<Vec<EnhancedWorkshopItem>, S7forgeError> {
+    let workshop_items: Vec<WorkshopItem> =
+        query_user_page(steam_game_id, steam_id, list_type, sort_order, page)
+            .await?
+            .items
+            .into_iter()
+            .flatten()
+            .collect();
+
+    if workshop_items.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let creator_ids: Vec<SteamId> = workshop_items
+        .iter()
+        .map(|item| SteamId::from_raw(item.owner.steam_id64))
+        .collect();
+
+    let creator_names = fetch_creator_names(creator_ids, steam_game_id).await?;
+
+    Ok(workshop_items
+        .into_iter()
+        .map(|item| {
+            let owner = item.owner.clone();
+            let creator_name = creator_names
+                .get(&item.owner.steam_id64)
+                .cloned()
+                .unwrap_or_else(|| "[unknown]".to_string());
+            EnhancedWorkshopItem::new(item, owner.steam_id64.to_string(), creator_name)
+        })
+        .collect())
+}
@@ -0,0 +1,92 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::commands::installed_items::installed_items;
+use crate::core::error::S7forgeError;
+use crate::utils::dir_size::dir_size;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VerifyIssue {
+    /// The manifest lists the item but its folder is gone
+    Missing,
+    /// The folder exists but holds no data
+    Empty,
+    /// Bytes on disk differ from the size recorded in the manifest
+    SizeMismatch,
+    /// Steam knows of a newer Workshop version than the one installed
+    Outdated,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct VerifyResult {
+    pub item_id: u64,
+    pub ok: bool,
+    pub expected_size: u64,
+    pub actual_size: u64,
+    pub time_updated: u64,
+    pub issues: Vec<VerifyIssue>,
+}
+
+/// Checks installed items against the appworkshop manifest; with no `item_ids`, every
+/// installed item is verified.
+pub fn verify_items(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<VerifyResult>, S7forgeError> {
+    let installed = installed_items(steam_game_id)?;
+
+    let mut results = Vec::new();
+    for item in installed {
+        if !item_ids.is_empty() && !item_ids.contains(&item.item_id) {
+            continue;
+        }
+
+        let mut issues = Vec::new();
+        let actual_size = match &item.path {
+            Some(path) => {
+                let size = dir_size(Path::new(path))
+                    .map_err(|e| S7forgeError::io(format!("Failed to measure {}", path), e))?;
+                if size == 0 {
+                    issues.push(VerifyIssue::Empty);
+                } else if size != item.size_on_disk {
+                    issues.push(VerifyIssue::SizeMismatch);
+                }
+                size
+            }
+            None => {
+                issues.push(VerifyIssue::Missing);
+                0
+            }
+        };
+        if item.latest_time_updated.is_some() {
+            issues.push(VerifyIssue::Outdated);
+        }
+
+        results.push(VerifyResult {
+            item_id: item.item_id,
+            ok: issues.is_empty(),
+            expected_size: item.size_on_disk,
+            actual_size,
+            time_updated: item.time_updated,
+            issues,
+        });
+    }
+
+    // Requested items Steam has no record of at all
+    for item_id in item_ids {
+        if !results.iter().any(|result| result.item_id == item_id) {
+            results.push(VerifyResult {
+                item_id,
+                ok: false,
+                expected_size: 0,
+                actual_size: 0,
+                time_updated: 0,
+                issues: vec![VerifyIssue::Missing],
+            });
+        }
+    }
+
+    Ok(results)
+}
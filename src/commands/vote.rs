@@ -0,0 +1,161 @@
+use futures_util::FutureExt;
+use schemars::JsonSchema;
+use serde::Serialize;
+use steamworks::PublishedFileId;
+use tokio::sync::mpsc;
+
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::steam_manager;
+
+#[derive(Debug, Clone, Copy, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum VoteState {
+    Up,
+    Down,
+    None,
+    Skipped,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct VoteResult {
+    pub item_id: u64,
+    pub vote: VoteState,
+}
+
+pub async fn vote(
+    steam_game_id: u32,
+    item_id: u64,
+    vote_up: bool,
+) -> Result<VoteResult, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let steam_client_clone = steam_client.clone();
+    let vote_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client_clone.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel();
+
+        ugc.set_user_item_vote(PublishedFileId(item_id), vote_up, move |result| {
+            let _ = tx_inner.send(result);
+        });
+
+        let start_time = std::time::Instant::now();
+        let timeout_duration = settings::get().steam_timeout;
+
+        loop {
+            let _ = tx.blocking_send(());
+            if let Ok(result) = rx_inner.try_recv() {
+                return result.map_err(|e| S7forgeError::item_steam_api(item_id, e));
+            }
+
+            if start_time.elapsed() > timeout_duration {
+                return Err(S7forgeError::Timeout("Operation"));
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut vote_result = None;
+    let mut fused_task = vote_task.fuse();
+
+    while vote_result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut fused_task => {
+                vote_result = Some(task_result.map_err(S7forgeError::Join)??);
+            }
+        }
+    }
+
+    // Read the vote back so the output reflects what Steam actually recorded
+    let vote = get_item_vote(&steam_client, steam_game_id, item_id).await?;
+
+    Ok(VoteResult { item_id, vote })
+}
+
+/// Returns the logged-in account's vote per item, keyed by item ID in request order.
+pub async fn get_votes(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<serde_json::Map<String, serde_json::Value>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let mut votes = serde_json::Map::new();
+
+    for item_id in item_ids {
+        let vote = get_item_vote(&steam_client, steam_game_id, item_id).await?;
+        votes.insert(
+            item_id.to_string(),
+            serde_json::to_value(vote)
+                .map_err(|e| S7forgeError::parse("Failed to serialize vote", e))?,
+        );
+    }
+
+    Ok(votes)
+}
+
+pub async fn get_item_vote(
+    steam_client: &steamworks::Client,
+    steam_game_id: u32,
+    item_id: u64,
+) -> Result<VoteState, S7forgeError> {
+    let (tx, mut rx) = mpsc::channel(32);
+
+    let steam_client_clone = steam_client.clone();
+    let vote_task = tokio::task::spawn_blocking(move || {
+        let ugc = steam_client_clone.ugc();
+        let (tx_inner, rx_inner) = std::sync::mpsc::channel();
+
+        ugc.get_user_item_vote(PublishedFileId(item_id), move |result| {
+            let _ = tx_inner.send(result);
+        });
+
+        let start_time = std::time::Instant::now();
+        let timeout_duration = settings::get().steam_timeout;
+
+        loop {
+            let _ = tx.blocking_send(());
+            if let Ok(result) = rx_inner.try_recv() {
+                return result
+                    .map(|vote| {
+                        if vote.vote_up {
+                            VoteState::Up
+                        } else if vote.vote_down {
+                            VoteState::Down
+                        } else if vote.vote_skipped {
+                            VoteState::Skipped
+                        } else {
+                            VoteState::None
+                        }
+                    })
+                    .map_err(|e| S7forgeError::item_steam_api(item_id, e));
+            }
+
+            if start_time.elapsed() > timeout_duration {
+                return Err(S7forgeError::Timeout("Operation"));
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut vote_result = None;
+    let mut fused_task = vote_task.fuse();
+
+    while vote_result.is_none() {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                steam_manager::run_callbacks(steam_game_id)?;
+            }
+            task_result = &mut fused_task => {
+                vote_result = Some(task_result.map_err(S7forgeError::Join)??);
+            }
+        }
+    }
+
+    Ok(vote_result.unwrap())
+}
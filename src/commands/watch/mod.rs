@@ -0,0 +1,342 @@
+pub mod webhook;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use steamworks::SteamId;
+
+use crate::commands::download_workshop_items::{RetryPolicy, download_items};
+use crate::commands::subscriptions::subscribed_item_ids;
+use crate::commands::user_items::query_user_items;
+use crate::commands::workshop_items::fetch_workshop_items;
+use crate::core::error::S7forgeError;
+use crate::core::feed::{FeedEntry, item_url, rss};
+use crate::core::logging;
+use crate::core::output;
+use crate::core::workshop_item::workshop::{UserListOrder, UserListType};
+use webhook::WebhookFormat;
+
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const FEED_LENGTH: usize = 50;
+
+#[derive(Debug)]
+pub struct WatchOptions {
+    pub interval: Duration,
+    pub download: bool,
+    pub webhooks: Vec<String>,
+    pub webhook_format: WebhookFormat,
+    /// SteamID64s whose new publications are reported alongside subscription updates
+    pub follow_creators: Vec<u64>,
+    /// RSS file rewritten with the most recent events
+    pub feed: Option<PathBuf>,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_WATCH_INTERVAL,
+            download: false,
+            webhooks: Vec::new(),
+            webhook_format: WebhookFormat::default(),
+            follow_creators: Vec::new(),
+            feed: None,
+        }
+    }
+}
+
+/// One NDJSON line on stdout per event.
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WatchEvent {
+    /// First poll finished; later polls are compared against it
+    Ready { item_count: usize },
+    NewItem {
+        item_id: u64,
+        title: String,
+        time_updated: u64,
+    },
+    ItemUpdated {
+        item_id: u64,
+        title: String,
+        previous_time_updated: u64,
+        time_updated: u64,
+    },
+    CreatorPublished {
+        creator_id: u64,
+        creator_name: String,
+        item_id: u64,
+        title: String,
+    },
+    Downloaded {
+        item_id: u64,
+        success: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// A failed poll or webhook; watching continues with the next interval
+    Error { message: String },
+}
+
+fn print_event(event: &WatchEvent) {
+    let Ok(mut value) = serde_json::to_value(event) else {
+        return;
+    };
+    output::format_value(&mut value);
+    if let Ok(line) = serde_json::to_string(&value) {
+        tracing::info!(target: logging::FILE_ONLY, event = %line, "Watch event");
+        let mut stdout = std::io::stdout().lock();
+        let _ = writeln!(stdout, "{}", line);
+        let _ = stdout.flush();
+    }
+}
+
+/// Prints events and forwards the notable ones to webhooks and the feed file.
+struct Notifier<'a> {
+    options: &'a WatchOptions,
+    feed_entries: VecDeque<FeedEntry>,
+}
+
+impl Notifier<'_> {
+    async fn emit(&mut self, event: WatchEvent) {
+        print_event(&event);
+
+        if !self.options.webhooks.is_empty() {
+            if let Some(payload) = webhook::payload(&event, self.options.webhook_format) {
+                for message in webhook::send(self.options.webhooks.clone(), payload).await {
+                    print_event(&WatchEvent::Error { message });
+                }
+            }
+        }
+
+        if let Some(path) = &self.options.feed {
+            if let Some(entry) = feed_entry(&event) {
+                self.feed_entries.push_front(entry);
+                self.feed_entries.truncate(FEED_LENGTH);
+                if let Err(error) = write_feed(path, &mut self.feed_entries) {
+                    print_event(&WatchEvent::Error {
+                        message: error.to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn feed_entry(event: &WatchEvent) -> Option<FeedEntry> {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default();
+
+    let (item_id, title, published_ms) = match event {
+        WatchEvent::NewItem {
+            item_id,
+            title,
+            time_updated,
+        } => (*item_id, format!("New: {}", title), *time_updated),
+        WatchEvent::ItemUpdated {
+            item_id,
+            title,
+            time_updated,
+            ..
+        } => (*item_id, format!("Updated: {}", title), *time_updated),
+        WatchEvent::CreatorPublished {
+            creator_name,
+            item_id,
+            title,
+            ..
+        } => (
+            *item_id,
+            format!("{} published {}", creator_name, title),
+            now_ms,
+        ),
+        _ => return None,
+    };
+
+    Some(FeedEntry {
+        guid: format!("{}-{}", item_id, published_ms),
+        description: title.clone(),
+        title,
+        link: item_url(item_id),
+        published_ms,
+    })
+}
+
+fn write_feed(path: &Path, entries: &mut VecDeque<FeedEntry>) -> Result<(), S7forgeError> {
+    let content = rss(
+        "Workshop updates",
+        "Recently updated subscribed items and new items from followed creators",
+        entries.make_contiguous(),
+    );
+
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, content)
+        .and_then(|_| fs::rename(&temp_path, path))
+        .map_err(|e| S7forgeError::io(format!("Failed to write feed {}", path.display()), e))
+}
+
+/// Polls subscribed items every interval and emits an event for each new or updated item,
+/// optionally downloading them. Runs until the process is stopped.
+pub async fn watch(steam_game_id: u32, options: WatchOptions) -> Result<String, S7forgeError> {
+    let mut known: Option<FxHashMap<u64, u64>> = None;
+    let mut known_creator_items: FxHashMap<u64, FxHashSet<u64>> = FxHashMap::default();
+    let mut notifier = Notifier {
+        options: &options,
+        feed_entries: VecDeque::new(),
+    };
+
+    loop {
+        match poll(steam_game_id).await {
+            Ok(items) => {
+                let current: FxHashMap<u64, u64> = items
+                    .iter()
+                    .map(|(item_id, _, time_updated)| (*item_id, *time_updated))
+                    .collect();
+
+                match &known {
+                    None => {
+                        notifier
+                            .emit(WatchEvent::Ready {
+                                item_count: current.len(),
+                            })
+                            .await
+                    }
+                    Some(previous) => {
+                        let mut changed = Vec::new();
+                        for (item_id, title, time_updated) in items {
+                            let event = match previous.get(&item_id) {
+                                None => WatchEvent::NewItem {
+                                    item_id,
+                                    title,
+                                    time_updated,
+                                },
+                                Some(&previous_time_updated)
+                                    if previous_time_updated != time_updated =>
+                                {
+                                    WatchEvent::ItemUpdated {
+                                        item_id,
+                                        title,
+                                        previous_time_updated,
+                                        time_updated,
+                                    }
+                                }
+                                Some(_) => continue,
+                            };
+                            notifier.emit(event).await;
+                            changed.push(item_id);
+                        }
+
+                        if options.download && !changed.is_empty() {
+                            download_changed(steam_game_id, changed, &mut notifier).await;
+                        }
+                    }
+                }
+
+                known = Some(current);
+            }
+            Err(error) => {
+                notifier
+                    .emit(WatchEvent::Error {
+                        message: error.to_string(),
+                    })
+                    .await
+            }
+        }
+
+        for &creator_id in &options.follow_creators {
+            poll_creator(
+                steam_game_id,
+                creator_id,
+                &mut known_creator_items,
+                &mut notifier,
+            )
+            .await;
+        }
+
+        tokio::time::sleep(options.interval).await;
+    }
+}
+
+async fn poll(steam_game_id: u32) -> Result<Vec<(u64, String, u64)>, S7forgeError> {
+    let item_ids = subscribed_item_ids(steam_game_id).await?;
+    Ok(fetch_workshop_items(steam_game_id, item_ids)
+        .await?
+        .into_iter()
+        .map(|item| (item.published_file_id, item.title, item.time_updated))
+        .collect())
+}
+
+// The first poll of a creator only records their existing items
+async fn poll_creator(
+    steam_game_id: u32,
+    creator_id: u64,
+    known_creator_items: &mut FxHashMap<u64, FxHashSet<u64>>,
+    notifier: &mut Notifier<'_>,
+) {
+    let items = match query_user_items(
+        steam_game_id,
+        SteamId::from_raw(creator_id),
+        UserListType::Published,
+        UserListOrder::CreationOrderDesc,
+        1,
+    )
+    .await
+    {
+        Ok(items) => items,
+        Err(e) => {
+            let message = format!("Failed to query items of creator {}: {}", creator_id, e);
+            notifier.emit(WatchEvent::Error { message }).await;
+            return;
+        }
+    };
+
+    let Some(known_items) = known_creator_items.get_mut(&creator_id) else {
+        known_creator_items.insert(
+            creator_id,
+            items
+                .iter()
+                .map(|item| item.workshop_item.published_file_id)
+                .collect(),
+        );
+        return;
+    };
+
+    for item in items {
+        if known_items.insert(item.workshop_item.published_file_id) {
+            let event = WatchEvent::CreatorPublished {
+                creator_id,
+                creator_name: item.creator_name,
+                item_id: item.workshop_item.published_file_id,
+                title: item.workshop_item.title,
+            };
+            notifier.emit(event).await;
+        }
+    }
+}
+
+async fn download_changed(steam_game_id: u32, item_ids: Vec<u64>, notifier: &mut Notifier<'_>) {
+    match download_items(steam_game_id, item_ids, 4, RetryPolicy::default()).await {
+        Ok(results) => {
+            for result in results {
+                let event = WatchEvent::Downloaded {
+                    item_id: result.item_id,
+                    success: result.success,
+                    error: result.error,
+                };
+                notifier.emit(event).await;
+            }
+        }
+        Err(error) => {
+            notifier
+                .emit(WatchEvent::Error {
+                    message: error.to_string(),
+                })
+                .await
+        }
+    }
+}
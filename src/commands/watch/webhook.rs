@@ -0,0 +1,79 @@
+use serde_json::{Value, json};
+use std::time::Duration;
+
+use super::WatchEvent;
+use crate::core::feed::item_url;
+
+const REQUEST_TIMEOUT_SECS: u64 = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WebhookFormat {
+    /// The watch event itself, as printed on stdout
+    #[default]
+    Json,
+    /// `{"content": ...}` message accepted by Discord (and Slack-compatible) webhooks
+    Discord,
+}
+
+impl WebhookFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "discord" => Ok(Self::Discord),
+            _ => Err(format!(
+                "Invalid webhook format: {}. Use json or discord",
+                value
+            )),
+        }
+    }
+}
+
+/// Payload for events worth notifying about; `None` for the rest (ready, downloads, errors).
+pub fn payload(event: &WatchEvent, format: WebhookFormat) -> Option<Value> {
+    let message = match event {
+        WatchEvent::NewItem { item_id, title, .. } => {
+            format!("New subscribed item: **{}**\n{}", title, item_url(*item_id))
+        }
+        WatchEvent::ItemUpdated { item_id, title, .. } => {
+            format!("Item updated: **{}**\n{}", title, item_url(*item_id))
+        }
+        WatchEvent::CreatorPublished {
+            creator_name,
+            item_id,
+            title,
+            ..
+        } => format!(
+            "{} published **{}**\n{}",
+            creator_name,
+            title,
+            item_url(*item_id)
+        ),
+        _ => return None,
+    };
+
+    match format {
+        WebhookFormat::Json => serde_json::to_value(event).ok(),
+        WebhookFormat::Discord => Some(json!({ "content": message })),
+    }
+}
+
+/// POSTs the payload to every URL, returning one error message per failed webhook.
+pub async fn send(urls: Vec<String>, payload: Value) -> Vec<String> {
+    tokio::task::spawn_blocking(move || {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .build();
+
+        urls.iter()
+            .filter_map(|url| {
+                agent
+                    .post(url)
+                    .send_json(&payload)
+                    .err()
+                    .map(|e| format!("Webhook {} failed: {}", url, e))
+            })
+            .collect()
+    })
+    .await
+    .unwrap_or_else(|e| vec![format!("Task error: {:?}", e)])
+}
@@ -1,24 +1,102 @@
 use bincode::{Decode, Encode};
+use schemars::JsonSchema;
 use std::fs;
+use std::time::Duration;
 
 use futures_util::FutureExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Serialize;
 use steamworks::{PublishedFileId, SteamId};
 
+use crate::commands::cache_inspect::CachedEntry;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
 use crate::core::steam_manager;
+use crate::core::web_api;
 use crate::core::workshop_item::workshop::{WorkshopItem, WorkshopItemsResult};
+use crate::utils::cache_codec;
+use crate::utils::cache_counters;
+use crate::utils::cache_db::{self, CacheBackend, CacheRow};
+use crate::utils::cache_file;
 use crate::utils::fetch_creator_names::fetch_creator_names;
 use crate::utils::get_cache_dir::get_cache_dir;
 
-#[derive(Debug, Encode, Decode)]
+/// Lookups of an entry within this many seconds of its recorded access don't rewrite the cache.
+const ACCESS_RESOLUTION_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct WorkshopItemCache {
     pub items: FxHashMap<u64, WorkshopItem>,
     pub deleted_items: FxHashSet<u64>,
     pub timestamp: u64,
+    /// Last lookup of each item or deleted marker, in seconds; the least recently used entries
+    /// are evicted first when the cache is over `cache.max_items` or `cache.max_size`
+    pub last_access: FxHashMap<u64, u64>,
+}
+
+impl WorkshopItemCache {
+    pub fn touch(&mut self, item_ids: &[u64], now: u64) {
+        for &item_id in item_ids {
+            if self.items.contains_key(&item_id) || self.deleted_items.contains(&item_id) {
+                self.last_access.insert(item_id, now);
+            }
+        }
+    }
+
+    /// Drops least recently used entries until the cache fits the configured limits.
+    /// Returns how many were dropped.
+    fn enforce_limits(&mut self) -> usize {
+        let (items, deleted_items) = (&self.items, &self.deleted_items);
+        self.last_access
+            .retain(|item_id, _| items.contains_key(item_id) || deleted_items.contains(item_id));
+
+        let settings = settings::get();
+        let max_entries = settings.item_cache_max_entries.unwrap_or(usize::MAX);
+        let max_bytes = settings.item_cache_max_bytes.unwrap_or(u64::MAX);
+        let mut entries = self.items.len() + self.deleted_items.len();
+        if entries <= max_entries && settings.item_cache_max_bytes.is_none() {
+            return 0;
+        }
+
+        // Sizes before compression, only needed for a byte limit; a deleted marker is its ID
+        let size_of = |item: &WorkshopItem| {
+            if settings.item_cache_max_bytes.is_some() {
+                bincode::encode_to_vec(item, bincode::config::standard())
+                    .map_or(0, |encoded| encoded.len() as u64)
+            } else {
+                0
+            }
+        };
+        let mut candidates: Vec<(u64, u64, u64)> = self
+            .items
+            .iter()
+            .map(|(item_id, item)| (*item_id, size_of(item)))
+            .chain(self.deleted_items.iter().map(|item_id| (*item_id, 8)))
+            .map(|(item_id, size)| {
+                let last_access = self.last_access.get(&item_id).copied().unwrap_or_default();
+                (last_access, item_id, size)
+            })
+            .collect();
+        candidates.sort_unstable();
+
+        let mut bytes: u64 = candidates.iter().map(|(.., size)| size).sum();
+        let mut evicted = 0;
+        for (_, item_id, size) in candidates {
+            if entries <= max_entries && bytes <= max_bytes {
+                break;
+            }
+            self.items.remove(&item_id);
+            self.deleted_items.remove(&item_id);
+            self.last_access.remove(&item_id);
+            entries -= 1;
+            bytes = bytes.saturating_sub(size);
+            evicted += 1;
+        }
+        evicted
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Encode, Decode)]
+#[derive(Debug, Clone, Serialize, JsonSchema, Encode, Decode)]
 pub struct EnhancedWorkshopItem {
     #[serde(flatten)]
     pub workshop_item: WorkshopItem,
@@ -36,53 +114,283 @@ impl EnhancedWorkshopItem {
     }
 }
 
+/// Reads the item cache as-is, including entries past their 24 hour lifetime. With the SQLite
+/// backend every row is gathered into one cache dated like its oldest row.
+pub fn read_item_cache() -> Result<Option<WorkshopItemCache>, S7forgeError> {
+    if settings::get().cache_backend == CacheBackend::Sqlite {
+        let rows = cache_db::all(cache_db::ITEMS)?;
+        return Ok((!rows.is_empty()).then(|| cache_from_rows(rows)));
+    }
+
+    let cache_path = get_cache_dir()?.join("workshop_items_cache.bin");
+    let Ok(cache_content) = fs::read(&cache_path) else {
+        return Ok(None);
+    };
+    cache_codec::decode::<WorkshopItemCache>(&cache_content)
+        .map(Some)
+        .map_err(|e| S7forgeError::cache(format!("Failed to read {}", cache_path.display()), e))
+}
+
+// Rows whose value can't be decoded are left out, so they are fetched again
+fn cache_from_rows(rows: Vec<CacheRow>) -> WorkshopItemCache {
+    let mut cache = WorkshopItemCache {
+        items: FxHashMap::default(),
+        deleted_items: FxHashSet::default(),
+        timestamp: rows
+            .iter()
+            .map(|row| row.cached_at)
+            .min()
+            .unwrap_or_default(),
+        last_access: FxHashMap::default(),
+    };
+    for row in rows {
+        let Ok(item_id) = row.key.parse::<u64>() else {
+            continue;
+        };
+        match row.decode::<WorkshopItem>() {
+            Some(Ok(item)) => {
+                cache.items.insert(item_id, item);
+            }
+            Some(Err(_)) => continue,
+            None => {
+                cache.deleted_items.insert(item_id);
+            }
+        }
+        cache.last_access.insert(item_id, row.last_access);
+    }
+    cache
+}
+
+/// The SQLite rows of a cache, keeping its cache time and access times.
+fn item_rows(cache: &WorkshopItemCache) -> Result<Vec<CacheRow>, S7forgeError> {
+    let accessed = |item_id: &u64| {
+        cache
+            .last_access
+            .get(item_id)
+            .copied()
+            .unwrap_or(cache.timestamp)
+    };
+    let mut rows = Vec::with_capacity(cache.items.len() + cache.deleted_items.len());
+    for (item_id, item) in &cache.items {
+        rows.push(
+            CacheRow::encode(item_id.to_string(), item, cache.timestamp)?
+                .app(item.consumer_steam_game_id)
+                .accessed(accessed(item_id)),
+        );
+    }
+    for item_id in &cache.deleted_items {
+        rows.push(
+            CacheRow::marker(item_id.to_string(), cache.timestamp).accessed(accessed(item_id)),
+        );
+    }
+    Ok(rows)
+}
+
+/// Rows for the SQLite cache from a `workshop_items_cache.bin` file.
+pub fn legacy_rows(content: &[u8]) -> Result<Vec<CacheRow>, S7forgeError> {
+    item_rows(&cache_codec::decode::<WorkshopItemCache>(content)?)
+}
+
+/// Adds the entries of `cache` to the SQLite cache, dated `cache.timestamp` and looked up at
+/// `now`, then evicts least recently used rows beyond the size limits.
+pub fn store_item_rows(cache: &WorkshopItemCache, now: u64) -> Result<(), S7forgeError> {
+    let rows: Vec<CacheRow> = item_rows(cache)?
+        .into_iter()
+        .map(|row| row.accessed(now))
+        .collect();
+    cache_db::put(cache_db::ITEMS, &rows)?;
+    let settings = settings::get();
+    cache_db::enforce_limits(
+        cache_db::ITEMS,
+        settings.item_cache_max_entries,
+        settings.item_cache_max_bytes,
+    )?;
+    Ok(())
+}
+
+/// Drops cached entries, including "deleted" markers, for the given items, or for every item
+/// of `app_id` when no IDs are given. Returns how many entries were removed.
+pub fn evict_cached_items(app_id: Option<u32>, item_ids: &[u64]) -> Result<usize, S7forgeError> {
+    if settings::get().cache_backend == CacheBackend::Sqlite {
+        return Ok(match (app_id, item_ids.is_empty()) {
+            (_, false) => cache_db::remove(cache_db::ITEMS, &item_keys(item_ids))?,
+            (Some(app_id), true) => cache_db::remove_app(cache_db::ITEMS, app_id)?,
+            (None, true) => 0,
+        });
+    }
+
+    let Some(mut cache) = read_item_cache()? else {
+        return Ok(0);
+    };
+
+    let before = cache.items.len() + cache.deleted_items.len();
+    if item_ids.is_empty() {
+        cache.items.retain(|_, item| {
+            app_id.is_none_or(|app_id| item.consumer_steam_game_id != Some(app_id))
+        });
+    } else {
+        for item_id in item_ids {
+            cache.items.remove(item_id);
+            cache.deleted_items.remove(item_id);
+        }
+    }
+    let removed = before - (cache.items.len() + cache.deleted_items.len());
+
+    if removed > 0 {
+        write_item_cache(&mut cache)?;
+    }
+    Ok(removed)
+}
+
+/// Replaces the item cache file, evicting least recently used entries beyond the size limits.
+pub fn write_item_cache(cache: &mut WorkshopItemCache) -> Result<(), S7forgeError> {
+    cache.enforce_limits();
+    cache_file::write(&get_cache_dir()?.join("workshop_items_cache.bin"), &*cache)
+}
+
+fn item_keys(item_ids: &[u64]) -> Vec<String> {
+    item_ids.iter().map(|item_id| item_id.to_string()).collect()
+}
+
+/// Item cache entries; items whose lookup found them deleted are listed with a `null` value.
+pub fn cached_entries() -> Result<Vec<CachedEntry>, S7forgeError> {
+    let settings = settings::get();
+    let ttl = |app_id: Option<u32>| -> Duration {
+        app_id.map_or(settings.item_cache_ttl, |app_id| {
+            settings.item_cache_ttl_for(app_id)
+        })
+    };
+    if settings.cache_backend == CacheBackend::Sqlite {
+        return cache_db::all(cache_db::ITEMS).map(|rows| {
+            rows.into_iter()
+                .map(|row| {
+                    let value = match row.decode::<WorkshopItem>() {
+                        Some(Ok(item)) => serde_json::to_value(&item).unwrap_or_default(),
+                        _ => serde_json::Value::Null,
+                    };
+                    let mut entry = CachedEntry::new(cache_db::DB_FILE, row.key.clone(), value)
+                        .cached(row.cached_at, ttl(row.app_id));
+                    entry.app_id = row.app_id;
+                    entry.item_id = row.key.parse().ok();
+                    entry
+                })
+                .collect()
+        });
+    }
+
+    let Some(cache) = read_item_cache()? else {
+        return Ok(Vec::new());
+    };
+
+    let mut entries: Vec<CachedEntry> = cache
+        .items
+        .into_iter()
+        .map(|(item_id, item)| {
+            let app_id = item.consumer_steam_game_id;
+            let mut entry = CachedEntry::new(
+                "workshop_items_cache.bin",
+                item_id.to_string(),
+                serde_json::to_value(&item).unwrap_or_default(),
+            )
+            .cached(cache.timestamp, ttl(app_id))
+            .item(item_id);
+            entry.app_id = app_id;
+            entry
+        })
+        .collect();
+    entries.extend(cache.deleted_items.into_iter().map(|item_id| {
+        CachedEntry::new(
+            "workshop_items_cache.bin",
+            item_id.to_string(),
+            serde_json::Value::Null,
+        )
+        .cached(cache.timestamp, settings.item_cache_ttl)
+        .item(item_id)
+    }));
+    Ok(entries)
+}
+
 pub async fn workshop_items(
     steam_game_id: u32,
     item_ids: Vec<u64>,
-) -> Result<Vec<EnhancedWorkshopItem>, String> {
+) -> Result<Vec<EnhancedWorkshopItem>, S7forgeError> {
     if item_ids.is_empty() {
         return Ok(Vec::new());
     }
 
     let cache_dir = get_cache_dir()?;
     fs::create_dir_all(&cache_dir)
-        .map_err(|e| format!("Failed to create cache directory: {:?}", e))?;
-
-    let cache_path = cache_dir.join("workshop_items_cache.bin");
-    let bincode_config = bincode::config::standard();
-
-    let mut cached_items: FxHashMap<u64, WorkshopItem> = FxHashMap::default();
-    let mut deleted_items: FxHashSet<u64> = FxHashSet::default();
-    if cache_path.exists() {
-        if let Ok(cache_content) = fs::read(&cache_path) {
-            if let Ok((cache_entry, _)) =
-                bincode::decode_from_slice::<WorkshopItemCache, _>(&cache_content, bincode_config)
-            {
-                let now = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or(std::time::Duration::ZERO)
-                    .as_secs();
-                let cache_duration_secs = 24 * 60 * 60; // 24 hours
-
-                if now.saturating_sub(cache_entry.timestamp) < cache_duration_secs {
-                    cached_items = cache_entry.items;
-                    deleted_items = cache_entry.deleted_items;
-                }
-            }
-        }
-    }
+        .map_err(|e| S7forgeError::io("Failed to create cache directory", e))?;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or(std::time::Duration::ZERO)
+        .as_secs();
+
+    let sqlite = settings::get().cache_backend == CacheBackend::Sqlite;
+    let cache_duration_secs = settings::get().item_cache_ttl_for(steam_game_id).as_secs();
+    let fresh = |cached_at: u64| now.saturating_sub(cached_at) < cache_duration_secs;
+    let cached = if sqlite {
+        // Each row expires on its own, so only the requested ones are read
+        let rows = cache_db::get(cache_db::ITEMS, &item_keys(&item_ids))?;
+        Some(cache_from_rows(
+            rows.into_iter()
+                .filter(|row| fresh(row.cached_at))
+                .collect(),
+        ))
+    } else {
+        cache_file::read::<WorkshopItemCache>(&get_cache_dir()?.join("workshop_items_cache.bin"))
+            .filter(|cache| fresh(cache.timestamp))
+    };
+    let WorkshopItemCache {
+        items: mut cached_items,
+        mut deleted_items,
+        timestamp: cache_timestamp,
+        last_access,
+    } = cached.unwrap_or_else(|| WorkshopItemCache {
+        items: FxHashMap::default(),
+        deleted_items: FxHashSet::default(),
+        timestamp: now,
+        last_access: FxHashMap::default(),
+    });
 
+    // With --no-cache every item is fetched again; the rest of the cache is kept for writing
+    let no_cache = settings::get().no_cache;
     let ids_to_fetch: Vec<u64> = item_ids
         .iter()
-        .filter(|id| !cached_items.contains_key(id) && !deleted_items.contains(id))
+        .filter(|id| no_cache || (!cached_items.contains_key(id) && !deleted_items.contains(id)))
         .cloned()
         .collect();
+    cache_counters::hit(
+        "workshop_items_cache.bin",
+        (item_ids.len() - ids_to_fetch.len()) as u64,
+    );
+    cache_counters::miss("workshop_items_cache.bin", ids_to_fetch.len() as u64);
 
     if ids_to_fetch.is_empty() {
         let workshop_items: Vec<WorkshopItem> = item_ids
             .iter()
             .filter_map(|id| cached_items.get(id).cloned())
             .collect();
+
+        // Only record accesses that moved, so repeated lookups don't rewrite the file each time
+        let stale_access = item_ids.iter().any(|id| {
+            last_access
+                .get(id)
+                .is_none_or(|&accessed| now.saturating_sub(accessed) >= ACCESS_RESOLUTION_SECS)
+        });
+        if stale_access && sqlite {
+            let _ = cache_db::touch(cache_db::ITEMS, &item_keys(&item_ids), now);
+        } else if stale_access {
+            let mut cache = WorkshopItemCache {
+                items: cached_items,
+                deleted_items,
+                timestamp: cache_timestamp,
+                last_access,
+            };
+            cache.touch(&item_ids, now);
+            let _ = write_item_cache(&mut cache);
+        }
         let creator_ids: Vec<SteamId> = workshop_items
             .iter()
             .map(|item| SteamId::from_raw(item.owner.steam_id64))
@@ -103,10 +411,125 @@ pub async fn workshop_items(
             .collect());
     }
 
-    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
+    let ids_for_tracking = ids_to_fetch.clone(); // Keep for later to track missing items
+    let queried_items = match steam_manager::initialize_client_or_fallback(steam_game_id).await {
+        Some(steam_client) => {
+            query_items_from_client(steam_client, steam_game_id, ids_to_fetch).await?
+        }
+        None => query_items_from_web_api(ids_to_fetch).await?,
+    };
+
+    let fetched_items = queried_items
+        .into_iter()
+        .filter_map(|item| match item {
+            Some(it) if it.file_type == "Community" => Some(it),
+            _ => None,
+        })
+        .collect::<Vec<WorkshopItem>>();
+
+    // Track which IDs we fetched to cache negative results (deleted/missing items)
+    let fetched_ids: rustc_hash::FxHashSet<u64> =
+        fetched_items.iter().map(|i| i.published_file_id).collect();
+
+    for item in &fetched_items {
+        cached_items.insert(item.published_file_id, item.clone());
+        deleted_items.remove(&item.published_file_id);
+    }
+
+    // Mark deleted/missing items (they were queried but returned nothing)
+    for id in &ids_for_tracking {
+        if !fetched_ids.contains(id) {
+            deleted_items.insert(*id);
+        }
+    }
+    let final_items: Vec<WorkshopItem> = item_ids
+        .iter()
+        .filter_map(|id| cached_items.get(id).cloned())
+        .collect();
+
+    if sqlite {
+        // Only the fetched entries are written; the cached ones just record the lookup
+        let fetched = WorkshopItemCache {
+            items: fetched_items
+                .into_iter()
+                .map(|item| (item.published_file_id, item))
+                .collect(),
+            deleted_items: ids_for_tracking
+                .iter()
+                .filter(|id| !fetched_ids.contains(id))
+                .copied()
+                .collect(),
+            timestamp: now,
+            last_access: FxHashMap::default(),
+        };
+        let _ = store_item_rows(&fetched, now)
+            .and_then(|_| cache_db::touch(cache_db::ITEMS, &item_keys(&item_ids), now));
+    } else {
+        let mut cache_struct = WorkshopItemCache {
+            items: cached_items,
+            deleted_items,
+            timestamp: now,
+            last_access,
+        };
+        cache_struct.touch(&item_ids, now);
+        let _ = write_item_cache(&mut cache_struct);
+    }
+
+    let creator_ids: Vec<SteamId> = final_items
+        .iter()
+        .map(|item| SteamId::from_raw(item.owner.steam_id64))
+        .collect();
+
+    let creator_names = fetch_creator_names(creator_ids, steam_game_id).await?;
+
+    Ok(final_items
+        .into_iter()
+        .map(|item| {
+            let owner = item.owner.clone();
+            let creator_name = creator_names
+                .get(&item.owner.steam_id64)
+                .cloned()
+                .unwrap_or_else(|| "[unknown]".to_string());
+            EnhancedWorkshopItem::new(item, owner.steam_id64.to_string(), creator_name)
+        })
+        .collect())
+}
+
+/// Queries current metadata straight from Steam, bypassing the cache. For callers such as
+/// `watch` that must see updates as soon as they are published.
+pub async fn fetch_workshop_items(
+    steam_game_id: u32,
+    item_ids: Vec<u64>,
+) -> Result<Vec<WorkshopItem>, S7forgeError> {
+    let steam_client = steam_manager::initialize_client_or_fallback(steam_game_id).await;
+
+    let mut items = Vec::new();
+    // Steam returns at most 50 results per UGC query
+    for chunk in item_ids.chunks(50) {
+        let queried = match &steam_client {
+            Some(steam_client) => {
+                query_items_from_client(steam_client.clone(), steam_game_id, chunk.to_vec()).await?
+            }
+            None => query_items_from_web_api(chunk.to_vec()).await?,
+        };
+        items.extend(
+            queried
+                .into_iter()
+                .flatten()
+                .filter(|item| item.file_type == "Community"),
+        );
+    }
+
+    Ok(items)
+}
 
+#[tracing::instrument(level = "debug", skip_all, fields(items = ids_to_fetch.len()))]
+async fn query_items_from_client(
+    steam_client: steamworks::Client,
+    steam_game_id: u32,
+    ids_to_fetch: Vec<u64>,
+) -> Result<Vec<Option<WorkshopItem>>, S7forgeError> {
     let (tx, mut rx) = tokio::sync::mpsc::channel(32);
-    let ids_for_tracking = ids_to_fetch.clone(); // Keep for later to track missing items
     let items_task = tokio::task::spawn_blocking(move || {
         let ugc = steam_client.ugc();
         let (tx_inner, rx_inner) = std::sync::mpsc::channel();
@@ -117,15 +540,16 @@ pub async fn workshop_items(
         query_handle
             .include_children(true)
             .fetch(move |fetch_result| {
+                tracing::debug!(ok = fetch_result.is_ok(), "Item query callback");
                 let _ = tx_inner.send(
                     fetch_result
                         .map(|query_results| WorkshopItemsResult::from_query_results(query_results))
-                        .map_err(|e| format!("Steam API error: {:?}", e)),
+                        .map_err(S7forgeError::steam_api),
                 );
             });
 
         let start_time = std::time::Instant::now();
-        let timeout_duration = std::time::Duration::from_secs(30);
+        let timeout_duration = settings::get().steam_timeout;
 
         loop {
             let _ = tx.blocking_send(());
@@ -134,7 +558,7 @@ pub async fn workshop_items(
             }
 
             if start_time.elapsed() > timeout_duration {
-                return Err("Operation timed out waiting for Steam response".to_string());
+                return Err(S7forgeError::Timeout("Operation"));
             }
 
             std::thread::sleep(std::time::Duration::from_millis(10));
@@ -151,72 +575,23 @@ pub async fn workshop_items(
             }
             task_result = &mut fused_task => {
                 items_result = Some(
-                    task_result.map_err(|e| format!("Task error: {:?}", e))?
+                    task_result.map_err(S7forgeError::Join)?
                 );
                 break;
             }
         }
     }
 
-    let items_result = items_result.unwrap()?;
-
-    let fetched_items = items_result
-        .items
-        .into_iter()
-        .filter_map(|item| match item {
-            Some(it) if it.file_type == "Community" => Some(it),
-            _ => None,
-        })
-        .collect::<Vec<WorkshopItem>>();
-
-    // Track which IDs we fetched to cache negative results (deleted/missing items)
-    let fetched_ids: rustc_hash::FxHashSet<u64> =
-        fetched_items.iter().map(|i| i.published_file_id).collect();
-
-    for item in &fetched_items {
-        cached_items.insert(item.published_file_id, item.clone());
-    }
-
-    // Mark deleted/missing items (they were queried but returned nothing)
-    for id in &ids_for_tracking {
-        if !fetched_ids.contains(id) {
-            deleted_items.insert(*id);
-        }
-    }
-    let timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or(std::time::Duration::ZERO)
-        .as_secs();
-    let cache_struct = WorkshopItemCache {
-        items: cached_items.clone(),
-        deleted_items: deleted_items.clone(),
-        timestamp,
-    };
-    let serialized_cache = bincode::encode_to_vec(&cache_struct, bincode_config)
-        .map_err(|e| format!("Failed to serialize cache: {:?}", e))?;
-    let _ = fs::write(&cache_path, serialized_cache);
-
-    let final_items: Vec<WorkshopItem> = item_ids
-        .iter()
-        .filter_map(|id| cached_items.get(id).cloned())
-        .collect();
-
-    let creator_ids: Vec<SteamId> = final_items
-        .iter()
-        .map(|item| SteamId::from_raw(item.owner.steam_id64))
-        .collect();
+    Ok(items_result.unwrap()?.items)
+}
 
-    let creator_names = fetch_creator_names(creator_ids, steam_game_id).await?;
+#[tracing::instrument(level = "debug", skip_all, fields(items = ids_to_fetch.len()))]
+async fn query_items_from_web_api(
+    ids_to_fetch: Vec<u64>,
+) -> Result<Vec<Option<WorkshopItem>>, S7forgeError> {
+    let items = tokio::task::spawn_blocking(move || web_api::published_file_details(&ids_to_fetch))
+        .await
+        .map_err(S7forgeError::Join)??;
 
-    Ok(final_items
-        .into_iter()
-        .map(|item| {
-            let owner = item.owner.clone();
-            let creator_name = creator_names
-                .get(&item.owner.steam_id64)
-                .cloned()
-                .unwrap_or_else(|| "[unknown]".to_string());
-            EnhancedWorkshopItem::new(item, owner.steam_id64.to_string(), creator_name)
-        })
-        .collect())
+    Ok(items.into_iter().map(Some).collect())
 }
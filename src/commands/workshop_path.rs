@@ -4,42 +4,44 @@ use std::fs;
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::commands::cache_inspect::CachedEntry;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
 use crate::core::steam_install_paths::steam_install_paths;
+use crate::utils::cache_codec;
+use crate::utils::cache_counters;
+use crate::utils::cache_file;
 use crate::utils::extract_quoted_strings::extract_quoted_strings;
 use crate::utils::get_cache_dir::get_cache_dir;
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Debug, Clone, Encode, Decode)]
 struct WorkshopPathCache {
     paths: FxHashMap<u32, Option<String>>,
     timestamp: u64,
 }
 
 pub fn workshop_path(app_id: u32) -> Option<String> {
-    // Try to load from cache
-    if let Ok(cache_dir) = get_cache_dir() {
+    // Try to load from cache; --no-cache skips this but still refreshes the cache below
+    if let Some(cache_dir) = get_cache_dir().ok().filter(|_| !settings::get().no_cache) {
         let cache_path = cache_dir.join("workshop_path_cache.bin");
-        if cache_path.exists() {
-            if let Ok(cache_content) = fs::read(&cache_path) {
-                let config = bincode::config::standard();
-                if let Ok((cache, _)) =
-                    bincode::decode_from_slice::<WorkshopPathCache, _>(&cache_content, config)
-                {
-                    let now = SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs();
-                    let cache_duration_secs = 60 * 60; // 1 hour
-
-                    if now.saturating_sub(cache.timestamp) < cache_duration_secs {
-                        if let Some(cached_result) = cache.paths.get(&app_id) {
-                            return cached_result.clone();
-                        }
-                    }
+        if let Some(cache) = cache_file::read::<WorkshopPathCache>(&cache_path) {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let cache_duration_secs = settings::get().path_cache_ttl_for(app_id).as_secs();
+
+            if now.saturating_sub(cache.timestamp) < cache_duration_secs {
+                if let Some(cached_result) = cache.paths.get(&app_id) {
+                    cache_counters::hit("workshop_path_cache.bin", 1);
+                    return cached_result.clone();
                 }
             }
         }
     }
 
+    cache_counters::miss("workshop_path_cache.bin", 1);
+
     // Compute the result
     let result = 'search: {
         match steam_install_paths() {
@@ -95,28 +97,7 @@ pub fn workshop_path(app_id: u32) -> Option<String> {
         let _ = fs::create_dir_all(&cache_dir);
         let cache_path = cache_dir.join("workshop_path_cache.bin");
 
-        let mut cache = if cache_path.exists() {
-            if let Ok(cache_content) = fs::read(&cache_path) {
-                let config = bincode::config::standard();
-                bincode::decode_from_slice::<WorkshopPathCache, _>(&cache_content, config)
-                    .map(|(c, _)| c)
-                    .unwrap_or_else(|_| WorkshopPathCache {
-                        paths: FxHashMap::default(),
-                        timestamp: SystemTime::now()
-                            .duration_since(UNIX_EPOCH)
-                            .unwrap_or_default()
-                            .as_secs(),
-                    })
-            } else {
-                WorkshopPathCache {
-                    paths: FxHashMap::default(),
-                    timestamp: SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs(),
-                }
-            }
-        } else {
+        let mut cache = cache_file::read::<WorkshopPathCache>(&cache_path).unwrap_or_else(|| {
             WorkshopPathCache {
                 paths: FxHashMap::default(),
                 timestamp: SystemTime::now()
@@ -124,7 +105,7 @@ pub fn workshop_path(app_id: u32) -> Option<String> {
                     .unwrap_or_default()
                     .as_secs(),
             }
-        };
+        });
 
         cache.paths.insert(app_id, result.clone());
         cache.timestamp = SystemTime::now()
@@ -132,11 +113,41 @@ pub fn workshop_path(app_id: u32) -> Option<String> {
             .unwrap_or_default()
             .as_secs();
 
-        let config = bincode::config::standard();
-        if let Ok(encoded) = bincode::encode_to_vec(&cache, config) {
-            let _ = fs::write(&cache_path, encoded);
-        }
+        let _ = cache_file::write(&cache_path, &cache);
     }
 
     result
 }
+
+/// Drops the cached workshop path of one app. Returns whether there was an entry.
+pub fn evict_cached_path(app_id: u32) -> Result<bool, S7forgeError> {
+    let cache_path = get_cache_dir()?.join("workshop_path_cache.bin");
+    let Some(mut cache) = cache_file::read::<WorkshopPathCache>(&cache_path) else {
+        return Ok(false);
+    };
+
+    if cache.paths.remove(&app_id).is_none() {
+        return Ok(false);
+    }
+    cache_file::write(&cache_path, &cache)?;
+    Ok(true)
+}
+
+pub fn cached_entries() -> Result<Vec<CachedEntry>, S7forgeError> {
+    let cache_path = get_cache_dir()?.join("workshop_path_cache.bin");
+    let Ok(cache_content) = fs::read(&cache_path) else {
+        return Ok(Vec::new());
+    };
+    let cache = cache_codec::decode::<WorkshopPathCache>(&cache_content)
+        .map_err(|e| S7forgeError::cache(format!("Failed to read {}", cache_path.display()), e))?;
+
+    Ok(cache
+        .paths
+        .into_iter()
+        .map(|(app_id, path)| {
+            CachedEntry::new("workshop_path_cache.bin", app_id.to_string(), path.into())
+                .cached(cache.timestamp, settings::get().path_cache_ttl_for(app_id))
+                .app(app_id)
+        })
+        .collect())
+}
@@ -0,0 +1,229 @@
+use std::path::Path;
+
+use crate::core::error::S7forgeError;
+
+#[derive(Debug, Clone)]
+pub enum AcfValue {
+    String(String),
+    Section(AcfSection),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AcfSection {
+    pub entries: Vec<(String, AcfValue)>,
+}
+
+impl AcfSection {
+    /// Keys are matched case-insensitively, like Steam does ("SizeOnDisk" vs "sizeondisk").
+    pub fn get(&self, key: &str) -> Option<&AcfValue> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value)
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        match self.get(key)? {
+            AcfValue::String(value) => Some(value),
+            AcfValue::Section(_) => None,
+        }
+    }
+
+    pub fn get_u64(&self, key: &str) -> Option<u64> {
+        self.get_str(key)?.trim().parse().ok()
+    }
+
+    pub fn get_section(&self, key: &str) -> Option<&AcfSection> {
+        match self.get(key)? {
+            AcfValue::Section(section) => Some(section),
+            AcfValue::String(_) => None,
+        }
+    }
+
+    pub fn get_section_mut(&mut self, key: &str) -> Option<&mut AcfSection> {
+        self.entries
+            .iter_mut()
+            .find(|(entry_key, _)| entry_key.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| match value {
+                AcfValue::Section(section) => Some(section),
+                AcfValue::String(_) => None,
+            })
+    }
+
+    /// Removes every entry named `key`, returning whether anything was removed.
+    pub fn remove(&mut self, key: &str) -> bool {
+        let before = self.entries.len();
+        self.entries
+            .retain(|(entry_key, _)| !entry_key.eq_ignore_ascii_case(key));
+        self.entries.len() != before
+    }
+
+    /// Child sections in file order, e.g. one per item under "WorkshopItemsInstalled".
+    pub fn sections(&self) -> impl Iterator<Item = (&str, &AcfSection)> {
+        self.entries.iter().filter_map(|(key, value)| match value {
+            AcfValue::Section(section) => Some((key.as_str(), section)),
+            AcfValue::String(_) => None,
+        })
+    }
+}
+
+/// Serializes a document back into the tab-indented layout Steam writes.
+pub fn to_string(root: &AcfSection) -> String {
+    let mut output = String::new();
+    write_section(&mut output, root, 0);
+    output
+}
+
+fn write_section(output: &mut String, section: &AcfSection, depth: usize) {
+    let indent = "\t".repeat(depth);
+    for (key, value) in &section.entries {
+        match value {
+            AcfValue::String(value) => {
+                output.push_str(&format!(
+                    "{}\"{}\"\t\t\"{}\"\n",
+                    indent,
+                    escape(key),
+                    escape(value)
+                ));
+            }
+            AcfValue::Section(child) => {
+                output.push_str(&format!("{}\"{}\"\n{}{{\n", indent, escape(key), indent));
+                write_section(output, child, depth + 1);
+                output.push_str(&format!("{}}}\n", indent));
+            }
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+enum Token {
+    Text(String),
+    Open,
+    Close,
+}
+
+/// Parses Valve's text KeyValues format (`.acf` manifests, `.vdf` files) read from `path`.
+/// The returned section holds the top-level key(s), e.g.
+/// `parse(path, &content)?.get_section("AppWorkshop")`.
+pub fn parse(path: &Path, content: &str) -> Result<AcfSection, S7forgeError> {
+    let tokens = tokenize(path, content)?;
+    let mut position = 0;
+    parse_section(path, &tokens, &mut position, true)
+}
+
+fn syntax_error(path: &Path, message: impl Into<String>) -> S7forgeError {
+    S7forgeError::Acf {
+        path: path.display().to_string(),
+        message: message.into(),
+    }
+}
+
+fn parse_section(
+    path: &Path,
+    tokens: &[Token],
+    position: &mut usize,
+    root: bool,
+) -> Result<AcfSection, S7forgeError> {
+    let mut section = AcfSection::default();
+
+    loop {
+        let key = match tokens.get(*position) {
+            None if root => return Ok(section),
+            None => return Err(syntax_error(path, "Unexpected end of file, missing '}'")),
+            Some(Token::Close) if !root => {
+                *position += 1;
+                return Ok(section);
+            }
+            Some(Token::Close) => return Err(syntax_error(path, "Unexpected '}'")),
+            Some(Token::Open) => return Err(syntax_error(path, "Unexpected '{' without a key")),
+            Some(Token::Text(key)) => key.clone(),
+        };
+        *position += 1;
+
+        let value = match tokens.get(*position) {
+            Some(Token::Text(value)) => {
+                *position += 1;
+                AcfValue::String(value.clone())
+            }
+            Some(Token::Open) => {
+                *position += 1;
+                AcfValue::Section(parse_section(path, tokens, position, false)?)
+            }
+            Some(Token::Close) | None => {
+                return Err(syntax_error(
+                    path,
+                    format!("Missing value for key \"{}\"", key),
+                ));
+            }
+        };
+
+        section.entries.push((key, value));
+    }
+}
+
+fn tokenize(path: &Path, content: &str) -> Result<Vec<Token>, S7forgeError> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' => tokens.push(Token::Open),
+            '}' => tokens.push(Token::Close),
+            '"' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('"') => text.push('"'),
+                            Some('\\') => text.push('\\'),
+                            Some('n') => text.push('\n'),
+                            Some('t') => text.push('\t'),
+                            Some('r') => text.push('\r'),
+                            Some(other) => {
+                                text.push('\\');
+                                text.push(other);
+                            }
+                            None => return Err(syntax_error(path, "Unterminated string")),
+                        },
+                        Some(other) => text.push(other),
+                        None => return Err(syntax_error(path, "Unterminated string")),
+                    }
+                }
+                tokens.push(Token::Text(text));
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            // Platform conditionals such as [$WIN32] apply to the previous entry; they are ignored
+            '[' => {
+                for next in chars.by_ref() {
+                    if next == ']' {
+                        break;
+                    }
+                }
+            }
+            ch if ch.is_whitespace() => {}
+            ch => {
+                let mut text = ch.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '"' | '{' | '}') {
+                        break;
+                    }
+                    text.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Text(text));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
@@ -1,6 +1,8 @@
 use std::sync::{Arc, Mutex};
 use steamworks::{Client, SingleClient};
 
+use crate::core::error::S7forgeError;
+
 #[derive(Default)]
 pub struct SteamState {
     client: Arc<Mutex<Option<(u32, Client)>>>,
@@ -33,7 +35,7 @@ impl SteamState {
         None
     }
 
-    pub fn run_callbacks(&self, steam_game_id: u32) -> Result<(), String> {
+    pub fn run_callbacks(&self, steam_game_id: u32) -> Result<(), S7forgeError> {
         let mut state = self.single_client.lock().unwrap();
         if let Some((current_steam_game_id, ref mut single_client)) = *state {
             if current_steam_game_id == steam_game_id {
@@ -41,7 +43,7 @@ impl SteamState {
                 return Ok(());
             }
         }
-        Err("Single client not found for given steam_game_id".to_string())
+        Err("Single client not found for given steam_game_id".into())
     }
 
     pub fn set_clients(&self, steam_game_id: u32, client: Client, single_client: SingleClient) {
@@ -0,0 +1,382 @@
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::core::error::S7forgeError;
+use crate::core::logging::LogRotation;
+use crate::core::output::{DateFormat, DescriptionFormat, OutputFormat, SizeFormat};
+use crate::core::settings::{AppSettings, Settings};
+use crate::utils::cache_db::CacheBackend;
+use crate::utils::parse_duration::parse_duration;
+use crate::utils::parse_size::parse_size;
+
+const CONFIG_FILE_NAME: &str = "s7forge.toml";
+
+/// Defaults read from `s7forge.toml` or the environment. Every key is optional; command-line
+/// flags win over environment variables, which win over the file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub app_id: Option<u32>,
+    pub web_api_key: Option<String>,
+    pub no_client: Option<bool>,
+    pub anonymous: Option<bool>,
+    pub progress_events: Option<bool>,
+    /// "json" (default) or "table"
+    pub output: Option<String>,
+    pub no_cache: Option<bool>,
+    pub dates: Option<String>,
+    pub sizes: Option<String>,
+    pub description_format: Option<String>,
+    pub envelope: Option<bool>,
+    /// 1 for debug output, 2 for trace
+    pub verbose: Option<u8>,
+    pub quiet: Option<bool>,
+    /// Start Steam when a command needs the client
+    pub auto_launch: Option<bool>,
+    /// Default for `--concurrency`
+    pub concurrency: Option<usize>,
+    pub cache: CacheConfig,
+    pub timeouts: TimeoutConfig,
+    pub log: LogConfig,
+    /// Per-game sections, `[apps.<app_id>]`
+    pub apps: FxHashMap<String, AppConfig>,
+}
+
+/// Cache location and lifetimes as durations, e.g. "12h"
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Replaces `<executable dir>/cache`
+    pub dir: Option<PathBuf>,
+    /// "files" (default) or "sqlite"
+    pub backend: Option<String>,
+    /// Workshop item details
+    pub items: Option<String>,
+    /// Workshop, library and app installation paths
+    pub paths: Option<String>,
+    /// Most entries kept in the item cache before the least recently used are evicted
+    pub max_items: Option<usize>,
+    /// Size limit of the item cache before compression, e.g. "64MiB"
+    pub max_size: Option<String>,
+}
+
+/// Defaults picked up by commands run with the section's `--app-id`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    /// Search filter for search-workshop and search-cache when `--tags` is not given
+    pub tags: Option<Vec<String>>,
+    /// `--target` of deploy-items when not given
+    pub deploy_target: Option<String>,
+    pub cache: AppCacheConfig,
+}
+
+/// Cache lifetimes for one app, overriding `[cache]`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppCacheConfig {
+    pub items: Option<String>,
+    pub paths: Option<String>,
+}
+
+/// Timeouts as durations, e.g. "45s"
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TimeoutConfig {
+    /// Waiting for a Steamworks callback
+    pub steam: Option<String>,
+    /// One Web API or community request
+    pub web: Option<String>,
+    /// A launched Steam client getting ready
+    pub launch: Option<String>,
+}
+
+/// JSON log file for long-running commands such as `watch`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    pub file: Option<PathBuf>,
+    /// "never", "hourly" or "daily" (default)
+    pub rotation: Option<String>,
+    /// Most log files kept when rotating; older ones are deleted
+    pub keep: Option<usize>,
+}
+
+/// `s7forge/s7forge.toml` in the platform config directory: `%APPDATA%` on Windows,
+/// `~/Library/Application Support` on macOS and `$XDG_CONFIG_HOME` (or `~/.config`) elsewhere.
+pub fn default_config_path() -> Option<PathBuf> {
+    let config_dir = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| {
+            PathBuf::from(home)
+                .join("Library")
+                .join("Application Support")
+        })
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+    };
+
+    config_dir.map(|dir| dir.join("s7forge").join(CONFIG_FILE_NAME))
+}
+
+impl Config {
+    /// Reads the file given with `--config`, which must exist, or else the default config
+    /// file if there is one.
+    pub fn load(path: Option<&Path>) -> Result<Self, S7forgeError> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match default_config_path() {
+                Some(path) if path.is_file() => path,
+                _ => return Ok(Self::default()),
+            },
+        };
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| S7forgeError::io(format!("Failed to read {}", path.display()), e))?;
+        toml::from_str(&content)
+            .map_err(|e| S7forgeError::Config(format!("Invalid config {}: {}", path.display(), e)))
+    }
+
+    /// Reads the `S7FORGE_*` environment variables. `STEAM_WEB_API_KEY` is still honoured when
+    /// `S7FORGE_WEB_API_KEY` is not set, and `S7FORGE_TIMEOUT` sets both timeouts.
+    pub fn from_env() -> Result<Self, S7forgeError> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        let flag = |name: &str| match var(name) {
+            None => Ok(None),
+            Some(value) => match value.to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" => Ok(Some(true)),
+                "0" | "false" | "no" => Ok(Some(false)),
+                _ => Err(S7forgeError::Config(format!(
+                    "Invalid {}: {} (expected true or false)",
+                    name, value
+                ))),
+            },
+        };
+        let timeout = var("S7FORGE_TIMEOUT");
+
+        Ok(Config {
+            app_id: var("S7FORGE_APP_ID")
+                .map(|app_id| {
+                    app_id.parse().map_err(|_| {
+                        S7forgeError::Config(format!("Invalid S7FORGE_APP_ID: {}", app_id))
+                    })
+                })
+                .transpose()?,
+            web_api_key: var("S7FORGE_WEB_API_KEY").or_else(|| var("STEAM_WEB_API_KEY")),
+            no_client: flag("S7FORGE_NO_CLIENT")?,
+            anonymous: flag("S7FORGE_ANONYMOUS")?,
+            progress_events: flag("S7FORGE_PROGRESS_EVENTS")?,
+            output: var("S7FORGE_OUTPUT"),
+            no_cache: flag("S7FORGE_NO_CACHE")?,
+            dates: var("S7FORGE_DATES"),
+            sizes: var("S7FORGE_SIZES"),
+            description_format: var("S7FORGE_DESCRIPTION_FORMAT"),
+            envelope: flag("S7FORGE_ENVELOPE")?,
+            verbose: var("S7FORGE_VERBOSE")
+                .map(|verbose| {
+                    verbose.parse().map_err(|_| {
+                        S7forgeError::Config(format!("Invalid S7FORGE_VERBOSE: {}", verbose))
+                    })
+                })
+                .transpose()?,
+            quiet: flag("S7FORGE_QUIET")?,
+            auto_launch: flag("S7FORGE_AUTO_LAUNCH")?,
+            concurrency: var("S7FORGE_CONCURRENCY")
+                .map(|concurrency| {
+                    concurrency.parse().map_err(|_| {
+                        S7forgeError::Config(format!(
+                            "Invalid S7FORGE_CONCURRENCY: {}",
+                            concurrency
+                        ))
+                    })
+                })
+                .transpose()?,
+            cache: CacheConfig {
+                dir: var("S7FORGE_CACHE_DIR").map(PathBuf::from),
+                backend: var("S7FORGE_CACHE_BACKEND"),
+                items: var("S7FORGE_CACHE_TTL"),
+                paths: None,
+                max_items: None,
+                max_size: None,
+            },
+            timeouts: TimeoutConfig {
+                steam: timeout.clone(),
+                web: timeout,
+                launch: None,
+            },
+            log: LogConfig {
+                file: var("S7FORGE_LOG_FILE").map(PathBuf::from),
+                rotation: var("S7FORGE_LOG_ROTATION"),
+                keep: None,
+            },
+            apps: FxHashMap::default(),
+        })
+    }
+
+    /// Keeps every key set here and takes the rest from `fallback`.
+    pub fn or(self, fallback: Config) -> Config {
+        Config {
+            app_id: self.app_id.or(fallback.app_id),
+            web_api_key: self.web_api_key.or(fallback.web_api_key),
+            no_client: self.no_client.or(fallback.no_client),
+            anonymous: self.anonymous.or(fallback.anonymous),
+            progress_events: self.progress_events.or(fallback.progress_events),
+            output: self.output.or(fallback.output),
+            no_cache: self.no_cache.or(fallback.no_cache),
+            dates: self.dates.or(fallback.dates),
+            sizes: self.sizes.or(fallback.sizes),
+            description_format: self.description_format.or(fallback.description_format),
+            envelope: self.envelope.or(fallback.envelope),
+            verbose: self.verbose.or(fallback.verbose),
+            quiet: self.quiet.or(fallback.quiet),
+            auto_launch: self.auto_launch.or(fallback.auto_launch),
+            concurrency: self.concurrency.or(fallback.concurrency),
+            cache: CacheConfig {
+                dir: self.cache.dir.or(fallback.cache.dir),
+                backend: self.cache.backend.or(fallback.cache.backend),
+                items: self.cache.items.or(fallback.cache.items),
+                paths: self.cache.paths.or(fallback.cache.paths),
+                max_items: self.cache.max_items.or(fallback.cache.max_items),
+                max_size: self.cache.max_size.or(fallback.cache.max_size),
+            },
+            timeouts: TimeoutConfig {
+                steam: self.timeouts.steam.or(fallback.timeouts.steam),
+                web: self.timeouts.web.or(fallback.timeouts.web),
+                launch: self.timeouts.launch.or(fallback.timeouts.launch),
+            },
+            log: LogConfig {
+                file: self.log.file.or(fallback.log.file),
+                rotation: self.log.rotation.or(fallback.log.rotation),
+                keep: self.log.keep.or(fallback.log.keep),
+            },
+            // Only the config file has app sections
+            apps: if self.apps.is_empty() {
+                fallback.apps
+            } else {
+                self.apps
+            },
+        }
+    }
+
+    /// Settings with the values set here in place of the built-in defaults.
+    pub fn settings(&self) -> Result<Settings, S7forgeError> {
+        let mut settings = Settings {
+            web_api_key: self.web_api_key.clone(),
+            no_client: self.no_client.unwrap_or_default(),
+            anonymous: self.anonymous.unwrap_or_default(),
+            progress_events: self.progress_events.unwrap_or_default(),
+            no_cache: self.no_cache.unwrap_or_default(),
+            envelope: self.envelope.unwrap_or_default(),
+            verbose: self.verbose.unwrap_or_default(),
+            quiet: self.quiet.unwrap_or_default(),
+            auto_launch: self.auto_launch.unwrap_or_default(),
+            ..Settings::default()
+        };
+
+        if let Some(output) = &self.output {
+            settings.output = OutputFormat::parse(output).map_err(S7forgeError::Config)?;
+            // Tables are read by people, so the formats default to the readable ones
+            if settings.output == OutputFormat::Table {
+                settings.dates = DateFormat::Relative;
+                settings.sizes = SizeFormat::Human;
+                settings.description_format = DescriptionFormat::Plain;
+            }
+        }
+        if let Some(dates) = &self.dates {
+            settings.dates = DateFormat::parse(dates).map_err(S7forgeError::Config)?;
+        }
+        if let Some(sizes) = &self.sizes {
+            settings.sizes = SizeFormat::parse(sizes).map_err(S7forgeError::Config)?;
+        }
+        if let Some(format) = &self.description_format {
+            settings.description_format =
+                DescriptionFormat::parse(format).map_err(S7forgeError::Config)?;
+        }
+        if let Some(concurrency) = self.concurrency {
+            if concurrency == 0 {
+                return Err(S7forgeError::Config(
+                    "concurrency must be at least 1".to_string(),
+                ));
+            }
+            settings.concurrency = concurrency;
+        }
+        if let Some(dir) = &self.cache.dir {
+            settings.cache_dir = Some(dir.clone());
+        }
+        if let Some(backend) = &self.cache.backend {
+            settings.cache_backend = CacheBackend::parse(backend).map_err(S7forgeError::Config)?;
+        }
+        if let Some(ttl) = &self.cache.items {
+            settings.item_cache_ttl = parse_duration(ttl).map_err(S7forgeError::Config)?;
+        }
+        if let Some(ttl) = &self.cache.paths {
+            settings.path_cache_ttl = parse_duration(ttl).map_err(S7forgeError::Config)?;
+        }
+        if let Some(max_items) = self.cache.max_items {
+            settings.item_cache_max_entries = Some(max_items);
+        }
+        if let Some(max_size) = &self.cache.max_size {
+            settings.item_cache_max_bytes =
+                Some(parse_size(max_size).map_err(S7forgeError::Config)?);
+        }
+        if let Some(timeout) = &self.timeouts.steam {
+            settings.steam_timeout = parse_duration(timeout).map_err(S7forgeError::Config)?;
+        }
+        if let Some(timeout) = &self.timeouts.web {
+            settings.web_timeout = parse_duration(timeout).map_err(S7forgeError::Config)?;
+        }
+        if let Some(timeout) = &self.timeouts.launch {
+            settings.launch_timeout = parse_duration(timeout).map_err(S7forgeError::Config)?;
+        }
+        if let Some(file) = &self.log.file {
+            settings.log_file = Some(file.clone());
+        }
+        if let Some(rotation) = &self.log.rotation {
+            settings.log_rotation = LogRotation::parse(rotation).map_err(S7forgeError::Config)?;
+        }
+        if let Some(keep) = self.log.keep {
+            if keep == 0 {
+                return Err(S7forgeError::Config(
+                    "log.keep must be at least 1".to_string(),
+                ));
+            }
+            settings.log_keep = Some(keep);
+        }
+        for (app_id, app) in &self.apps {
+            let app_id = app_id.parse().map_err(|_| {
+                S7forgeError::Config(format!("Invalid app id in [apps.{}]", app_id))
+            })?;
+            settings.apps.insert(app_id, app.settings()?);
+        }
+
+        Ok(settings)
+    }
+}
+
+impl AppConfig {
+    fn settings(&self) -> Result<AppSettings, S7forgeError> {
+        Ok(AppSettings {
+            tags: self.tags.clone().unwrap_or_default(),
+            deploy_target: self.deploy_target.clone(),
+            item_cache_ttl: self
+                .cache
+                .items
+                .as_deref()
+                .map(parse_duration)
+                .transpose()
+                .map_err(S7forgeError::Config)?,
+            path_cache_ttl: self
+                .cache
+                .paths
+                .as_deref()
+                .map(parse_duration)
+                .transpose()
+                .map_err(S7forgeError::Config)?,
+        })
+    }
+}
@@ -0,0 +1,255 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::error::Error as _;
+
+/// Stable category of a failure, printed as `code` so frontends can branch on it instead of
+/// matching message text. New codes may be added; existing ones keep their meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// Unknown command, missing or malformed option
+    InvalidArguments,
+    /// The config file, a `S7FORGE_*` variable or a global flag can't be used, or a setting
+    /// the command needs, such as the Web API key, is missing
+    ConfigError,
+    /// The Steam client isn't running or refused to initialize for the app
+    SteamNotRunning,
+    /// The command needs the Steam client but `--anonymous` or `--no-client` is set
+    SteamClientRequired,
+    /// Steam answered a request with an error result
+    SteamApiError,
+    /// Steam didn't answer in time
+    Timeout,
+    /// The Steam Web API, community site or GitHub couldn't be reached or answered with an
+    /// error
+    WebApiError,
+    /// An item, collection, path or app doesn't exist or isn't installed
+    NotFound,
+    /// The command needs an item the account isn't subscribed to
+    NotSubscribed,
+    /// The item is on the blacklist. Not reported at the moment: commands skip blacklisted
+    /// items with a warning instead of failing
+    #[allow(dead_code)]
+    Blacklisted,
+    /// The cache database or a cache file failed
+    CacheError,
+    /// Reading, writing or parsing a local file failed
+    IoError,
+    /// Anything not covered above
+    Unknown,
+}
+
+impl ErrorCode {
+    /// Process exit status for a failed command, so scripts can tell failure classes apart
+    /// without reading stderr. 10 is taken by `check-updates --quiet-if-none`.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Self::InvalidArguments | Self::ConfigError => 2,
+            Self::SteamNotRunning | Self::SteamClientRequired => 3,
+            Self::NotFound | Self::NotSubscribed => 4,
+            Self::Timeout => 5,
+            Self::WebApiError => 6,
+            Self::SteamApiError
+            | Self::Blacklisted
+            | Self::CacheError
+            | Self::IoError
+            | Self::Unknown => 1,
+        }
+    }
+}
+
+/// Error returned by commands and the core modules. Failures of the Steam client, the file
+/// system and HTTP keep their source error; everything else is a message.
+#[derive(Debug, thiserror::Error)]
+pub enum S7forgeError {
+    /// An argument error from the command line parser
+    #[error(transparent)]
+    Args(#[from] lexopt::Error),
+    #[error("Failed to initialize Steam client: {0:?}")]
+    SteamInit(#[source] steamworks::SteamAPIInitError),
+    /// An option value or combination of options a command can't use
+    #[error("{0}")]
+    InvalidArgs(String),
+    /// A setting from the config file, the environment or a global flag that can't be used
+    #[error("{0}")]
+    Config(String),
+    #[error("This command requires the Steam client and is not available in anonymous mode")]
+    ClientRequired,
+    /// A failed Steam request, with the workshop item it was about if there is one
+    #[error("Steam API error{}: {source:?}", for_item(.item_id))]
+    SteamApi {
+        item_id: Option<u64>,
+        #[source]
+        source: steamworks::SteamError,
+    },
+    /// What timed out, e.g. "Operation" or "Upload"
+    #[error("{0} timed out waiting for Steam response")]
+    Timeout(&'static str),
+    #[error("Workshop item {0} not found")]
+    ItemNotFound(u64),
+    /// A path, app, collection or profile that doesn't exist or isn't installed
+    #[error("{0}")]
+    NotFound(String),
+    #[error("Workshop item {0} is not subscribed")]
+    NotSubscribed(u64),
+    /// Steam didn't download an item, or didn't put its files in place
+    #[error("Failed to download item {item_id}: {message}")]
+    Download { item_id: u64, message: String },
+    #[error("{context}: {source}")]
+    Io {
+        context: String,
+        #[source]
+        source: std::io::Error,
+    },
+    /// A Steam manifest or other KeyValues file that can't be parsed
+    #[error("Failed to parse {path}: {message}")]
+    Acf { path: String, message: String },
+    /// The cache database or a cache file couldn't be used
+    #[error("{context}: {source}")]
+    Cache {
+        context: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("{context}: {source}")]
+    Parse {
+        context: String,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// A failed Steam Web API, community or GitHub request, or an unreadable response
+    #[error("{context}: {source}")]
+    Http {
+        context: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("Task join error: {0:?}")]
+    Join(#[from] tokio::task::JoinError),
+    /// Anything without a category of its own; reported as `UNKNOWN`
+    #[error("{0}")]
+    Other(String),
+}
+
+impl S7forgeError {
+    /// A failed file system operation, e.g. `io(format!("Failed to read {}", path), e)`.
+    pub fn io(context: impl Into<String>, source: std::io::Error) -> Self {
+        Self::Io {
+            context: context.into(),
+            source,
+        }
+    }
+
+    pub fn steam_api(source: steamworks::SteamError) -> Self {
+        Self::SteamApi {
+            item_id: None,
+            source,
+        }
+    }
+
+    /// A failed Steam request about one item, e.g. a vote or a subscription.
+    pub fn item_steam_api(item_id: u64, source: steamworks::SteamError) -> Self {
+        Self::SteamApi {
+            item_id: Some(item_id),
+            source,
+        }
+    }
+
+    pub fn cache(
+        context: impl Into<String>,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self::Cache {
+            context: context.into(),
+            source: source.into(),
+        }
+    }
+
+    pub fn parse(context: impl Into<String>, source: serde_json::Error) -> Self {
+        Self::Parse {
+            context: context.into(),
+            source,
+        }
+    }
+
+    pub fn http(
+        context: impl Into<String>,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self::Http {
+            context: context.into(),
+            source: source.into(),
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::Args(_) | Self::InvalidArgs(_) => ErrorCode::InvalidArguments,
+            Self::Config(_) => ErrorCode::ConfigError,
+            Self::SteamInit(_) => ErrorCode::SteamNotRunning,
+            Self::ClientRequired => ErrorCode::SteamClientRequired,
+            Self::SteamApi { .. } | Self::Download { .. } => ErrorCode::SteamApiError,
+            Self::Timeout(_) => ErrorCode::Timeout,
+            Self::ItemNotFound(_) | Self::NotFound(_) => ErrorCode::NotFound,
+            Self::NotSubscribed(_) => ErrorCode::NotSubscribed,
+            Self::Cache { .. } => ErrorCode::CacheError,
+            Self::Io { .. } | Self::Acf { .. } | Self::Parse { .. } => ErrorCode::IoError,
+            Self::Http { .. } => ErrorCode::WebApiError,
+            Self::Join(_) | Self::Other(_) => ErrorCode::Unknown,
+        }
+    }
+}
+
+fn for_item(item_id: &Option<u64>) -> String {
+    item_id.map_or_else(String::new, |item_id| format!(" for item {}", item_id))
+}
+
+impl From<String> for S7forgeError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
+impl From<&str> for S7forgeError {
+    fn from(message: &str) -> Self {
+        Self::Other(message.to_string())
+    }
+}
+
+/// The JSON object printed on stderr when a command fails.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ErrorReport {
+    pub code: ErrorCode,
+    pub message: String,
+    pub context: Option<ErrorContext>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ErrorContext {
+    /// The underlying OS, Steam or HTTP error
+    pub cause: String,
+}
+
+impl From<&S7forgeError> for ErrorReport {
+    fn from(error: &S7forgeError) -> Self {
+        let message = error.to_string();
+        let cause = match error {
+            // The parser's message is complete; its source is the same text again
+            S7forgeError::Args(_) => None,
+            error => error.source().map(|source| source.to_string()),
+        };
+        Self {
+            code: error.code(),
+            message,
+            context: cause.map(|cause| ErrorContext { cause }),
+        }
+    }
+}
+
+impl ErrorReport {
+    pub fn print(&self) {
+        if let Ok(line) = serde_json::to_string(self) {
+            eprintln!("{}", line);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::utils::civil_date::civil_from_days;
+
+pub struct FeedEntry {
+    /// Unique per update, so feed readers show a new entry every time an item changes
+    pub guid: String,
+    pub title: String,
+    pub link: String,
+    pub description: String,
+    /// Unix time in milliseconds
+    pub published_ms: u64,
+}
+
+pub fn item_url(item_id: u64) -> String {
+    format!(
+        "https://steamcommunity.com/sharedfiles/filedetails/?id={}",
+        item_id
+    )
+}
+
+/// Renders an RSS 2.0 document.
+pub fn rss(title: &str, description: &str, entries: &[FeedEntry]) -> String {
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str("<rss version=\"2.0\">\n<channel>\n");
+    output.push_str(&format!("<title>{}</title>\n", escape(title)));
+    output.push_str("<link>https://steamcommunity.com/workshop/</link>\n");
+    output.push_str(&format!(
+        "<description>{}</description>\n",
+        escape(description)
+    ));
+    if let Some(latest) = entries.iter().map(|entry| entry.published_ms).max() {
+        output.push_str(&format!(
+            "<lastBuildDate>{}</lastBuildDate>\n",
+            rfc822(latest)
+        ));
+    }
+
+    for entry in entries {
+        output.push_str("<item>\n");
+        output.push_str(&format!("<title>{}</title>\n", escape(&entry.title)));
+        output.push_str(&format!("<link>{}</link>\n", escape(&entry.link)));
+        output.push_str(&format!(
+            "<guid isPermaLink=\"false\">{}</guid>\n",
+            escape(&entry.guid)
+        ));
+        output.push_str(&format!(
+            "<description>{}</description>\n",
+            escape(&entry.description)
+        ));
+        output.push_str(&format!(
+            "<pubDate>{}</pubDate>\n",
+            rfc822(entry.published_ms)
+        ));
+        output.push_str("</item>\n");
+    }
+
+    output.push_str("</channel>\n</rss>\n");
+    output
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// e.g. "Tue, 10 Jun 2003 04:00:00 GMT"
+fn rfc822(unix_ms: u64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let seconds = unix_ms / 1000;
+    let days = seconds / 86400;
+    let time = seconds % 86400;
+
+    let (year, month, day) = civil_from_days(days as i64);
+
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+        WEEKDAYS[(days % 7) as usize],
+        day,
+        MONTHS[month as usize - 1],
+        year,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
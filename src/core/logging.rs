@@ -0,0 +1,148 @@
+//! Diagnostics on stderr through `tracing`. Warnings and upload progress are shown by
+//! default; `-v` adds Steam client and callback activity, cache hits and misses, Web API
+//! requests and how long each phase took, `-vv` also every callback poll. `--quiet` turns
+//! all of it off, leaving only the output on stdout and the error object on stderr.
+//!
+//! With `--log-file` the same events, at least at debug level, are also written to a file as
+//! JSON lines, together with failed commands and `watch` events. `--quiet` doesn't apply to it.
+//!
+//! `--progress-events` and the error object are written directly and not affected.
+
+use std::io::IsTerminal;
+use std::path::Path;
+use tracing::Subscriber;
+use tracing::level_filters::LevelFilter;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::Layer;
+use tracing_subscriber::filter::{FilterExt, filter_fn};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::fmt::time::uptime;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+
+/// Target of events that only belong in the log file, e.g.
+/// `tracing::error!(target: logging::FILE_ONLY, ...)` for what stdout or stderr already shows.
+pub const FILE_ONLY: &str = "s7forge::log_file";
+
+/// How often `--log-file` starts a new file, set with `log.rotation`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LogRotation {
+    /// Always the same file
+    Never,
+    Hourly,
+    #[default]
+    Daily,
+}
+
+impl LogRotation {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "never" => Ok(Self::Never),
+            "hourly" => Ok(Self::Hourly),
+            "daily" => Ok(Self::Daily),
+            other => Err(format!(
+                "Invalid log rotation: {} (expected never, hourly or daily)",
+                other
+            )),
+        }
+    }
+}
+
+/// The most detailed level printed on stderr for the current settings.
+pub fn level() -> LevelFilter {
+    let settings = settings::get();
+    if settings.quiet {
+        return LevelFilter::OFF;
+    }
+    match settings.verbose {
+        0 => LevelFilter::INFO,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Installs the stderr logger and the log file, if one is set. Called once the settings are
+/// known.
+pub fn init() -> Result<(), S7forgeError> {
+    let level = level();
+    let ansi = std::io::stderr().is_terminal();
+    let not_file_only = filter_fn(|metadata| metadata.target() != FILE_ONLY);
+
+    let stderr = if level >= LevelFilter::DEBUG {
+        // Time since start and when each span closes, so slow phases stand out
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_ansi(ansi)
+            .with_timer(uptime())
+            .with_span_events(FmtSpan::CLOSE)
+            .with_filter(level.and(not_file_only))
+            .boxed()
+    } else {
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_ansi(ansi)
+            .without_time()
+            .with_target(false)
+            .with_filter(level.and(not_file_only))
+            .boxed()
+    };
+
+    let file = settings::get()
+        .log_file
+        .as_deref()
+        .map(file_layer)
+        .transpose()?;
+
+    let _ = tracing_subscriber::registry()
+        .with(stderr)
+        .with(file)
+        .try_init();
+    Ok(())
+}
+
+// `s7forge.log` rotated daily becomes `s7forge.2024-05-01.log`, `s7forge.2024-05-02.log`, ...
+fn file_layer<S>(path: &Path) -> Result<Box<dyn Layer<S> + Send + Sync>, S7forgeError>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let settings = settings::get();
+    let rotation = match settings.log_rotation {
+        LogRotation::Never => Rotation::NEVER,
+        LogRotation::Hourly => Rotation::HOURLY,
+        LogRotation::Daily => Rotation::DAILY,
+    };
+    let directory = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(".".as_ref());
+    let prefix = path.file_stem().ok_or_else(|| {
+        S7forgeError::InvalidArgs(format!("Invalid log file path: {}", path.display()))
+    })?;
+
+    let mut builder = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(prefix.to_string_lossy());
+    if let Some(extension) = path.extension() {
+        builder = builder.filename_suffix(extension.to_string_lossy());
+    }
+    if let Some(keep) = settings.log_keep {
+        builder = builder.max_log_files(keep);
+    }
+    let appender = builder.build(directory).map_err(|e| {
+        S7forgeError::io(
+            format!("Failed to create log file {}", path.display()),
+            std::io::Error::other(e),
+        )
+    })?;
+
+    Ok(tracing_subscriber::fmt::layer()
+        .json()
+        .with_writer(appender)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_filter(level().max(LevelFilter::DEBUG))
+        .boxed())
+}
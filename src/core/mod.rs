@@ -1,6 +1,17 @@
+pub mod acf;
 pub mod client;
+pub mod config;
+pub mod error;
+pub mod feed;
 pub mod localplayer;
+pub mod logging;
+pub mod output;
+pub mod progress;
+pub mod settings;
 pub mod steam_install_paths;
 pub mod steam_manager;
+pub mod steam_process;
+pub mod table;
+pub mod web_api;
 pub mod workshop;
 pub mod workshop_item;
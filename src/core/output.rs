@@ -0,0 +1,332 @@
+use serde_json::{Value, json};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::settings::{self, Settings};
+use crate::core::table;
+use crate::utils::bbcode::{self, Target};
+use crate::utils::civil_date::civil_from_days;
+
+/// Who the output is for, set with the global `--output` flag.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// JSON for programs
+    #[default]
+    Json,
+    /// For people at a terminal: aligned columns, and progress bars on stderr while commands
+    /// run. Dates, sizes and descriptions default to relative, human and plain.
+    Table,
+}
+
+impl OutputFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "json" => Ok(Self::Json),
+            "table" => Ok(Self::Table),
+            other => Err(format!(
+                "Invalid output format: {} (expected json or table)",
+                other
+            )),
+        }
+    }
+}
+
+/// How timestamps are written in command output, set with the global `--dates` flag.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DateFormat {
+    /// Unix time as Steam reports it (milliseconds for item times, seconds elsewhere)
+    #[default]
+    Epoch,
+    /// ISO 8601 in UTC, e.g. "2024-05-01T18:30:00Z"
+    Iso,
+    /// Age relative to now, e.g. "3 days ago"
+    Relative,
+}
+
+impl DateFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "epoch" => Ok(Self::Epoch),
+            "iso" => Ok(Self::Iso),
+            "relative" => Ok(Self::Relative),
+            other => Err(format!(
+                "Invalid date format: {} (expected iso, epoch or relative)",
+                other
+            )),
+        }
+    }
+}
+
+/// How byte counts are written in command output, set with the global `--sizes` flag.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SizeFormat {
+    #[default]
+    Bytes,
+    /// Binary units instead of the byte count, e.g. "1.4 GiB"
+    Human,
+    /// Byte count kept, with an added `<field>_human` string
+    Both,
+}
+
+impl SizeFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "bytes" => Ok(Self::Bytes),
+            "human" => Ok(Self::Human),
+            "both" => Ok(Self::Both),
+            other => Err(format!(
+                "Invalid size format: {} (expected bytes, human or both)",
+                other
+            )),
+        }
+    }
+}
+
+/// How workshop descriptions are written, set with the global `--description-format` flag.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DescriptionFormat {
+    /// Steam's BBCode markup, unchanged
+    #[default]
+    Bbcode,
+    Markdown,
+    Plain,
+    Html,
+}
+
+impl DescriptionFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "bbcode" => Ok(Self::Bbcode),
+            "markdown" => Ok(Self::Markdown),
+            "plain" => Ok(Self::Plain),
+            "html" => Ok(Self::Html),
+            other => Err(format!(
+                "Invalid description format: {} (expected bbcode, markdown, plain or html)",
+                other
+            )),
+        }
+    }
+
+    fn target(self) -> Option<Target> {
+        match self {
+            Self::Bbcode => None,
+            Self::Markdown => Some(Target::Markdown),
+            Self::Plain => Some(Target::Plain),
+            Self::Html => Some(Target::Html),
+        }
+    }
+}
+
+/// Version of the `--envelope` output. Bumped whenever a command's output changes in a way
+/// that breaks existing parsers, e.g. a renamed, removed or retyped field.
+pub const SCHEMA_VERSION: u32 = 1;
+
+fn is_raw() -> bool {
+    let settings = settings::get();
+    settings.dates == DateFormat::Epoch
+        && settings.sizes == SizeFormat::Bytes
+        && settings.description_format == DescriptionFormat::Bbcode
+}
+
+/// Rewrites timestamps, byte counts and descriptions in a command's JSON output according to
+/// the global output settings. Output that isn't JSON (e.g. RSS) is returned unchanged.
+pub fn format_output(output: String) -> String {
+    if is_raw() {
+        return output;
+    }
+    let Ok(mut value) = serde_json::from_str::<Value>(&output) else {
+        return output;
+    };
+    format_value(&mut value);
+    serde_json::to_string_pretty(&value).unwrap_or(output)
+}
+
+/// A command's output as printed: formatted, then drawn as tables with `--output table` or
+/// wrapped with `--envelope`.
+pub fn present(command: &str, output: String) -> String {
+    let output = format_output(output);
+    if settings::get().output == OutputFormat::Table {
+        return match serde_json::from_str::<Value>(&output) {
+            Ok(value) => table::render(&value),
+            Err(_) => output,
+        };
+    }
+    envelope(command, output)
+}
+
+/// Wraps formatted output in `{"schema_version", "command", "data"}` when `--envelope` is set.
+/// Output that isn't JSON (e.g. RSS) becomes a string in `data`.
+pub fn envelope(command: &str, output: String) -> String {
+    if !settings::get().envelope {
+        return output;
+    }
+    let data = serde_json::from_str::<Value>(&output).unwrap_or(Value::String(output));
+    let value = json!({
+        "schema_version": SCHEMA_VERSION,
+        "command": command,
+        "data": data,
+    });
+    serde_json::to_string_pretty(&value).unwrap_or_default()
+}
+
+/// In-place variant of [`format_output`] for output that is serialized line by line.
+pub fn format_value(value: &mut Value) {
+    if is_raw() {
+        return;
+    }
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default();
+    convert(value, settings::get(), now_ms);
+}
+
+fn convert(value: &mut Value, settings: &Settings, now_ms: u64) {
+    let (dates, sizes) = (settings.dates, settings.sizes);
+    match value {
+        Value::Object(map) => {
+            let mut human_sizes = Vec::new();
+            for (key, field) in map.iter_mut() {
+                if let (Some(target), Value::String(text)) =
+                    (settings.description_format.target(), &mut *field)
+                {
+                    if is_description_key(key) {
+                        *text = bbcode::convert(text, target);
+                        continue;
+                    }
+                }
+
+                match field.as_u64() {
+                    // Zero is Steam's "unknown", e.g. time_added_to_user_list outside user lists
+                    Some(timestamp)
+                        if dates != DateFormat::Epoch && timestamp > 0 && is_timestamp_key(key) =>
+                    {
+                        *field = Value::String(format_timestamp(timestamp, dates, now_ms));
+                    }
+                    Some(bytes) if sizes == SizeFormat::Human && is_size_key(key) => {
+                        *field = Value::String(human_size(bytes));
+                    }
+                    Some(bytes) if sizes == SizeFormat::Both && is_size_key(key) => {
+                        human_sizes.push((format!("{}_human", key), human_size(bytes)));
+                    }
+                    _ => convert(field, settings, now_ms),
+                }
+            }
+            for (key, size) in human_sizes {
+                map.insert(key, Value::String(size));
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                convert(value, settings, now_ms);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_description_key(key: &str) -> bool {
+    key == "description" || key.ends_with("_description") || key == "change_note"
+}
+
+fn is_size_key(key: &str) -> bool {
+    key != "page_size"
+        && (key.ends_with("_size")
+            || key.starts_with("size_")
+            || key.starts_with("bytes_")
+            || key.ends_with("_bytes"))
+}
+
+// e.g. "512 B", "1.4 GiB"
+fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+fn is_timestamp_key(key: &str) -> bool {
+    key == "timestamp"
+        || key.starts_with("time_")
+        || key.ends_with("_time_updated")
+        || key.ends_with("_time_created")
+        || key.ends_with("_at")
+}
+
+fn format_timestamp(timestamp: u64, dates: DateFormat, now_ms: u64) -> String {
+    // Item times are in milliseconds, manifest and bookkeeping times in seconds; seconds
+    // stay below 10^11 until the year 5138, milliseconds pass it in 1973
+    let unix_ms = if timestamp < 100_000_000_000 {
+        timestamp.saturating_mul(1000)
+    } else {
+        timestamp
+    };
+
+    match dates {
+        DateFormat::Epoch => timestamp.to_string(),
+        DateFormat::Iso => iso8601(unix_ms),
+        DateFormat::Relative => relative(unix_ms, now_ms),
+    }
+}
+
+fn iso8601(unix_ms: u64) -> String {
+    let seconds = unix_ms / 1000;
+    let time = seconds % 86400;
+    let (year, month, day) = civil_from_days((seconds / 86400) as i64);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+fn relative(unix_ms: u64, now_ms: u64) -> String {
+    let (seconds, future) = if unix_ms > now_ms {
+        ((unix_ms - now_ms) / 1000, true)
+    } else {
+        ((now_ms - unix_ms) / 1000, false)
+    };
+    if seconds < 60 {
+        return "just now".to_string();
+    }
+
+    const UNITS: [(u64, &str); 6] = [
+        (365 * 86400, "year"),
+        (30 * 86400, "month"),
+        (7 * 86400, "week"),
+        (86400, "day"),
+        (3600, "hour"),
+        (60, "minute"),
+    ];
+    let (size, name) = UNITS
+        .iter()
+        .find(|(size, _)| seconds >= *size)
+        .copied()
+        .unwrap_or((60, "minute"));
+    let count = seconds / size;
+    let unit = if count == 1 {
+        name.to_string()
+    } else {
+        format!("{}s", name)
+    };
+
+    if future {
+        format!("in {} {}", count, unit)
+    } else {
+        format!("{} {} ago", count, unit)
+    }
+}
@@ -0,0 +1,106 @@
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressFinish, ProgressStyle};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::io::{IsTerminal, Write};
+
+use crate::core::output::OutputFormat;
+use crate::core::settings;
+
+/// One NDJSON line on stderr, emitted only with `--progress-events`.
+#[derive(Debug, Serialize)]
+pub struct ProgressEvent<'a> {
+    pub phase: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<u64>,
+    pub bytes_downloaded: u64,
+    pub bytes_total: u64,
+    pub percent: f32,
+}
+
+impl<'a> ProgressEvent<'a> {
+    pub fn new(
+        phase: &'a str,
+        item_id: Option<u64>,
+        bytes_downloaded: u64,
+        bytes_total: u64,
+    ) -> Self {
+        let percent = if bytes_total > 0 {
+            (bytes_downloaded as f32 / bytes_total as f32) * 100.0
+        } else {
+            0.0
+        };
+
+        Self {
+            phase,
+            item_id,
+            bytes_downloaded,
+            bytes_total,
+            percent,
+        }
+    }
+}
+
+pub fn enabled() -> bool {
+    settings::get().progress_events
+}
+
+pub fn emit(event: ProgressEvent) {
+    if !enabled() {
+        return;
+    }
+
+    if let Ok(line) = serde_json::to_string(&event) {
+        let mut stderr = std::io::stderr().lock();
+        let _ = writeln!(stderr, "{}", line);
+        let _ = stderr.flush();
+    }
+}
+
+/// Whether progress bars are drawn: with `--output table` when stdout and stderr are a
+/// terminal. Piped runs, `--quiet` and `--progress-events` get no bars.
+pub fn bars_enabled() -> bool {
+    let settings = settings::get();
+    settings.output == OutputFormat::Table
+        && !settings.quiet
+        && !settings.progress_events
+        && std::io::stdout().is_terminal()
+        && std::io::stderr().is_terminal()
+}
+
+fn draw_target() -> ProgressDrawTarget {
+    if bars_enabled() {
+        ProgressDrawTarget::stderr()
+    } else {
+        ProgressDrawTarget::hidden()
+    }
+}
+
+/// A bar counting `len` steps, e.g. items subscribed. Hidden when bars are disabled, so
+/// callers can update it unconditionally; it is cleared from the terminal when dropped.
+pub fn count_bar(len: u64, message: impl Into<Cow<'static, str>>) -> ProgressBar {
+    let style = ProgressStyle::with_template("{msg:>12} [{bar:30}] {pos}/{len} ({elapsed})")
+        .unwrap_or_else(|_| ProgressStyle::default_bar())
+        .progress_chars("=> ");
+    ProgressBar::with_draw_target(Some(len), draw_target())
+        .with_style(style)
+        .with_message(message)
+        .with_finish(ProgressFinish::AndClear)
+}
+
+/// A bar for a transfer of `len` bytes.
+pub fn bytes_bar(len: u64, message: impl Into<Cow<'static, str>>) -> ProgressBar {
+    let style = ProgressStyle::with_template(
+        "{msg:>12} [{bar:30}] {bytes}/{total_bytes} ({bytes_per_sec}, {eta})",
+    )
+    .unwrap_or_else(|_| ProgressStyle::default_bar())
+    .progress_chars("=> ");
+    ProgressBar::with_draw_target(Some(len), draw_target())
+        .with_style(style)
+        .with_message(message)
+        .with_finish(ProgressFinish::AndClear)
+}
+
+/// Holds several bars drawn together, such as one per concurrent download.
+pub fn multi_bar() -> MultiProgress {
+    MultiProgress::with_draw_target(draw_target())
+}
@@ -0,0 +1,128 @@
+use once_cell::sync::OnceCell;
+use rustc_hash::FxHashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::core::logging::LogRotation;
+use crate::core::output::{DateFormat, DescriptionFormat, OutputFormat, SizeFormat};
+use crate::utils::cache_db::CacheBackend;
+
+static SETTINGS: OnceCell<Settings> = OnceCell::new();
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub no_client: bool,
+    pub anonymous: bool,
+    pub web_api_key: Option<String>,
+    pub progress_events: bool,
+    pub output: OutputFormat,
+    /// Ignore cached results for this run; fresh results are still written to the cache
+    pub no_cache: bool,
+    pub dates: DateFormat,
+    pub sizes: SizeFormat,
+    pub description_format: DescriptionFormat,
+    /// Wrap output in `{"schema_version", "command", "data"}`
+    pub envelope: bool,
+    /// How many times `-v` was given: 1 for debug, 2 or more for trace
+    pub verbose: u8,
+    /// No diagnostics on stderr, only the error object; wins over `verbose`
+    pub quiet: bool,
+    /// JSON log written with `--log-file`
+    pub log_file: Option<PathBuf>,
+    pub log_rotation: LogRotation,
+    /// Most rotated log files kept, including the current one
+    pub log_keep: Option<usize>,
+    /// Default for commands that take `--concurrency`
+    pub concurrency: usize,
+    /// Overrides `<executable dir>/cache`
+    pub cache_dir: Option<PathBuf>,
+    /// Where items, creator names and searches are cached
+    pub cache_backend: CacheBackend,
+    pub item_cache_ttl: Duration,
+    pub path_cache_ttl: Duration,
+    /// Most items (and deleted markers) kept in the item cache
+    pub item_cache_max_entries: Option<usize>,
+    /// Largest size of the item cache before compression
+    pub item_cache_max_bytes: Option<u64>,
+    /// How long to wait for a Steamworks callback
+    pub steam_timeout: Duration,
+    /// How long one Web API or community request may take
+    pub web_timeout: Duration,
+    /// Start Steam when a command needs the client and it isn't running
+    pub auto_launch: bool,
+    /// How long a launched Steam client may take until Steamworks initializes
+    pub launch_timeout: Duration,
+    /// `[apps.<app_id>]` sections of the config file
+    pub apps: FxHashMap<u32, AppSettings>,
+}
+
+/// Defaults that only apply to commands run for one app.
+#[derive(Debug, Default, Clone)]
+pub struct AppSettings {
+    /// Search filter used when `--tags` is not given
+    pub tags: Vec<String>,
+    /// `--target` of deploy-items when not given
+    pub deploy_target: Option<String>,
+    pub item_cache_ttl: Option<Duration>,
+    pub path_cache_ttl: Option<Duration>,
+}
+
+impl Settings {
+    pub fn app(&self, app_id: u32) -> Option<&AppSettings> {
+        self.apps.get(&app_id)
+    }
+
+    pub fn item_cache_ttl_for(&self, app_id: u32) -> Duration {
+        self.app(app_id)
+            .and_then(|app| app.item_cache_ttl)
+            .unwrap_or(self.item_cache_ttl)
+    }
+
+    pub fn path_cache_ttl_for(&self, app_id: u32) -> Duration {
+        self.app(app_id)
+            .and_then(|app| app.path_cache_ttl)
+            .unwrap_or(self.path_cache_ttl)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            no_client: false,
+            anonymous: false,
+            web_api_key: None,
+            progress_events: false,
+            output: OutputFormat::default(),
+            no_cache: false,
+            dates: DateFormat::default(),
+            sizes: SizeFormat::default(),
+            description_format: DescriptionFormat::default(),
+            envelope: false,
+            verbose: 0,
+            quiet: false,
+            log_file: None,
+            log_rotation: LogRotation::default(),
+            log_keep: None,
+            concurrency: 4,
+            cache_dir: None,
+            cache_backend: CacheBackend::default(),
+            item_cache_ttl: Duration::from_secs(24 * 60 * 60),
+            path_cache_ttl: Duration::from_secs(60 * 60),
+            item_cache_max_entries: None,
+            item_cache_max_bytes: None,
+            steam_timeout: Duration::from_secs(30),
+            web_timeout: Duration::from_secs(30),
+            auto_launch: false,
+            launch_timeout: Duration::from_secs(120),
+            apps: FxHashMap::default(),
+        }
+    }
+}
+
+pub fn init(settings: Settings) {
+    let _ = SETTINGS.set(settings);
+}
+
+pub fn get() -> &'static Settings {
+    SETTINGS.get_or_init(Settings::default)
+}
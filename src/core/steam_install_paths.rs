@@ -1,7 +1,9 @@
 use winreg::RegKey;
 use winreg::enums::*;
 
-pub fn steam_install_paths() -> Result<Vec<String>, String> {
+use crate::core::error::S7forgeError;
+
+pub fn steam_install_paths() -> Result<Vec<String>, S7forgeError> {
     let hkcu = RegKey::predef(HKEY_CURRENT_USER);
     let mut paths = Vec::new();
 
@@ -1,7 +1,11 @@
 use once_cell::sync::Lazy;
-use steamworks::Client;
+use std::time::{Duration, Instant};
+use steamworks::{Client, SingleClient};
 
 use crate::core::client::SteamState;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::steam_process;
 
 pub static STEAM_MANAGER: Lazy<SteamManager> = Lazy::new(SteamManager::new);
 
@@ -16,29 +20,93 @@ impl SteamManager {
         }
     }
 
-    pub async fn initialize_client(&self, app_id: u32) -> Result<steamworks::Client, String> {
+    pub async fn initialize_client(&self, app_id: u32) -> Result<steamworks::Client, S7forgeError> {
+        self.initialize(app_id, settings::get().auto_launch).await
+    }
+
+    /// With `launch`, starts Steam when it isn't running and waits for it.
+    pub async fn initialize(
+        &self,
+        app_id: u32,
+        launch: bool,
+    ) -> Result<steamworks::Client, S7forgeError> {
+        if settings::get().anonymous {
+            return Err(S7forgeError::ClientRequired);
+        }
+
         if !self.steam_state.has_client(app_id) {
             self.steam_state.drop_all_clients();
-            let (steam_client, single_client) = Client::init_app(app_id)
-                .map_err(|err| format!("Failed to initialize Steam client: {:?}", err))?;
+            let start_time = Instant::now();
+            let (steam_client, single_client) = match Client::init_app(app_id) {
+                Ok(clients) => clients,
+                Err(e) if launch => {
+                    tracing::debug!("Steam client not available ({:?}), launching Steam", e);
+                    steam_process::launch()?;
+                    wait_for_client(app_id).await?
+                }
+                Err(e) => return Err(S7forgeError::SteamInit(e)),
+            };
+            tracing::debug!(app_id, elapsed = ?start_time.elapsed(), "Steam client initialized");
             self.steam_state
                 .set_clients(app_id, steam_client, single_client);
         }
 
         self.steam_state
             .get_client(app_id)
-            .ok_or_else(|| "Failed to get Steam client".to_string())
+            .ok_or_else(|| "Failed to get Steam client".into())
     }
 
-    pub fn run_callbacks(&self, app_id: u32) -> Result<(), String> {
+    /// Drops the client, e.g. before Steam is restarted under it.
+    pub fn reset(&self) {
+        self.steam_state.drop_all_clients();
+    }
+
+    pub fn run_callbacks(&self, app_id: u32) -> Result<(), S7forgeError> {
+        tracing::trace!(app_id, "Running Steam callbacks");
         self.steam_state.run_callbacks(app_id)
     }
 }
 
-pub async fn initialize_client(app_id: u32) -> Result<steamworks::Client, String> {
+pub async fn initialize_client(app_id: u32) -> Result<steamworks::Client, S7forgeError> {
     STEAM_MANAGER.initialize_client(app_id).await
 }
 
-pub fn run_callbacks(app_id: u32) -> Result<(), String> {
+/// Like [`initialize_client`], but launches Steam first when it isn't running.
+pub async fn initialize_client_launching(app_id: u32) -> Result<steamworks::Client, S7forgeError> {
+    STEAM_MANAGER.initialize(app_id, true).await
+}
+
+pub fn run_callbacks(app_id: u32) -> Result<(), S7forgeError> {
     STEAM_MANAGER.run_callbacks(app_id)
 }
+
+/// Returns `None` when `--no-client` is set or the Steam client can't be initialized,
+/// signalling the caller to fall back to the Steam Web API.
+pub async fn initialize_client_or_fallback(app_id: u32) -> Option<steamworks::Client> {
+    if settings::get().no_client || settings::get().anonymous {
+        return None;
+    }
+
+    match STEAM_MANAGER.initialize_client(app_id).await {
+        Ok(client) => Some(client),
+        Err(e) => {
+            tracing::debug!("Falling back to the Steam Web API: {}", e);
+            None
+        }
+    }
+}
+
+/// Retries Steamworks initialization until the client accepts it, for up to
+/// `timeouts.launch`.
+async fn wait_for_client(app_id: u32) -> Result<(Client, SingleClient), S7forgeError> {
+    let deadline = Instant::now() + settings::get().launch_timeout;
+    loop {
+        match Client::init_app(app_id) {
+            Ok(clients) => return Ok(clients),
+            Err(_) if Instant::now() < deadline => {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            Err(_) => return Err(S7forgeError::Timeout("Steam launch")),
+        }
+    }
+}
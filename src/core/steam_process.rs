@@ -0,0 +1,123 @@
+//! The Steam client as a process: finding, launching, shutting down and killing it, without
+//! going through Steamworks.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::core::error::S7forgeError;
+use crate::core::steam_install_paths::steam_install_paths;
+
+fn executable() -> Result<PathBuf, S7forgeError> {
+    if cfg!(target_os = "macos") {
+        // Not on the PATH; the binary inside the app bundle takes the same arguments
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        return [
+            Some(PathBuf::from("/Applications")),
+            home.map(|home| home.join("Applications")),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|dir| dir.join("Steam.app/Contents/MacOS/steam_osx"))
+        .find(|executable| executable.is_file())
+        .ok_or_else(|| {
+            S7forgeError::NotFound(
+                "Steam.app not found in /Applications or ~/Applications; is Steam installed?"
+                    .to_string(),
+            )
+        });
+    }
+    if !cfg!(windows) {
+        // Installed on the PATH by the distribution's package
+        return Ok(PathBuf::from("steam"));
+    }
+    steam_install_paths()?
+        .into_iter()
+        .map(|path| PathBuf::from(path).join("steam.exe"))
+        .find(|executable| executable.is_file())
+        .ok_or_else(|| {
+            S7forgeError::NotFound("Steam executable not found; is Steam installed?".to_string())
+        })
+}
+
+// The launched process hands the request to a running client, if any, and exits
+fn run(arg: &str) -> Result<(), S7forgeError> {
+    let executable = executable()?;
+    Command::new(&executable)
+        .arg(arg)
+        .spawn()
+        .map_err(|e| S7forgeError::io(format!("Failed to launch {}", executable.display()), e))?;
+    Ok(())
+}
+
+/// Starts the Steam client in the background. It takes a while until it is signed in and
+/// Steamworks can initialize.
+pub fn launch() -> Result<(), S7forgeError> {
+    tracing::info!("Launching Steam");
+    run("-silent")
+}
+
+/// Asks the running client to exit, like `steam://exit`.
+pub fn shutdown() -> Result<(), S7forgeError> {
+    tracing::info!("Shutting down Steam");
+    run("-shutdown")
+}
+
+/// Process ID of the running Steam client, as recorded by Steam itself.
+#[cfg(windows)]
+pub fn pid() -> Option<u32> {
+    use winreg::RegKey;
+    use winreg::enums::HKEY_CURRENT_USER;
+
+    let pid: u32 = RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey("SOFTWARE\\Valve\\Steam\\ActiveProcess")
+        .and_then(|key| key.get_value("pid"))
+        .ok()
+        .filter(|pid| *pid != 0)?;
+    // The value outlives a crashed client
+    let tasks = Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH", "/FO", "CSV"])
+        .output()
+        .ok()?;
+    String::from_utf8_lossy(&tasks.stdout)
+        .to_ascii_lowercase()
+        .contains("\"steam.exe\"")
+        .then_some(pid)
+}
+
+/// Process ID of the running Steam client, as recorded by Steam itself.
+#[cfg(not(windows))]
+pub fn pid() -> Option<u32> {
+    let home = PathBuf::from(std::env::var_os("HOME")?);
+    let pid: u32 = std::fs::read_to_string(home.join(".steam").join("steam.pid"))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    // The file outlives a crashed client
+    Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .output()
+        .is_ok_and(|output| output.status.success())
+        .then_some(pid)
+}
+
+/// Ends the client with a signal, for when it ignores [`shutdown`].
+pub fn kill(pid: u32) -> Result<(), S7forgeError> {
+    tracing::warn!("Steam didn't exit in time, killing process {}", pid);
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("taskkill");
+        command.args(["/PID", &pid.to_string(), "/F"]);
+        command
+    } else {
+        let mut command = Command::new("kill");
+        command.args(["-TERM", &pid.to_string()]);
+        command
+    };
+    let status = command
+        .status()
+        .map_err(|e| S7forgeError::io(format!("Failed to kill Steam process {}", pid), e))?;
+    if !status.success() {
+        return Err(format!("Failed to kill Steam process {}: {}", pid, status).into());
+    }
+    Ok(())
+}
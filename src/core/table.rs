@@ -0,0 +1,269 @@
+//! `--output table`: command output drawn as aligned columns for reading at a terminal.
+//!
+//! Lists of objects become tables, maps keyed by item ID become tables with an `id` column and
+//! other objects are printed as `key  value` lines followed by their nested lists. Rows that
+//! carry install or result flags get a colored `status` column. Colors are left out when stdout
+//! isn't a terminal or `NO_COLOR` is set.
+
+use console::{Alignment, Color, Style, pad_str, truncate_str};
+use serde_json::{Map, Value};
+
+/// Columns of workshop items; the full item has more fields than fit on a line
+const ITEM_COLUMNS: &[&str] = &[
+    "published_file_id",
+    "title",
+    "creator_name",
+    "file_size",
+    "time_updated",
+    "num_upvotes",
+    "tags",
+    "description",
+];
+
+const MAX_WIDTH: usize = 40;
+const MAX_DESCRIPTION_WIDTH: usize = 60;
+const SEPARATOR: &str = "  ";
+
+pub fn render(value: &Value) -> String {
+    let mut lines = Vec::new();
+    render_into(value, &mut lines);
+    lines.join("\n")
+}
+
+fn render_into(value: &Value, lines: &mut Vec<String>) {
+    match value {
+        Value::Array(values) if values.is_empty() => lines.push(dim("(none)")),
+        Value::Array(values) => {
+            let rows: Vec<(Option<&str>, &Map<String, Value>)> = values
+                .iter()
+                .filter_map(Value::as_object)
+                .map(|row| (None, row))
+                .collect();
+            if rows.len() == values.len() {
+                table(&rows, lines);
+            } else {
+                lines.extend(values.iter().map(cell));
+            }
+        }
+        Value::Object(map) if !map.is_empty() && map.values().all(Value::is_object) => {
+            let rows: Vec<(Option<&str>, &Map<String, Value>)> = map
+                .iter()
+                .filter_map(|(key, row)| Some((Some(key.as_str()), row.as_object()?)))
+                .collect();
+            table(&rows, lines);
+        }
+        Value::Object(map) => {
+            let (scalars, nested): (Vec<_>, Vec<_>) =
+                map.iter().partition(|(_, field)| is_cell(field));
+            let key_width = scalars.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+            for (key, field) in scalars {
+                lines.push(format!(
+                    "{}{}{}",
+                    Style::new()
+                        .bold()
+                        .apply_to(pad_str(key, key_width, Alignment::Left, None)),
+                    SEPARATOR,
+                    cell(field)
+                ));
+            }
+            for (key, field) in nested {
+                if !lines.is_empty() {
+                    lines.push(String::new());
+                }
+                lines.push(
+                    Style::new()
+                        .bold()
+                        .apply_to(format!("{}:", key))
+                        .to_string(),
+                );
+                render_into(field, lines);
+            }
+        }
+        scalar => lines.push(cell(scalar)),
+    }
+}
+
+/// Rows are drawn with their key, if any, as the first column.
+fn table(rows: &[(Option<&str>, &Map<String, Value>)], lines: &mut Vec<String>) {
+    let is_item = rows
+        .iter()
+        .all(|(_, row)| row.contains_key("published_file_id") && row.contains_key("title"));
+    let mut columns: Vec<&str> = Vec::new();
+    if is_item {
+        columns.extend(
+            ITEM_COLUMNS
+                .iter()
+                .filter(|column| rows.iter().any(|(_, row)| row.contains_key(**column))),
+        );
+    } else {
+        for (_, row) in rows {
+            for (key, field) in row.iter() {
+                if is_cell(field) && !columns.contains(&key.as_str()) {
+                    columns.push(key);
+                }
+            }
+        }
+    }
+
+    let statuses: Vec<Option<(&str, Color)>> = rows.iter().map(|(_, row)| status(row)).collect();
+    let has_keys = rows.iter().any(|(key, _)| key.is_some());
+    let has_status = statuses.iter().any(Option::is_some);
+
+    let mut header = Vec::new();
+    if has_keys {
+        header.push("id");
+    }
+    if has_status {
+        header.push("status");
+    }
+    header.extend(&columns);
+
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .zip(&statuses)
+        .map(|((key, row), status)| {
+            let mut cells = Vec::new();
+            if has_keys {
+                cells.push(key.unwrap_or_default().to_string());
+            }
+            if has_status {
+                cells.push(status.map(|(text, _)| text).unwrap_or_default().to_string());
+            }
+            cells.extend(
+                columns
+                    .iter()
+                    .map(|column| row.get(*column).map(cell).unwrap_or_default()),
+            );
+            cells
+        })
+        .collect();
+
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let limit = if *name == "description" {
+                MAX_DESCRIPTION_WIDTH
+            } else {
+                MAX_WIDTH
+            };
+            cells
+                .iter()
+                .map(|row| console::measure_text_width(&row[index]))
+                .max()
+                .unwrap_or(0)
+                .min(limit)
+                .max(name.len())
+        })
+        .collect();
+    // Numbers line up on the right
+    let right_aligned: Vec<bool> = (0..header.len())
+        .map(|index| {
+            cells.iter().all(|row| {
+                row[index].is_empty() || row[index].chars().all(|c| c.is_ascii_digit() || c == '.')
+            })
+        })
+        .collect();
+
+    let header_style = Style::new().bold().underlined();
+    lines.push(join_row(
+        header
+            .iter()
+            .zip(&widths)
+            .map(|(name, width)| {
+                header_style
+                    .apply_to(pad_str(name, *width, Alignment::Left, None))
+                    .to_string()
+            })
+            .collect(),
+    ));
+
+    let status_column = has_keys as usize;
+    for (row, status) in cells.iter().zip(&statuses) {
+        let row = row
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                let alignment = if right_aligned[index] {
+                    Alignment::Right
+                } else {
+                    Alignment::Left
+                };
+                let text = if console::measure_text_width(text) > widths[index] {
+                    truncate_str(text, widths[index], "…")
+                } else {
+                    text.into()
+                };
+                let padded = pad_str(&text, widths[index], alignment, None).to_string();
+                match status {
+                    Some((_, color)) if has_status && index == status_column => {
+                        Style::new().fg(*color).apply_to(padded).to_string()
+                    }
+                    _ => padded,
+                }
+            })
+            .collect();
+        lines.push(join_row(row));
+    }
+}
+
+fn join_row(cells: Vec<String>) -> String {
+    cells.join(SEPARATOR).trim_end().to_string()
+}
+
+/// What the row's flags say about the item, most important first.
+fn status(row: &Map<String, Value>) -> Option<(&'static str, Color)> {
+    let flag = |key: &str| row.get(key).and_then(Value::as_bool);
+
+    if flag("deleted") == Some(true) {
+        Some(("deleted", Color::Red))
+    } else if flag("banned") == Some(true) {
+        Some(("banned", Color::Red))
+    } else if flag("success") == Some(false) || flag("ok") == Some(false) {
+        Some(("failed", Color::Red))
+    } else if flag("needs_update") == Some(true)
+        || row
+            .get("latest_time_updated")
+            .is_some_and(|time| !time.is_null())
+    {
+        Some(("needs update", Color::Yellow))
+    } else if flag("downloading") == Some(true) || flag("download_pending") == Some(true) {
+        Some(("downloading", Color::Cyan))
+    } else if flag("installed") == Some(true) || row.contains_key("size_on_disk") {
+        // installed-items rows are installed by definition
+        Some(("installed", Color::Green))
+    } else if flag("installed") == Some(false) {
+        Some(("not installed", Color::White))
+    } else if flag("success") == Some(true) || flag("ok") == Some(true) {
+        Some(("ok", Color::Green))
+    } else {
+        None
+    }
+}
+
+// Values that fit in one cell: scalars and lists of scalars
+fn is_cell(value: &Value) -> bool {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .all(|value| is_cell(value) && !value.is_array()),
+        Value::Object(_) => false,
+        _ => true,
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::Bool(true) => "yes".to_string(),
+        Value::Bool(false) => "no".to_string(),
+        // Descriptions and notes span lines; a cell has one
+        Value::String(text) => text.split_whitespace().collect::<Vec<_>>().join(" "),
+        Value::Array(values) => values.iter().map(cell).collect::<Vec<_>>().join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn dim(text: &str) -> String {
+    Style::new().dim().apply_to(text).to_string()
+}
@@ -0,0 +1,446 @@
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::core::workshop_item::workshop::{WorkshopItem, WorkshopItemsResult};
+
+const API_BASE: &str = "https://api.steampowered.com";
+const COMMUNITY_BASE: &str = "https://steamcommunity.com";
+
+pub fn json_u64(value: &Value, key: &str) -> Option<u64> {
+    match value.get(key)? {
+        Value::Number(number) => number.as_u64(),
+        Value::String(text) => text.parse().ok(),
+        Value::Bool(flag) => Some(*flag as u64),
+        _ => None,
+    }
+}
+
+pub fn json_str(value: &Value, key: &str) -> Option<String> {
+    value.get(key)?.as_str().map(|text| text.to_string())
+}
+
+pub fn json_bool(value: &Value, key: &str) -> Option<bool> {
+    match value.get(key)? {
+        Value::Bool(flag) => Some(*flag),
+        Value::Number(number) => number.as_u64().map(|n| n != 0),
+        _ => None,
+    }
+}
+
+fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .timeout(settings::get().web_timeout)
+        .build()
+}
+
+fn require_api_key() -> Result<String, S7forgeError> {
+    settings::get().web_api_key.clone().ok_or_else(|| {
+        S7forgeError::Config(
+            "This request requires a Steam Web API key (--web-api-key or STEAM_WEB_API_KEY)"
+                .to_string(),
+        )
+    })
+}
+
+// ureq's error messages start with the request URL, and the URL of a GET request carries
+// the API key, so only the status or the kind of failure is kept
+fn request_error(error: ureq::Error) -> S7forgeError {
+    let cause = match error {
+        ureq::Error::Status(code, response) => {
+            format!("status code {} {}", code, response.status_text())
+        }
+        ureq::Error::Transport(transport) => match std::error::Error::source(&transport) {
+            Some(source) => format!("{}: {}", transport.kind(), source),
+            None => transport.kind().to_string(),
+        },
+    };
+    S7forgeError::http("Steam Web API request failed", cause)
+}
+
+#[tracing::instrument(level = "debug", skip(form))]
+fn post_form(path: &str, form: &[(String, String)]) -> Result<Value, S7forgeError> {
+    let form: Vec<(&str, &str)> = form
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+
+    agent()
+        .post(&format!("{}/{}", API_BASE, path))
+        .send_form(&form)
+        .map_err(request_error)?
+        .into_json::<Value>()
+        .map_err(|e| S7forgeError::http("Failed to parse Steam Web API response", e))
+}
+
+#[tracing::instrument(level = "debug", skip(query))]
+fn get_query(path: &str, query: &[(String, String)]) -> Result<Value, S7forgeError> {
+    let mut request = agent().get(&format!("{}/{}", API_BASE, path));
+    for (key, value) in query {
+        request = request.query(key, value);
+    }
+
+    request
+        .call()
+        .map_err(request_error)?
+        .into_json::<Value>()
+        .map_err(|e| S7forgeError::http("Failed to parse Steam Web API response", e))
+}
+
+/// `ISteamRemoteStorage/GetPublishedFileDetails` - works without an API key.
+pub fn published_file_details(item_ids: &[u64]) -> Result<Vec<WorkshopItem>, S7forgeError> {
+    if item_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut form = vec![("itemcount".to_string(), item_ids.len().to_string())];
+    for (index, id) in item_ids.iter().enumerate() {
+        form.push((format!("publishedfileids[{}]", index), id.to_string()));
+    }
+
+    let response = post_form("ISteamRemoteStorage/GetPublishedFileDetails/v1/", &form)?;
+
+    Ok(response
+        .pointer("/response/publishedfiledetails")
+        .and_then(|details| details.as_array())
+        .map(|details| {
+            details
+                .iter()
+                .filter_map(WorkshopItem::from_web_api)
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// `IPublishedFileService/GetDetails` - requires a Web API key. Returns the raw detail
+/// objects; `options` switches on extra data, e.g. `("includeadditionalpreviews", "true")`.
+pub fn file_details(
+    item_ids: &[u64],
+    options: &[(&str, &str)],
+) -> Result<Vec<Value>, S7forgeError> {
+    let mut details = Vec::new();
+
+    for chunk in item_ids.chunks(100) {
+        let mut query = vec![("key".to_string(), require_api_key()?)];
+        for (index, id) in chunk.iter().enumerate() {
+            query.push((format!("publishedfileids[{}]", index), id.to_string()));
+        }
+        for (key, value) in options {
+            query.push((key.to_string(), value.to_string()));
+        }
+
+        let response = get_query("IPublishedFileService/GetDetails/v1/", &query)?;
+        if let Some(chunk_details) = response
+            .pointer("/response/publishedfiledetails")
+            .and_then(|details| details.as_array())
+        {
+            details.extend(
+                chunk_details
+                    .iter()
+                    .filter(|detail| json_u64(detail, "result").is_none_or(|result| result == 1))
+                    .cloned(),
+            );
+        }
+    }
+
+    Ok(details)
+}
+
+/// `IPublishedFileService/GetChangeHistory` - requires a Web API key. Returns the raw change
+/// entries, newest first, and the total number of entries the item has.
+pub fn change_history(item_id: u64, count: u32) -> Result<(Vec<Value>, u64), S7forgeError> {
+    let query = vec![
+        ("key".to_string(), require_api_key()?),
+        ("publishedfileid".to_string(), item_id.to_string()),
+        ("startindex".to_string(), "0".to_string()),
+        ("count".to_string(), count.to_string()),
+    ];
+
+    let response = get_query("IPublishedFileService/GetChangeHistory/v1/", &query)?;
+    let response = response.get("response").cloned().unwrap_or_default();
+    let changes = response
+        .get("changes")
+        .and_then(|changes| changes.as_array())
+        .cloned()
+        .unwrap_or_default();
+    let total = json_u64(&response, "total").unwrap_or(changes.len() as u64);
+
+    Ok((changes, total))
+}
+
+/// `ISteamRemoteStorage/GetCollectionDetails` - returns child item IDs in collection order.
+pub fn collection_children(collection_id: u64) -> Result<Vec<u64>, S7forgeError> {
+    let form = vec![
+        ("collectioncount".to_string(), "1".to_string()),
+        ("publishedfileids[0]".to_string(), collection_id.to_string()),
+    ];
+
+    let response = post_form("ISteamRemoteStorage/GetCollectionDetails/v1/", &form)?;
+    let collection = response
+        .pointer("/response/collectiondetails/0")
+        .ok_or_else(|| S7forgeError::NotFound("Collection not found".to_string()))?;
+
+    if json_u64(collection, "result") != Some(1) {
+        return Err(S7forgeError::NotFound("Collection not found".to_string()));
+    }
+
+    let mut children: Vec<(u64, u64)> = collection
+        .get("children")
+        .and_then(|children| children.as_array())
+        .map(|children| {
+            children
+                .iter()
+                .filter_map(|child| {
+                    Some((
+                        json_u64(child, "sortorder").unwrap_or_default(),
+                        json_u64(child, "publishedfileid")?,
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    children.sort_by_key(|(sort_order, _)| *sort_order);
+
+    Ok(children.into_iter().map(|(_, id)| id).collect())
+}
+
+pub struct QueryFilesRequest<'a> {
+    pub app_id: u32,
+    pub query_type: u32,
+    pub page: u32,
+    /// Replaces `page` when set; "*" starts from the first result
+    pub cursor: Option<&'a str>,
+    pub search_text: &'a str,
+    pub tags: &'a [String],
+    pub match_all_tags: bool,
+    pub kv_tags: &'a [(String, String)],
+    pub with_kv_tags: bool,
+    /// ELanguage value for translated titles and descriptions
+    pub language: Option<u32>,
+    pub trend_days: Option<u32>,
+}
+
+/// `IPublishedFileService/QueryFiles` - requires a Web API key.
+pub fn query_files(request: &QueryFilesRequest) -> Result<WorkshopItemsResult, S7forgeError> {
+    let mut query = vec![
+        ("key".to_string(), require_api_key()?),
+        ("appid".to_string(), request.app_id.to_string()),
+        ("query_type".to_string(), request.query_type.to_string()),
+        ("numperpage".to_string(), "50".to_string()),
+        ("filetype".to_string(), "0".to_string()),
+        ("return_vote_data".to_string(), "true".to_string()),
+        ("return_tags".to_string(), "true".to_string()),
+        ("return_children".to_string(), "true".to_string()),
+        ("return_previews".to_string(), "true".to_string()),
+        ("return_metadata".to_string(), "true".to_string()),
+    ];
+
+    // Paged queries stop at Steam's indexed page limit, cursors can walk the whole catalogue
+    match request.cursor {
+        Some(cursor) => query.push(("cursor".to_string(), cursor.to_string())),
+        None => query.push(("page".to_string(), request.page.to_string())),
+    }
+    if !request.search_text.trim().is_empty() {
+        query.push(("search_text".to_string(), request.search_text.to_string()));
+    }
+    if let Some(days) = request.trend_days {
+        query.push(("days".to_string(), days.to_string()));
+    }
+    for (index, tag) in request.tags.iter().enumerate() {
+        query.push((format!("requiredtags[{}]", index), tag.clone()));
+    }
+    if !request.tags.is_empty() {
+        query.push((
+            "match_all_tags".to_string(),
+            request.match_all_tags.to_string(),
+        ));
+    }
+    for (index, (key, value)) in request.kv_tags.iter().enumerate() {
+        query.push((format!("required_kv_tags[{}][key]", index), key.clone()));
+        query.push((format!("required_kv_tags[{}][value]", index), value.clone()));
+    }
+    if request.with_kv_tags {
+        query.push(("return_kv_tags".to_string(), "true".to_string()));
+    }
+    if let Some(language) = request.language {
+        query.push(("language".to_string(), language.to_string()));
+        query.push(("return_short_description".to_string(), "false".to_string()));
+    }
+
+    let response = get_query("IPublishedFileService/QueryFiles/v1/", &query)?;
+
+    Ok(WorkshopItemsResult {
+        items: response
+            .pointer("/response/publishedfiledetails")
+            .and_then(|details| details.as_array())
+            .map(|details| {
+                details
+                    .iter()
+                    .filter_map(WorkshopItem::from_web_api)
+                    .map(Some)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        total_results: response
+            .get("response")
+            .and_then(|response| json_u64(response, "total"))
+            .and_then(|total| u32::try_from(total).ok())
+            .unwrap_or_default(),
+        was_cached: false,
+        next_cursor: response
+            .get("response")
+            .and_then(|response| json_str(response, "next_cursor")),
+    })
+}
+
+/// `ISteamUser/GetPlayerSummaries` - requires a Web API key, so without one the
+/// result is simply empty and callers fall back to "[unknown]".
+pub fn player_names(steam_ids: &[u64]) -> Result<FxHashMap<u64, String>, S7forgeError> {
+    let mut names = FxHashMap::default();
+    let Some(key) = settings::get().web_api_key.clone() else {
+        return Ok(names);
+    };
+
+    for chunk in steam_ids.chunks(100) {
+        let ids = chunk
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        let query = vec![
+            ("key".to_string(), key.clone()),
+            ("steamids".to_string(), ids),
+        ];
+        let response = get_query("ISteamUser/GetPlayerSummaries/v2/", &query)?;
+
+        if let Some(players) = response
+            .pointer("/response/players")
+            .and_then(|players| players.as_array())
+        {
+            for player in players {
+                if let (Some(id), Some(name)) =
+                    (json_u64(player, "steamid"), json_str(player, "personaname"))
+                {
+                    names.insert(id, name);
+                }
+            }
+        }
+    }
+
+    Ok(names)
+}
+
+#[derive(Debug, Default)]
+pub struct PlayerSummary {
+    pub persona_name: Option<String>,
+    pub profile_url: Option<String>,
+    pub avatar: Option<String>,
+    pub avatar_medium: Option<String>,
+    pub avatar_full: Option<String>,
+}
+
+/// Profile and avatar URLs for one account. Uses `ISteamUser/GetPlayerSummaries` when a key is
+/// configured, otherwise the public community profile XML (which needs no key).
+#[tracing::instrument(level = "debug")]
+pub fn player_summary(steam_id: u64) -> Result<Option<PlayerSummary>, S7forgeError> {
+    if let Some(key) = settings::get().web_api_key.clone() {
+        let query = vec![
+            ("key".to_string(), key),
+            ("steamids".to_string(), steam_id.to_string()),
+        ];
+        let response = get_query("ISteamUser/GetPlayerSummaries/v2/", &query)?;
+
+        return Ok(response
+            .pointer("/response/players/0")
+            .map(|player| PlayerSummary {
+                persona_name: json_str(player, "personaname"),
+                profile_url: json_str(player, "profileurl"),
+                avatar: json_str(player, "avatar"),
+                avatar_medium: json_str(player, "avatarmedium"),
+                avatar_full: json_str(player, "avatarfull"),
+            }));
+    }
+
+    let body = agent()
+        .get(&format!("{}/profiles/{}/", COMMUNITY_BASE, steam_id))
+        .query("xml", "1")
+        .call()
+        .map_err(|e| S7forgeError::http("Steam community request failed", e))?
+        .into_string()
+        .map_err(|e| S7forgeError::http("Failed to read Steam community response", e))?;
+
+    if xml_tag(&body, "steamID64").is_none() {
+        return Ok(None);
+    }
+
+    let custom_url = xml_tag(&body, "customURL").filter(|url| !url.is_empty());
+    Ok(Some(PlayerSummary {
+        persona_name: xml_tag(&body, "steamID"),
+        profile_url: Some(match custom_url {
+            Some(custom) => format!("{}/id/{}/", COMMUNITY_BASE, custom),
+            None => format!("{}/profiles/{}/", COMMUNITY_BASE, steam_id),
+        }),
+        avatar: xml_tag(&body, "avatarIcon"),
+        avatar_medium: xml_tag(&body, "avatarMedium"),
+        avatar_full: xml_tag(&body, "avatarFull"),
+    }))
+}
+
+/// One page of an item's comment thread from the community site's comment renderer, which
+/// needs no API key. Comments come back as rendered HTML in `comments_html`, newest first.
+#[tracing::instrument(level = "debug")]
+pub fn item_comments_page(
+    owner_id: u64,
+    item_id: u64,
+    start: u32,
+    count: u32,
+) -> Result<Value, S7forgeError> {
+    let response = agent()
+        .post(&format!(
+            "{}/comment/PublishedFile_Public/render/{}/{}/",
+            COMMUNITY_BASE, owner_id, item_id
+        ))
+        .send_form(&[
+            ("start", start.to_string().as_str()),
+            ("count", count.to_string().as_str()),
+        ])
+        .map_err(|e| S7forgeError::http("Steam community request failed", e))?
+        .into_json::<Value>()
+        .map_err(|e| S7forgeError::http("Failed to parse Steam community response", e))?;
+
+    if json_bool(&response, "success") != Some(true) {
+        return Err(S7forgeError::NotFound(format!(
+            "Comments of item {} are not available",
+            item_id
+        )));
+    }
+    Ok(response)
+}
+
+/// The HTML of an item's community page, for details no API returns.
+#[tracing::instrument(level = "debug")]
+pub fn item_page(item_id: u64) -> Result<String, S7forgeError> {
+    agent()
+        .get(&format!("{}/sharedfiles/filedetails/", COMMUNITY_BASE))
+        .query("id", &item_id.to_string())
+        .call()
+        .map_err(|e| S7forgeError::http("Steam community request failed", e))?
+        .into_string()
+        .map_err(|e| S7forgeError::http("Failed to read Steam community response", e))
+}
+
+// The community profile XML is flat and small, so a tag lookup is all that's needed
+fn xml_tag(body: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = body.find(&open)? + open.len();
+    let end = start + body[start..].find(&close)?;
+    let value = body[start..end].trim();
+    let value = value
+        .strip_prefix("<![CDATA[")
+        .and_then(|inner| inner.strip_suffix("]]>"))
+        .unwrap_or(value);
+
+    Some(value.trim().to_string())
+}
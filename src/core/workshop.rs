@@ -23,9 +23,10 @@
 // Modified by Burak Kartal on [24/06/2025]
 
 use bincode::{Decode, Encode};
-use serde::Serialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Encode, Decode)]
+#[derive(Debug, Clone, Serialize, JsonSchema, Deserialize, Encode, Decode)]
 pub enum UgcItemVisibility {
     Public,
     FriendsOnly,
@@ -33,6 +34,11 @@ pub enum UgcItemVisibility {
     Unlisted,
 }
 
+impl UgcItemVisibility {
+    /// Values accepted by `--visibility`; "private" is also taken for hidden
+    pub const NAMES: [&'static str; 4] = ["public", "friends-only", "hidden", "unlisted"];
+}
+
 impl From<steamworks::PublishedFileVisibility> for UgcItemVisibility {
     fn from(visibility: steamworks::PublishedFileVisibility) -> Self {
         match visibility {
@@ -44,6 +50,24 @@ impl From<steamworks::PublishedFileVisibility> for UgcItemVisibility {
     }
 }
 
+impl std::str::FromStr for UgcItemVisibility {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "public" => Ok(UgcItemVisibility::Public),
+            "friends-only" => Ok(UgcItemVisibility::FriendsOnly),
+            "private" | "hidden" => Ok(UgcItemVisibility::Private),
+            "unlisted" => Ok(UgcItemVisibility::Unlisted),
+            _ => Err(format!(
+                "Invalid visibility: {} (expected {})",
+                value,
+                UgcItemVisibility::NAMES.join(", ")
+            )),
+        }
+    }
+}
+
 impl From<UgcItemVisibility> for steamworks::PublishedFileVisibility {
     fn from(val: UgcItemVisibility) -> Self {
         match val {
@@ -55,7 +79,7 @@ impl From<UgcItemVisibility> for steamworks::PublishedFileVisibility {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum UpdateStatus {
     Invalid,
     PreparingConfig,
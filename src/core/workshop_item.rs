@@ -110,15 +110,26 @@ fn is_filtered_tag(tag: &str) -> bool {
     excluded_tags.contains(tag) || is_version_tag(tag)
 }
 
+fn format_tags<S: AsRef<str>>(tags: &[S]) -> String {
+    tags.iter()
+        .map(|tag| tag.as_ref())
+        .filter(|tag| !is_filtered_tag(tag))
+        .map(|tag| capitalize(tag.to_string()).replace("Ui", "UI"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 pub mod workshop {
     use bincode::{Decode, Encode};
-    use serde::Serialize;
-    use steamworks::FileType;
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+    use steamworks::{FileType, SteamId};
 
     use crate::core::localplayer::PlayerSteamId;
+    use crate::core::web_api::{json_bool, json_str, json_u64};
     use crate::core::workshop::UgcItemVisibility;
 
-    use super::{capitalize, is_filtered_tag};
+    use super::format_tags;
 
     pub enum UGCQueryType {
         RankedByVote,
@@ -289,7 +300,7 @@ pub mod workshop {
             }
         }
     }
-    #[derive(Debug, Clone, Serialize, Encode, Decode)]
+    #[derive(Debug, Clone, Serialize, JsonSchema, Deserialize, Encode, Decode)]
     pub struct WorkshopItemStatistic {
         pub num_subscriptions: Option<u64>, //   0	gets the number of subscriptions.
         pub num_favorites: Option<u64>,     //   1	gets the number of favorites.
@@ -337,8 +348,32 @@ pub mod workshop {
                 ),
             }
         }
+
+        fn from_web_api(details: &serde_json::Value) -> Self {
+            Self {
+                num_subscriptions: json_u64(details, "subscriptions"),
+                num_favorites: json_u64(details, "favorited"),
+                num_followers: json_u64(details, "followers"),
+                num_unique_subscriptions: json_u64(details, "lifetime_subscriptions"),
+                num_unique_favorites: json_u64(details, "lifetime_favorited"),
+                num_unique_followers: json_u64(details, "lifetime_followers"),
+                num_unique_website_views: json_u64(details, "views"),
+                report_score: None,
+                num_seconds_played: json_u64(details, "lifetime_playtime"),
+                num_playtime_sessions: json_u64(details, "lifetime_playtime_sessions"),
+                num_comments: json_u64(details, "num_comments_public"),
+                num_seconds_played_during_time_period: None,
+                num_playtime_sessions_during_time_period: None,
+            }
+        }
+    }
+    #[derive(Debug, Clone, Serialize, JsonSchema, Deserialize, Encode, Decode)]
+    pub struct KeyValueTag {
+        pub key: String,
+        pub value: String,
     }
-    #[derive(Debug, Clone, Serialize, Encode, Decode)]
+
+    #[derive(Debug, Clone, Serialize, JsonSchema, Deserialize, Encode, Decode)]
     pub struct WorkshopItem {
         pub published_file_id: u64,
         pub creator_steam_game_id: Option<u32>,
@@ -357,12 +392,18 @@ pub mod workshop {
         pub url: String,
         pub num_upvotes: u32,
         pub num_downvotes: u32,
+        /// Steam's vote score between 0 and 1, used by the top-rated ranking
+        pub score: f32,
         pub num_children: u32,
         pub preview_url: Option<String>,
         pub statistics: WorkshopItemStatistic,
         pub required_items: Vec<u64>,
         pub file_type: String,
-        pub file_size: u32,
+        /// Bytes; the Web API reports sizes past 4 GiB that the Steamworks query can't
+        pub file_size: u64,
+        /// Only present when the query asked Steam to return key/value tags
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub key_value_tags: Option<Vec<KeyValueTag>>,
     }
 
     impl WorkshopItem {
@@ -412,33 +453,155 @@ pub mod workshop {
                     visibility: item.visibility.into(),
                     banned: item.banned,
                     accepted_for_use: item.accepted_for_use,
-                    tags: item
-                        .tags
-                        .iter()
-                        .filter(|tag| !is_filtered_tag(tag))
-                        .map(|tag| capitalize(tag.clone()).replace("Ui", "UI"))
-                        .collect::<Vec<String>>()
-                        .join(", "),
+                    tags: format_tags(&item.tags),
                     tags_truncated: item.tags_truncated,
                     url: item.url,
                     num_upvotes: item.num_upvotes,
                     num_downvotes: item.num_downvotes,
+                    score: item.score,
                     num_children: item.num_children,
                     preview_url: results.preview_url(index),
                     statistics: WorkshopItemStatistic::from_query_results(results, index),
                     required_items,
                     file_type: file_type.to_string(),
-                    file_size: item.file_size,
+                    file_size: u64::from(item.file_size),
+                    key_value_tags: Some(results.key_value_tags(index))
+                        .filter(|tags| !tags.is_empty())
+                        .map(|tags| {
+                            tags.into_iter()
+                                .map(|(key, value)| KeyValueTag { key, value })
+                                .collect()
+                        }),
                 }
             })
         }
+
+        /// Builds an item from a `GetPublishedFileDetails`/`QueryFiles` Web API entry,
+        /// producing the same shape as the Steamworks query path.
+        pub fn from_web_api(details: &serde_json::Value) -> Option<Self> {
+            if json_u64(details, "result").is_some_and(|result| result != 1) {
+                return None;
+            }
+
+            let published_file_id = json_u64(details, "publishedfileid")?;
+            let owner = SteamId::from_raw(json_u64(details, "creator").unwrap_or_default());
+
+            let tags: Vec<String> = details
+                .get("tags")
+                .and_then(|tags| tags.as_array())
+                .map(|tags| {
+                    tags.iter()
+                        .filter_map(|tag| {
+                            json_str(tag, "tag").or_else(|| json_str(tag, "display_name"))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let required_items: Vec<u64> = details
+                .get("children")
+                .and_then(|children| children.as_array())
+                .map(|children| {
+                    children
+                        .iter()
+                        .filter_map(|child| json_u64(child, "publishedfileid"))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let file_type = match json_u64(details, "file_type").unwrap_or(0) {
+                0 => "Community",
+                1 => "Microtransaction",
+                2 => "Collection",
+                3 => "Art",
+                4 => "Video",
+                5 => "Screenshot",
+                6 => "Game",
+                7 => "Software",
+                8 => "Concept",
+                9 => "WebGuide",
+                10 => "IntegratedGuide",
+                11 => "Merch",
+                12 => "ControllerBinding",
+                13 => "SteamworksAccessInvite",
+                14 => "SteamVideo",
+                _ => "GameManagedItem",
+            };
+
+            let visibility = match json_u64(details, "visibility").unwrap_or(0) {
+                1 => UgcItemVisibility::FriendsOnly,
+                2 => UgcItemVisibility::Private,
+                3 => UgcItemVisibility::Unlisted,
+                _ => UgcItemVisibility::Public,
+            };
+
+            let vote_data = details.get("vote_data");
+
+            Some(Self {
+                published_file_id,
+                creator_steam_game_id: json_u64(details, "creator_app_id").map(|id| id as u32),
+                consumer_steam_game_id: json_u64(details, "consumer_app_id").map(|id| id as u32),
+                title: json_str(details, "title").unwrap_or_default(),
+                description: json_str(details, "description")
+                    .or_else(|| json_str(details, "file_description"))
+                    .unwrap_or_default(),
+                owner: PlayerSteamId::from_steamid(owner),
+                time_created: json_u64(details, "time_created")
+                    .unwrap_or_default()
+                    .saturating_mul(1000),
+                time_updated: json_u64(details, "time_updated")
+                    .unwrap_or_default()
+                    .saturating_mul(1000),
+                time_added_to_user_list: 0,
+                visibility,
+                banned: json_bool(details, "banned").unwrap_or(false),
+                accepted_for_use: json_bool(details, "can_subscribe").unwrap_or(true),
+                tags: format_tags(&tags),
+                tags_truncated: false,
+                url: json_str(details, "url").unwrap_or_default(),
+                num_upvotes: vote_data
+                    .and_then(|votes| json_u64(votes, "votes_up"))
+                    .unwrap_or_default() as u32,
+                num_downvotes: vote_data
+                    .and_then(|votes| json_u64(votes, "votes_down"))
+                    .unwrap_or_default() as u32,
+                score: vote_data
+                    .and_then(|votes| votes.get("score"))
+                    .and_then(|score| score.as_f64())
+                    .unwrap_or_default() as f32,
+                num_children: json_u64(details, "num_children")
+                    .unwrap_or(required_items.len() as u64) as u32,
+                preview_url: json_str(details, "preview_url").filter(|url| !url.is_empty()),
+                statistics: WorkshopItemStatistic::from_web_api(details),
+                required_items,
+                file_type: file_type.to_string(),
+                file_size: json_u64(details, "file_size").unwrap_or_default(),
+                key_value_tags: details
+                    .get("kvtags")
+                    .and_then(|tags| tags.as_array())
+                    .map(|tags| {
+                        tags.iter()
+                            .filter_map(|tag| {
+                                Some(KeyValueTag {
+                                    key: json_str(tag, "key")?,
+                                    value: json_str(tag, "value")?,
+                                })
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .filter(|tags| !tags.is_empty()),
+            })
+        }
     }
 
     #[derive(Debug)]
     pub struct WorkshopItemsResult {
         pub items: Vec<Option<WorkshopItem>>,
+        pub total_results: u32,
         #[allow(dead_code)]
         pub was_cached: bool,
+        /// Cursor for the following page, only set by cursor-based queries
+        pub next_cursor: Option<String>,
     }
 
     impl WorkshopItemsResult {
@@ -447,7 +610,9 @@ pub mod workshop {
                 items: (0..query_results.returned_results())
                     .map(|i| WorkshopItem::from_query_results(&query_results, i))
                     .collect(),
+                total_results: query_results.total_results(),
                 was_cached: query_results.was_cached(),
+                next_cursor: None,
             }
         }
     }
@@ -1,206 +1,94 @@
-pub fn print_main_help() {
-    println!("s7forge - Steam utility\n");
-    println!("USAGE:");
-    println!("    s7forge <COMMAND>\n");
-    println!("COMMANDS:");
-    println!("    check-item-download     Check download status of a workshop item");
-    println!("    collection-items        Get items from a workshop collection");
-    println!("    workshop-items          Get detailed information about workshop items");
-    println!("    subscribe               Subscribe to workshop items");
-    println!("    unsubscribe             Unsubscribe from workshop items");
-    println!("    download-workshop-item  Download a workshop item you own");
-    println!("    subscribed-items        List all items you're subscribed to for a game");
-    println!("    search-workshop         Search workshop content by text query");
-    println!("    workshop-path           Get the local workshop path for a game");
-    println!("    app-installation-path   Get the installation path for a Steam app");
-    println!("    steam-library-paths     List all Steam library folder paths");
-    println!("    clear-cache             Clear all cached data");
-    println!("    discover-tags           Discover all available workshop tags for a game");
-    println!("    help                    Print this message\n");
-    println!("For more information on a specific command, use: s7forge <COMMAND> --help");
-}
-
-pub fn print_check_item_help() {
-    println!("Check download status of a workshop item\n");
-    println!("USAGE:");
-    println!("    s7forge check-item-download --app-id <APP_ID> --item-id <ITEM_ID>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>      Steam App ID of the game");
-    println!("    --item-id <ITEM_ID>    Workshop item ID to check download status for");
-    println!("    -h, --help             Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge check-item-download --app-id 548430 --item-id 123456789");
-}
-
-pub fn print_collection_items_help() {
-    println!("Get items from a workshop collection\n");
-    println!("USAGE:");
-    println!("    s7forge collection-items --app-id <APP_ID> --item-id <ITEM_ID>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>      Steam App ID of the game");
-    println!("    --item-id <ITEM_ID>    Collection ID to get items from");
-    println!("    -h, --help             Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge collection-items --app-id 548430 --item-id 987654321");
-}
-
-pub fn print_search_workshop_help() {
-    println!("Search workshop content by text query with flexible sorting options\n");
-    println!("USAGE:");
-    println!("    s7forge search-workshop --app-id <APP_ID> [OPTIONS]\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>        Steam App ID of the game");
-    println!("    --query <QUERY>          Text to search for (optional for most sort methods)");
-    println!(
-        "    --sort-by <SORT>         Sort by: relevance, recent, popular, most-subscribed, recently-updated [default: relevance]"
-    );
-    println!(
-        "    --period <PERIOD>        Time period filter: today, one-week, three-months, six-months, one-year (only for 'popular' sort)"
-    );
-    println!("    --page <PAGE>            Page number for pagination [default: 1]");
-    println!("    --tags <TAGS>            Filter by tags, comma-separated (e.g., 'mod,weapon')");
-    println!("    -h, --help               Print help\n");
-    println!("EXAMPLES:");
-    println!("    s7forge search-workshop --app-id 548430 --query \"tank\" --sort-by relevance");
-    println!("    s7forge search-workshop --app-id 548430 --sort-by recent --tags \"mod,weapon\"");
-    println!("    s7forge search-workshop --app-id 548430 --sort-by popular --period one-week");
-}
-
-pub fn print_clear_cache_help() {
-    println!("Clear all cached data (creator names, workshop items)\n");
-    println!("USAGE:");
-    println!("    s7forge clear-cache\n");
-    println!("OPTIONS:");
-    println!("    -h, --help    Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge clear-cache");
-}
-
-pub fn print_steam_library_paths_help() {
-    println!("List all Steam library folder paths\n");
-    println!("USAGE:");
-    println!("    s7forge steam-library-paths\n");
-    println!("OPTIONS:");
-    println!("    -h, --help    Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge steam-library-paths");
-}
-
-pub fn print_workshop_items_help() {
-    println!("Get detailed information about workshop items\n");
-    println!("USAGE:");
-    println!("    s7forge workshop-items --app-id <APP_ID> --item-ids <ITEM_IDS>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>          Steam App ID of the game");
-    println!("    --item-ids <ITEM_IDS>      Workshop item IDs (comma-separated)");
-    println!("    -h, --help                 Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge workshop-items --app-id 548430 --item-ids 123,456,789");
-}
-
-pub fn print_subscribe_help() {
-    println!("Subscribe to workshop items\n");
-    println!("USAGE:");
-    println!("    s7forge subscribe --app-id <APP_ID> --item-ids <ITEM_IDS>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>          Steam App ID of the game");
-    println!("    --item-ids <ITEM_IDS>      Workshop item IDs to subscribe to (comma-separated)");
-    println!("    -h, --help                 Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge subscribe --app-id 548430 --item-ids 123,456,789");
-}
-
-pub fn print_unsubscribe_help() {
-    println!("Unsubscribe from workshop items\n");
-    println!("USAGE:");
-    println!("    s7forge unsubscribe --app-id <APP_ID> --item-ids <ITEM_IDS>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>          Steam App ID of the game");
-    println!(
-        "    --item-ids <ITEM_IDS>      Workshop item IDs to unsubscribe from (comma-separated)"
-    );
-    println!("    -h, --help                 Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge unsubscribe --app-id 548430 --item-ids 123,456,789");
-}
-
-pub fn print_download_workshop_item_help() {
-    println!("Download a workshop item you own\n");
-    println!("USAGE:");
-    println!("    s7forge download-workshop-item --app-id <APP_ID> --item-id <ITEM_ID>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>      Steam App ID of the game");
-    println!("    --item-id <ITEM_ID>    Workshop item ID to download");
-    println!("    -h, --help             Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge download-workshop-item --app-id 548430 --item-id 123456789");
-}
-
-pub fn print_subscribed_items_help() {
-    println!("List all items you're subscribed to for a game\n");
-    println!("USAGE:");
-    println!("    s7forge subscribed-items --app-id <APP_ID>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>      Steam App ID of the game");
-    println!("    -h, --help             Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge subscribed-items --app-id 548430");
-}
-
-pub fn print_workshop_path_help() {
-    println!("Get the local workshop path for a game\n");
-    println!("USAGE:");
-    println!("    s7forge workshop-path --app-id <APP_ID>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>      Steam App ID of the game");
-    println!("    -h, --help             Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge workshop-path --app-id 548430");
-}
+use crate::command_table::{COMMANDS, GLOBAL_OPTIONS, HELP, OptionSpec};
+
+/// Prints the `--help` of `command`, generated from its entry in the command table.
+pub fn print_command_help(command: &str) {
+    let Some(spec) = COMMANDS.iter().find(|spec| spec.name == command) else {
+        print_general_help();
+        return;
+    };
+
+    for line in spec.description {
+        println!("{}", line);
+    }
+    println!();
 
-pub fn print_discover_tags_help() {
-    println!("Discover all available workshop tags for a game\n");
     println!("USAGE:");
-    println!("    s7forge discover-tags --app-id <APP_ID>\n");
-    println!("OPTIONS:");
-    println!("    --app-id <APP_ID>      Steam App ID of the game");
-    println!("    -h, --help             Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge discover-tags --app-id 548430");
-}
+    for line in spec.usage {
+        println!("    {}", line);
+    }
+    println!();
 
-pub fn print_app_installation_path_help() {
-    println!("Get the installation path for a Steam app\n");
-    println!("USAGE:");
-    println!("    s7forge app-installation-path --app-id <APP_ID>\n");
     println!("OPTIONS:");
-    println!("    --app-id <APP_ID>      Steam App ID of the game");
-    println!("    -h, --help             Print help\n");
-    println!("EXAMPLE:");
-    println!("    s7forge app-installation-path --app-id 548430");
-}
-
-pub fn print_combined_help() {
-    println!("Execute multiple commands in one invocation\n");
-    println!("USAGE:");
-    println!("    s7forge --app-id <APP_ID> combined [SUBCOMMANDS]\n");
-    println!("NOTES:");
-    println!("    - Global --app-id is used for all commands unless overridden");
-    println!("    - Each subcommand can have its own specific options");
-    println!("    - Options are specified after the subcommand flag\\n");
-    println!("EXAMPLES:");
-    println!("    # Simple: two commands without extra options");
-    println!("    s7forge --app-id 1142710 combined --subscribed-items --workshop-path");
+    print_options(spec.options.iter().chain([&HELP]));
     println!();
-    println!("    # Advanced: mix commands with different options");
-    println!(
-        "    s7forge --app-id 548430 combined --workshop-path --search-workshop --query \\\"tank\\\" --page 1"
-    );
-    println!();
-    println!("    # Multiple parameterized commands");
-    println!(
-        "    s7forge --app-id 1142710 combined --workshop-items --item-ids 123,456 --discover-tags"
-    );
+
+    for section in spec.sections {
+        println!("{}:", section.title);
+        for line in section.lines {
+            println!("    {}", line);
+        }
+        println!();
+    }
+
+    if spec.examples.is_empty() {
+        return;
+    }
+    let example_count = spec
+        .examples
+        .iter()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .count();
+    let title = if example_count > 1 {
+        "EXAMPLES"
+    } else {
+        "EXAMPLE"
+    };
+    println!("{}:", title);
+    for line in spec.examples {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("    {}", line);
+        }
+    }
+}
+
+// `-v, --verbose` or `--app-id <APP_ID>`
+fn option_label(option: &OptionSpec) -> String {
+    let mut label = String::new();
+    if let Some(short) = option.short {
+        label.push_str(short);
+        label.push_str(", ");
+    }
+    label.push_str(option.name);
+    if !option.placeholder.is_empty() {
+        label.push_str(" <");
+        label.push_str(option.placeholder);
+        label.push('>');
+    }
+    label
+}
+
+// One aligned row per option; further lines of the description line up under the first
+fn print_options<'a>(options: impl Iterator<Item = &'a OptionSpec> + Clone) {
+    let width = options
+        .clone()
+        .map(|option| option_label(option).len())
+        .max()
+        .unwrap_or_default()
+        + 4;
+
+    for option in options {
+        let mut lines = option.help.lines();
+        println!(
+            "    {:<width$}{}",
+            option_label(option),
+            lines.next().unwrap_or_default()
+        );
+        for line in lines {
+            println!("    {:<width$}{}", "", line);
+        }
+    }
 }
 
 pub fn print_general_help() {
@@ -208,25 +96,21 @@ pub fn print_general_help() {
     println!("USAGE:");
     println!("    s7forge --app-id <APP_ID> <COMMAND> [OPTIONS]\n");
     println!("GLOBAL OPTIONS:");
-    println!("    --app-id <APP_ID>        Steam App ID (required for most commands)\n");
+    print_options(GLOBAL_OPTIONS.iter());
+    println!();
     println!("COMMANDS:");
-    println!("    combined                 Execute multiple commands at once");
-    println!("    search-workshop          Search for workshop items");
-    println!("    discover-tags            Discover available workshop tags for a game");
-    println!("    workshop-items           Get details about workshop items");
-    println!("    collection-items         Get items from a workshop collection");
-    println!("    subscribed-items         List all items you're subscribed to");
-    println!("    check-item-download      Check if a workshop item is downloaded");
-    println!("    subscribe                Subscribe to workshop items");
-    println!("    unsubscribe              Unsubscribe from workshop items");
-    println!("    download-workshop-item   Download a workshop item you own");
-    println!("    clear-cache              Clear the Steam workshop cache");
-    println!("    workshop-path            Get the local workshop path for a game");
-    println!("    steam-library-paths      List all Steam library paths");
-    println!("    app-installation-path    Get the installation path for a Steam app\n");
-    println!("OPTIONS:");
-    println!("    -h, --help               Print help");
-    println!("    -v, --version            Print version\n");
+    for command in COMMANDS {
+        println!("    {:<25}{}", command.name, command.about);
+    }
+    println!();
+    println!("EXIT CODES:");
+    println!("    0    Success");
+    println!("    1    Other error");
+    println!("    2    Invalid arguments or configuration");
+    println!("    3    Steam client not running or not available");
+    println!("    4    Item or path not found, or item not subscribed");
+    println!("    5    Timed out waiting for Steam");
+    println!("    6    Steam Web API or network error\n");
     println!("Use 's7forge <COMMAND> --help' for more information on a specific command.");
 }
 
@@ -1,4 +1,5 @@
 mod cli;
+mod command_table;
 mod commands;
 mod core;
 mod help;
@@ -6,86 +7,260 @@ mod utils;
 
 use cli::{Command, parse_args};
 use serde_json::json;
+use std::io::Write;
+use tracing::Instrument;
+
+use crate::commands::item_index::IndexAction;
+use crate::commands::profiles::ProfileAction;
+use crate::core::error::{ErrorReport, S7forgeError};
+use crate::core::logging;
+use crate::core::output;
 
 #[tokio::main]
 async fn main() {
-    let command = match parse_args() {
-        Ok(cmd) => cmd,
-        Err(err) => {
-            eprintln!("Error: {}", err);
-            std::process::exit(1);
+    let (name, command) = match parse_args() {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            ErrorReport::from(&error).print();
+            std::process::exit(error.code().exit_code());
         }
     };
 
-    let result = execute_command(command).await;
+    if let Err(error) = logging::init() {
+        ErrorReport::from(&error).print();
+        std::process::exit(error.code().exit_code());
+    }
+
+    // With --quiet-if-none, any output means updates were found
+    let updates_exit_code = matches!(
+        command,
+        Command::CheckUpdates {
+            quiet_if_none: true,
+            ..
+        }
+    )
+    .then_some(commands::check_updates::UPDATES_AVAILABLE_EXIT_CODE);
+
+    let result = execute_command(command)
+        .instrument(tracing::debug_span!("run", command = %name))
+        .await;
+    utils::cache_counters::save();
 
     match result {
         Ok(output) => {
-            println!("{}", output);
-            std::process::exit(0);
+            if output.is_empty() {
+                std::process::exit(0);
+            }
+            println!("{}", output::present(&name, output));
+            std::process::exit(updates_exit_code.unwrap_or(0));
         }
         Err(error) => {
-            eprintln!("Error: {:?}", error);
-            std::process::exit(1);
+            tracing::error!(target: logging::FILE_ONLY, code = ?error.code(), "{} failed: {}", name, error);
+            ErrorReport::from(&error).print();
+            std::process::exit(error.code().exit_code());
         }
     }
 }
 
-async fn execute_command(command: Command) -> Result<String, String> {
+async fn execute_command(command: Command) -> Result<String, S7forgeError> {
     match command {
-        Command::Combined { commands } => {
+        Command::Combined {
+            app_id: global_app_id,
+            commands,
+        } => {
             let mut results = serde_json::Map::new();
+            // One result per app, so blocks with their own --app-id don't overwrite each other
+            let per_app = |name: &str, app_id: u32| {
+                if Some(app_id) == global_app_id {
+                    name.to_string()
+                } else {
+                    format!("{}-{}", name, app_id)
+                }
+            };
+
+            // Output of the last block, what a --then block takes its item IDs from
+            let mut previous: Option<serde_json::Value> = None;
 
             for (idx, cmd) in commands.into_iter().enumerate() {
+                let (cmd, piped) = match cmd {
+                    Command::Then { command } => (*command, true),
+                    cmd => (cmd, false),
+                };
                 let key = match &cmd {
-                    Command::SubscribedItems { .. } => "subscribed-items".to_string(),
-                    Command::WorkshopPath { .. } => "workshop-path".to_string(),
+                    Command::SubscribedItems { app_id, .. } => per_app("subscribed-items", *app_id),
+                    Command::WorkshopPath { app_id } => per_app("workshop-path", *app_id),
                     Command::SearchWorkshop { .. } => format!("search-workshop-{}", idx),
                     Command::WorkshopItems { .. } => format!("workshop-items-{}", idx),
                     Command::CheckItemDownload { .. } => format!("check-item-download-{}", idx),
                     Command::CollectionItems { .. } => format!("collection-items-{}", idx),
                     Command::DiscoverTags { .. } => format!("discover-tags-{}", idx),
+                    Command::Subscribe { .. } => format!("subscribe-{}", idx),
+                    Command::Unsubscribe { .. } => format!("unsubscribe-{}", idx),
+                    Command::DownloadWorkshopItem { .. } => {
+                        format!("download-workshop-item-{}", idx)
+                    }
                     _ => format!("command-{}", idx),
                 };
 
-                match execute_single_command(cmd).await {
-                    Ok(output) => {
-                        if let Ok(value) = serde_json::from_str::<serde_json::Value>(&output) {
-                            results.insert(key, value);
-                        } else {
-                            results.insert(key, json!(output));
-                        }
+                let result = match (piped, previous.take()) {
+                    (false, _) => execute_single_command(cmd).await,
+                    (true, Some(output)) => {
+                        execute_single_command(with_item_ids(cmd, piped_item_ids(&output))).await
                     }
-                    Err(error) => {
-                        results.insert(key, json!({ "error": error }));
+                    (true, None) => Err("Skipped: the previous block failed".into()),
+                };
+                let value = match result {
+                    Ok(output) => {
+                        let value = serde_json::from_str::<serde_json::Value>(&output)
+                            .unwrap_or_else(|_| json!(output));
+                        previous = Some(value.clone());
+                        value
                     }
-                }
+                    Err(error) => error_value(&error),
+                };
+                results.insert(key, value);
             }
 
             Ok(serde_json::to_string_pretty(&results).unwrap())
         }
+        Command::Batch { app_id } => {
+            for line in std::io::stdin().lines() {
+                let line = line.map_err(|e| S7forgeError::io("Failed to read stdin", e))?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let mut value = match cli::parse_batch_line(&line, app_id) {
+                    Ok(cmd) => match Box::pin(execute_command(cmd)).await {
+                        Ok(output) => json!({
+                            "result": serde_json::from_str::<serde_json::Value>(&output)
+                                .unwrap_or_else(|_| json!(output))
+                        }),
+                        Err(error) => error_value(&error),
+                    },
+                    Err(error) => error_value(&error),
+                };
+                // Lets callers match results to requests without counting lines
+                if let Some(id) = serde_json::from_str::<serde_json::Value>(&line)
+                    .ok()
+                    .and_then(|request| request.get("id").cloned())
+                {
+                    value["id"] = id;
+                }
+                output::format_value(&mut value);
+
+                let mut stdout = std::io::stdout().lock();
+                let _ = writeln!(stdout, "{}", value);
+                let _ = stdout.flush();
+            }
+            Ok(String::new())
+        }
         cmd => execute_single_command(cmd).await,
     }
 }
 
-async fn execute_single_command(command: Command) -> Result<String, String> {
+/// A failed `combined` block or `batch` line, with the code it would have been printed with on
+/// its own.
+fn error_value(error: &S7forgeError) -> serde_json::Value {
+    json!({
+        "error": error.to_string(),
+        "code": error.code(),
+    })
+}
+
+/// Item IDs in a block's output: the `published_file_id` of listed items (including the
+/// `items` of search pages and collections) and the `item_id` of successful results.
+fn piped_item_ids(output: &serde_json::Value) -> Vec<u64> {
+    let mut item_ids = Vec::new();
+    match output {
+        serde_json::Value::Array(values) => {
+            for value in values {
+                item_ids.extend(piped_item_ids(value));
+            }
+        }
+        serde_json::Value::Object(object) => {
+            let id = object
+                .get("published_file_id")
+                .or_else(|| object.get("item_id"))
+                .and_then(|id| id.as_u64().or_else(|| id.as_str()?.parse().ok()));
+            let failed = object.get("success") == Some(&json!(false));
+            if let Some(id) = id.filter(|_| !failed) {
+                item_ids.push(id);
+            } else if let Some(items) = object.get("items") {
+                item_ids.extend(piped_item_ids(items));
+            }
+        }
+        _ => {}
+    }
+    let mut seen = rustc_hash::FxHashSet::default();
+    item_ids.retain(|id| seen.insert(*id));
+    item_ids
+}
+
+fn with_item_ids(command: Command, item_ids: Vec<u64>) -> Command {
+    match command {
+        Command::WorkshopItems {
+            app_id,
+            with_local_meta,
+            ..
+        } => Command::WorkshopItems {
+            app_id,
+            item_ids,
+            with_local_meta,
+        },
+        Command::Subscribe {
+            app_id,
+            with_dependencies,
+            ..
+        } => Command::Subscribe {
+            app_id,
+            item_ids,
+            with_dependencies,
+        },
+        Command::Unsubscribe { app_id, .. } => Command::Unsubscribe { app_id, item_ids },
+        command => command,
+    }
+}
+
+async fn execute_single_command(command: Command) -> Result<String, S7forgeError> {
     match command {
         Command::CheckItemDownload { app_id, item_id } => {
             commands::check_item_download::check_item_download(app_id, item_id)
                 .await
                 .map(|info| serde_json::to_string_pretty(&info).unwrap())
         }
+        Command::CheckItemsDownload { app_id, item_ids } => {
+            commands::check_item_download::check_items_download(app_id, item_ids)
+                .await
+                .map(|infos| serde_json::to_string_pretty(&infos).unwrap())
+        }
+        Command::ItemState { app_id, item_ids } => {
+            commands::item_state::item_state(app_id, item_ids)
+                .await
+                .map(|states| serde_json::to_string_pretty(&states).unwrap())
+        }
         Command::CollectionItems { app_id, item_id } => {
             commands::collection_items::collection_items(app_id, item_id)
                 .await
                 .map(|items| serde_json::to_string_pretty(&items).unwrap())
         }
-        Command::WorkshopItems { app_id, item_ids } => {
-            commands::workshop_items::workshop_items(app_id, item_ids)
-                .await
-                .map(|items| serde_json::to_string_pretty(&items).unwrap())
+        Command::WorkshopItems {
+            app_id,
+            item_ids,
+            with_local_meta,
+        } => {
+            let items = commands::workshop_items::workshop_items(app_id, item_ids).await?;
+            if with_local_meta {
+                commands::local_meta::merge_local_meta(app_id, &items)
+                    .map(|items| serde_json::to_string_pretty(&items).unwrap())
+            } else {
+                Ok(serde_json::to_string_pretty(&items).unwrap())
+            }
         }
-        Command::Subscribe { app_id, item_ids } => commands::subscribe::subscribe(app_id, item_ids)
+        Command::Subscribe {
+            app_id,
+            item_ids,
+            with_dependencies,
+        } => commands::subscribe::subscribe(app_id, item_ids, with_dependencies)
             .await
             .map(|results| serde_json::to_string_pretty(&results).unwrap()),
         Command::Unsubscribe { app_id, item_ids } => {
@@ -93,27 +268,72 @@ async fn execute_single_command(command: Command) -> Result<String, String> {
                 .await
                 .map(|results| serde_json::to_string_pretty(&results).unwrap())
         }
-        Command::DownloadWorkshopItem { app_id, item_id } => {
-            commands::download_workshop_item::download_workshop_item(app_id, item_id)
+        Command::DownloadWorkshopItem {
+            app_id,
+            item_id,
+            backend,
+            steamcmd_path,
+            retry,
+        } => match backend.as_str() {
+            "steamcmd" => commands::download_workshop_item::download_workshop_item_steamcmd(
+                app_id,
+                item_id,
+                steamcmd_path,
+            )
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+            _ => commands::download_workshop_item::download_workshop_item(app_id, item_id, retry)
                 .await
-                .map(|_| "\"Workshop item download completed successfully\"".to_string())
+                .map(|_| "\"Workshop item download completed successfully\"".to_string()),
+        },
+        Command::SubscribedItems {
+            app_id,
+            with_local_meta,
+        } => {
+            let items = commands::subscribed_items::subscribed_items(app_id).await?;
+            if with_local_meta {
+                commands::local_meta::merge_local_meta(app_id, &items)
+                    .map(|items| serde_json::to_string_pretty(&items).unwrap())
+            } else {
+                Ok(serde_json::to_string_pretty(&items).unwrap())
+            }
         }
-        Command::SubscribedItems { app_id } => commands::subscribed_items::subscribed_items(app_id)
-            .await
-            .map(|items| serde_json::to_string_pretty(&items).unwrap()),
         Command::SearchWorkshop {
             app_id,
             query,
-            sort_by,
-            period,
-            page,
-            tags,
-        } => commands::search_workshop::search_workshop(app_id, query, sort_by, period, page, tags)
-            .await
+            all_pages_limit,
+            date_filter,
+        } => {
+            // Date filters are applied while walking pages, so they always page through results
+            let limit = all_pages_limit.or_else(|| {
+                (!date_filter.is_empty())
+                    .then_some(commands::search_workshop::DEFAULT_ALL_PAGES_LIMIT)
+            });
+            match limit {
+                Some(limit) => {
+                    commands::search_workshop::search_workshop_all_pages(
+                        app_id,
+                        query,
+                        limit,
+                        date_filter,
+                    )
+                    .await
+                }
+                None => commands::search_workshop::search_workshop(app_id, query).await,
+            }
+        }
+        .map(|items| serde_json::to_string_pretty(&items).unwrap()),
+        Command::InstalledItems { app_id } => commands::installed_items::installed_items(app_id)
             .map(|items| serde_json::to_string_pretty(&items).unwrap()),
+        Command::OrphanedItems { app_id } => commands::orphaned_items::orphaned_items(app_id)
+            .await
+            .map(|orphans| serde_json::to_string_pretty(&orphans).unwrap()),
         Command::WorkshopPath { app_id } => match commands::workshop_path::workshop_path(app_id) {
             Some(path) => Ok(serde_json::to_string_pretty(&path).unwrap()),
-            None => Err(format!("Workshop path not found for app ID {}", app_id)),
+            None => Err(S7forgeError::NotFound(format!(
+                "Workshop path not found for app ID {}",
+                app_id
+            ))),
         },
         Command::AppInstallationPath { app_id } => {
             commands::app_installation_path::app_installation_path(app_id)
@@ -121,11 +341,327 @@ async fn execute_single_command(command: Command) -> Result<String, String> {
         }
         Command::SteamLibraryPaths => commands::steam_library_paths::steam_library_paths()
             .map(|paths| serde_json::to_string_pretty(&paths).unwrap()),
-        Command::ClearCache => commands::clear_cache::clear_cache()
+        Command::Schema { command } => commands::schema::schema(command)
+            .map(|schema| serde_json::to_string_pretty(&schema).unwrap()),
+        Command::Completions { shell } => {
+            // A script, printed as is rather than as JSON output
+            print!("{}", commands::completions::completions(shell));
+            Ok(String::new())
+        }
+        Command::SteamStatus { app_id, launch } => {
+            commands::steam_status::steam_status(app_id, launch)
+                .await
+                .map(|status| serde_json::to_string_pretty(&status).unwrap())
+        }
+        Command::ListDlc { app_id } => commands::list_dlc::list_dlc(app_id)
+            .await
+            .map(|dlc| serde_json::to_string_pretty(&dlc).unwrap()),
+        Command::CheckOwnership { app_id } => commands::check_ownership::check_ownership(app_id)
+            .await
+            .map(|ownership| serde_json::to_string_pretty(&ownership).unwrap()),
+        Command::AccountInfo { app_id } => commands::account_info::account_info(app_id)
+            .await
+            .map(|account| serde_json::to_string_pretty(&account).unwrap()),
+        Command::RestartSteam { app_id } => commands::restart_steam::restart_steam(app_id)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::SelfUpdate { check_only } => commands::self_update::self_update(check_only)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::ClearCache { selection } => commands::clear_cache::clear_cache(selection)
             .map(|message| serde_json::to_string_pretty(&message).unwrap()),
+        Command::CacheStats => commands::cache_stats::cache_stats()
+            .map(|stats| serde_json::to_string_pretty(&stats).unwrap()),
+        Command::CacheInspect { selection } => commands::cache_inspect::cache_inspect(selection)
+            .map(|entries| serde_json::to_string_pretty(&entries).unwrap()),
+        Command::CacheExport { app_id, output } => {
+            commands::cache_export::cache_export(app_id, output)
+                .map(|result| serde_json::to_string_pretty(&result).unwrap())
+        }
+        Command::CacheImport { input } => commands::cache_export::cache_import(input)
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
         Command::DiscoverTags { app_id } => commands::discover_tags::discover_tags(app_id)
             .await
             .map(|tags| serde_json::to_string_pretty(&tags).unwrap()),
-        Command::Combined { .. } => unreachable!("Combined should be handled in execute_command"),
+        Command::CreateItem { app_id, update } => {
+            commands::publish::create_item::create_item(app_id, update)
+                .await
+                .map(|result| serde_json::to_string_pretty(&result).unwrap())
+        }
+        Command::UploadItem {
+            app_id,
+            item_id,
+            update,
+        } => commands::publish::upload_item::upload_item(app_id, item_id, update)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::UpdateItem {
+            app_id,
+            item_id,
+            update,
+        } => commands::publish::update_item::update_item(app_id, item_id, update)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::SetItemVisibility {
+            app_id,
+            item_id,
+            visibility,
+        } => {
+            commands::publish::set_item_visibility::set_item_visibility(app_id, item_id, visibility)
+                .await
+                .map(|result| serde_json::to_string_pretty(&result).unwrap())
+        }
+        Command::DeleteItem { app_id, item_ids } => {
+            commands::publish::delete_item::delete_item(app_id, item_ids)
+                .await
+                .map(|results| serde_json::to_string_pretty(&results).unwrap())
+        }
+        Command::MyPublishedItems { app_id, page } => {
+            commands::my_published_items::my_published_items(app_id, page)
+                .await
+                .map(|items| serde_json::to_string_pretty(&items).unwrap())
+        }
+        Command::ItemsByCreator {
+            app_id,
+            steam_id,
+            page,
+        } => commands::items_by_creator::items_by_creator(app_id, steam_id, page)
+            .await
+            .map(|items| serde_json::to_string_pretty(&items).unwrap()),
+        Command::CreatorProfile { app_id, steam_id } => {
+            commands::creator_profile::creator_profile(app_id, steam_id)
+                .await
+                .map(|profile| serde_json::to_string_pretty(&profile).unwrap())
+        }
+        Command::Favorite { app_id, item_ids } => commands::favorites::favorite(app_id, item_ids)
+            .await
+            .map(|results| serde_json::to_string_pretty(&results).unwrap()),
+        Command::Unfavorite { app_id, item_ids } => {
+            commands::favorites::unfavorite(app_id, item_ids)
+                .await
+                .map(|results| serde_json::to_string_pretty(&results).unwrap())
+        }
+        Command::ListFavorites { app_id, page } => {
+            commands::favorites::list_favorites(app_id, page)
+                .await
+                .map(|items| serde_json::to_string_pretty(&items).unwrap())
+        }
+        Command::Vote {
+            app_id,
+            item_id,
+            vote_up,
+        } => commands::vote::vote(app_id, item_id, vote_up)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::GetVote { app_id, item_ids } => commands::vote::get_votes(app_id, item_ids)
+            .await
+            .map(|votes| serde_json::to_string_pretty(&votes).unwrap()),
+        Command::CreateCollection {
+            app_id,
+            update,
+            item_ids,
+        } => commands::publish::collection::create_collection(app_id, update, item_ids)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::CollectionAdd {
+            app_id,
+            collection_id,
+            item_ids,
+        } => commands::publish::collection::collection_add(app_id, collection_id, item_ids)
+            .await
+            .map(|results| serde_json::to_string_pretty(&results).unwrap()),
+        Command::CollectionRemove {
+            app_id,
+            collection_id,
+            item_ids,
+        } => commands::publish::collection::collection_remove(app_id, collection_id, item_ids)
+            .await
+            .map(|results| serde_json::to_string_pretty(&results).unwrap()),
+        Command::DependencyGraph { app_id, item_ids } => {
+            commands::dependency_graph::dependency_graph(app_id, item_ids)
+                .await
+                .map(|graph| serde_json::to_string_pretty(&graph).unwrap())
+        }
+        Command::UnsubscribeAll {
+            app_id,
+            filter,
+            dry_run,
+        } => commands::unsubscribe_all::unsubscribe_all(app_id, filter, dry_run)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::ExportSubscriptions { app_id, output } => {
+            commands::subscriptions::export::export_subscriptions(app_id, output)
+                .await
+                .map(|result| serde_json::to_string_pretty(&result).unwrap())
+        }
+        Command::ImportSubscriptions {
+            app_id,
+            input,
+            prune,
+        } => commands::subscriptions::import::import_subscriptions(app_id, input, prune)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::SubscriptionsDiff {
+            app_id,
+            collection_id,
+        } => commands::subscriptions::diff::subscriptions_diff(app_id, collection_id)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::Sync {
+            app_id,
+            input,
+            dry_run,
+        } => commands::subscriptions::sync::sync(app_id, input, dry_run)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::CheckUpdates {
+            app_id,
+            rss,
+            quiet_if_none,
+        } => {
+            let updates = commands::check_updates::check_updates(app_id).await?;
+            if quiet_if_none && updates.is_empty() {
+                return Ok(String::new());
+            }
+
+            Ok(if rss {
+                commands::check_updates::updates_feed(app_id, &updates)
+            } else {
+                serde_json::to_string_pretty(&updates).unwrap()
+            })
+        }
+        Command::DownloadUpdates {
+            app_id,
+            concurrency,
+            retry,
+        } => commands::download_updates::download_updates(app_id, concurrency, retry)
+            .await
+            .map(|results| serde_json::to_string_pretty(&results).unwrap()),
+        Command::DownloadWorkshopItems {
+            app_id,
+            item_ids,
+            concurrency,
+            retry,
+        } => {
+            commands::download_workshop_items::download_items(app_id, item_ids, concurrency, retry)
+                .await
+                .map(|results| serde_json::to_string_pretty(&results).unwrap())
+        }
+        Command::CleanupWorkshop { app_id, dry_run } => {
+            commands::cleanup_workshop::cleanup_workshop(app_id, dry_run)
+                .await
+                .map(|result| serde_json::to_string_pretty(&result).unwrap())
+        }
+        Command::ForceRedownload {
+            app_id,
+            item_id,
+            retry,
+        } => commands::force_redownload::force_redownload(app_id, item_id, retry)
+            .await
+            .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        Command::RemoveItemFiles { app_id, item_ids } => {
+            commands::remove_item_files::remove_item_files(app_id, item_ids)
+                .map(|results| serde_json::to_string_pretty(&results).unwrap())
+        }
+        Command::VerifyItems { app_id, item_ids } => {
+            commands::verify_items::verify_items(app_id, item_ids)
+                .map(|results| serde_json::to_string_pretty(&results).unwrap())
+        }
+        Command::ItemInstallPath { app_id, item_id } => {
+            commands::item_install_path::item_install_path(app_id, item_id)
+                .await
+                .map(|info| serde_json::to_string_pretty(&info).unwrap())
+        }
+        Command::DeployItems {
+            app_id,
+            item_ids,
+            target,
+            name_template,
+            copy,
+        } => commands::deploy::deploy_items::deploy_items(
+            app_id,
+            item_ids,
+            target,
+            name_template,
+            copy,
+        )
+        .await
+        .map(|results| serde_json::to_string_pretty(&results).unwrap()),
+        Command::Undeploy { target, item_ids } => {
+            commands::deploy::undeploy::undeploy(target, item_ids)
+                .map(|results| serde_json::to_string_pretty(&results).unwrap())
+        }
+        Command::ItemConflicts { app_id, item_ids } => {
+            commands::item_conflicts::item_conflicts(app_id, item_ids)
+                .await
+                .map(|conflicts| serde_json::to_string_pretty(&conflicts).unwrap())
+        }
+        Command::Profile { app_id, action } => match action {
+            ProfileAction::Save(name) => commands::profiles::save_profile(app_id, name)
+                .await
+                .map(|profile| serde_json::to_string_pretty(&profile).unwrap()),
+            ProfileAction::List => commands::profiles::list_profiles(app_id)
+                .map(|profiles| serde_json::to_string_pretty(&profiles).unwrap()),
+            ProfileAction::Activate(name) => commands::profiles::activate_profile(app_id, name)
+                .await
+                .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+            ProfileAction::Delete(name) => commands::profiles::delete_profile(app_id, name)
+                .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+        },
+        Command::ItemMeta { app_id, action } => commands::local_meta::local_meta(app_id, action)
+            .map(|entries| serde_json::to_string_pretty(&entries).unwrap()),
+        Command::Blacklist { app_id, action } => commands::blacklist::blacklist(app_id, action)
+            .map(|entries| serde_json::to_string_pretty(&entries).unwrap()),
+        Command::Watch { app_id, options } => commands::watch::watch(app_id, options).await,
+        Command::Index { app_id, action } => match action {
+            IndexAction::Update => commands::item_index::update_index(app_id)
+                .await
+                .map(|result| serde_json::to_string_pretty(&result).unwrap()),
+            IndexAction::Query(query) => commands::item_index::query_index(app_id, &query)
+                .map(|items| serde_json::to_string_pretty(&items).unwrap()),
+        },
+        Command::ItemPreviews { item_ids } => commands::item_previews::item_previews(item_ids)
+            .await
+            .map(|previews| serde_json::to_string_pretty(&previews).unwrap()),
+        Command::ItemChangelog { item_id, limit } => {
+            commands::item_changelog::item_changelog(item_id, limit)
+                .await
+                .map(|changelog| serde_json::to_string_pretty(&changelog).unwrap())
+        }
+        Command::ItemComments { item_id, page } => {
+            commands::item_comments::item_comments(item_id, page)
+                .await
+                .map(|comments| serde_json::to_string_pretty(&comments).unwrap())
+        }
+        Command::ItemStats { app_id, item_ids } => {
+            commands::item_stats::item_stats(app_id, item_ids)
+                .await
+                .map(|stats| serde_json::to_string_pretty(&stats).unwrap())
+        }
+        Command::RequiredDlc { app_id, item_ids } => {
+            commands::required_dlc::required_dlc(app_id, item_ids)
+                .await
+                .map(|dlc| serde_json::to_string_pretty(&dlc).unwrap())
+        }
+        Command::DownloadPreviews {
+            app_id,
+            item_ids,
+            target,
+            concurrency,
+        } => commands::download_previews::download_previews(
+            app_id,
+            item_ids,
+            target.into(),
+            concurrency,
+        )
+        .await
+        .map(|results| serde_json::to_string_pretty(&results).unwrap()),
+        Command::SearchCache { app_id, search } => {
+            commands::search_cache::search_cache(app_id, search)
+                .map(|items| serde_json::to_string_pretty(&items).unwrap())
+        }
+        Command::Combined { .. } | Command::Then { .. } | Command::Batch { .. } => {
+            unreachable!("Combined and batch should be handled in execute_command")
+        }
     }
 }
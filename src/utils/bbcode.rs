@@ -0,0 +1,350 @@
+//! Converts Steam's BBCode dialect (as used in workshop descriptions and change notes) to
+//! Markdown, plain text or HTML. Unknown tags are dropped but their content is kept; tags
+//! that are never closed are left as written.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Markdown,
+    Plain,
+    Html,
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open {
+        tag: String,
+        arg: Option<&'a str>,
+        raw: &'a str,
+    },
+    Close {
+        tag: String,
+        raw: &'a str,
+    },
+    ListItem,
+}
+
+// Placeholders that separate list items and table rows/cells until the enclosing tag is
+// rendered; any left over (e.g. a [*] outside a list) are removed at the end
+const ITEM_MARKER: char = '\u{1}';
+const ROW_MARKER: char = '\u{2}';
+const CELL_MARKER: char = '\u{3}';
+
+pub fn convert(text: &str, target: Target) -> String {
+    let mut stack: Vec<(String, Option<&str>, &str, String)> = Vec::new();
+    let mut output = String::new();
+
+    for token in tokenize(text) {
+        let buffer = stack.last_mut().map_or(&mut output, |(.., inner)| inner);
+        match token {
+            Token::Text(text) => buffer.push_str(&text_for(text, target)),
+            Token::ListItem => buffer.push(ITEM_MARKER),
+            Token::Open { tag, .. } if tag == "hr" => {
+                buffer.push_str(match target {
+                    Target::Markdown => "\n---\n",
+                    Target::Html => "<hr>",
+                    Target::Plain => "\n",
+                });
+            }
+            Token::Open { tag, arg, raw } => stack.push((tag, arg, raw, String::new())),
+            Token::Close { tag, raw } => {
+                let Some(position) = stack.iter().rposition(|(open, ..)| *open == tag) else {
+                    let buffer = stack.last_mut().map_or(&mut output, |(.., inner)| inner);
+                    buffer.push_str(&text_for(raw, target));
+                    continue;
+                };
+                // Tags opened inside this one but never closed are kept as text
+                while stack.len() > position + 1 {
+                    let (_, _, raw, inner) = stack.pop().unwrap();
+                    let buffer = &mut stack.last_mut().unwrap().3;
+                    buffer.push_str(&text_for(raw, target));
+                    buffer.push_str(&inner);
+                }
+                let (tag, arg, _, inner) = stack.pop().unwrap();
+                let rendered = render(&tag, arg, &inner, target);
+                stack
+                    .last_mut()
+                    .map_or(&mut output, |(.., inner)| inner)
+                    .push_str(&rendered);
+            }
+        }
+    }
+
+    // Never closed: keep the opening tag as text
+    while let Some((_, _, raw, inner)) = stack.pop() {
+        let buffer = stack.last_mut().map_or(&mut output, |(.., inner)| inner);
+        buffer.push_str(&text_for(raw, target));
+        buffer.push_str(&inner);
+    }
+
+    output
+        .replace([ITEM_MARKER, ROW_MARKER, CELL_MARKER], "")
+        .trim()
+        .to_string()
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('[') {
+        let Some(length) = rest[start..].find(']') else {
+            break;
+        };
+        let raw = &rest[start..=start + length];
+        let body = &raw[1..raw.len() - 1];
+
+        let token = if body == "*" {
+            Some(Token::ListItem)
+        } else if let Some(tag) = body.strip_prefix('/') {
+            is_tag_name(tag).then(|| Token::Close {
+                tag: tag.to_ascii_lowercase(),
+                raw,
+            })
+        } else {
+            let (tag, arg) = match body.split_once('=') {
+                Some((tag, arg)) => (tag, Some(arg.trim_matches(['"', '\'']))),
+                None => (body, None),
+            };
+            is_tag_name(tag).then(|| Token::Open {
+                tag: tag.to_ascii_lowercase(),
+                arg,
+                raw,
+            })
+        };
+
+        match token {
+            Some(Token::Open { tag, .. }) if tag == "noparse" => {
+                if start > 0 {
+                    tokens.push(Token::Text(&rest[..start]));
+                }
+                let content = &rest[start + raw.len()..];
+                let end = content.find("[/noparse]").unwrap_or(content.len());
+                tokens.push(Token::Text(&content[..end]));
+                rest = content[end..]
+                    .strip_prefix("[/noparse]")
+                    .unwrap_or_default();
+            }
+            Some(token) => {
+                if start > 0 {
+                    tokens.push(Token::Text(&rest[..start]));
+                }
+                tokens.push(token);
+                rest = &rest[start + raw.len()..];
+            }
+            None => {
+                tokens.push(Token::Text(&rest[..=start]));
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+    tokens
+}
+
+fn is_tag_name(tag: &str) -> bool {
+    !tag.is_empty() && tag.len() <= 16 && tag.chars().all(|ch| ch.is_ascii_alphanumeric())
+}
+
+fn text_for(text: &str, target: Target) -> String {
+    match target {
+        Target::Html => escape_html(text).replace('\n', "<br>\n"),
+        Target::Markdown | Target::Plain => text.to_string(),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render(tag: &str, arg: Option<&str>, inner: &str, target: Target) -> String {
+    match target {
+        Target::Markdown => markdown(tag, arg, inner),
+        Target::Plain => plain(tag, arg, inner),
+        Target::Html => html(tag, arg, inner),
+    }
+}
+
+fn list_items(inner: &str) -> impl Iterator<Item = &str> {
+    inner
+        .split(ITEM_MARKER)
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+// [previewyoutube=VIDEO_ID;full]
+fn youtube_url(arg: Option<&str>) -> String {
+    let video_id = arg
+        .unwrap_or_default()
+        .split(';')
+        .next()
+        .unwrap_or_default();
+    format!("https://www.youtube.com/watch?v={}", video_id)
+}
+
+fn table_rows(inner: &str) -> Vec<Vec<String>> {
+    inner
+        .split(ROW_MARKER)
+        .filter(|row| !row.trim().is_empty())
+        .map(|row| {
+            row.split(CELL_MARKER)
+                .filter(|cell| !cell.trim().is_empty())
+                .map(|cell| cell.trim().replace('\n', " "))
+                .collect()
+        })
+        .collect()
+}
+
+fn markdown(tag: &str, arg: Option<&str>, inner: &str) -> String {
+    match tag {
+        "h1" => format!("\n# {}\n", inner.trim()),
+        "h2" => format!("\n## {}\n", inner.trim()),
+        "h3" => format!("\n### {}\n", inner.trim()),
+        "b" => format!("**{}**", inner),
+        "i" => format!("*{}*", inner),
+        "strike" => format!("~~{}~~", inner),
+        "url" => match arg {
+            Some(url) => format!("[{}]({})", inner, url),
+            None => format!("<{}>", inner.trim()),
+        },
+        "img" => format!("![]({})", inner.trim()),
+        "previewyoutube" => youtube_url(arg),
+        "list" => format!(
+            "\n{}\n",
+            list_items(inner)
+                .map(|item| format!("- {}", item))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        "olist" => format!(
+            "\n{}\n",
+            list_items(inner)
+                .enumerate()
+                .map(|(index, item)| format!("{}. {}", index + 1, item))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        "quote" => {
+            let quoted = inner
+                .trim()
+                .lines()
+                .map(|line| format!("> {}", line))
+                .collect::<Vec<_>>()
+                .join("\n");
+            match arg {
+                Some(author) => format!("\n> **{}:**\n{}\n", author, quoted),
+                None => format!("\n{}\n", quoted),
+            }
+        }
+        "code" => format!("\n```\n{}\n```\n", inner.trim_matches('\n')),
+        "tr" => format!("{}{}", ROW_MARKER, inner),
+        "th" | "td" => format!("{}{}", CELL_MARKER, inner),
+        "table" => {
+            let rows = table_rows(inner);
+            let mut output = String::from("\n");
+            for (index, row) in rows.iter().enumerate() {
+                output.push_str(&format!("| {} |\n", row.join(" | ")));
+                if index == 0 {
+                    output.push_str(&format!("|{}\n", " --- |".repeat(row.len())));
+                }
+            }
+            output
+        }
+        _ => inner.to_string(),
+    }
+}
+
+fn plain(tag: &str, arg: Option<&str>, inner: &str) -> String {
+    match tag {
+        "h1" | "h2" | "h3" => format!("\n{}\n", inner.trim()),
+        "url" => match arg {
+            Some(url) if inner.trim() != url => format!("{} ({})", inner, url),
+            _ => inner.to_string(),
+        },
+        "img" => String::new(),
+        "previewyoutube" => youtube_url(arg),
+        "list" | "olist" => format!(
+            "\n{}\n",
+            list_items(inner)
+                .map(|item| format!("- {}", item))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        "tr" => format!("{}{}", ROW_MARKER, inner),
+        "th" | "td" => format!("{}{}", CELL_MARKER, inner),
+        "table" => format!(
+            "\n{}\n",
+            table_rows(inner)
+                .iter()
+                .map(|row| row.join("\t"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        _ => inner.to_string(),
+    }
+}
+
+fn html(tag: &str, arg: Option<&str>, inner: &str) -> String {
+    let inner_block = inner.trim_matches(|ch| ch == '\n').trim_end_matches("<br>");
+    match tag {
+        "h1" | "h2" | "h3" => format!("<{}>{}</{}>", tag, inner_block, tag),
+        "b" => format!("<strong>{}</strong>", inner),
+        "i" => format!("<em>{}</em>", inner),
+        "u" => format!("<u>{}</u>", inner),
+        "strike" => format!("<s>{}</s>", inner),
+        "spoiler" => format!("<span class=\"spoiler\">{}</span>", inner),
+        "url" => {
+            let url = arg
+                .map(escape_html)
+                .unwrap_or_else(|| inner.trim().to_string());
+            format!("<a href=\"{}\">{}</a>", url, inner)
+        }
+        "img" => format!("<img src=\"{}\">", inner.trim()),
+        "previewyoutube" => {
+            let url = youtube_url(arg);
+            format!("<a href=\"{}\">{}</a>", url, url)
+        }
+        "list" | "olist" => {
+            let list_tag = if tag == "list" { "ul" } else { "ol" };
+            let items: String = list_items(inner)
+                .map(|item| {
+                    item.trim_start_matches("<br>")
+                        .trim_end_matches("<br>")
+                        .trim()
+                })
+                .map(|item| format!("<li>{}</li>", item))
+                .collect();
+            format!("<{}>{}</{}>", list_tag, items, list_tag)
+        }
+        "quote" => match arg {
+            Some(author) => format!(
+                "<blockquote><cite>{}</cite>{}</blockquote>",
+                escape_html(author),
+                inner_block
+            ),
+            None => format!("<blockquote>{}</blockquote>", inner_block),
+        },
+        "code" => format!(
+            "<pre><code>{}</code></pre>",
+            inner_block.replace("<br>\n", "\n")
+        ),
+        "table" => format!("<table>{}</table>", strip_breaks(inner)),
+        "tr" => format!("<tr>{}</tr>", strip_breaks(inner)),
+        "th" | "td" => format!("<{}>{}</{}>", tag, inner_block, tag),
+        _ => inner.to_string(),
+    }
+}
+
+// Line breaks between table rows and cells are layout in the BBCode source, not content
+fn strip_breaks(inner: &str) -> String {
+    inner
+        .split("<br>\n")
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .concat()
+}
@@ -0,0 +1,52 @@
+//! Encoding of the cache files and SQLite cache entries: bincode compressed with zstd. Files
+//! written before compression was added are plain bincode and are still read; they are
+//! compressed the next time they are written.
+
+use bincode::{Decode, Encode};
+use std::io::Write;
+
+use crate::core::error::S7forgeError;
+
+// Every zstd frame starts with these bytes
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+const COMPRESSION_LEVEL: i32 = 3;
+
+pub fn encode<T: Encode>(value: &T) -> Result<Vec<u8>, S7forgeError> {
+    encode_with_size(value).map(|(compressed, _)| compressed)
+}
+
+/// Like `encode`, also returning the size before compression.
+pub fn encode_with_size<T: Encode>(value: &T) -> Result<(Vec<u8>, u64), S7forgeError> {
+    let encoded = bincode::encode_to_vec(value, bincode::config::standard())
+        .map_err(|e| S7forgeError::cache("Failed to serialize cache", e))?;
+    // The frame checksum lets a damaged file fail to decode instead of yielding garbage
+    let compressed = zstd::stream::Encoder::new(Vec::new(), COMPRESSION_LEVEL)
+        .and_then(|mut encoder| {
+            encoder.include_checksum(true)?;
+            encoder.write_all(&encoded)?;
+            encoder.finish()
+        })
+        .map_err(|e| S7forgeError::cache("Failed to compress cache", e))?;
+    Ok((compressed, encoded.len() as u64))
+}
+
+pub fn decode<T: Decode<()>>(content: &[u8]) -> Result<T, S7forgeError> {
+    // A plain file that happens to start with the magic bytes fails to decompress and is
+    // read as it is
+    let decompressed = content
+        .starts_with(&ZSTD_MAGIC)
+        .then(|| zstd::decode_all(content).ok())
+        .flatten();
+    let content = decompressed.as_deref().unwrap_or(content);
+
+    // Trailing bytes mean the file isn't what was written, e.g. a damaged compressed file
+    // read as plain bincode
+    match bincode::decode_from_slice(content, bincode::config::standard()) {
+        Ok((value, read)) if read == content.len() => Ok(value),
+        Ok(_) => Err(S7forgeError::cache(
+            "Failed to decode cache",
+            "unexpected trailing data",
+        )),
+        Err(e) => Err(S7forgeError::cache("Failed to decode cache", e)),
+    }
+}
@@ -0,0 +1,87 @@
+//! Cache hits and misses of the current run. They are saved next to the caches when the run
+//! ends, so `cache-stats` can show how the last run that used each cache was served.
+
+use bincode::{Decode, Encode};
+use once_cell::sync::Lazy;
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::utils::cache_file;
+use crate::utils::get_cache_dir::get_cache_dir;
+
+pub const COUNTERS_FILE: &str = "cache_counters.bin";
+
+static COUNTERS: Lazy<Mutex<FxHashMap<&'static str, (u64, u64)>>> =
+    Lazy::new(|| Mutex::new(FxHashMap::default()));
+
+#[derive(Debug, Clone, Serialize, JsonSchema, Encode, Decode)]
+pub struct RunCounters {
+    pub hits: u64,
+    pub misses: u64,
+    /// When the run ended
+    pub recorded_at: u64,
+}
+
+/// Counts `count` lookups in `cache_file` that were answered from the cache.
+pub fn hit(cache_file: &'static str, count: u64) {
+    record(cache_file, count, 0);
+}
+
+/// Counts `count` lookups in `cache_file` that had to go to Steam, including those skipped
+/// with `--no-cache`.
+pub fn miss(cache_file: &'static str, count: u64) {
+    record(cache_file, 0, count);
+}
+
+fn record(cache_file: &'static str, hits: u64, misses: u64) {
+    if hits == 0 && misses == 0 {
+        return;
+    }
+    tracing::debug!(cache = cache_file, hits, misses, "Cache lookup");
+    if let Ok(mut counters) = COUNTERS.lock() {
+        let counter = counters.entry(cache_file).or_default();
+        counter.0 += hits;
+        counter.1 += misses;
+    }
+}
+
+/// Counters saved by earlier runs, by cache file name.
+pub fn load() -> FxHashMap<String, RunCounters> {
+    let Ok(cache_dir) = get_cache_dir() else {
+        return FxHashMap::default();
+    };
+    cache_file::read(&cache_dir.join(COUNTERS_FILE)).unwrap_or_default()
+}
+
+/// Replaces the saved counters of every cache used in this run. Caches the run didn't touch
+/// keep the counters of their own last run.
+pub fn save() {
+    let counters = match COUNTERS.lock() {
+        Ok(counters) if !counters.is_empty() => counters.clone(),
+        _ => return,
+    };
+    let Ok(cache_dir) = get_cache_dir() else {
+        return;
+    };
+
+    let recorded_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut saved = load();
+    for (cache_file, (hits, misses)) in counters {
+        saved.insert(
+            cache_file.to_string(),
+            RunCounters {
+                hits,
+                misses,
+                recorded_at,
+            },
+        );
+    }
+
+    let _ = cache_file::write(&cache_dir.join(COUNTERS_FILE), &saved);
+}
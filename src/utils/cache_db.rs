@@ -0,0 +1,427 @@
+//! SQLite store for the caches selected with `cache.backend = "sqlite"`: workshop items,
+//! creator names and searches. Every entry is a row with its own cache time, so entries expire
+//! one by one and a lookup only writes the rows it changed. The database runs in WAL mode so
+//! several s7forge processes can share it.
+//!
+//! Cache files left by the file backend are moved into the database the first time it is
+//! opened, and removed afterwards.
+
+use bincode::{Decode, Encode};
+use rusqlite::{Connection, OptionalExtension, params};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::commands::{search_workshop, workshop_items};
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+use crate::utils::cache_codec;
+use crate::utils::fetch_creator_names;
+use crate::utils::get_cache_dir::get_cache_dir;
+
+pub const DB_FILE: &str = "cache.sqlite";
+
+/// Row groups, named after the `CacheKind` they belong to
+pub const ITEMS: &str = "items";
+pub const CREATORS: &str = "creators";
+pub const SEARCHES: &str = "searches";
+
+const SCHEMA: &str = "
+PRAGMA journal_mode = WAL;
+CREATE TABLE IF NOT EXISTS entries (
+    cache TEXT NOT NULL,
+    key TEXT NOT NULL,
+    app_id INTEGER,
+    value BLOB,
+    size INTEGER NOT NULL,
+    cached_at INTEGER NOT NULL,
+    last_access INTEGER NOT NULL,
+    PRIMARY KEY (cache, key)
+);
+CREATE INDEX IF NOT EXISTS entries_last_access ON entries(cache, last_access);
+";
+
+// Another process holding the write lock usually finishes within milliseconds
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Where cached items, creator names and searches are kept, set with `cache.backend`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CacheBackend {
+    /// One bincode file per cache, rewritten as a whole
+    #[default]
+    Files,
+    /// One row per entry in `cache.sqlite`
+    Sqlite,
+}
+
+impl CacheBackend {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "files" => Ok(Self::Files),
+            "sqlite" => Ok(Self::Sqlite),
+            other => Err(format!(
+                "Invalid cache backend: {} (expected files or sqlite)",
+                other
+            )),
+        }
+    }
+}
+
+/// One cached entry. The value is encoded like the cache files; entries without a value mark
+/// something known not to exist, such as a deleted workshop item.
+#[derive(Debug, Clone)]
+pub struct CacheRow {
+    pub key: String,
+    pub app_id: Option<u32>,
+    pub value: Option<Vec<u8>>,
+    /// Size before compression, counted against `cache.max_size`
+    pub size: u64,
+    pub cached_at: u64,
+    pub last_access: u64,
+}
+
+impl CacheRow {
+    pub fn encode<T: Encode>(key: String, value: &T, cached_at: u64) -> Result<Self, S7forgeError> {
+        let (value, size) = cache_codec::encode_with_size(value)?;
+        Ok(Self {
+            key,
+            app_id: None,
+            value: Some(value),
+            size,
+            cached_at,
+            last_access: cached_at,
+        })
+    }
+
+    pub fn marker(key: String, cached_at: u64) -> Self {
+        Self {
+            key,
+            app_id: None,
+            value: None,
+            size: 8,
+            cached_at,
+            last_access: cached_at,
+        }
+    }
+
+    pub fn app(mut self, app_id: Option<u32>) -> Self {
+        self.app_id = app_id;
+        self
+    }
+
+    pub fn accessed(mut self, last_access: u64) -> Self {
+        self.last_access = last_access;
+        self
+    }
+
+    /// The stored value, or `None` for a marker.
+    pub fn decode<T: Decode<()>>(&self) -> Option<Result<T, S7forgeError>> {
+        self.value.as_deref().map(cache_codec::decode::<T>)
+    }
+}
+
+fn db_path() -> Result<PathBuf, S7forgeError> {
+    Ok(get_cache_dir()?.join(DB_FILE))
+}
+
+fn sql_error(e: rusqlite::Error) -> S7forgeError {
+    S7forgeError::cache("Failed to use cache database", e)
+}
+
+/// Opens the database, creating it as needed. With the SQLite backend selected, cache files
+/// are moved into it first.
+pub fn open() -> Result<Connection, S7forgeError> {
+    let path = db_path()?;
+    let mut connection = Connection::open(&path)
+        .map_err(|e| S7forgeError::cache(format!("Failed to open {}", path.display()), e))?;
+    connection
+        .busy_timeout(BUSY_TIMEOUT)
+        .and_then(|_| connection.execute_batch(SCHEMA))
+        .map_err(|e| S7forgeError::cache(format!("Failed to initialize {}", path.display()), e))?;
+    if settings::get().cache_backend == CacheBackend::Sqlite {
+        migrate_files(&mut connection)?;
+    }
+    Ok(connection)
+}
+
+/// Opens the database for reading; `None` when there is neither a database nor cache files to
+/// move into one, so read-only commands don't create it.
+fn open_existing() -> Result<Option<Connection>, S7forgeError> {
+    let cache_dir = get_cache_dir()?;
+    let has_files = settings::get().cache_backend == CacheBackend::Sqlite
+        && LEGACY_FILES
+            .iter()
+            .any(|(_, file)| cache_dir.join(file).exists());
+    if !has_files && !cache_dir.join(DB_FILE).exists() {
+        return Ok(None);
+    }
+    open().map(Some)
+}
+
+const LEGACY_FILES: [(&str, &str); 3] = [
+    (ITEMS, "workshop_items_cache.bin"),
+    (CREATORS, "creator_names_cache.bin"),
+    (SEARCHES, "search_workshop_cache.bin"),
+];
+
+// Rows already in the database are newer than the file and are kept. A file that can't be
+// decoded is dropped, as the file backend would replace it on its next write anyway.
+fn migrate_files(connection: &mut Connection) -> Result<(), S7forgeError> {
+    let cache_dir = get_cache_dir()?;
+    for (cache, file) in LEGACY_FILES {
+        let path = cache_dir.join(file);
+        let Ok(content) = fs::read(&path) else {
+            continue;
+        };
+        let rows = match cache {
+            ITEMS => workshop_items::legacy_rows(&content),
+            CREATORS => fetch_creator_names::legacy_rows(&content),
+            _ => search_workshop::legacy_rows(&content),
+        }
+        .unwrap_or_default();
+
+        let transaction = connection.transaction().map_err(sql_error)?;
+        for row in &rows {
+            insert_row(&transaction, cache, row, "INSERT OR IGNORE")?;
+        }
+        transaction.commit().map_err(sql_error)?;
+        fs::remove_file(&path)
+            .map_err(|e| S7forgeError::io(format!("Failed to remove {}", path.display()), e))?;
+    }
+    Ok(())
+}
+
+fn insert_row(
+    connection: &Connection,
+    cache: &str,
+    row: &CacheRow,
+    insert: &str,
+) -> Result<(), S7forgeError> {
+    connection
+        .execute(
+            &format!(
+                "{} INTO entries (cache, key, app_id, value, size, cached_at, last_access)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                insert
+            ),
+            params![
+                cache,
+                row.key,
+                row.app_id,
+                row.value,
+                row.size as i64,
+                row.cached_at as i64,
+                row.last_access as i64
+            ],
+        )
+        .map(|_| ())
+        .map_err(sql_error)
+}
+
+fn read_row(row: &rusqlite::Row) -> rusqlite::Result<CacheRow> {
+    Ok(CacheRow {
+        key: row.get(0)?,
+        app_id: row.get(1)?,
+        value: row.get(2)?,
+        size: row.get::<_, i64>(3)? as u64,
+        cached_at: row.get::<_, i64>(4)? as u64,
+        last_access: row.get::<_, i64>(5)? as u64,
+    })
+}
+
+const SELECT_ROWS: &str =
+    "SELECT key, app_id, value, size, cached_at, last_access FROM entries WHERE cache = ?1";
+
+/// Rows of `cache` with the given keys; missing keys are skipped.
+pub fn get(cache: &str, keys: &[String]) -> Result<Vec<CacheRow>, S7forgeError> {
+    let Some(connection) = open_existing()? else {
+        return Ok(Vec::new());
+    };
+    let mut statement = connection
+        .prepare(&format!("{} AND key = ?2", SELECT_ROWS))
+        .map_err(sql_error)?;
+    let mut rows = Vec::new();
+    for key in keys {
+        if let Some(row) = statement
+            .query_row(params![cache, key], read_row)
+            .optional()
+            .map_err(sql_error)?
+        {
+            rows.push(row);
+        }
+    }
+    Ok(rows)
+}
+
+/// Every row of `cache`, expired or not.
+pub fn all(cache: &str) -> Result<Vec<CacheRow>, S7forgeError> {
+    let Some(connection) = open_existing()? else {
+        return Ok(Vec::new());
+    };
+    let mut statement = connection.prepare(SELECT_ROWS).map_err(sql_error)?;
+    statement
+        .query_map(params![cache], read_row)
+        .and_then(|rows| rows.collect())
+        .map_err(sql_error)
+}
+
+/// Adds or replaces rows of `cache`.
+pub fn put(cache: &str, rows: &[CacheRow]) -> Result<(), S7forgeError> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+    let mut connection = open()?;
+    let transaction = connection.transaction().map_err(sql_error)?;
+    for row in rows {
+        insert_row(&transaction, cache, row, "INSERT OR REPLACE")?;
+    }
+    transaction.commit().map_err(sql_error)
+}
+
+/// Records a lookup of the given keys.
+pub fn touch(cache: &str, keys: &[String], now: u64) -> Result<(), S7forgeError> {
+    if keys.is_empty() {
+        return Ok(());
+    }
+    let mut connection = open()?;
+    let transaction = connection.transaction().map_err(sql_error)?;
+    for key in keys {
+        transaction
+            .execute(
+                "UPDATE entries SET last_access = ?3 WHERE cache = ?1 AND key = ?2",
+                params![cache, key, now as i64],
+            )
+            .map_err(sql_error)?;
+    }
+    transaction.commit().map_err(sql_error)
+}
+
+/// Removes the given keys of `cache`. Returns how many rows were removed.
+pub fn remove(cache: &str, keys: &[String]) -> Result<usize, S7forgeError> {
+    let Some(mut connection) = open_existing()? else {
+        return Ok(0);
+    };
+    let transaction = connection.transaction().map_err(sql_error)?;
+    let mut removed = 0;
+    for key in keys {
+        removed += transaction
+            .execute(
+                "DELETE FROM entries WHERE cache = ?1 AND key = ?2",
+                params![cache, key],
+            )
+            .map_err(sql_error)?;
+    }
+    transaction.commit().map_err(sql_error)?;
+    Ok(removed)
+}
+
+/// Removes the rows of `cache` belonging to `app_id`. Returns how many rows were removed.
+pub fn remove_app(cache: &str, app_id: u32) -> Result<usize, S7forgeError> {
+    let Some(connection) = open_existing()? else {
+        return Ok(0);
+    };
+    connection
+        .execute(
+            "DELETE FROM entries WHERE cache = ?1 AND app_id = ?2",
+            params![cache, app_id],
+        )
+        .map_err(sql_error)
+}
+
+/// Removes every row of `cache`.
+pub fn clear(cache: &str) -> Result<usize, S7forgeError> {
+    let Some(connection) = open_existing()? else {
+        return Ok(0);
+    };
+    connection
+        .execute("DELETE FROM entries WHERE cache = ?1", params![cache])
+        .map_err(sql_error)
+}
+
+/// Removes rows of `cache` written before `cached_before`.
+pub fn remove_expired(cache: &str, cached_before: u64) -> Result<usize, S7forgeError> {
+    let Some(connection) = open_existing()? else {
+        return Ok(0);
+    };
+    connection
+        .execute(
+            "DELETE FROM entries WHERE cache = ?1 AND cached_at < ?2",
+            params![cache, cached_before as i64],
+        )
+        .map_err(sql_error)
+}
+
+/// Bytes the values of `cache` take in the database, and when the newest row was written.
+pub fn size(cache: &str) -> Result<(u64, Option<u64>), S7forgeError> {
+    let Some(connection) = open_existing()? else {
+        return Ok((0, None));
+    };
+    connection
+        .query_row(
+            "SELECT COALESCE(SUM(LENGTH(value)), 0), MAX(cached_at) FROM entries WHERE cache = ?1",
+            params![cache],
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)? as u64,
+                    row.get::<_, Option<i64>>(1)?
+                        .map(|cached_at| cached_at as u64),
+                ))
+            },
+        )
+        .map_err(sql_error)
+}
+
+/// Drops the least recently used rows of `cache` until it has at most `max_entries` rows
+/// taking at most `max_bytes`. Returns how many were dropped.
+pub fn enforce_limits(
+    cache: &str,
+    max_entries: Option<usize>,
+    max_bytes: Option<u64>,
+) -> Result<usize, S7forgeError> {
+    if max_entries.is_none() && max_bytes.is_none() {
+        return Ok(0);
+    }
+    let mut connection = open()?;
+    let transaction = connection.transaction().map_err(sql_error)?;
+    let (mut entries, mut bytes) = transaction
+        .query_row(
+            "SELECT COUNT(*), COALESCE(SUM(size), 0) FROM entries WHERE cache = ?1",
+            params![cache],
+            |row| Ok((row.get::<_, i64>(0)? as usize, row.get::<_, i64>(1)? as u64)),
+        )
+        .map_err(sql_error)?;
+    let max_entries = max_entries.unwrap_or(usize::MAX);
+    let max_bytes = max_bytes.unwrap_or(u64::MAX);
+    if entries <= max_entries && bytes <= max_bytes {
+        return Ok(0);
+    }
+
+    let candidates: Vec<(String, u64)> = {
+        let mut statement = transaction
+            .prepare("SELECT key, size FROM entries WHERE cache = ?1 ORDER BY last_access, key")
+            .map_err(sql_error)?;
+        statement
+            .query_map(params![cache], |row| {
+                Ok((row.get(0)?, row.get::<_, i64>(1)? as u64))
+            })
+            .and_then(|rows| rows.collect::<Result<_, _>>())
+            .map_err(sql_error)?
+    };
+    let mut evicted = 0;
+    for (key, size) in candidates {
+        if entries <= max_entries && bytes <= max_bytes {
+            break;
+        }
+        transaction
+            .execute(
+                "DELETE FROM entries WHERE cache = ?1 AND key = ?2",
+                params![cache, key],
+            )
+            .map_err(sql_error)?;
+        entries -= 1;
+        bytes = bytes.saturating_sub(size);
+        evicted += 1;
+    }
+    transaction.commit().map_err(sql_error)?;
+    Ok(evicted)
+}
@@ -0,0 +1,107 @@
+//! Reading and writing whole cache files. Writes go to a temporary file next to the cache file
+//! that is then renamed over it, so a crash mid-write leaves the previous file in place instead
+//! of a truncated one.
+//!
+//! Decoded files are also kept in memory for the rest of the process, so the subcommands of a
+//! `combined` run share them instead of decoding the same file again. A kept value is only used
+//! while the file's modification time and size are unchanged, so writes by other processes are
+//! still seen.
+
+use bincode::{Decode, Encode};
+use once_cell::sync::Lazy;
+use rustc_hash::FxHashMap;
+use std::any::Any;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use crate::core::error::S7forgeError;
+use crate::utils::cache_codec;
+
+struct MemoryEntry {
+    modified: Option<SystemTime>,
+    len: u64,
+    value: Box<dyn Any + Send>,
+}
+
+static MEMORY: Lazy<Mutex<FxHashMap<PathBuf, MemoryEntry>>> =
+    Lazy::new(|| Mutex::new(FxHashMap::default()));
+
+fn file_stamp(path: &Path) -> Option<(Option<SystemTime>, u64)> {
+    fs::metadata(path)
+        .ok()
+        .map(|metadata| (metadata.modified().ok(), metadata.len()))
+}
+
+fn remember<T: Clone + Send + 'static>(path: &Path, value: &T) {
+    let Some((modified, len)) = file_stamp(path) else {
+        return;
+    };
+    if let Ok(mut memory) = MEMORY.lock() {
+        memory.insert(
+            path.to_path_buf(),
+            MemoryEntry {
+                modified,
+                len,
+                value: Box::new(value.clone()),
+            },
+        );
+    }
+}
+
+fn recall<T: Clone + 'static>(path: &Path) -> Option<T> {
+    let (modified, len) = file_stamp(path)?;
+    let memory = MEMORY.lock().ok()?;
+    memory
+        .get(path)
+        .filter(|entry| entry.modified == modified && entry.len == len)
+        .and_then(|entry| entry.value.downcast_ref::<T>())
+        .cloned()
+}
+
+/// The decoded cache file, or `None` when there is none. A file that exists but can't be
+/// decoded is corrupt or from an incompatible version; it is removed with a warning so the
+/// next write rebuilds it.
+pub fn read<T: Decode<()> + Clone + Send + 'static>(path: &Path) -> Option<T> {
+    if let Some(value) = recall(path) {
+        return Some(value);
+    }
+    let content = fs::read(path).ok()?;
+    match cache_codec::decode(&content) {
+        Ok(value) => {
+            remember(path, &value);
+            Some(value)
+        }
+        Err(e) => {
+            tracing::warn!("Removing unreadable cache file {}: {}", path.display(), e);
+            let _ = fs::remove_file(path);
+            None
+        }
+    }
+}
+
+pub fn write<T: Encode + Clone + Send + 'static>(
+    path: &Path,
+    value: &T,
+) -> Result<(), S7forgeError> {
+    let encoded = cache_codec::encode(value)?;
+    let file_name = path.file_name().ok_or_else(|| {
+        S7forgeError::cache("Invalid cache file path", path.display().to_string())
+    })?;
+    // The process ID keeps concurrent runs from writing the same temporary file
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    fs::write(&temp_path, encoded)
+        .and_then(|_| fs::rename(&temp_path, path))
+        .map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            S7forgeError::io(format!("Failed to write {}", path.display()), e)
+        })?;
+    remember(path, value);
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Recursively copies `source` into `destination`, which must not exist yet.
+pub fn copy_dir(source: &Path, destination: &Path) -> io::Result<()> {
+    fs::create_dir(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let target = destination.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
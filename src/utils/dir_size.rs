@@ -0,0 +1,18 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Total size of all files under `path`. Symlinks are counted as links, not followed,
+/// so linked content stored elsewhere isn't attributed to this folder.
+pub fn dir_size(path: &Path) -> io::Result<u64> {
+    let metadata = fs::symlink_metadata(path)?;
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
+    }
+
+    let mut total = 0;
+    for entry in fs::read_dir(path)? {
+        total += dir_size(&entry?.path())?;
+    }
+    Ok(total)
+}
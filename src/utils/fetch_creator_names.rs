@@ -2,48 +2,116 @@ use bincode::{Decode, Encode};
 use futures_util::FutureExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
 use steamworks::SteamId;
 
+use crate::commands::cache_inspect::CachedEntry;
+use crate::core::error::S7forgeError;
+use crate::core::settings;
 use crate::core::steam_manager;
+use crate::core::web_api;
+use crate::utils::cache_codec;
+use crate::utils::cache_counters;
+use crate::utils::cache_db::{self, CacheBackend, CacheRow};
+use crate::utils::cache_file;
 use crate::utils::get_cache_dir::get_cache_dir;
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Debug, Clone, Encode, Decode)]
 struct CreatorNameCache {
     names: FxHashMap<u64, String>,
 }
 
+/// Names already in the cache, without contacting Steam.
+pub fn cached_creator_names() -> FxHashMap<u64, String> {
+    load_names(None)
+}
+
+// Only the requested creators are read from SQLite; the file is always read whole
+fn load_names(creator_ids: Option<&[u64]>) -> FxHashMap<u64, String> {
+    if settings::get().cache_backend == CacheBackend::Sqlite {
+        let rows = match creator_ids {
+            Some(creator_ids) => {
+                let keys: Vec<String> = creator_ids.iter().map(|id| id.to_string()).collect();
+                cache_db::get(cache_db::CREATORS, &keys)
+            }
+            None => cache_db::all(cache_db::CREATORS),
+        };
+        return rows
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|row| Some((row.key.parse().ok()?, row.decode::<String>()?.ok()?)))
+            .collect();
+    }
+
+    let Ok(cache_dir) = get_cache_dir() else {
+        return FxHashMap::default();
+    };
+    cache_file::read::<CreatorNameCache>(&cache_dir.join("creator_names_cache.bin"))
+        .map(|cache_entry| cache_entry.names)
+        .unwrap_or_default()
+}
+
+/// Adds names to the cache, replacing cached names of the same creators.
+pub fn store_creator_names(names: FxHashMap<u64, String>) -> Result<(), S7forgeError> {
+    if settings::get().cache_backend == CacheBackend::Sqlite {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let rows = names
+            .iter()
+            .map(|(steam_id, name)| CacheRow::encode(steam_id.to_string(), name, now))
+            .collect::<Result<Vec<_>, _>>()?;
+        return cache_db::put(cache_db::CREATORS, &rows);
+    }
+
+    let mut cached_names = cached_creator_names();
+    cached_names.extend(names);
+
+    cache_file::write(
+        &get_cache_dir()?.join("creator_names_cache.bin"),
+        &CreatorNameCache {
+            names: cached_names,
+        },
+    )
+}
+
+/// Rows for the SQLite cache from a `creator_names_cache.bin` file. Names have no cache time,
+/// so they are dated now.
+pub fn legacy_rows(content: &[u8]) -> Result<Vec<CacheRow>, S7forgeError> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    cache_codec::decode::<CreatorNameCache>(content)?
+        .names
+        .iter()
+        .map(|(steam_id, name)| CacheRow::encode(steam_id.to_string(), name, now))
+        .collect()
+}
+
+#[tracing::instrument(level = "debug", skip_all, fields(creators = creator_ids.len()))]
 pub async fn fetch_creator_names(
     creator_ids: Vec<SteamId>,
     steam_game_id: u32,
-) -> Result<FxHashMap<u64, String>, String> {
+) -> Result<FxHashMap<u64, String>, S7forgeError> {
     if creator_ids.is_empty() {
         return Ok(FxHashMap::default());
     }
 
-    let steam_client = steam_manager::initialize_client(steam_game_id).await?;
-
-    let cache_dir = get_cache_dir()?;
-    fs::create_dir_all(&cache_dir)
-        .map_err(|e| format!("Failed to create cache directory: {:?}", e))?;
-
-    let cache_path = cache_dir.join("creator_names_cache.bin");
-    let bincode_config = bincode::config::standard();
-
-    let mut cached_names: FxHashMap<u64, String> = FxHashMap::default();
-    if cache_path.exists() {
-        if let Ok(cache_content) = fs::read(&cache_path) {
-            if let Ok((cache_entry, _)) =
-                bincode::decode_from_slice::<CreatorNameCache, _>(&cache_content, bincode_config)
-            {
-                cached_names = cache_entry.names;
-            }
-        }
-    }
+    let raw_ids: Vec<u64> = creator_ids.iter().map(|id| id.raw()).collect();
+    let mut cached_names = load_names(Some(&raw_ids));
+    let no_cache = settings::get().no_cache;
     let ids_to_fetch: Vec<SteamId> = creator_ids
         .iter()
-        .filter(|id| !cached_names.contains_key(&id.raw()))
+        .filter(|id| no_cache || !cached_names.contains_key(&id.raw()))
         .cloned()
         .collect();
+    cache_counters::hit(
+        "creator_names_cache.bin",
+        (creator_ids.len() - ids_to_fetch.len()) as u64,
+    );
+    cache_counters::miss("creator_names_cache.bin", ids_to_fetch.len() as u64);
     if ids_to_fetch.is_empty() {
         return Ok(creator_ids
             .into_iter()
@@ -51,6 +119,26 @@ pub async fn fetch_creator_names(
             .collect());
     }
 
+    let Some(steam_client) = steam_manager::initialize_client_or_fallback(steam_game_id).await
+    else {
+        // Without a Steam client names come from the Web API when a key is configured;
+        // they aren't written to the cache so a later client run can still resolve them.
+        let raw_ids: Vec<u64> = ids_to_fetch.iter().map(|id| id.raw()).collect();
+        let fetched_names = tokio::task::spawn_blocking(move || web_api::player_names(&raw_ids))
+            .await
+            .map_err(S7forgeError::Join)??;
+        cached_names.extend(fetched_names);
+
+        return Ok(creator_ids
+            .into_iter()
+            .filter_map(|id| {
+                cached_names
+                    .get(&id.raw())
+                    .map(|name| (id.raw(), name.clone()))
+            })
+            .collect());
+    };
+
     let (creator_tx, mut creator_rx) = tokio::sync::mpsc::channel(32);
 
     let creator_task = tokio::task::spawn_blocking({
@@ -101,9 +189,7 @@ pub async fn fetch_creator_names(
                 steam_manager::run_callbacks(steam_game_id)?;
             }
             task_result = &mut fused_creator_task => {
-                creator_result = Some(
-                    task_result.map_err(|e| format!("Creator task error: {:?}", e))?
-                );
+                creator_result = Some(task_result.map_err(S7forgeError::Join)?);
                 break;
             }
         }
@@ -111,17 +197,12 @@ pub async fn fetch_creator_names(
 
     let fetched_names = creator_result.unwrap();
 
-    cached_names.extend(
-        fetched_names
-            .iter()
-            .map(|(id, name)| (id.raw(), name.clone())),
-    );
-    let cache_struct = CreatorNameCache {
-        names: cached_names.clone(),
-    };
-    let serialized_cache = bincode::encode_to_vec(&cache_struct, bincode_config)
-        .map_err(|e| format!("Failed to serialize creator name cache: {:?}", e))?;
-    let _ = fs::write(&cache_path, serialized_cache);
+    let fetched_names: FxHashMap<u64, String> = fetched_names
+        .into_iter()
+        .map(|(id, name)| (id.raw(), name))
+        .collect();
+    cached_names.extend(fetched_names.clone());
+    let _ = store_creator_names(fetched_names);
     let result = creator_ids
         .into_iter()
         .filter_map(|id| {
@@ -133,3 +214,30 @@ pub async fn fetch_creator_names(
 
     Ok(result)
 }
+
+/// Creator names never expire, so their entries have no lifetime.
+pub fn cached_entries() -> Result<Vec<CachedEntry>, S7forgeError> {
+    if settings::get().cache_backend == CacheBackend::Sqlite {
+        return Ok(cached_creator_names()
+            .into_iter()
+            .map(|(steam_id, name)| {
+                CachedEntry::new(cache_db::DB_FILE, steam_id.to_string(), name.into())
+            })
+            .collect());
+    }
+
+    let cache_path = get_cache_dir()?.join("creator_names_cache.bin");
+    let Ok(cache_content) = fs::read(&cache_path) else {
+        return Ok(Vec::new());
+    };
+    let cache = cache_codec::decode::<CreatorNameCache>(&cache_content)
+        .map_err(|e| S7forgeError::cache(format!("Failed to read {}", cache_path.display()), e))?;
+
+    Ok(cache
+        .names
+        .into_iter()
+        .map(|(steam_id, name)| {
+            CachedEntry::new("creator_names_cache.bin", steam_id.to_string(), name.into())
+        })
+        .collect())
+}
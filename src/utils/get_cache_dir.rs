@@ -1,16 +1,33 @@
 use std::path::PathBuf;
 
-pub fn get_cache_dir() -> Result<PathBuf, String> {
-    let exe_path =
-        std::env::current_exe().map_err(|e| format!("Failed to get executable path: {}", e))?;
-    let exe_dir = exe_path
-        .parent()
-        .ok_or("Failed to get executable directory")?;
-    let cache_dir = exe_dir.join("cache");
+use crate::core::error::S7forgeError;
+use crate::core::settings;
+
+/// `<executable dir>/cache`, unless another directory is configured (`cache.dir` in the config
+/// file or `S7FORGE_CACHE_DIR`).
+pub fn get_cache_dir() -> Result<PathBuf, S7forgeError> {
+    let cache_dir = match &settings::get().cache_dir {
+        Some(cache_dir) => cache_dir.clone(),
+        None => {
+            let exe_path = std::env::current_exe()
+                .map_err(|e| S7forgeError::io("Failed to get executable path", e))?;
+            let exe_dir = exe_path.parent().ok_or_else(|| {
+                S7forgeError::cache(
+                    "Failed to locate the cache directory",
+                    format!("{} has no parent directory", exe_path.display()),
+                )
+            })?;
+            exe_dir.join("cache")
+        }
+    };
 
     if !cache_dir.exists() {
-        std::fs::create_dir_all(&cache_dir)
-            .map_err(|e| format!("Failed to create cache directory: {:?}", e))?;
+        std::fs::create_dir_all(&cache_dir).map_err(|e| {
+            S7forgeError::io(
+                format!("Failed to create cache directory {}", cache_dir.display()),
+                e,
+            )
+        })?;
     }
 
     Ok(cache_dir)
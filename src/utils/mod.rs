@@ -1,3 +1,17 @@
+pub mod bbcode;
+pub mod cache_codec;
+pub mod cache_counters;
+pub mod cache_db;
+pub mod cache_file;
+pub mod civil_date;
+pub mod copy_dir;
+pub mod dir_size;
 pub mod extract_quoted_strings;
 pub mod fetch_creator_names;
 pub mod get_cache_dir;
+pub mod html_to_text;
+pub mod parse_date;
+pub mod parse_duration;
+pub mod parse_size;
+pub mod remove_path;
+pub mod symlink_dir;
@@ -0,0 +1,71 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::utils::parse_duration::parse_duration;
+
+/// Parses `2024-05-01`, `2024-05-01T18:30:00Z` (UTC) or a relative age such as `30d` or `12h`
+/// (meaning that long before now) into a Unix time in milliseconds.
+pub fn parse_date(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    if !value.contains('-') {
+        let age = parse_duration(value)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| format!("System clock error: {}", e))?;
+        return Ok(now.saturating_sub(age).as_millis() as u64);
+    }
+
+    let invalid = || {
+        format!(
+            "Invalid date: {} (expected YYYY-MM-DD or a relative age like 30d)",
+            value
+        )
+    };
+    let (date, time) = match value.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time.trim_end_matches('Z'))),
+        None => (value, None),
+    };
+
+    let mut date_parts = date.split('-').map(|part| part.parse::<i64>());
+    let (Some(Ok(year)), Some(Ok(month)), Some(Ok(day)), None) = (
+        date_parts.next(),
+        date_parts.next(),
+        date_parts.next(),
+        date_parts.next(),
+    ) else {
+        return Err(invalid());
+    };
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(invalid());
+    }
+
+    let mut seconds_of_day = 0;
+    if let Some(time) = time {
+        let parts: Vec<i64> = time
+            .split(':')
+            .map(|part| part.parse().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+        let (hours, minutes, seconds) = match parts.as_slice() {
+            [hours, minutes] => (*hours, *minutes, 0),
+            [hours, minutes, seconds] => (*hours, *minutes, *seconds),
+            _ => return Err(invalid()),
+        };
+        if !(0..24).contains(&hours) || !(0..60).contains(&minutes) || !(0..60).contains(&seconds) {
+            return Err(invalid());
+        }
+        seconds_of_day = hours * 3600 + minutes * 60 + seconds;
+    }
+
+    // Civil date to days since 1970-01-01 (Howard Hinnant's algorithm)
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let seconds = days * 86400 + seconds_of_day;
+    u64::try_from(seconds)
+        .map(|seconds| seconds * 1000)
+        .map_err(|_| invalid())
+}
@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+/// Parses durations like `90`, `30s`, `15m`, `2h` or `1d`; bare numbers are seconds.
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let (number, unit) = match value.find(|ch: char| !ch.is_ascii_digit()) {
+        Some(index) => value.split_at(index),
+        None => (value, "s"),
+    };
+
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("Invalid duration: {}", value))?;
+    let seconds = match unit {
+        "s" => number,
+        "m" => number.saturating_mul(60),
+        "h" => number.saturating_mul(60 * 60),
+        "d" => number.saturating_mul(24 * 60 * 60),
+        _ => {
+            return Err(format!(
+                "Invalid duration unit in {}: use s, m, h or d",
+                value
+            ));
+        }
+    };
+
+    Ok(Duration::from_secs(seconds))
+}
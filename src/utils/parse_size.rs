@@ -0,0 +1,27 @@
+/// Parses byte sizes like `4096`, `512KiB`, `64MiB` or `2GiB`; bare numbers are bytes and
+/// `K`, `M` and `G` are accepted for the binary units.
+pub fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let (number, unit) = match value.find(|ch: char| !ch.is_ascii_digit()) {
+        Some(index) => value.split_at(index),
+        None => (value, "B"),
+    };
+
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("Invalid size: {}", value))?;
+    let multiplier: u64 = match unit.trim() {
+        "B" => 1,
+        "K" | "KiB" => 1024,
+        "M" | "MiB" => 1024 * 1024,
+        "G" | "GiB" => 1024 * 1024 * 1024,
+        _ => {
+            return Err(format!(
+                "Invalid size unit in {}: use B, KiB, MiB or GiB",
+                value
+            ));
+        }
+    };
+
+    Ok(number.saturating_mul(multiplier))
+}
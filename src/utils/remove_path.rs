@@ -0,0 +1,14 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Removes a file, a symlink (without touching its target) or a whole directory tree.
+pub fn remove_path(path: &Path) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+    if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        // Directory symlinks on Windows must be removed as directories
+        fs::remove_file(path).or_else(|_| fs::remove_dir(path))
+    }
+}
@@ -0,0 +1,14 @@
+use std::io;
+use std::path::Path;
+
+pub fn symlink_dir(original: &Path, link: &Path) -> io::Result<()> {
+    #[cfg(windows)]
+    {
+        std::os::windows::fs::symlink_dir(original, link)
+    }
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(original, link)
+    }
+}
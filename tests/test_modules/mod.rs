@@ -1,8 +1,43 @@
 pub mod steam_tests;
+pub mod test_anonymous_mode;
 pub mod test_app_installation_path;
+pub mod test_batch;
+pub mod test_blacklist;
+pub mod test_cache_export;
+pub mod test_cache_stats;
 pub mod test_clear_cache;
 pub mod test_cli_help;
+pub mod test_combined;
+pub mod test_completions;
+pub mod test_config_file;
+pub mod test_deploy;
 pub mod test_discover_tags_help;
+pub mod test_download_backend;
+pub mod test_error_codes;
+pub mod test_installed_items;
+pub mod test_item_changelog;
+pub mod test_item_comments;
+pub mod test_item_id_files;
+pub mod test_item_index;
+pub mod test_item_previews;
+pub mod test_item_stats;
+pub mod test_items_by_creator;
+pub mod test_local_meta;
+pub mod test_logging;
+pub mod test_output_format;
+pub mod test_profiles;
+pub mod test_publish_help;
+pub mod test_remove_item_files;
+pub mod test_required_dlc;
+pub mod test_schema;
+pub mod test_search_cache;
+pub mod test_search_workshop_args;
+pub mod test_self_update;
 pub mod test_steam_library_paths;
+pub mod test_steam_status;
+pub mod test_subscription_snapshot_files;
+pub mod test_vote;
+pub mod test_watch;
+pub mod test_workshop_items_no_client;
 pub mod test_workshop_path_non_steam;
 pub mod utils;
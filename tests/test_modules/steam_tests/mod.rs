@@ -1,10 +1,22 @@
 pub mod test_check_item_download;
+pub mod test_check_updates;
+pub mod test_cleanup_workshop;
 pub mod test_collection_items;
+pub mod test_dependency_graph;
 pub mod test_discover_tags;
+pub mod test_error_codes;
+pub mod test_favorites;
+pub mod test_item_conflicts;
+pub mod test_item_install_path;
+pub mod test_item_state;
+pub mod test_my_published_items;
+pub mod test_orphaned_items;
 pub mod test_search_workshop_comprehensive;
 pub mod test_search_workshop_popular;
 pub mod test_search_workshop_recent;
 pub mod test_search_workshop_relevance;
 pub mod test_subscribe_and_unsubscribe;
 pub mod test_subscribed_items;
+pub mod test_subscription_snapshot;
+pub mod test_unsubscribe_all;
 pub mod test_workshop_items;
@@ -82,3 +82,34 @@ fn test_check_item_download() {
         }
     });
 }
+
+#[test]
+fn test_check_item_download_batch() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let item_id = config.item_id.to_string();
+        let output = run_command(&[
+            "check-item-download",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--item-ids",
+            &item_id,
+        ]);
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+            let info = value
+                .get(&item_id)
+                .expect("Expected an entry keyed by the item ID");
+            assert!(
+                info.get("download_complete")
+                    .is_some_and(|complete| complete.is_boolean()),
+                "Expected 'download_complete' boolean, got: {}",
+                stdout
+            );
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Batch check item download test failed: {}", stderr);
+        }
+    });
+}
@@ -0,0 +1,17 @@
+use crate::test_modules::utils::{TestConfig, assert_json_array, run_command, steam_test_or_skip};
+
+#[test]
+fn test_check_updates() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&["check-updates", "--app-id", &config.app_id.to_string()]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            assert_json_array(&stdout);
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Check updates test failed: {}", stderr);
+        }
+    });
+}
@@ -0,0 +1,35 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+#[test]
+fn test_cleanup_workshop_dry_run() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&[
+            "cleanup-workshop",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--dry-run",
+        ]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+
+            assert_eq!(value.get("dry_run"), Some(&serde_json::Value::Bool(true)));
+            let folders = value
+                .get("folders")
+                .and_then(|folders| folders.as_array())
+                .expect("Missing 'folders' array");
+            assert!(folders.iter().all(|folder| {
+                folder.get("deleted") == Some(&serde_json::Value::Bool(false))
+                    && folder
+                        .get("path")
+                        .and_then(|path| path.as_str())
+                        .is_some_and(|path| std::path::Path::new(path).exists())
+            }));
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Cleanup workshop test failed: {}", stderr);
+        }
+    });
+}
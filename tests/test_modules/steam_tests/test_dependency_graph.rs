@@ -0,0 +1,39 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+#[test]
+fn test_dependency_graph() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&[
+            "dependency-graph",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--item-ids",
+            &config.item_id.to_string(),
+        ]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+
+            let nodes = value
+                .get("nodes")
+                .and_then(|nodes| nodes.as_array())
+                .expect("Missing 'nodes' array");
+            let requested = nodes
+                .iter()
+                .find(|node| node.get("item_id").and_then(|id| id.as_u64()) == Some(config.item_id))
+                .expect("Requested item should be a node");
+            assert_eq!(
+                requested.get("requested"),
+                Some(&serde_json::Value::Bool(true))
+            );
+
+            assert!(value.get("edges").is_some_and(|edges| edges.is_array()));
+            assert!(value.get("cycles").is_some_and(|cycles| cycles.is_array()));
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Dependency graph test failed: {}", stderr);
+        }
+    });
+}
@@ -0,0 +1,50 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+// Steam failures must keep their own code instead of ending up as UNKNOWN
+fn error_code(output: &std::process::Output) -> Option<String> {
+    if output.status.success() {
+        return None;
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let value = assert_valid_json(stderr.lines().last().unwrap_or_default());
+    value["code"].as_str().map(str::to_string)
+}
+
+#[test]
+fn test_unsubscribe_failure_is_steam_api_error() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&[
+            "unsubscribe",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--item-ids",
+            "1",
+        ]);
+
+        if let Some(code) = error_code(&output) {
+            assert!(
+                matches!(code.as_str(), "STEAM_API_ERROR" | "TIMEOUT"),
+                "Got: {}",
+                code
+            );
+        }
+    });
+}
+
+#[test]
+fn test_force_redownload_of_unsubscribed_item_is_not_subscribed() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&[
+            "force-redownload",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--item-id",
+            "1",
+        ]);
+
+        assert_eq!(error_code(&output).as_deref(), Some("NOT_SUBSCRIBED"));
+        assert_eq!(output.status.code(), Some(4));
+    });
+}
@@ -0,0 +1,46 @@
+use crate::test_modules::utils::{TestConfig, assert_json_array, run_command, steam_test_or_skip};
+
+#[test]
+fn test_list_favorites() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&["list-favorites", "--app-id", &config.app_id.to_string()]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            assert_json_array(&stdout);
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("List favorites test failed: {}", stderr);
+        }
+    });
+}
+
+// WARNING: This test actually modifies the account's favorites
+#[test]
+#[ignore] // Ignored by default - run with `cargo test -- --ignored` if you want to test favorites
+fn test_favorite_and_unfavorite() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let app_id = config.app_id.to_string();
+        let item_id = config.item_id.to_string();
+
+        let favorite_output =
+            run_command(&["favorite", "--app-id", &app_id, "--item-ids", &item_id]);
+        assert!(
+            favorite_output.status.success(),
+            "Favorite failed: {}",
+            String::from_utf8_lossy(&favorite_output.stderr)
+        );
+        assert_json_array(&String::from_utf8_lossy(&favorite_output.stdout));
+
+        let unfavorite_output =
+            run_command(&["unfavorite", "--app-id", &app_id, "--item-ids", &item_id]);
+        assert!(
+            unfavorite_output.status.success(),
+            "Unfavorite failed: {}",
+            String::from_utf8_lossy(&unfavorite_output.stderr)
+        );
+        assert_json_array(&String::from_utf8_lossy(&unfavorite_output.stdout));
+    });
+}
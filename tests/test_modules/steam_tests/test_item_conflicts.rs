@@ -0,0 +1,25 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+#[test]
+fn test_item_conflicts() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&["item-conflicts", "--app-id", &config.app_id.to_string()]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+            let conflicts = value.as_array().expect("Expected JSON array");
+            for conflict in conflicts {
+                let item_ids = conflict
+                    .get("item_ids")
+                    .and_then(|ids| ids.as_array())
+                    .expect("Missing 'item_ids' array");
+                assert!(item_ids.len() > 1, "A conflict needs at least two items");
+            }
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Item conflicts test failed: {}", stderr);
+        }
+    });
+}
@@ -0,0 +1,30 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+#[test]
+fn test_item_install_path() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&[
+            "item-install-path",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--item-id",
+            &config.item_id.to_string(),
+        ]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+
+            let path = value
+                .get("path")
+                .and_then(|path| path.as_str())
+                .expect("Missing 'path'");
+            assert!(std::path::Path::new(path).is_absolute());
+            assert!(value.get("size_on_disk").is_some_and(|size| size.is_u64()));
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Item install path test failed: {}", stderr);
+        }
+    });
+}
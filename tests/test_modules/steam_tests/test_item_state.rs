@@ -0,0 +1,42 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+#[test]
+fn test_item_state() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&[
+            "item-state",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--item-ids",
+            &config.item_id.to_string(),
+        ]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+
+            let state = value
+                .get(config.item_id.to_string())
+                .expect("State should be keyed by item ID");
+            for flag in [
+                "subscribed",
+                "installed",
+                "needs_update",
+                "downloading",
+                "download_pending",
+                "legacy",
+            ] {
+                assert!(
+                    state.get(flag).is_some_and(|value| value.is_boolean()),
+                    "Missing '{}' flag",
+                    flag
+                );
+            }
+            assert!(state.get("raw").is_some_and(|raw| raw.is_u64()));
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Item state test failed: {}", stderr);
+        }
+    });
+}
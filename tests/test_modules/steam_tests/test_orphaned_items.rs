@@ -0,0 +1,30 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+#[test]
+fn test_orphaned_items() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&["orphaned-items", "--app-id", &config.app_id.to_string()]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+
+            let folders = value
+                .get("folders")
+                .and_then(|folders| folders.as_array())
+                .expect("Missing 'folders' array");
+            let total: u64 = folders
+                .iter()
+                .filter_map(|folder| folder.get("size_bytes").and_then(|size| size.as_u64()))
+                .sum();
+            assert_eq!(
+                value.get("total_bytes").and_then(|t| t.as_u64()),
+                Some(total)
+            );
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Orphaned items test failed: {}", stderr);
+        }
+    });
+}
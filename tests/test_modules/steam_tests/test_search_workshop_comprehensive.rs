@@ -35,8 +35,8 @@ fn test_search_workshop_all_sort_methods() {
             let stdout = String::from_utf8_lossy(&output.stdout);
             let value = assert_valid_json(&stdout);
             assert!(
-                value.is_array(),
-                "Expected JSON array for sort method: {}",
+                value["items"].is_array(),
+                "Expected items array for sort method: {}",
                 sort_method
             );
 
@@ -82,8 +82,8 @@ fn test_search_workshop_potentially_problematic_sorts() {
                 let stdout = String::from_utf8_lossy(&output.stdout);
                 let value = assert_valid_json(&stdout);
                 assert!(
-                    value.is_array(),
-                    "Expected JSON array for sort method: {}",
+                    value["items"].is_array(),
+                    "Expected items array for sort method: {}",
                     sort_method
                 );
                 println!("✓ Sort method '{}' works correctly", sort_method);
@@ -204,11 +204,11 @@ fn test_search_workshop_negative_cases() {
             let stdout = String::from_utf8_lossy(&output.stdout);
             let value = assert_valid_json(&stdout);
             assert!(
-                value.is_array(),
-                "Should return array even for invalid app ID"
+                value["items"].is_array(),
+                "Should return items even for invalid app ID"
             );
 
-            let items = value.as_array().unwrap();
+            let items = value["items"].as_array().unwrap();
             println!(
                 "✓ Invalid app ID handled gracefully (returned {} items)",
                 items.len()
@@ -279,7 +279,7 @@ fn test_search_workshop_pagination() {
 
         let stdout1 = String::from_utf8_lossy(&output1.stdout);
         let value1 = assert_valid_json(&stdout1);
-        let page1_items = value1.as_array().unwrap();
+        let page1_items = value1["items"].as_array().unwrap();
 
         if page1_items.is_empty() {
             println!("⚠ No items found on first page, skipping pagination test");
@@ -305,7 +305,7 @@ fn test_search_workshop_pagination() {
 
         let stdout2 = String::from_utf8_lossy(&output2.stdout);
         let value2 = assert_valid_json(&stdout2);
-        let page2_items = value2.as_array().unwrap();
+        let page2_items = value2["items"].as_array().unwrap();
 
         if !page2_items.is_empty() {
             let page1_first_id = page1_items[0].get("published_file_id").unwrap();
@@ -343,7 +343,7 @@ fn test_search_workshop_with_tags() {
         if output.status.success() {
             let stdout = String::from_utf8_lossy(&output.stdout);
             let value = assert_valid_json(&stdout);
-            assert!(value.is_array(), "Expected JSON array");
+            assert!(value["items"].is_array(), "Expected items array");
 
             println!("✓ Tag filtering with 'mod' works correctly");
         } else {
@@ -365,7 +365,7 @@ fn test_search_workshop_with_tags() {
         if output.status.success() {
             let stdout = String::from_utf8_lossy(&output.stdout);
             let value = assert_valid_json(&stdout);
-            assert!(value.is_array(), "Expected JSON array");
+            assert!(value["items"].is_array(), "Expected items array");
 
             println!("✓ Multiple tag filtering works correctly");
         } else {
@@ -373,3 +373,123 @@ fn test_search_workshop_with_tags() {
         }
     });
 }
+
+#[test]
+fn test_search_workshop_all_pages() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_search_workshop_command(&[
+            "search-workshop",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--sort-by",
+            "recent",
+            "--all-pages",
+            "--limit",
+            "60",
+        ]);
+
+        assert!(
+            output.status.success(),
+            "All-pages search should succeed but failed with: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let value = assert_valid_json(&stdout);
+        let items = value["items"].as_array().expect("Expected items array");
+        assert!(items.len() <= 60, "Expected at most 60 items");
+
+        let mut ids: Vec<u64> = items
+            .iter()
+            .filter_map(|item| item.get("published_file_id")?.as_u64())
+            .collect();
+        let total = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), total, "Expected deduplicated items");
+
+        println!("✓ Collected {} items across pages", total);
+    });
+}
+
+#[test]
+fn test_search_workshop_match_all_tags() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_search_workshop_command(&[
+            "search-workshop",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--sort-by",
+            "recent",
+            "--tags",
+            "mod,campaign",
+            "--match-all-tags",
+        ]);
+
+        if !output.status.success() {
+            println!("⚠ Tag filtering may not be supported for this app ID");
+            return;
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let value = assert_valid_json(&stdout);
+        let items = value["items"].as_array().expect("Expected items array");
+
+        for item in items {
+            let tags = item["tags"].as_str().unwrap_or_default().to_lowercase();
+            assert!(
+                tags.contains("mod") && tags.contains("campaign"),
+                "Expected both tags on every item, got: {}",
+                tags
+            );
+        }
+
+        println!("✓ {} items carry both tags", items.len());
+    });
+}
+
+#[test]
+fn test_search_workshop_updated_after() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_search_workshop_command(&[
+            "search-workshop",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--sort-by",
+            "recently-updated",
+            "--updated-after",
+            "30d",
+            "--limit",
+            "100",
+        ]);
+
+        assert!(
+            output.status.success(),
+            "Date-filtered search should succeed but failed with: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let value = assert_valid_json(&stdout);
+        let items = value["items"].as_array().expect("Expected items array");
+        let cutoff_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+            - 31 * 24 * 60 * 60 * 1000;
+
+        for item in items {
+            let time_updated = item["time_updated"].as_u64().unwrap_or_default();
+            assert!(
+                time_updated >= cutoff_ms,
+                "Item updated before the cutoff: {}",
+                time_updated
+            );
+        }
+
+        println!("✓ {} items updated in the last 30 days", items.len());
+    });
+}
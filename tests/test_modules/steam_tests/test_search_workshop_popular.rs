@@ -20,9 +20,13 @@ fn test_search_workshop_popular() {
         if output.status.success() {
             let stdout = String::from_utf8_lossy(&output.stdout);
             let value = assert_valid_json(&stdout);
-            assert!(value.is_array(), "Expected JSON array, got: {}", stdout);
+            assert!(
+                value["items"].is_array(),
+                "Expected items array, got: {}",
+                stdout
+            );
 
-            let items_array = value.as_array().unwrap();
+            let items_array = value["items"].as_array().unwrap();
             println!("✓ Found {} popular items (one-week)", items_array.len());
 
             if !items_array.is_empty() {
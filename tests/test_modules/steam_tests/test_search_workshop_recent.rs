@@ -24,9 +24,20 @@ fn test_search_workshop_recent() {
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         let value = assert_valid_json(&stdout);
-        assert!(value.is_array(), "Expected JSON array, got: {}", stdout);
+        assert!(
+            value["items"].is_array(),
+            "Expected items array, got: {}",
+            stdout
+        );
+        assert_eq!(value["page"], 1, "Expected page 1");
+        assert_eq!(value["page_size"], 50, "Expected page_size 50");
+        assert!(
+            value["total_results"].is_number(),
+            "Expected numeric total_results"
+        );
+        assert!(value["has_more"].is_boolean(), "Expected boolean has_more");
 
-        let items_array = value.as_array().unwrap();
+        let items_array = value["items"].as_array().unwrap();
         println!("✓ Found {} recent items", items_array.len());
 
         if !items_array.is_empty() {
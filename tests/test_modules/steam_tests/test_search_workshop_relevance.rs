@@ -26,9 +26,13 @@ fn test_search_workshop_relevance() {
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         let value = assert_valid_json(&stdout);
-        assert!(value.is_array(), "Expected JSON array, got: {}", stdout);
+        assert!(
+            value["items"].is_array(),
+            "Expected items array, got: {}",
+            stdout
+        );
 
-        let items_array = value.as_array().unwrap();
+        let items_array = value["items"].as_array().unwrap();
         println!(
             "✓ Found {} search results for 'test' (relevance)",
             items_array.len()
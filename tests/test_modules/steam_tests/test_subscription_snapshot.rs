@@ -0,0 +1,74 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+#[test]
+fn test_export_subscriptions() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let snapshot_path = std::env::temp_dir().join("s7forge_test_subscriptions.json");
+        let output = run_command(&[
+            "export-subscriptions",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--output",
+            &snapshot_path.to_string_lossy(),
+        ]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+            assert!(
+                value
+                    .get("item_count")
+                    .is_some_and(|count| count.is_number())
+            );
+
+            let snapshot = std::fs::read_to_string(&snapshot_path).expect("Snapshot not written");
+            let snapshot = assert_valid_json(&snapshot);
+            assert_eq!(
+                snapshot.get("version").and_then(|v| v.as_u64()),
+                Some(1),
+                "Unexpected snapshot version"
+            );
+            assert_eq!(
+                snapshot.get("app_id").and_then(|v| v.as_u64()),
+                Some(config.app_id as u64)
+            );
+            assert!(snapshot.get("items").is_some_and(|items| items.is_array()));
+
+            let _ = std::fs::remove_file(&snapshot_path);
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Export subscriptions test failed: {}", stderr);
+        }
+    });
+}
+
+#[test]
+fn test_subscriptions_diff() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&[
+            "subscriptions-diff",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--collection-id",
+            &config.collection_id.to_string(),
+        ]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+            for key in ["missing", "extra", "unchanged"] {
+                assert!(
+                    value.get(key).is_some_and(|ids| ids.is_array()),
+                    "Expected '{}' array, got: {}",
+                    key,
+                    stdout
+                );
+            }
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Subscriptions diff test failed: {}", stderr);
+        }
+    });
+}
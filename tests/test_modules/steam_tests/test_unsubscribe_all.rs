@@ -0,0 +1,35 @@
+use crate::test_modules::utils::{TestConfig, assert_valid_json, run_command, steam_test_or_skip};
+
+#[test]
+fn test_unsubscribe_all_dry_run() {
+    steam_test_or_skip(|| {
+        let config = TestConfig::load();
+        let output = run_command(&[
+            "unsubscribe-all",
+            "--app-id",
+            &config.app_id.to_string(),
+            "--title-contains",
+            "s7forge-nonexistent-title",
+            "--dry-run",
+        ]);
+
+        if output.status.success() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let value = assert_valid_json(&stdout);
+
+            assert_eq!(value.get("dry_run"), Some(&serde_json::Value::Bool(true)));
+            let matched = value
+                .get("matched")
+                .and_then(|matched| matched.as_array())
+                .expect("Missing 'matched' array");
+            assert!(matched.is_empty(), "No title should match: {}", stdout);
+            assert!(
+                value.get("results").is_none(),
+                "Dry run must not unsubscribe anything"
+            );
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            println!("Unsubscribe-all dry run test failed: {}", stderr);
+        }
+    });
+}
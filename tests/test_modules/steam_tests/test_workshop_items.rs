@@ -101,6 +101,26 @@ fn test_workshop_items() {
                 "Expected 'num_downvotes' to be a number"
             );
 
+            let score = first_item.get("score").expect("Missing 'score' field");
+            assert!(
+                score
+                    .as_f64()
+                    .is_some_and(|score| (0.0..=1.0).contains(&score)),
+                "Expected 'score' between 0 and 1, got: {}",
+                score
+            );
+
+            assert!(
+                first_item
+                    .get("banned")
+                    .is_some_and(|banned| banned.is_boolean()),
+                "Expected 'banned' to be a boolean"
+            );
+            assert!(
+                first_item.get("visibility").is_some(),
+                "Missing 'visibility' field"
+            );
+
             let file_size = first_item
                 .get("file_size")
                 .expect("Missing 'file_size' field");
@@ -0,0 +1,46 @@
+use crate::test_modules::utils::{TestConfig, assert_json_array, run_command};
+
+#[test]
+fn test_anonymous_workshop_items() {
+    let config = TestConfig::load();
+    let output = run_command(&[
+        "--anonymous",
+        "workshop-items",
+        "--app-id",
+        &config.app_id.to_string(),
+        "--item-ids",
+        &config.item_id.to_string(),
+    ]);
+
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_json_array(&stdout);
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("\"code\":"));
+    }
+}
+
+#[test]
+fn test_anonymous_rejects_client_commands() {
+    let config = TestConfig::load();
+    let output = run_command(&[
+        "--anonymous",
+        "subscribed-items",
+        "--app-id",
+        &config.app_id.to_string(),
+    ]);
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("anonymous mode"),
+        "Expected anonymous mode error, got: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains("\"code\":\"STEAM_CLIENT_REQUIRED\""),
+        "Got: {}",
+        stderr
+    );
+}
@@ -14,6 +14,6 @@ fn test_app_installation_path() {
         assert_valid_json(&stdout);
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        assert!(stderr.contains("Error:"));
+        assert!(stderr.contains("\"code\":"));
     }
 }
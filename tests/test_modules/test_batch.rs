@@ -0,0 +1,66 @@
+use crate::test_modules::utils::{assert_valid_json, run_command_with_stdin};
+
+#[test]
+fn test_batch_reports_errors_per_line() {
+    let output = run_command_with_stdin(
+        &["batch"],
+        "not json\n\n{\"id\": \"b\", \"command\": \"no-such-command\"}\n",
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<_> = stdout.lines().map(assert_valid_json).collect();
+    assert_eq!(lines.len(), 2, "Got: {}", stdout);
+    assert!(
+        lines[0]["error"]
+            .as_str()
+            .is_some_and(|error| error.starts_with("Invalid JSON")),
+        "Got: {}",
+        lines[0]
+    );
+    assert_eq!(lines[1]["id"], "b");
+    assert!(
+        lines[1]["error"]
+            .as_str()
+            .is_some_and(|error| error.contains("Unknown command: no-such-command")),
+        "Got: {}",
+        lines[1]
+    );
+}
+
+#[test]
+fn test_batch_cannot_nest() {
+    let output = run_command_with_stdin(&["batch"], "{\"command\": \"batch\"}\n");
+    assert!(output.status.success());
+    let value = assert_valid_json(String::from_utf8_lossy(&output.stdout).trim());
+    assert_eq!(value["error"], "batch can't run inside batch");
+}
+
+#[test]
+fn test_batch_rejects_commands_that_exit_or_never_return() {
+    let output = run_command_with_stdin(
+        &["batch"],
+        concat!(
+            "{\"id\": 1, \"command\": \"check-updates\", \"quiet_if_none\": true}\n",
+            "{\"id\": 2, \"command\": \"watch\"}\n",
+            "{\"id\": 3, \"command\": \"steam-library-paths\", \"args\": [\"--help\"]}\n",
+            "{\"id\": 4, \"command\": \"check-updates\", \"args\": [\"--quiet-if-none\"]}\n",
+        ),
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<_> = stdout.lines().map(assert_valid_json).collect();
+    assert_eq!(lines.len(), 4, "Got: {}", stdout);
+    assert_eq!(
+        lines[0]["error"],
+        "--quiet-if-none isn't available in batch"
+    );
+    assert_eq!(lines[1]["error"], "watch isn't available in batch");
+    assert_eq!(lines[2]["error"], "help isn't available in batch");
+    assert_eq!(
+        lines[3]["error"],
+        "--quiet-if-none isn't available in batch"
+    );
+    for line in &lines {
+        assert_eq!(line["code"], "INVALID_ARGUMENTS", "Got: {}", line);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+// A made-up app ID keeps the test away from a real blacklist
+const TEST_APP_ID: &str = "4294968";
+
+#[test]
+fn test_blacklist_add_list_remove() {
+    let output = run_command(&[
+        "blacklist",
+        "add",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+        "--reason",
+        "Crashes on load",
+    ]);
+    assert!(output.status.success());
+
+    let output = run_command(&["blacklist", "list", "--app-id", TEST_APP_ID]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = assert_valid_json(&stdout);
+    assert_eq!(value["123"]["reason"], "Crashes on load");
+
+    let output = run_command(&[
+        "blacklist",
+        "remove",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = assert_valid_json(&stdout);
+    assert!(
+        value.get("123").is_some(),
+        "Removed entry should be reported"
+    );
+}
+
+#[test]
+fn test_blacklist_add_missing_ids() {
+    let output = run_command(&["blacklist", "add", "--app-id", TEST_APP_ID]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --item-ids"),
+        "Expected missing item ids error, got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,92 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+#[test]
+fn test_cache_import_missing_input() {
+    let output = run_command(&["cache-import"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Missing --input"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_cache_import_newer_version() {
+    let path = std::env::temp_dir().join("s7forge_test_cache_export_v99.json");
+    std::fs::write(
+        &path,
+        r#"{"version": 99, "exported_at": 0, "cached_at": 0, "items": []}"#,
+    )
+    .unwrap();
+
+    let output = run_command(&["cache-import", "--input", path.to_str().unwrap()]);
+    let _ = std::fs::remove_file(&path);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Cache export version 99 is newer"),
+        "Got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_cache_import_empty_export() {
+    let path = std::env::temp_dir().join("s7forge_test_cache_export_empty.json");
+    std::fs::write(
+        &path,
+        r#"{"version": 1, "exported_at": 0, "cached_at": 0, "items": []}"#,
+    )
+    .unwrap();
+
+    let output = run_command(&["cache-import", "--input", path.to_str().unwrap()]);
+    let _ = std::fs::remove_file(&path);
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(value.get("added").and_then(|added| added.as_u64()), Some(0));
+}
+
+fn run_with_sqlite_cache(cache_dir: &std::path::Path, args: &[&str]) -> std::process::Output {
+    std::process::Command::new("cargo")
+        .args(["run", "--"])
+        .args(args)
+        .env("S7FORGE_CACHE_DIR", cache_dir)
+        .env("S7FORGE_CACHE_BACKEND", "sqlite")
+        .output()
+        .expect("Failed to execute command")
+}
+
+#[test]
+fn test_cache_import_into_sqlite_backend() {
+    let cache_dir = std::env::temp_dir().join("s7forge_test_sqlite_cache");
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    let path = std::env::temp_dir().join("s7forge_test_cache_export_sqlite.json");
+    std::fs::write(
+        &path,
+        r#"{"version": 1, "exported_at": 0, "cached_at": 0, "items": [], "deleted_items": [123]}"#,
+    )
+    .unwrap();
+
+    let import = run_with_sqlite_cache(
+        &cache_dir,
+        &["cache-import", "--input", path.to_str().unwrap()],
+    );
+    let inspect = run_with_sqlite_cache(&cache_dir, &["cache-inspect", "--what", "items"]);
+    let item_file = cache_dir.join("workshop_items_cache.bin").exists();
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    assert!(
+        import.status.success(),
+        "Got: {}",
+        String::from_utf8_lossy(&import.stderr)
+    );
+    assert!(
+        !item_file,
+        "Expected no item cache file with the SQLite backend"
+    );
+    let value = assert_valid_json(&String::from_utf8_lossy(&inspect.stdout));
+    let entries = value.as_array().expect("Expected a list of entries");
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["cache"], "cache.sqlite");
+    assert_eq!(entries[0]["key"], "123");
+    assert_eq!(entries[0]["expired"], true);
+}
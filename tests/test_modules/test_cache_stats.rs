@@ -0,0 +1,42 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+#[test]
+fn test_cache_stats() {
+    let output = run_command(&["cache-stats"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = assert_valid_json(&stdout);
+
+    assert!(value.get("cache_dir").is_some_and(|dir| dir.is_string()));
+    assert!(value.get("total_bytes").is_some_and(|total| total.is_u64()));
+    let caches = value
+        .get("caches")
+        .and_then(|caches| caches.as_array())
+        .expect("Expected 'caches' array");
+    for cache in caches {
+        assert!(cache.get("file").is_some_and(|file| file.is_string()));
+        assert!(cache.get("entries").is_some_and(|entries| entries.is_u64()));
+    }
+}
+
+#[test]
+fn test_cache_inspect_library() {
+    run_command(&["steam-library-paths"]);
+    let output = run_command(&["cache-inspect", "--what", "library"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = assert_valid_json(&stdout);
+
+    let entries = value.as_array().expect("Expected array of cached entries");
+    for entry in entries {
+        assert_eq!(
+            entry.get("cache").and_then(|cache| cache.as_str()),
+            Some("library_paths_cache.bin")
+        );
+        assert!(
+            entry
+                .get("expired")
+                .is_some_and(|expired| expired.is_boolean())
+        );
+    }
+}
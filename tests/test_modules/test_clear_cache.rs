@@ -64,3 +64,33 @@ fn test_clear_cache() {
         );
     }
 }
+
+#[test]
+fn test_clear_cache_invalid_what() {
+    let output = run_command(&["clear-cache", "--what", "everything"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid cache: everything"),
+        "Expected invalid cache error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_clear_cache_item_ids_need_items() {
+    let output = run_command(&[
+        "clear-cache",
+        "--what",
+        "library",
+        "--item-ids",
+        "3300840848",
+    ]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--item-ids can only be used with --what items or all"),
+        "Expected filter error, got: {}",
+        stderr
+    );
+}
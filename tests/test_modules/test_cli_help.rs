@@ -69,3 +69,109 @@ fn test_cli_help() {
         "Help should mention '--version' option"
     );
 }
+
+#[test]
+fn test_subscribe_help_with_dependencies() {
+    let output = run_command(&["subscribe", "--help"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("--with-dependencies"),
+        "Help should mention '--with-dependencies' option"
+    );
+}
+
+#[test]
+fn test_general_help_lists_progress_events() {
+    let output = run_command(&["--help"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("--progress-events"),
+        "Help should mention the global '--progress-events' option"
+    );
+}
+
+// Indented lines of a help section, up to the blank line that ends it
+fn help_section<'a>(help: &'a str, title: &str) -> Vec<&'a str> {
+    help.lines()
+        .skip_while(|line| *line != format!("{}:", title))
+        .skip(1)
+        .take_while(|line| !line.is_empty())
+        .collect()
+}
+
+// Long option names of a help section's rows, with whether they take a value
+fn help_options(rows: &[&str]) -> Vec<(String, bool)> {
+    rows.iter()
+        .filter_map(|row| {
+            let mut words = row
+                .split_whitespace()
+                .skip_while(|word| !word.starts_with("--"));
+            let name = words.next()?.trim_end_matches(',');
+            let takes_value = words.next().is_some_and(|word| word.starts_with('<'));
+            (name != "--help").then(|| (name.to_string(), takes_value))
+        })
+        .collect()
+}
+
+#[test]
+fn test_every_listed_command_and_option_is_accepted() {
+    // The general help and each command's help are generated from the command table, so
+    // every command and option they list must get past the parser
+    let output = run_command(&["--help"]);
+    assert!(output.status.success());
+    let general_help = String::from_utf8_lossy(&output.stdout).to_string();
+
+    let commands: Vec<&str> = help_section(&general_help, "COMMANDS")
+        .iter()
+        .filter_map(|row| row.split_whitespace().next())
+        .collect();
+    assert!(
+        commands.len() > 50,
+        "Expected the command list, got: {:?}",
+        commands
+    );
+
+    for (option, takes_value) in help_options(&help_section(&general_help, "GLOBAL OPTIONS")) {
+        let mut args = vec![option.as_str()];
+        if takes_value {
+            args.push("1");
+        }
+        args.push("--help");
+        let output = run_command(&args);
+        assert!(
+            output.status.success(),
+            "Global option {} was rejected: {}",
+            option,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    for command in commands {
+        let output = run_command(&[command, "--help"]);
+        assert!(output.status.success(), "{} --help failed", command);
+        let help = String::from_utf8_lossy(&output.stdout).to_string();
+
+        for (option, takes_value) in help_options(&help_section(&help, "OPTIONS")) {
+            // Values may be rejected, so only an unknown option counts as a failure
+            let mut args = vec![command, option.as_str()];
+            if takes_value {
+                args.push("1");
+            }
+            args.push("--help");
+            let output = run_command(&args);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let error = stderr.lines().last().unwrap_or_default();
+            assert!(
+                !error.contains("Unknown option") && !error.contains("invalid option"),
+                "{} doesn't accept {}: {}",
+                command,
+                option,
+                error
+            );
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+#[test]
+fn test_combined_requires_app_id_per_block() {
+    let output = run_command(&["combined", "--workshop-path"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--app-id required for workshop-path in combined"),
+        "Got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_combined_block_app_ids() {
+    let output = run_command(&[
+        "--app-id",
+        "548430",
+        "combined",
+        "--workshop-path",
+        "--workshop-path",
+        "--app-id",
+        "1142710",
+    ]);
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert!(value.get("workshop-path").is_some(), "Got: {}", value);
+    assert!(
+        value.get("workshop-path-1142710").is_some(),
+        "Got: {}",
+        value
+    );
+}
+
+#[test]
+fn test_combined_block_app_id_needs_value() {
+    let output = run_command(&["combined", "--workshop-path", "--app-id"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing value for --app-id"),
+        "Got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_combined_then_invalid_command() {
+    let output = run_command(&[
+        "--app-id",
+        "548430",
+        "combined",
+        "--workshop-path",
+        "--then",
+        "discover-tags",
+    ]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid --then command: discover-tags"),
+        "Got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_combined_then_rejects_item_ids() {
+    let output = run_command(&[
+        "--app-id",
+        "548430",
+        "combined",
+        "--workshop-path",
+        "--then",
+        "workshop-items",
+        "--item-ids",
+        "123",
+    ]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("takes its item IDs from the previous block"),
+        "Got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_combined_then_block_is_keyed() {
+    let output = run_command(&[
+        "--app-id",
+        "548430",
+        "combined",
+        "--workshop-path",
+        "--then",
+        "workshop-items",
+    ]);
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert!(value.get("workshop-items-1").is_some(), "Got: {}", value);
+}
+
+#[test]
+fn test_combined_download_block_requires_item_id() {
+    let output = run_command(&[
+        "--app-id",
+        "548430",
+        "combined",
+        "--subscribe",
+        "--item-ids",
+        "123",
+        "--download-workshop-item",
+        "--backend",
+        "steamcmd",
+    ]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Missing --item-id"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_combined_download_block_validates_backend() {
+    let output = run_command(&[
+        "--app-id",
+        "548430",
+        "combined",
+        "--download-workshop-item",
+        "--item-id",
+        "123",
+        "--backend",
+        "torrent",
+    ]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid backend: torrent"),
+        "Got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,47 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_completions_bash() {
+    let output = run_command(&["completions", "bash"]);
+
+    assert!(output.status.success());
+    let script = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        script.contains("complete -F _s7forge s7forge"),
+        "Got: {}",
+        script
+    );
+    assert!(script.contains("search-workshop)"));
+    assert!(script.contains("--sort-by) COMPREPLY=($(compgen -W \"relevance"));
+}
+
+#[test]
+fn test_completions_cover_every_shell() {
+    for (shell, expected) in [
+        ("zsh", "#compdef s7forge"),
+        (
+            "fish",
+            "complete -c s7forge -n __fish_use_subcommand -a search-workshop",
+        ),
+        (
+            "powershell",
+            "Register-ArgumentCompleter -Native -CommandName s7forge",
+        ),
+    ] {
+        let output = run_command(&["completions", shell]);
+
+        assert!(output.status.success());
+        let script = String::from_utf8_lossy(&output.stdout);
+        assert!(script.contains(expected), "{}: {}", shell, script);
+        assert!(script.contains("item-ids"), "{}: {}", shell, script);
+    }
+}
+
+#[test]
+fn test_completions_invalid_shell() {
+    let output = run_command(&["completions", "tcsh"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid shell: tcsh"), "Got: {}", stderr);
+}
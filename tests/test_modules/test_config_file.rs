@@ -0,0 +1,188 @@
+use crate::test_modules::utils::run_command;
+use std::fs;
+
+#[test]
+fn test_config_file_missing() {
+    let output = run_command(&["--config", "does-not-exist.toml", "steam-library-paths"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Failed to read does-not-exist.toml"),
+        "Expected unreadable config error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_config_file_rejects_unknown_keys() {
+    let path = std::env::temp_dir().join("s7forge_test_unknown_key.toml");
+    fs::write(&path, "app_id = 548430\nthreads = 8\n").unwrap();
+
+    let output = run_command(&["--config", &path.to_string_lossy(), "steam-library-paths"]);
+    let _ = fs::remove_file(&path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid config") && stderr.contains("threads"),
+        "Expected unknown key error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_config_file_values_are_validated() {
+    let path = std::env::temp_dir().join("s7forge_test_invalid_value.toml");
+    fs::write(&path, "dates = \"rfc2822\"\n").unwrap();
+
+    let output = run_command(&["--config", &path.to_string_lossy(), "steam-library-paths"]);
+    let _ = fs::remove_file(&path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid date format"),
+        "Expected invalid date format error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_flags_override_config_file() {
+    let path = std::env::temp_dir().join("s7forge_test_override.toml");
+    fs::write(&path, "dates = \"rfc2822\"\n").unwrap();
+
+    // The flag replaces the invalid value from the file, so parsing succeeds
+    let output = run_command(&[
+        "--config",
+        &path.to_string_lossy(),
+        "--dates",
+        "iso",
+        "steam-library-paths",
+    ]);
+    let _ = fs::remove_file(&path);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("Invalid date format"),
+        "Expected --dates to override the config file, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_environment_overrides_config_file() {
+    let path = std::env::temp_dir().join("s7forge_test_env_override.toml");
+    fs::write(&path, "dates = \"rfc2822\"\n").unwrap();
+
+    let output = std::process::Command::new("cargo")
+        .args(["run", "--", "steam-library-paths"])
+        .env("S7FORGE_CONFIG", &path)
+        .env("S7FORGE_DATES", "relative")
+        .output()
+        .expect("Failed to execute command");
+    let _ = fs::remove_file(&path);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("Invalid date format"),
+        "Expected S7FORGE_DATES to override the config file, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_environment_values_are_validated() {
+    let output = std::process::Command::new("cargo")
+        .args(["run", "--", "steam-library-paths"])
+        .env("S7FORGE_NO_CLIENT", "maybe")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid S7FORGE_NO_CLIENT"),
+        "Expected invalid environment variable error, got: {}",
+        stderr
+    );
+    assert!(stderr.contains("CONFIG_ERROR"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_app_section_deploy_target_is_used() {
+    let path = std::env::temp_dir().join("s7forge_test_app_section.toml");
+    fs::write(&path, "[apps.548430]\ndeploy_target = \"mods\"\n").unwrap();
+
+    let output = run_command(&[
+        "--config",
+        &path.to_string_lossy(),
+        "deploy-items",
+        "--app-id",
+        "548430",
+    ]);
+    let _ = fs::remove_file(&path);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("Missing --target"),
+        "Expected deploy_target from [apps.548430] to be used, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_app_section_requires_numeric_app_id() {
+    let path = std::env::temp_dir().join("s7forge_test_app_section_name.toml");
+    fs::write(&path, "[apps.drg]\ntags = [\"Map\"]\n").unwrap();
+
+    let output = run_command(&["--config", &path.to_string_lossy(), "steam-library-paths"]);
+    let _ = fs::remove_file(&path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid app id in [apps.drg]"),
+        "Expected invalid app section error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_cache_max_size_is_validated() {
+    let path = std::env::temp_dir().join("s7forge_test_cache_max_size.toml");
+    fs::write(
+        &path,
+        "[cache]\nmax_items = 500\nmax_size = \"64 furlongs\"\n",
+    )
+    .unwrap();
+
+    let output = run_command(&["--config", &path.to_string_lossy(), "steam-library-paths"]);
+    let _ = fs::remove_file(&path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid size unit in 64 furlongs"),
+        "Expected invalid size error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_cache_backend_is_validated() {
+    let path = std::env::temp_dir().join("s7forge_test_cache_backend.toml");
+    fs::write(&path, "[cache]\nbackend = \"redis\"\n").unwrap();
+
+    let output = run_command(&["--config", &path.to_string_lossy(), "steam-library-paths"]);
+    let _ = fs::remove_file(&path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid cache backend: redis"),
+        "Expected invalid backend error, got: {}",
+        stderr
+    );
+}
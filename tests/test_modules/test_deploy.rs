@@ -0,0 +1,79 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+#[test]
+fn test_deploy_items_missing_target() {
+    let output = run_command(&["deploy-items", "--app-id", "548430", "--item-ids", "123"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --target"),
+        "Expected missing target error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_undeploy_leaves_unknown_folders_alone() {
+    let target = std::env::temp_dir().join("s7forge_test_undeploy");
+    let foreign = target.join("123");
+    std::fs::create_dir_all(&foreign).expect("Failed to create test folder");
+
+    let output = run_command(&[
+        "undeploy",
+        "--target",
+        target.to_str().unwrap(),
+        "--item-ids",
+        "123",
+    ]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = assert_valid_json(&stdout);
+    assert_eq!(
+        value[0].get("success"),
+        Some(&serde_json::Value::Bool(false))
+    );
+    assert!(
+        foreign.exists(),
+        "Undeploy removed a folder it didn't create"
+    );
+
+    let _ = std::fs::remove_dir_all(&target);
+}
+
+#[test]
+fn test_undeploy_refuses_names_outside_target() {
+    let root = std::env::temp_dir().join("s7forge_test_undeploy_escape");
+    let target = root.join("mods");
+    let outside = root.join("outside");
+    std::fs::create_dir_all(&target).expect("Failed to create test folder");
+    std::fs::create_dir_all(&outside).expect("Failed to create test folder");
+    std::fs::write(
+        target.join(".s7forge-deploy.json"),
+        r#"{"items": [{"item_id": 123, "name": "../outside", "mode": "copy"}]}"#,
+    )
+    .expect("Failed to write deploy manifest");
+
+    let output = run_command(&[
+        "undeploy",
+        "--target",
+        target.to_str().unwrap(),
+        "--item-ids",
+        "123",
+    ]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = assert_valid_json(&stdout);
+    assert_eq!(
+        value[0].get("success"),
+        Some(&serde_json::Value::Bool(false))
+    );
+    assert!(
+        outside.exists(),
+        "Undeploy removed a folder outside the target"
+    );
+
+    let _ = std::fs::remove_dir_all(&root);
+}
@@ -0,0 +1,170 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_download_invalid_backend() {
+    let output = run_command(&[
+        "download-workshop-item",
+        "--app-id",
+        "548430",
+        "--item-id",
+        "123456789",
+        "--backend",
+        "ftp",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid backend"),
+        "Expected invalid backend error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_download_updates_zero_concurrency() {
+    let output = run_command(&[
+        "download-updates",
+        "--app-id",
+        "548430",
+        "--concurrency",
+        "0",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--concurrency must be at least 1"),
+        "Expected concurrency error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_download_workshop_items_missing_ids() {
+    let output = run_command(&["download-workshop-items", "--app-id", "548430"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --item-ids"),
+        "Expected missing item ids error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_download_workshop_item_invalid_retries() {
+    let output = run_command(&[
+        "download-workshop-item",
+        "--app-id",
+        "548430",
+        "--item-id",
+        "123456789",
+        "--retries",
+        "many",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("many"),
+        "Expected retries parse error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_force_redownload_missing_item_id() {
+    let output = run_command(&["force-redownload", "--app-id", "548430"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --item-id"),
+        "Expected missing item id error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_check_updates_invalid_format() {
+    let output = run_command(&["check-updates", "--app-id", "548430", "--format", "atom"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid format"),
+        "Expected invalid format error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_download_previews_requires_target() {
+    let output = run_command(&[
+        "download-previews",
+        "--app-id",
+        "548430",
+        "--item-ids",
+        "123",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --target"),
+        "Expected missing target error, got: {}",
+        stderr
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_steamcmd_backend_reports_progress_and_stderr() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let script_path = std::env::temp_dir().join("s7forge_test_fake_steamcmd.sh");
+    std::fs::write(
+        &script_path,
+        "#!/bin/sh\n\
+         echo 'Update state (0x61) downloading, progress: 50.00 (512 / 1024)'\n\
+         echo 'ERROR! Download item 123456789 failed (Failure).'\n\
+         echo 'Connection to Steam servers lost' >&2\n\
+         exit 1\n",
+    )
+    .expect("Failed to write the fake SteamCMD script");
+    std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))
+        .expect("Failed to make the fake SteamCMD script executable");
+
+    let output = run_command(&[
+        "--progress-events",
+        "download-workshop-item",
+        "--app-id",
+        "548430",
+        "--item-id",
+        "123456789",
+        "--backend",
+        "steamcmd",
+        "--steamcmd-path",
+        script_path.to_str().unwrap(),
+    ]);
+    let _ = std::fs::remove_file(&script_path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(
+            r#""phase":"downloading","item_id":123456789,"bytes_downloaded":512,"bytes_total":1024"#
+        ),
+        "Expected a progress event for the SteamCMD download, got: {}",
+        stderr
+    );
+    let error = stderr.lines().last().unwrap_or_default();
+    assert!(
+        error.contains("Download item 123456789 failed")
+            && error.contains("Connection to Steam servers lost"),
+        "Expected the SteamCMD status and stderr in the error, got: {}",
+        error
+    );
+}
@@ -0,0 +1,50 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+#[test]
+fn test_argument_errors_are_json() {
+    let output = run_command(&["no-such-command"]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let value = assert_valid_json(stderr.lines().last().unwrap_or_default());
+    assert_eq!(value["code"], "INVALID_ARGUMENTS");
+    assert_eq!(value["message"], "Unknown command: no-such-command");
+    assert!(value["context"].is_null(), "Got: {}", value);
+}
+
+#[test]
+fn test_wrapped_errors_carry_cause() {
+    let output = run_command(&["cache-import", "--input", "/nonexistent/s7forge-cache.json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let value = assert_valid_json(stderr.lines().last().unwrap_or_default());
+    assert_eq!(value["code"], "IO_ERROR");
+    assert!(value["context"]["cause"].is_string(), "Got: {}", value);
+}
+
+#[test]
+fn test_invalid_option_values_are_argument_errors() {
+    let output = run_command(&["creator-profile", "--app-id", "294100", "--steam-id", "5"]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let value = assert_valid_json(stderr.lines().last().unwrap_or_default());
+    assert_eq!(value["code"], "INVALID_ARGUMENTS");
+    assert_eq!(value["message"], "Invalid SteamID64: 5");
+}
+
+#[test]
+fn test_plain_messages_have_no_cause() {
+    let path = std::env::temp_dir().join("s7forge_test_error_codes_v99.json");
+    std::fs::write(
+        &path,
+        r#"{"version": 99, "exported_at": 0, "cached_at": 0, "items": []}"#,
+    )
+    .unwrap();
+
+    let output = run_command(&["cache-import", "--input", path.to_str().unwrap()]);
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let value = assert_valid_json(stderr.lines().last().unwrap_or_default());
+    assert_eq!(value["code"], "UNKNOWN");
+    assert!(value["context"].is_null(), "Got: {}", value);
+}
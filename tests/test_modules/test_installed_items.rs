@@ -0,0 +1,57 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+#[test]
+fn test_installed_items() {
+    let output = run_command(&["installed-items", "--app-id", "548430"]);
+
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let value = assert_valid_json(&stdout);
+        let items = value.as_array().expect("Expected JSON array");
+        for item in items {
+            assert!(item.get("item_id").is_some_and(|id| id.is_u64()));
+            assert!(item.get("size_on_disk").is_some_and(|size| size.is_u64()));
+            assert!(item.get("manifest_id").is_some_and(|id| id.is_u64()));
+        }
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("\"code\":"));
+    }
+}
+
+#[test]
+fn test_installed_items_missing_app_id() {
+    let output = run_command(&["installed-items"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --app-id"),
+        "Expected missing app id error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_verify_items() {
+    let output = run_command(&["verify-items", "--app-id", "548430"]);
+
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let value = assert_valid_json(&stdout);
+        let results = value.as_array().expect("Expected JSON array");
+        for result in results {
+            let issues = result
+                .get("issues")
+                .and_then(|issues| issues.as_array())
+                .expect("Missing 'issues' array");
+            assert_eq!(
+                result.get("ok"),
+                Some(&serde_json::Value::Bool(issues.is_empty()))
+            );
+        }
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("\"code\":"));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_item_changelog_requires_item_id() {
+    let output = run_command(&["item-changelog"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --item-id"),
+        "Expected missing item ID error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_item_changelog_rejects_zero_limit() {
+    let output = run_command(&["item-changelog", "--item-id", "123", "--limit", "0"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--limit must be at least 1"),
+        "Expected invalid limit error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_item_changelog_errors_hide_api_key() {
+    let key = "S7FORGETESTKEY0123456789ABCDEF";
+    let output = run_command(&["--web-api-key", key, "item-changelog", "--item-id", "123"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // Earlier lines are cargo echoing the command line
+    let error = stderr.lines().last().unwrap_or_default();
+    assert!(
+        error.contains("Steam Web API request failed"),
+        "Expected request error, got: {}",
+        stderr
+    );
+    assert!(!error.contains(key), "API key leaked: {}", error);
+}
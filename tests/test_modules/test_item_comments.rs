@@ -0,0 +1,27 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_item_comments_requires_item_id() {
+    let output = run_command(&["item-comments", "--page", "2"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --item-id"),
+        "Expected missing item ID error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_item_comments_rejects_page_zero() {
+    let output = run_command(&["item-comments", "--item-id", "123", "--page", "0"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--page must be at least 1"),
+        "Expected invalid page error, got: {}",
+        stderr
+    );
+}
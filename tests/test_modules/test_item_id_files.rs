@@ -0,0 +1,48 @@
+use crate::test_modules::utils::run_command;
+use std::fs;
+
+#[test]
+fn test_item_ids_file_missing() {
+    let output = run_command(&[
+        "workshop-items",
+        "--app-id",
+        "548430",
+        "--item-ids",
+        "@does-not-exist.txt",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Failed to read does-not-exist.txt"),
+        "Expected unreadable file error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_item_ids_file_reports_invalid_line() {
+    let path = std::env::temp_dir().join("s7forge_test_item_ids.txt");
+    fs::write(
+        &path,
+        "# modlist\n123\nhttps://steamcommunity.com/sharedfiles/filedetails/?id=456 # url\nnot-an-id\n",
+    )
+    .unwrap();
+
+    let output = run_command(&[
+        "unsubscribe",
+        "--app-id",
+        "548430",
+        "--item-ids",
+        &format!("@{}", path.display()),
+    ]);
+    let _ = fs::remove_file(&path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid item ID: not-an-id (line 4 of"),
+        "Expected invalid line error, got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,37 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+// A made-up app ID gets its own, empty index
+const TEST_APP_ID: &str = "4294969";
+
+#[test]
+fn test_index_query_empty_index() {
+    let output = run_command(&[
+        "index",
+        "query",
+        "--app-id",
+        TEST_APP_ID,
+        "--tag",
+        "Map",
+        "--min-size",
+        "1024",
+        "--updated-since",
+        "30d",
+    ]);
+
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(value.as_array().map(|items| items.len()), Some(0));
+}
+
+#[test]
+fn test_index_unknown_action() {
+    let output = run_command(&["index", "rebuild", "--app-id", TEST_APP_ID]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unknown index action"),
+        "Expected unknown action error, got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,14 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_item_previews_requires_item_ids() {
+    let output = run_command(&["item-previews"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --item-ids"),
+        "Expected missing item IDs error, got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,52 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_items_by_creator_missing_steam_id() {
+    let output = run_command(&["items-by-creator", "--app-id", "548430"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --steam-id"),
+        "Expected missing steam id error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_items_by_creator_invalid_steam_id() {
+    let output = run_command(&[
+        "items-by-creator",
+        "--app-id",
+        "548430",
+        "--steam-id",
+        "12345",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid SteamID64"),
+        "Expected invalid SteamID64 error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_creator_profile_invalid_steam_id() {
+    let output = run_command(&[
+        "creator-profile",
+        "--app-id",
+        "548430",
+        "--steam-id",
+        "12345",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid SteamID64"),
+        "Expected invalid SteamID64 error, got: {}",
+        stderr
+    );
+}
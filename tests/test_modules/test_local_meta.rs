@@ -0,0 +1,72 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+// A made-up app ID keeps the test away from real local metadata
+const TEST_APP_ID: &str = "4294967";
+
+#[test]
+fn test_item_meta_set_get_remove() {
+    let output = run_command(&[
+        "item-meta",
+        "set",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+        "--note",
+        "Crashes with 456",
+        "--tags",
+        "broken,ui",
+        "--pin",
+    ]);
+    assert!(output.status.success());
+
+    let output = run_command(&[
+        "item-meta",
+        "get",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = assert_valid_json(&stdout);
+    assert_eq!(value["123"]["note"], "Crashes with 456");
+    assert_eq!(value["123"]["tags"], serde_json::json!(["broken", "ui"]));
+    assert_eq!(value["123"]["pinned"], true);
+
+    let output = run_command(&[
+        "item-meta",
+        "remove",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+    ]);
+    assert!(output.status.success());
+
+    let output = run_command(&["item-meta", "list", "--app-id", TEST_APP_ID]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = assert_valid_json(&stdout);
+    assert!(value.get("123").is_none());
+}
+
+#[test]
+fn test_item_meta_set_without_fields() {
+    let output = run_command(&[
+        "item-meta",
+        "set",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Nothing to set"),
+        "Expected nothing to set error, got: {}",
+        stderr
+    );
+}
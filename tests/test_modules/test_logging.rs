@@ -0,0 +1,86 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+// A made-up app ID keeps the test away from a real blacklist
+const TEST_APP_ID: &str = "4294971";
+
+#[test]
+fn test_verbose_logs_to_stderr() {
+    let output = run_command(&["-v", "blacklist", "list", "--app-id", TEST_APP_ID]);
+
+    assert!(output.status.success());
+    assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("DEBUG") && stderr.contains("run{command=blacklist}"),
+        "Got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_quiet_wins_over_verbose() {
+    let output = run_command(&["-q", "-v", "blacklist", "list", "--app-id", TEST_APP_ID]);
+
+    assert!(output.status.success());
+    // stderr also holds cargo's own output, so only look for log lines
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("run{command=blacklist}"),
+        "Got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_quiet_keeps_error_object() {
+    let output = run_command(&["--quiet", "schema", "no-such-command"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let value = assert_valid_json(stderr.lines().last().unwrap_or_default());
+    assert_eq!(value["code"], "INVALID_ARGUMENTS");
+}
+
+#[test]
+fn test_log_file_records_failed_command() {
+    let log_dir = std::env::temp_dir().join("s7forge_test_log_file");
+    let _ = std::fs::remove_dir_all(&log_dir);
+    let output = run_command(&[
+        "--quiet",
+        "--log-file",
+        log_dir.join("s7forge.log").to_str().unwrap(),
+        "schema",
+        "no-such-command",
+    ]);
+    assert_eq!(output.status.code(), Some(2));
+
+    // Rotated daily, so the file name carries the date
+    let mut lines = Vec::new();
+    for entry in std::fs::read_dir(&log_dir).expect("Log directory should be created") {
+        let content = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+        lines.extend(content.lines().map(assert_valid_json));
+    }
+    let _ = std::fs::remove_dir_all(&log_dir);
+
+    assert!(
+        lines.iter().any(|line| line["level"] == "ERROR"
+            && line["fields"]["message"]
+                .as_str()
+                .is_some_and(|message| message.contains("no-such-command"))),
+        "Got: {:?}",
+        lines
+    );
+}
+
+#[test]
+fn test_log_rotation_is_validated() {
+    let output = std::process::Command::new("cargo")
+        .args(["run", "--", "schema"])
+        .env("S7FORGE_LOG_ROTATION", "weekly")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid log rotation"), "Got: {}", stderr);
+}
@@ -0,0 +1,179 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+// A made-up app ID keeps the test away from a real blacklist
+const TEST_APP_ID: &str = "4294970";
+
+#[test]
+fn test_dates_iso_and_relative() {
+    let output = run_command(&[
+        "blacklist",
+        "add",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+    ]);
+    assert!(output.status.success());
+
+    let output = run_command(&[
+        "--dates",
+        "iso",
+        "blacklist",
+        "list",
+        "--app-id",
+        TEST_APP_ID,
+    ]);
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    let added_at = value["123"]["added_at"].as_str().unwrap_or_default();
+    assert!(
+        added_at.len() == 20 && added_at.contains('T') && added_at.ends_with('Z'),
+        "Expected an ISO 8601 timestamp, got: {}",
+        value["123"]["added_at"]
+    );
+
+    let output = run_command(&[
+        "--dates",
+        "relative",
+        "blacklist",
+        "list",
+        "--app-id",
+        TEST_APP_ID,
+    ]);
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    let added_at = value["123"]["added_at"].as_str().unwrap_or_default();
+    assert!(
+        added_at == "just now" || added_at.ends_with(" ago"),
+        "Expected a relative timestamp, got: {}",
+        value["123"]["added_at"]
+    );
+
+    let output = run_command(&[
+        "blacklist",
+        "remove",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+    ]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_dates_invalid_format() {
+    let output = run_command(&["--dates", "rfc2822", "steam-library-paths"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid date format"),
+        "Expected invalid date format error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_sizes_invalid_format() {
+    let output = run_command(&["--sizes", "kb", "steam-library-paths"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid size format"),
+        "Expected invalid size format error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_description_format_invalid() {
+    let output = run_command(&["--description-format", "rtf", "steam-library-paths"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid description format"),
+        "Expected invalid description format error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_envelope_wraps_output() {
+    let output = run_command(&["--envelope", "blacklist", "list", "--app-id", TEST_APP_ID]);
+
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(value["schema_version"], 1);
+    assert_eq!(value["command"], "blacklist");
+    assert!(value["data"].is_object(), "Got: {}", value);
+}
+
+#[test]
+fn test_output_format_invalid() {
+    let output = run_command(&["--output", "yaml", "steam-library-paths"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid output format"),
+        "Expected invalid output format error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_output_table_piped_has_no_progress_bars() {
+    let output = run_command(&[
+        "--output",
+        "table",
+        "blacklist",
+        "list",
+        "--app-id",
+        TEST_APP_ID,
+    ]);
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains('\u{1b}'), "Got: {:?}", stderr);
+}
+
+#[test]
+fn test_output_table_draws_columns() {
+    let output = run_command(&[
+        "blacklist",
+        "add",
+        "--app-id",
+        TEST_APP_ID,
+        "--item-ids",
+        "123",
+    ]);
+    assert!(output.status.success());
+
+    let output = run_command(&[
+        "--output",
+        "table",
+        "blacklist",
+        "list",
+        "--app-id",
+        TEST_APP_ID,
+    ]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let header = lines.next().unwrap_or_default();
+    assert!(
+        header.starts_with("id") && header.contains("added_at"),
+        "Got: {}",
+        stdout
+    );
+    // Dates default to relative in tables
+    assert!(
+        lines.any(|line| line.trim_start().starts_with("123")
+            && (line.contains("ago") || line.contains("just now"))),
+        "Got: {}",
+        stdout
+    );
+}
@@ -0,0 +1,55 @@
+use crate::test_modules::utils::{assert_json_array, run_command};
+
+#[test]
+fn test_profile_list() {
+    let output = run_command(&["profile", "list", "--app-id", "548430"]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_json_array(&stdout);
+}
+
+#[test]
+fn test_profile_unknown_action() {
+    let output = run_command(&["profile", "rename", "--app-id", "548430"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unknown profile action"),
+        "Expected unknown action error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_profile_activate_missing_name() {
+    let output = run_command(&["profile", "activate", "--app-id", "548430"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing profile name"),
+        "Expected missing name error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_profile_activate_nonexistent() {
+    let output = run_command(&[
+        "profile",
+        "activate",
+        "s7forge-test-does-not-exist",
+        "--app-id",
+        "548430",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("does not exist"),
+        "Expected missing profile error, got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,104 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_create_item_help() {
+    let output = run_command(&["create-item", "--help"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("--content"),
+        "Help should mention '--content' option"
+    );
+    assert!(
+        stdout.contains("--visibility"),
+        "Help should mention '--visibility' option"
+    );
+}
+
+#[test]
+fn test_upload_item_invalid_visibility() {
+    let output = run_command(&[
+        "upload-item",
+        "--app-id",
+        "548430",
+        "--item-id",
+        "123456789",
+        "--visibility",
+        "everyone",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid visibility"),
+        "Expected invalid visibility error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_update_item_rejects_content() {
+    let output = run_command(&[
+        "update-item",
+        "--app-id",
+        "548430",
+        "--item-id",
+        "123456789",
+        "--content",
+        ".",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("use upload-item"),
+        "Expected update-item to reject --content, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_set_item_visibility_requires_visibility() {
+    let output = run_command(&[
+        "set-item-visibility",
+        "--app-id",
+        "548430",
+        "--item-id",
+        "123456789",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --visibility"),
+        "Expected missing visibility error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_delete_item_requires_confirm() {
+    let output = run_command(&["delete-item", "--app-id", "548430", "--item-ids", "123"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--confirm"),
+        "Expected delete-item to require --confirm, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_collection_add_requires_collection_id() {
+    let output = run_command(&["collection-add", "--app-id", "548430", "--item-ids", "123"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --collection-id"),
+        "Expected missing collection id error, got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,14 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_remove_item_files_missing_ids() {
+    let output = run_command(&["remove-item-files", "--app-id", "548430"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --item-ids"),
+        "Expected missing item ids error, got: {}",
+        stderr
+    );
+}
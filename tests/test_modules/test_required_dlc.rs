@@ -0,0 +1,32 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_required_dlc_requires_app_id() {
+    let output = run_command(&["required-dlc", "--item-ids", "123"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --app-id"),
+        "Expected missing app ID error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_list_dlc_requires_app_id() {
+    let output = run_command(&["list-dlc"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Missing --app-id"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_list_dlc_needs_client() {
+    let output = run_command(&["--anonymous", "list-dlc", "--app-id", "294100"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("STEAM_CLIENT_REQUIRED"), "Got: {}", stderr);
+}
@@ -0,0 +1,32 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+#[test]
+fn test_schema_lists_commands() {
+    let output = run_command(&["schema"]);
+
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(value["schema_version"], 1);
+    assert!(value["commands"]["workshop-items"].is_object());
+    assert!(value["commands"]["subscribe"].is_object());
+    assert_eq!(value["error"]["title"], "ErrorReport");
+}
+
+#[test]
+fn test_schema_for_one_command() {
+    let output = run_command(&["schema", "vote"]);
+
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(value["title"], "VoteResult");
+    assert_eq!(value["properties"]["item_id"]["type"], "integer");
+}
+
+#[test]
+fn test_schema_unknown_command() {
+    let output = run_command(&["schema", "no-such-command"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("INVALID_ARGUMENTS"), "Got: {}", stderr);
+}
@@ -0,0 +1,35 @@
+use crate::test_modules::utils::{assert_valid_json, run_command};
+
+#[test]
+fn test_search_cache_without_matches() {
+    // Runs without Steam; a title nobody uses matches nothing whatever the cache holds
+    let output = run_command(&[
+        "search-cache",
+        "--app-id",
+        "548430",
+        "--query",
+        "s7forge-no-such-title-0b7f",
+    ]);
+
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(
+        value.as_array().map(|items| items.len()),
+        Some(0),
+        "Expected an empty array, got: {}",
+        value
+    );
+}
+
+#[test]
+fn test_search_cache_requires_app_id() {
+    let output = run_command(&["search-cache", "--query", "tank"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --app-id"),
+        "Expected missing app ID error, got: {}",
+        stderr
+    );
+}
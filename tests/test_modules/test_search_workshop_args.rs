@@ -0,0 +1,183 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_search_workshop_limit_requires_all_pages() {
+    let output = run_command(&["search-workshop", "--app-id", "548430", "--limit", "100"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--limit requires --all-pages"),
+        "Expected --limit error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_search_workshop_creator_rejects_query() {
+    let output = run_command(&[
+        "--no-client",
+        "search-workshop",
+        "--app-id",
+        "548430",
+        "--creator",
+        "76561198000000000",
+        "--query",
+        "tank",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--query cannot be combined with --creator"),
+        "Expected creator/query error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_search_workshop_invalid_date() {
+    let output = run_command(&[
+        "search-workshop",
+        "--app-id",
+        "548430",
+        "--updated-after",
+        "2024-13-01",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid date"),
+        "Expected invalid date error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_search_workshop_invalid_language() {
+    let output = run_command(&[
+        "search-workshop",
+        "--app-id",
+        "548430",
+        "--language",
+        "klingon",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid language") && stderr.contains("schinese"),
+        "Expected invalid language error listing valid values, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_search_workshop_invalid_sort_lists_values() {
+    let output = run_command(&[
+        "search-workshop",
+        "--app-id",
+        "548430",
+        "--sort-by",
+        "newest",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid sort") && stderr.contains("total-playtime"),
+        "Expected invalid sort error listing valid values, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_search_workshop_invalid_kv_tag() {
+    let output = run_command(&[
+        "search-workshop",
+        "--app-id",
+        "548430",
+        "--kv-tag",
+        "version",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("expected key=value"),
+        "Expected invalid --kv-tag error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_search_workshop_cursor_rejects_creator() {
+    let output = run_command(&[
+        "--no-client",
+        "search-workshop",
+        "--app-id",
+        "548430",
+        "--creator",
+        "76561198000000000",
+        "--cursor",
+        "*",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--cursor cannot be combined with --creator"),
+        "Expected creator/cursor error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_search_workshop_invalid_period_lists_values() {
+    let output = run_command(&[
+        "--no-client",
+        "search-workshop",
+        "--app-id",
+        "548430",
+        "--sort-by",
+        "popular",
+        "--period",
+        "one-yaer",
+    ]);
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let error = stderr.lines().last().unwrap_or_default();
+    assert!(
+        error.contains("INVALID_ARGUMENTS")
+            && error.contains("Invalid period: one-yaer")
+            && error.contains("three-months"),
+        "Expected invalid period error listing valid values, got: {}",
+        error
+    );
+}
+
+#[test]
+fn test_search_workshop_creator_rejects_unsupported_sort() {
+    let output = run_command(&[
+        "--no-client",
+        "search-workshop",
+        "--app-id",
+        "548430",
+        "--creator",
+        "76561198000000000",
+        "--sort-by",
+        "top-rated",
+    ]);
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let error = stderr.lines().last().unwrap_or_default();
+    assert!(
+        error.contains("--sort-by top-rated cannot be combined with --creator")
+            && error.contains("most-subscribed"),
+        "Expected creator/sort error listing the supported sorts, got: {}",
+        error
+    );
+}
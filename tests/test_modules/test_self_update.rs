@@ -0,0 +1,24 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_self_update_help() {
+    let output = run_command(&["self-update", "--help"]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--check"), "Got: {}", stdout);
+    assert!(stdout.contains("SHA256SUMS"), "Got: {}", stdout);
+}
+
+#[test]
+fn test_self_update_takes_no_version() {
+    let output = run_command(&["self-update", "0.2.0"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unexpected value: 0.2.0"),
+        "Got: {}",
+        stderr
+    );
+}
@@ -9,6 +9,101 @@ fn test_steam_library_paths() {
         assert_valid_json(&stdout);
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        assert!(stderr.contains("Error:"));
+        assert!(stderr.contains("\"code\":"));
+    }
+}
+
+#[test]
+fn test_steam_library_paths_no_cache() {
+    let cached = run_command(&["steam-library-paths"]);
+    let fresh = run_command(&["--no-cache", "steam-library-paths"]);
+
+    assert_eq!(cached.status.success(), fresh.status.success());
+    if fresh.status.success() {
+        assert_eq!(
+            assert_valid_json(&String::from_utf8_lossy(&cached.stdout)),
+            assert_valid_json(&String::from_utf8_lossy(&fresh.stdout))
+        );
+    }
+}
+
+fn run_with_cache_dir(cache_dir: &std::path::Path) -> std::process::Output {
+    std::process::Command::new("cargo")
+        .args(["run", "--", "steam-library-paths"])
+        .env("S7FORGE_CACHE_DIR", cache_dir)
+        .output()
+        .expect("Failed to execute command")
+}
+
+#[test]
+fn test_steam_library_paths_cache_is_compressed() {
+    let cache_dir = std::env::temp_dir().join("s7forge_test_compressed_cache");
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    let output = run_with_cache_dir(&cache_dir);
+    let cache_file = std::fs::read(cache_dir.join("library_paths_cache.bin"));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    if output.status.success() {
+        let content = cache_file.expect("Expected library paths to be cached");
+        assert!(
+            content.starts_with(&[0x28, 0xB5, 0x2F, 0xFD]),
+            "Expected a zstd frame"
+        );
+    }
+}
+
+#[test]
+fn test_steam_library_paths_reads_uncompressed_cache() {
+    let cache_dir = std::env::temp_dir().join("s7forge_test_uncompressed_cache");
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    std::fs::create_dir_all(&cache_dir).unwrap();
+
+    // Plain bincode as written by earlier versions: one path, then a varint u32 timestamp
+    let path = b"/legacy/library";
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as u32;
+    let mut content = vec![1, path.len() as u8];
+    content.extend_from_slice(path);
+    content.push(252);
+    content.extend_from_slice(&now.to_le_bytes());
+    std::fs::write(cache_dir.join("library_paths_cache.bin"), content).unwrap();
+
+    let output = run_with_cache_dir(&cache_dir);
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    assert!(output.status.success());
+    let value = assert_valid_json(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(value, serde_json::json!(["/legacy/library"]));
+}
+
+#[test]
+fn test_steam_library_paths_removes_corrupt_cache() {
+    let cache_dir = std::env::temp_dir().join("s7forge_test_corrupt_cache");
+    let _ = std::fs::remove_dir_all(&cache_dir);
+    std::fs::create_dir_all(&cache_dir).unwrap();
+
+    // A zstd frame cut off mid-write
+    std::fs::write(
+        cache_dir.join("library_paths_cache.bin"),
+        [0x28, 0xB5, 0x2F, 0xFD, 0x24, 0x11],
+    )
+    .unwrap();
+
+    let output = run_with_cache_dir(&cache_dir);
+    let rebuilt = std::fs::read(cache_dir.join("library_paths_cache.bin"));
+    let _ = std::fs::remove_dir_all(&cache_dir);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Removing unreadable cache file"),
+        "Expected a corruption warning, got: {}",
+        stderr
+    );
+    if output.status.success() {
+        let content = rebuilt.expect("Expected the cache to be rebuilt");
+        assert!(content.starts_with(&[0x28, 0xB5, 0x2F, 0xFD]));
     }
 }
@@ -0,0 +1,65 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_steam_status_help() {
+    let output = run_command(&["steam-status", "--help"]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--launch"), "Got: {}", stdout);
+    assert!(stdout.contains("offline_mode"), "Got: {}", stdout);
+}
+
+#[test]
+fn test_steam_status_needs_client() {
+    let output = run_command(&["--anonymous", "steam-status"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("STEAM_CLIENT_REQUIRED"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_restart_steam_help() {
+    let output = run_command(&["restart-steam", "--help"]);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("-shutdown"), "Got: {}", stdout);
+}
+
+#[test]
+fn test_restart_steam_needs_client() {
+    let output = run_command(&["--anonymous", "restart-steam"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("STEAM_CLIENT_REQUIRED"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_account_info_needs_client() {
+    let output = run_command(&["--anonymous", "account-info"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("STEAM_CLIENT_REQUIRED"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_check_ownership_requires_app_id() {
+    let output = run_command(&["check-ownership"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Missing --app-id"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_check_ownership_needs_client() {
+    let output = run_command(&["--anonymous", "check-ownership", "--app-id", "294100"]);
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("STEAM_CLIENT_REQUIRED"), "Got: {}", stderr);
+}
@@ -0,0 +1,75 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_import_subscriptions_missing_file() {
+    let output = run_command(&[
+        "import-subscriptions",
+        "--app-id",
+        "548430",
+        "--input",
+        "does-not-exist/subscriptions.json",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Failed to read snapshot"),
+        "Expected snapshot read error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_import_subscriptions_app_mismatch() {
+    let snapshot_path = std::env::temp_dir().join("s7forge_test_snapshot_mismatch.json");
+    std::fs::write(
+        &snapshot_path,
+        r#"{"version":1,"app_id":1,"exported_at":0,"items":[]}"#,
+    )
+    .expect("Failed to write test snapshot");
+
+    let output = run_command(&[
+        "import-subscriptions",
+        "--app-id",
+        "548430",
+        "--input",
+        &snapshot_path.to_string_lossy(),
+    ]);
+    let _ = std::fs::remove_file(&snapshot_path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("exported for app 1"),
+        "Expected app mismatch error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_sync_rejects_newer_snapshot_version() {
+    let snapshot_path = std::env::temp_dir().join("s7forge_test_snapshot_version.json");
+    std::fs::write(
+        &snapshot_path,
+        r#"{"version":99,"app_id":548430,"exported_at":0,"items":[]}"#,
+    )
+    .expect("Failed to write test snapshot");
+
+    let output = run_command(&[
+        "sync",
+        "--app-id",
+        "548430",
+        "--input",
+        &snapshot_path.to_string_lossy(),
+        "--dry-run",
+    ]);
+    let _ = std::fs::remove_file(&snapshot_path);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("newer than supported"),
+        "Expected snapshot version error, got: {}",
+        stderr
+    );
+}
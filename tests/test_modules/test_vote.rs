@@ -0,0 +1,48 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_vote_requires_direction() {
+    let output = run_command(&["vote", "--app-id", "548430", "--item-id", "123456789"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --up or --down"),
+        "Expected missing vote direction error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_vote_rejects_up_and_down() {
+    let output = run_command(&[
+        "vote",
+        "--app-id",
+        "548430",
+        "--item-id",
+        "123456789",
+        "--up",
+        "--down",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("only one of --up or --down"),
+        "Expected conflicting vote error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_get_vote_requires_item_ids() {
+    let output = run_command(&["get-vote", "--app-id", "548430"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Missing --item-ids"),
+        "Expected missing item ids error, got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,48 @@
+use crate::test_modules::utils::run_command;
+
+#[test]
+fn test_watch_invalid_interval() {
+    let output = run_command(&["watch", "--app-id", "548430", "--interval", "15x"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid duration unit"),
+        "Expected invalid interval error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_watch_zero_interval() {
+    let output = run_command(&["watch", "--app-id", "548430", "--interval", "0s"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--interval must be greater than zero"),
+        "Expected zero interval error, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_watch_invalid_webhook_format() {
+    let output = run_command(&[
+        "watch",
+        "--app-id",
+        "548430",
+        "--webhook",
+        "http://localhost/hook",
+        "--webhook-format",
+        "slack",
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid webhook format"),
+        "Expected invalid webhook format error, got: {}",
+        stderr
+    );
+}
@@ -0,0 +1,22 @@
+use crate::test_modules::utils::{TestConfig, assert_json_array, run_command};
+
+#[test]
+fn test_workshop_items_no_client() {
+    let config = TestConfig::load();
+    let output = run_command(&[
+        "--no-client",
+        "workshop-items",
+        "--app-id",
+        &config.app_id.to_string(),
+        "--item-ids",
+        &config.item_id.to_string(),
+    ]);
+
+    if output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_json_array(&stdout);
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("\"code\":"));
+    }
+}
@@ -10,6 +10,6 @@ fn test_workshop_path_non_steam() {
         assert_valid_json(&stdout);
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        assert!(stderr.contains("Error:"));
+        assert!(stderr.contains("\"code\":"));
     }
 }
@@ -42,6 +42,27 @@ pub fn run_command(args: &[&str]) -> std::process::Output {
         .expect("Failed to execute command")
 }
 
+pub fn run_command_with_stdin(args: &[&str], stdin: &str) -> std::process::Output {
+    use std::io::Write;
+
+    let mut child = Command::new("cargo")
+        .arg("run")
+        .arg("--")
+        .args(args)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+    child
+        .stdin
+        .take()
+        .expect("Failed to open stdin")
+        .write_all(stdin.as_bytes())
+        .expect("Failed to write stdin");
+    child.wait_with_output().expect("Failed to execute command")
+}
+
 pub fn run_search_workshop_command(args: &[&str]) -> std::process::Output {
     let clear_cache_output = run_command(&["clear-cache"]);
     if !clear_cache_output.status.success() {